@@ -2146,14 +2146,7 @@ mod xyk {
 			));
 
 			//For populating oracle
-			assert_ok!(XYK::sell(
-				RuntimeOrigin::signed(BOB.into()),
-				HDX,
-				DAI,
-				100 * UNITS,
-				0,
-				false
-			));
+			assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB.into()), HDX, DAI, 100 * UNITS, 0));
 
 			let alice_init_hdx_balance = 5000 * UNITS;
 			assert_ok!(Balances::set_balance(
@@ -2202,14 +2195,7 @@ mod xyk {
 				HDX,
 				200 * UNITS as i128,
 			));
-			assert_ok!(XYK::sell(
-				RuntimeOrigin::signed(BOB.into()),
-				HDX,
-				DAI,
-				100 * UNITS,
-				0,
-				false
-			));
+			assert_ok!(XYK::sell(RuntimeOrigin::signed(BOB.into()), HDX, DAI, 100 * UNITS, 0));
 
 			let alice_init_hdx_balance = 5000 * UNITS;
 			assert_ok!(Balances::set_balance(
@@ -2300,8 +2286,7 @@ mod all_pools {
 				stable_asset_1,
 				DAI,
 				100 * UNITS,
-				0,
-				false
+				0
 			));
 
 			set_relaychain_block_number(10);
@@ -2894,8 +2879,7 @@ mod with_onchain_route {
 				DAI,
 				DOT,
 				10000000 * UNITS,
-				u128::MIN,
-				false
+				u128::MIN
 			));
 
 			set_relaychain_block_number(10);
@@ -65,3 +65,46 @@ fn remove_nondustable_account_should_work_with_dust_removal_whitelist() {
 		assert!(!hydradx_runtime::DustRemovalWhitelist::contains(&ALICE.into()));
 	});
 }
+
+#[test]
+fn long_term_orders_account_should_be_in_dust_removal_whitelist() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		assert_ok!(hydradx_runtime::Balances::set_balance(
+			RuntimeOrigin::root(),
+			BOB.into(),
+			5000 * UNITS,
+			0,
+		));
+		assert_ok!(hydradx_runtime::Tokens::set_balance(
+			RuntimeOrigin::root(),
+			BOB.into(),
+			DAI,
+			5000 * UNITS,
+			0,
+		));
+		assert_ok!(hydradx_runtime::XYK::create_pool(
+			RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			1000 * UNITS,
+			DAI,
+			2000 * UNITS,
+		));
+
+		let account = hydradx_runtime::XYK::long_term_orders_account();
+		assert!(hydradx_runtime::DustRemovalWhitelist::contains(&account));
+
+		//Act
+		assert_ok!(hydradx_runtime::XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			DAI,
+			100 * UNITS,
+			10,
+		));
+
+		//Assert
+		assert!(hydradx_runtime::DustRemovalWhitelist::contains(&account));
+	});
+}
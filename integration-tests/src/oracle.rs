@@ -1,20 +1,25 @@
 #![cfg(test)]
 
 use crate::polkadot_test_net::*;
+use crate::router::init_stableswap;
 
 use frame_support::{
 	assert_ok,
-	sp_runtime::{FixedU128, Permill},
+	sp_runtime::{helpers_128bit::multiply_by_rational_with_rounding, traits::Zero, FixedU128, Permill, Rounding},
 	traits::{tokens::fungibles::Mutate, OnFinalize, OnInitialize},
 };
-use hydradx_runtime::{EmaOracle, RuntimeOrigin};
+use hydradx_adapters::OraclePriceProvider;
+use hydradx_runtime::{EmaOracle, RuntimeOrigin, Stableswap, XYK};
 use hydradx_traits::{
-	AggregatedPriceOracle,
+	router::{PoolType, Trade},
+	AggregatedPriceOracle, OnTradeHandler,
 	OraclePeriod::{self, *},
+	PriceOracle,
 };
-use pallet_ema_oracle::OracleError;
+use orml_traits::MultiCurrency;
+use pallet_ema_oracle::{ordered_pair, OnActivityHandler, OracleError};
 use polkadot_primitives::v2::BlockNumber;
-use primitives::constants::chain::OMNIPOOL_SOURCE;
+use primitives::constants::chain::{OMNIPOOL_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use xcm_emulator::TestExt;
 
 pub fn hydradx_run_to_block(to: BlockNumber) {
@@ -137,3 +142,172 @@ fn omnipool_hub_asset_trades_are_ingested_into_oracle() {
 		}
 	});
 }
+
+#[test]
+fn xyk_share_token_price_is_ingested_into_oracle_when_liquidity_changes() {
+	TestNet::reset();
+
+	let asset_a = 1;
+	let asset_b = 2;
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			100 * UNITS,
+			asset_b,
+			200 * UNITS,
+		));
+
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			asset_b,
+			50 * UNITS,
+			200 * UNITS,
+		));
+
+		let share_token = XYK::share_token(XYK::pair_account_from_assets(asset_a, asset_b));
+
+		// act
+		hydradx_run_to_block(3);
+
+		// assert
+		// share is backed by both pool assets, so it is worth twice the reserve of each asset per share
+		let expected_a = ((300 * UNITS, 150 * UNITS).into(), 0);
+		// amount of asset B required to add liquidity is rounded up
+		let expected_b = ((600 * UNITS + 2, 150 * UNITS).into(), 0);
+		for supported_period in SUPPORTED_PERIODS {
+			assert_eq!(
+				EmaOracle::get_price(asset_a, share_token, *supported_period, XYK_SOURCE),
+				Ok(expected_a)
+			);
+			assert_eq!(
+				EmaOracle::get_price(asset_b, share_token, *supported_period, XYK_SOURCE),
+				Ok(expected_b)
+			);
+		}
+
+		let route = [Trade {
+			pool: PoolType::XYK,
+			asset_in: share_token,
+			asset_out: asset_b,
+		}];
+		let price = OraclePriceProvider::<AssetId, EmaOracle, hydradx_runtime::LRNA>::price(&route, Short)
+			.map(|price| (price.n, price.d));
+		assert_eq!(price, Some((150 * UNITS, 600 * UNITS + 2)));
+	});
+}
+
+#[test]
+fn xyk_liquidity_can_be_added_when_share_token_price_cannot_be_ingested_into_oracle() {
+	TestNet::reset();
+
+	let asset_a = 1;
+	let asset_b = 2;
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			100 * UNITS,
+			asset_b,
+			200 * UNITS,
+		));
+
+		hydradx_run_to_block(3);
+
+		// pool assets entry is in the accumulator, there is no room for share token entries
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			asset_b,
+			UNITS,
+			0
+		));
+		let mut asset = 1_000;
+		while OnActivityHandler::<hydradx_runtime::Runtime>::on_trade(
+			XYK_SOURCE,
+			asset,
+			asset + 1,
+			UNITS,
+			UNITS,
+			UNITS,
+			UNITS,
+			(1, 1).into(),
+		)
+		.is_ok()
+		{
+			asset += 2;
+		}
+
+		// act
+		assert_ok!(XYK::add_liquidity(
+			RuntimeOrigin::signed(ALICE.into()),
+			asset_a,
+			asset_b,
+			50 * UNITS,
+			200 * UNITS,
+		));
+
+		// assert
+		hydradx_run_to_block(4);
+
+		// share token price is not updated by the liquidity change
+		let share_token = XYK::share_token(XYK::pair_account_from_assets(asset_a, asset_b));
+		assert_eq!(
+			EmaOracle::get_price(asset_a, share_token, LastBlock, XYK_SOURCE).map(|(price, _)| price),
+			Ok((200 * UNITS, 100 * UNITS).into())
+		);
+	});
+}
+
+#[test]
+fn stableswap_trades_are_ingested_into_oracle_with_share_amounts() {
+	TestNet::reset();
+
+	Hydra::execute_with(|| {
+		// arrange
+		hydradx_run_to_block(2);
+
+		let (pool_id, asset_in, asset_out) = init_stableswap().unwrap();
+
+		hydradx_run_to_block(3);
+
+		let amount_in = 10_000_000_000_000u128;
+		let asset_out_balance = Currencies::free_balance(asset_out, &AccountId::from(CHARLIE));
+
+		// act
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(CHARLIE.into()),
+			pool_id,
+			asset_in,
+			asset_out,
+			amount_in,
+			0,
+		));
+		let amount_out = Currencies::free_balance(asset_out, &AccountId::from(CHARLIE)) - asset_out_balance;
+
+		hydradx_run_to_block(4);
+
+		// assert
+		for (asset_id, amount) in [(asset_in, amount_in), (asset_out, amount_out)] {
+			let (entry, _) = EmaOracle::oracle((STABLESWAP_SOURCE, ordered_pair(asset_id, pool_id), LastBlock))
+				.expect("oracle entry should exist");
+			assert_eq!(ordered_pair(asset_id, pool_id), (asset_id, pool_id));
+			assert_eq!(entry.volume.a_in, amount);
+
+			// share side of the trade is the traded amount valued in shares
+			let share_amount =
+				multiply_by_rational_with_rounding(amount, entry.price.d, entry.price.n, Rounding::Down).unwrap();
+			assert!(!share_amount.is_zero());
+			assert_eq!(entry.volume.b_out, share_amount);
+		}
+	});
+}
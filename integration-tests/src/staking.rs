@@ -5,18 +5,21 @@ use frame_support::traits::{LockIdentifier, WithdrawReasons};
 use frame_support::{
 	assert_noop, assert_ok,
 	dispatch::DispatchResult,
+	storage::{with_transaction, TransactionOutcome},
 	traits::{Bounded, OnInitialize, StorePreimage},
 };
 use frame_system::RawOrigin;
 use hydradx_runtime::{
-	Balances, BlockNumber, Currencies, Democracy, Omnipool, Preimage, Scheduler, Staking, System, Tokens, Vesting,
+	Balances, BlockNumber, Currencies, Democracy, FeeDiscountMinStake, Omnipool, Preimage, Scheduler, Staking,
+	StakingTradeFeeDiscount, System, Tokens, TradeFeeDiscount, Vesting, XYK,
 };
+use hydradx_traits::FeeDiscountProvider;
 use orml_traits::currency::MultiCurrency;
 use orml_vesting::VestingSchedule;
 use pallet_democracy::{AccountVote, Conviction, ReferendumIndex, Vote};
 use primitives::constants::time::DAYS;
 use primitives::AccountId;
-use sp_runtime::AccountId32;
+use sp_runtime::{AccountId32, Permill};
 use xcm_emulator::TestExt;
 
 type CallOf<T> = <T as frame_system::Config>::RuntimeCall;
@@ -1311,3 +1314,81 @@ fn assert_lock(who: &AccountId, amount: Balance, lock_id: LockIdentifier) {
 		);
 	}
 }
+
+#[test]
+fn stakers_should_get_trade_fee_discount() {
+	TestNet::reset();
+	Hydra::execute_with(|| {
+		use sp_core::Get;
+
+		System::set_block_number(0);
+		init_omnipool();
+		assert_ok!(Staking::initialize_staking(RawOrigin::Root.into()));
+
+		let staking_account = pallet_staking::Pallet::<hydradx_runtime::Runtime>::pot_account_id();
+		assert_ok!(Currencies::update_balance(
+			RawOrigin::Root.into(),
+			staking_account,
+			HDX,
+			(10_000 * UNITS) as i128,
+		));
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			ALICE.into(),
+			1_000_000 * UNITS,
+			0,
+		));
+		assert_ok!(Balances::set_balance(
+			RawOrigin::Root.into(),
+			BOB.into(),
+			5_000 * UNITS,
+			0,
+		));
+		assert_ok!(XYK::create_pool(
+			hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+			HDX,
+			1_000 * UNITS,
+			DAI,
+			2_000 * UNITS,
+		));
+
+		let alice = AccountId::from(ALICE);
+		assert_eq!(TradeFeeDiscount::discount(&alice, HDX, DAI), Permill::zero());
+
+		assert_ok!(Staking::stake(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			FeeDiscountMinStake::get()
+		));
+
+		assert_eq!(
+			TradeFeeDiscount::discount(&alice, HDX, DAI),
+			StakingTradeFeeDiscount::get()
+		);
+
+		// same trade of an account without stake is reverted to keep the pool state
+		let received_without_discount = with_transaction(|| {
+			let balance = Currencies::free_balance(DAI, &AccountId::from(BOB));
+			let result = XYK::sell(
+				hydradx_runtime::RuntimeOrigin::signed(BOB.into()),
+				HDX,
+				DAI,
+				10 * UNITS,
+				0,
+			)
+			.map(|_| Currencies::free_balance(DAI, &AccountId::from(BOB)) - balance);
+			TransactionOutcome::Rollback(result)
+		})
+		.unwrap();
+
+		let balance = Currencies::free_balance(DAI, &alice);
+		assert_ok!(XYK::sell(
+			hydradx_runtime::RuntimeOrigin::signed(ALICE.into()),
+			HDX,
+			DAI,
+			10 * UNITS,
+			0,
+		));
+
+		assert!(Currencies::free_balance(DAI, &alice) - balance > received_without_discount);
+	});
+}
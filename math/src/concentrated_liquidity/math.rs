@@ -0,0 +1,522 @@
+use crate::concentrated_liquidity::types::{Liquidity, SqrtPrice, SwapStep, Tick};
+use crate::types::{Balance, Price};
+use crate::{
+	ensure, to_balance, to_u256,
+	MathError::{self, DivisionByZero, Overflow},
+};
+use core::convert::TryFrom;
+use num_traits::Zero;
+use primitive_types::{U256, U512};
+use sp_arithmetic::{FixedPointNumber, Permill};
+
+/// Lowest tick which can be used. Corresponds to price of `2^-64`.
+pub const MIN_TICK: Tick = -443_636;
+
+/// Highest tick which can be used. Corresponds to price of `2^64`.
+pub const MAX_TICK: Tick = 443_636;
+
+/// Square root price at `MIN_TICK`.
+pub const MIN_SQRT_PRICE: SqrtPrice = 4_295_048_017;
+
+/// Square root price at `MAX_TICK`.
+pub const MAX_SQRT_PRICE: SqrtPrice = 79_226_673_515_401_279_992_447_579_062;
+
+const RESOLUTION: usize = 64;
+
+/// `sqrt(1.0001^-(2^i))` as Q128.128 fixed point numbers.
+const TICK_RATIOS: [u128; 19] = [
+	0xfffcb933bd6fad37aa2d162d1a594001,
+	0xfff97272373d413259a46990580e213a,
+	0xfff2e50f5f656932ef12357cf3c7fdcc,
+	0xffe5caca7e10e4e61c3624eaa0941cd0,
+	0xffcb9843d60f6159c9db58835c926644,
+	0xff973b41fa98c081472e6896dfb254c0,
+	0xff2ea16466c96a3843ec78b326b52861,
+	0xfe5dee046a99a2a811c461f1969c3053,
+	0xfcbe86c7900a88aedcffc83b479aa3a4,
+	0xf987a7253ac413176f2b074cf7815e54,
+	0xf3392b0822b70005940c7a398e4b70f3,
+	0xe7159475a2c29b7443b29c7fa6e889d9,
+	0xd097f3bdfd2022b8845ad8f792aa5825,
+	0xa9f746462d870fdf8a65dc1f90e061e5,
+	0x70d869a156d2a1b890bb3df62baf32f7,
+	0x31be135f97d08fd981231505542fcfa6,
+	0x9aa508b5b7a84e1c677de54f3e99bc9,
+	0x5d6af8dedb81196699c329225ee604,
+	0x2216e584f5fa1ea926041bedfe98,
+];
+
+fn div_round_up(numerator: U256, denominator: U256) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (result, remainder) = numerator.div_mod(denominator);
+	if remainder.is_zero() {
+		Ok(result)
+	} else {
+		result.checked_add(U256::one()).ok_or(Overflow)
+	}
+}
+
+/// Divide 512-bit `numerator` by `denominator` and round the result up.
+fn div_round_up_wide(numerator: U512, denominator: U256) -> Result<U256, MathError> {
+	ensure!(!denominator.is_zero(), DivisionByZero);
+	let (result, remainder) = numerator.div_mod(U512::from(denominator));
+	let result = U256::try_from(result).map_err(|_| Overflow)?;
+	if remainder.is_zero() {
+		Ok(result)
+	} else {
+		result.checked_add(U256::one()).ok_or(Overflow)
+	}
+}
+
+fn to_sqrt_price(value: U256) -> Result<SqrtPrice, MathError> {
+	SqrtPrice::try_from(value).map_err(|_| Overflow)
+}
+
+/// Calculate square root price at given tick.
+///
+/// Result is rounded up.
+///
+/// - `tick` - tick index, must be in range `[MIN_TICK, MAX_TICK]`
+///
+/// Returns MathError in case of error
+pub fn sqrt_price_at_tick(tick: Tick) -> Result<SqrtPrice, MathError> {
+	ensure!((MIN_TICK..=MAX_TICK).contains(&tick), Overflow);
+
+	let abs_tick = tick.unsigned_abs();
+
+	let mut ratio = U256::one() << 128;
+	for (i, r) in TICK_RATIOS.iter().enumerate() {
+		if abs_tick & (1u32 << i) != 0 {
+			ratio = ratio.checked_mul(U256::from(*r)).ok_or(Overflow)? >> 128;
+		}
+	}
+
+	if tick > 0 {
+		ratio = U256::MAX / ratio;
+	}
+
+	// Q128.128 -> Q64.64, rounding up
+	let shift = 128 - RESOLUTION;
+	let rounding = if (ratio & ((U256::one() << shift) - 1)).is_zero() {
+		U256::zero()
+	} else {
+		U256::one()
+	};
+
+	to_sqrt_price((ratio >> shift) + rounding)
+}
+
+/// Calculate the greatest tick whose square root price is lower or equal to given square root price.
+///
+/// - `sqrt_price` - square root price, must be in range `[MIN_SQRT_PRICE, MAX_SQRT_PRICE]`
+///
+/// Returns MathError in case of error
+pub fn tick_at_sqrt_price(sqrt_price: SqrtPrice) -> Result<Tick, MathError> {
+	ensure!((MIN_SQRT_PRICE..=MAX_SQRT_PRICE).contains(&sqrt_price), Overflow);
+
+	let (mut low, mut high) = (MIN_TICK, MAX_TICK);
+	while low < high {
+		// Upper middle so the loop always makes progress
+		let mid = low + (high - low + 1) / 2;
+		if sqrt_price_at_tick(mid)? <= sqrt_price {
+			low = mid;
+		} else {
+			high = mid - 1;
+		}
+	}
+
+	Ok(low)
+}
+
+/// Convert price of asset 0 denominated in asset 1 to its square root in Q64.64 format.
+///
+/// Returns MathError in case of error
+pub fn sqrt_price_from_price(price: Price) -> Result<SqrtPrice, MathError> {
+	let inner = U256::from(price.into_inner());
+	let accuracy = U256::from(Price::accuracy());
+
+	let price_x128 = inner.checked_mul(U256::one() << 128).ok_or(Overflow)? / accuracy;
+
+	to_sqrt_price(price_x128.integer_sqrt())
+}
+
+/// Calculate amount of asset 0 between two square root prices for given liquidity.
+/// Formula : L * (sqrt_b - sqrt_a) / (sqrt_a * sqrt_b)
+///
+/// Returns MathError in case of error
+pub fn amount_0_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+	ensure!(!lower.is_zero(), DivisionByZero);
+
+	let (lower_hp, upper_hp, liquidity_hp) = to_u256!(lower, upper, liquidity);
+
+	// L * 2^64 * (sqrt_b - sqrt_a) does not fit into 256 bits for large liquidity and wide price range.
+	// Result of division by sqrt_b is lower than L * 2^64.
+	let numerator = (liquidity_hp << RESOLUTION).full_mul(upper_hp - lower_hp);
+
+	let result = if round_up {
+		div_round_up(div_round_up_wide(numerator, upper_hp)?, lower_hp)?
+	} else {
+		U256::try_from(numerator / U512::from(upper_hp)).map_err(|_| Overflow)? / lower_hp
+	};
+
+	to_balance!(result)
+}
+
+/// Calculate amount of asset 1 between two square root prices for given liquidity.
+/// Formula : L * (sqrt_b - sqrt_a)
+///
+/// Returns MathError in case of error
+pub fn amount_1_delta(
+	sqrt_price_a: SqrtPrice,
+	sqrt_price_b: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<Balance, MathError> {
+	let (lower, upper) = ordered(sqrt_price_a, sqrt_price_b);
+
+	let (lower_hp, upper_hp, liquidity_hp) = to_u256!(lower, upper, liquidity);
+
+	let numerator = liquidity_hp.checked_mul(upper_hp - lower_hp).ok_or(Overflow)?;
+
+	let result = if round_up {
+		div_round_up(numerator, U256::one() << RESOLUTION)?
+	} else {
+		numerator >> RESOLUTION
+	};
+
+	to_balance!(result)
+}
+
+/// Calculate amounts of asset 0 and asset 1 which correspond to given liquidity of a position.
+///
+/// - `sqrt_price` - current square root price of the pool
+/// - `sqrt_price_lower`, `sqrt_price_upper` - square root prices at position's range boundaries
+/// - `liquidity` - liquidity of a position
+/// - `round_up` - rounding direction, should be up when providing liquidity and down when removing it
+///
+/// Returns MathError in case of error
+pub fn amounts_for_liquidity(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	liquidity: Liquidity,
+	round_up: bool,
+) -> Result<(Balance, Balance), MathError> {
+	if sqrt_price <= sqrt_price_lower {
+		Ok((
+			amount_0_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+			Balance::zero(),
+		))
+	} else if sqrt_price < sqrt_price_upper {
+		Ok((
+			amount_0_delta(sqrt_price, sqrt_price_upper, liquidity, round_up)?,
+			amount_1_delta(sqrt_price_lower, sqrt_price, liquidity, round_up)?,
+		))
+	} else {
+		Ok((
+			Balance::zero(),
+			amount_1_delta(sqrt_price_lower, sqrt_price_upper, liquidity, round_up)?,
+		))
+	}
+}
+
+/// Calculate the maximum liquidity which can be provided with given amounts of asset 0 and asset 1.
+///
+/// - `sqrt_price` - current square root price of the pool
+/// - `sqrt_price_lower`, `sqrt_price_upper` - square root prices at position's range boundaries
+/// - `amount_0`, `amount_1` - maximum amounts of assets to provide
+///
+/// Returns MathError in case of error
+pub fn liquidity_for_amounts(
+	sqrt_price: SqrtPrice,
+	sqrt_price_lower: SqrtPrice,
+	sqrt_price_upper: SqrtPrice,
+	amount_0: Balance,
+	amount_1: Balance,
+) -> Result<Liquidity, MathError> {
+	ensure!(sqrt_price_lower < sqrt_price_upper, DivisionByZero);
+
+	if sqrt_price <= sqrt_price_lower {
+		liquidity_for_amount_0(sqrt_price_lower, sqrt_price_upper, amount_0)
+	} else if sqrt_price < sqrt_price_upper {
+		Ok(
+			liquidity_for_amount_0(sqrt_price, sqrt_price_upper, amount_0)?.min(liquidity_for_amount_1(
+				sqrt_price_lower,
+				sqrt_price,
+				amount_1,
+			)?),
+		)
+	} else {
+		liquidity_for_amount_1(sqrt_price_lower, sqrt_price_upper, amount_1)
+	}
+}
+
+/// Formula : amount_0 * sqrt_a * sqrt_b / (sqrt_b - sqrt_a)
+fn liquidity_for_amount_0(lower: SqrtPrice, upper: SqrtPrice, amount_0: Balance) -> Result<Liquidity, MathError> {
+	let (lower_hp, upper_hp, amount_hp) = to_u256!(lower, upper, amount_0);
+
+	let intermediate = lower_hp.checked_mul(upper_hp).ok_or(Overflow)? >> RESOLUTION;
+	let result = amount_hp.checked_mul(intermediate).ok_or(Overflow)? / (upper_hp - lower_hp);
+
+	to_balance!(result)
+}
+
+/// Formula : amount_1 / (sqrt_b - sqrt_a)
+fn liquidity_for_amount_1(lower: SqrtPrice, upper: SqrtPrice, amount_1: Balance) -> Result<Liquidity, MathError> {
+	let (lower_hp, upper_hp, amount_hp) = to_u256!(lower, upper, amount_1);
+
+	let result = (amount_hp << RESOLUTION) / (upper_hp - lower_hp);
+
+	to_balance!(result)
+}
+
+/// Calculate square root price after `amount` of asset 0 is added to (or removed from) the pool.
+/// Formula : L * sqrt_p / (L +- amount * sqrt_p)
+///
+/// Result is always rounded up.
+fn next_sqrt_price_from_amount_0(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	if amount.is_zero() {
+		return Ok(sqrt_price);
+	}
+
+	let (sqrt_price_hp, liquidity_hp, amount_hp) = to_u256!(sqrt_price, liquidity, amount);
+
+	let numerator = liquidity_hp << RESOLUTION;
+	let product = amount_hp.checked_mul(sqrt_price_hp).ok_or(Overflow)?;
+
+	let denominator = if add {
+		numerator.checked_add(product).ok_or(Overflow)?
+	} else {
+		ensure!(numerator > product, Overflow);
+		numerator - product
+	};
+
+	to_sqrt_price(div_round_up_wide(numerator.full_mul(sqrt_price_hp), denominator)?)
+}
+
+/// Calculate square root price after `amount` of asset 1 is added to (or removed from) the pool.
+/// Formula : sqrt_p +- amount / L
+///
+/// Result is always rounded down.
+fn next_sqrt_price_from_amount_1(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount: Balance,
+	add: bool,
+) -> Result<SqrtPrice, MathError> {
+	ensure!(!liquidity.is_zero(), DivisionByZero);
+
+	let (sqrt_price_hp, liquidity_hp, amount_hp) = to_u256!(sqrt_price, liquidity, amount);
+
+	if add {
+		let quotient = (amount_hp << RESOLUTION) / liquidity_hp;
+		to_sqrt_price(sqrt_price_hp.checked_add(quotient).ok_or(Overflow)?)
+	} else {
+		let quotient = div_round_up(amount_hp << RESOLUTION, liquidity_hp)?;
+		ensure!(sqrt_price_hp > quotient, Overflow);
+		to_sqrt_price(sqrt_price_hp - quotient)
+	}
+}
+
+/// Calculate square root price after selling `amount_in` into the pool.
+///
+/// - `zero_for_one` - true if asset 0 is sold for asset 1
+///
+/// Returns MathError in case of error
+pub fn next_sqrt_price_from_input(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_in: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	if zero_for_one {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_in, true)
+	} else {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_in, true)
+	}
+}
+
+/// Calculate square root price after buying `amount_out` from the pool.
+///
+/// - `zero_for_one` - true if asset 0 is sold for asset 1
+///
+/// Returns MathError in case of error
+pub fn next_sqrt_price_from_output(
+	sqrt_price: SqrtPrice,
+	liquidity: Liquidity,
+	amount_out: Balance,
+	zero_for_one: bool,
+) -> Result<SqrtPrice, MathError> {
+	if zero_for_one {
+		next_sqrt_price_from_amount_1(sqrt_price, liquidity, amount_out, false)
+	} else {
+		next_sqrt_price_from_amount_0(sqrt_price, liquidity, amount_out, false)
+	}
+}
+
+/// Calculate a single swap step within a range of constant liquidity.
+///
+/// Price moves from `sqrt_price` towards `sqrt_price_target` until either the target is reached
+/// or `amount_remaining` is exhausted. Direction of the swap is determined by the target price.
+///
+/// - `sqrt_price` - current square root price
+/// - `sqrt_price_target` - square root price which can not be exceeded in this step
+/// - `liquidity` - active liquidity
+/// - `amount_remaining` - amount in (including fee) when `exact_in` is true, amount out otherwise
+/// - `fee` - trade fee charged from amount in
+/// - `exact_in` - true for sell, false for buy
+///
+/// Returns MathError in case of error
+pub fn compute_swap_step(
+	sqrt_price: SqrtPrice,
+	sqrt_price_target: SqrtPrice,
+	liquidity: Liquidity,
+	amount_remaining: Balance,
+	fee: Permill,
+	exact_in: bool,
+) -> Result<SwapStep, MathError> {
+	let zero_for_one = sqrt_price >= sqrt_price_target;
+	let fee_parts = fee.deconstruct() as u128;
+	let fee_complement = 1_000_000u128.checked_sub(fee_parts).ok_or(Overflow)?;
+	ensure!(!fee_complement.is_zero(), DivisionByZero);
+
+	let mut amount_in_to_target = Balance::zero();
+	let mut amount_out_to_target = Balance::zero();
+
+	let next_sqrt_price = if exact_in {
+		let (remaining_hp, complement_hp) = to_u256!(amount_remaining, fee_complement);
+		let amount_remaining_less_fee = to_balance!(remaining_hp * complement_hp / U256::from(1_000_000u128))?;
+
+		amount_in_to_target = if zero_for_one {
+			amount_0_delta(sqrt_price_target, sqrt_price, liquidity, true)?
+		} else {
+			amount_1_delta(sqrt_price, sqrt_price_target, liquidity, true)?
+		};
+
+		if amount_remaining_less_fee >= amount_in_to_target {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_input(sqrt_price, liquidity, amount_remaining_less_fee, zero_for_one)?
+		}
+	} else {
+		amount_out_to_target = if zero_for_one {
+			amount_1_delta(sqrt_price_target, sqrt_price, liquidity, false)?
+		} else {
+			amount_0_delta(sqrt_price, sqrt_price_target, liquidity, false)?
+		};
+
+		if amount_remaining >= amount_out_to_target {
+			sqrt_price_target
+		} else {
+			next_sqrt_price_from_output(sqrt_price, liquidity, amount_remaining, zero_for_one)?
+		}
+	};
+
+	let target_reached = next_sqrt_price == sqrt_price_target;
+
+	let (amount_in, mut amount_out) = if zero_for_one {
+		(
+			if target_reached && exact_in {
+				amount_in_to_target
+			} else {
+				amount_0_delta(next_sqrt_price, sqrt_price, liquidity, true)?
+			},
+			if target_reached && !exact_in {
+				amount_out_to_target
+			} else {
+				amount_1_delta(next_sqrt_price, sqrt_price, liquidity, false)?
+			},
+		)
+	} else {
+		(
+			if target_reached && exact_in {
+				amount_in_to_target
+			} else {
+				amount_1_delta(sqrt_price, next_sqrt_price, liquidity, true)?
+			},
+			if target_reached && !exact_in {
+				amount_out_to_target
+			} else {
+				amount_0_delta(sqrt_price, next_sqrt_price, liquidity, false)?
+			},
+		)
+	};
+
+	if !exact_in && amount_out > amount_remaining {
+		amount_out = amount_remaining;
+	}
+
+	let fee_amount = if exact_in && !target_reached {
+		// Whatever is left of the remaining amount is taken as fee
+		amount_remaining.checked_sub(amount_in).ok_or(Overflow)?
+	} else {
+		let (amount_in_hp, fee_parts_hp, complement_hp) = to_u256!(amount_in, fee_parts, fee_complement);
+		to_balance!(div_round_up(
+			amount_in_hp.checked_mul(fee_parts_hp).ok_or(Overflow)?,
+			complement_hp
+		)?)?
+	};
+
+	Ok(SwapStep {
+		sqrt_price: next_sqrt_price,
+		amount_in,
+		amount_out,
+		fee_amount,
+	})
+}
+
+/// Calculate fee growth per unit of liquidity as Q64.64 fixed point number.
+///
+/// Fee growth is accumulated with wrapping arithmetic, only differences of accumulated values are meaningful.
+///
+/// Returns MathError in case of error
+pub fn fee_growth_delta(fee_amount: Balance, liquidity: Liquidity) -> Result<u128, MathError> {
+	ensure!(!liquidity.is_zero(), DivisionByZero);
+
+	let (fee_hp, liquidity_hp) = to_u256!(fee_amount, liquidity);
+
+	Ok(((fee_hp << RESOLUTION) / liquidity_hp).low_u128())
+}
+
+/// Calculate fees owed to a position given growth of fees inside its range since the last update.
+///
+/// Returns MathError in case of error
+pub fn fees_owed(fee_growth_inside_delta: u128, liquidity: Liquidity) -> Result<Balance, MathError> {
+	let (growth_hp, liquidity_hp) = to_u256!(fee_growth_inside_delta, liquidity);
+
+	to_balance!(growth_hp.checked_mul(liquidity_hp).ok_or(Overflow)? >> RESOLUTION)
+}
+
+/// Calculate virtual reserves of asset 0 and asset 1 which correspond to active liquidity at given price.
+/// Formula : x = L / sqrt_price, y = L * sqrt_price
+///
+/// Returns MathError in case of error
+pub fn virtual_reserves(sqrt_price: SqrtPrice, liquidity: Liquidity) -> Result<(Balance, Balance), MathError> {
+	ensure!(!sqrt_price.is_zero(), DivisionByZero);
+
+	let (sqrt_price_hp, liquidity_hp) = to_u256!(sqrt_price, liquidity);
+
+	let reserve_0 = (liquidity_hp << RESOLUTION) / sqrt_price_hp;
+	let reserve_1 = liquidity_hp.checked_mul(sqrt_price_hp).ok_or(Overflow)? >> RESOLUTION;
+
+	Ok((to_balance!(reserve_0)?, to_balance!(reserve_1)?))
+}
+
+fn ordered(a: SqrtPrice, b: SqrtPrice) -> (SqrtPrice, SqrtPrice) {
+	if a <= b {
+		(a, b)
+	} else {
+		(b, a)
+	}
+}
@@ -0,0 +1,226 @@
+use crate::concentrated_liquidity::types::SwapStep;
+use crate::concentrated_liquidity::*;
+use crate::types::Balance;
+use crate::types::Price;
+use crate::MathError::Overflow;
+use sp_arithmetic::Permill;
+
+const ONE: Balance = 1_000_000_000_000;
+
+const SQRT_PRICE_ONE: u128 = 1u128 << 64;
+
+#[test]
+fn sqrt_price_at_tick_should_work() {
+	assert_eq!(sqrt_price_at_tick(0), Ok(SQRT_PRICE_ONE));
+	assert_eq!(sqrt_price_at_tick(1), Ok(18_447_666_387_855_959_851));
+	assert_eq!(sqrt_price_at_tick(-1), Ok(18_445_821_805_675_392_312));
+	assert_eq!(sqrt_price_at_tick(6931), Ok(26_086_568_254_500_584_002));
+	assert_eq!(sqrt_price_at_tick(-6932), Ok(13_043_699_376_587_823_078));
+	assert_eq!(sqrt_price_at_tick(MIN_TICK), Ok(MIN_SQRT_PRICE));
+	assert_eq!(sqrt_price_at_tick(MAX_TICK), Ok(MAX_SQRT_PRICE));
+}
+
+#[test]
+fn sqrt_price_at_tick_should_fail_when_tick_is_out_of_range() {
+	assert_eq!(sqrt_price_at_tick(MIN_TICK - 1), Err(Overflow));
+	assert_eq!(sqrt_price_at_tick(MAX_TICK + 1), Err(Overflow));
+}
+
+#[test]
+fn tick_at_sqrt_price_should_be_inverse_of_sqrt_price_at_tick() {
+	for tick in [MIN_TICK, -6932, -100, -1, 0, 1, 100, 6931, 46054, MAX_TICK] {
+		let sqrt_price = sqrt_price_at_tick(tick).unwrap();
+		assert_eq!(tick_at_sqrt_price(sqrt_price), Ok(tick));
+		if tick < MAX_TICK {
+			assert_eq!(tick_at_sqrt_price(sqrt_price_at_tick(tick + 1).unwrap() - 1), Ok(tick));
+		}
+	}
+}
+
+#[test]
+fn tick_at_sqrt_price_should_fail_when_price_is_out_of_range() {
+	assert_eq!(tick_at_sqrt_price(MIN_SQRT_PRICE - 1), Err(Overflow));
+	assert_eq!(tick_at_sqrt_price(MAX_SQRT_PRICE + 1), Err(Overflow));
+}
+
+#[test]
+fn sqrt_price_from_price_should_work() {
+	assert_eq!(sqrt_price_from_price(Price::from(1)), Ok(SQRT_PRICE_ONE));
+	assert_eq!(sqrt_price_from_price(Price::from(4)), Ok(2 * SQRT_PRICE_ONE));
+	assert_eq!(sqrt_price_from_price(Price::from(2)), Ok(26_087_635_650_665_564_424));
+	assert_eq!(
+		tick_at_sqrt_price(sqrt_price_from_price(Price::from(4)).unwrap()),
+		Ok(13863)
+	);
+}
+
+#[test]
+fn liquidity_for_amounts_should_work() {
+	let sqrt_price = sqrt_price_at_tick(0).unwrap();
+	let lower = sqrt_price_at_tick(-6932).unwrap();
+	let upper = sqrt_price_at_tick(6931).unwrap();
+
+	let liquidity = liquidity_for_amounts(sqrt_price, lower, upper, 1_000 * ONE, 1_000 * ONE).unwrap();
+	assert_eq!(liquidity, 3_414_138_713_961_846);
+
+	// Asset 1 is the limiting one
+	assert_eq!(
+		amounts_for_liquidity(sqrt_price, lower, upper, liquidity, true),
+		Ok((999_879_296_081_825, 1_000_000_000_000_000))
+	);
+	assert_eq!(
+		amounts_for_liquidity(sqrt_price, lower, upper, liquidity, false),
+		Ok((999_879_296_081_824, 999_999_999_999_999))
+	);
+}
+
+#[test]
+fn amounts_for_liquidity_should_be_single_sided_when_price_is_out_of_range() {
+	let lower = sqrt_price_at_tick(-6932).unwrap();
+	let upper = sqrt_price_at_tick(6931).unwrap();
+	let liquidity = 3_414_138_713_961_846;
+
+	assert_eq!(
+		amounts_for_liquidity(lower, lower, upper, liquidity, true),
+		Ok((2_414_105_700_810_200, 0))
+	);
+	assert_eq!(
+		amounts_for_liquidity(upper, lower, upper, liquidity, true),
+		Ok((0, 2_413_985_004_577_302))
+	);
+}
+
+#[test]
+fn amount_0_delta_should_not_overflow_when_liquidity_and_price_range_are_max() {
+	assert_eq!(
+		amount_0_delta(SQRT_PRICE_ONE, MAX_SQRT_PRICE, u128::MAX, false),
+		Ok(340_282_366_841_708_811_922_262_727_772_361_969_535)
+	);
+	assert_eq!(
+		amount_0_delta(SQRT_PRICE_ONE, MAX_SQRT_PRICE, u128::MAX, true),
+		Ok(340_282_366_841_708_811_922_262_727_772_361_969_536)
+	);
+}
+
+#[test]
+fn amount_0_delta_should_fail_when_result_exceeds_balance() {
+	assert_eq!(
+		amount_0_delta(MIN_SQRT_PRICE, MAX_SQRT_PRICE, u128::MAX, true),
+		Err(Overflow)
+	);
+}
+
+#[test]
+fn next_sqrt_price_from_input_should_not_overflow_when_liquidity_and_price_are_max() {
+	assert_eq!(
+		next_sqrt_price_from_input(MAX_SQRT_PRICE, u128::MAX, ONE, true),
+		Ok(79_226_673_515_401_278_992_485_166_325)
+	);
+}
+
+#[test]
+fn compute_swap_step_should_work_when_selling() {
+	let sqrt_price = sqrt_price_at_tick(0).unwrap();
+	let lower = sqrt_price_at_tick(-6932).unwrap();
+	let upper = sqrt_price_at_tick(6931).unwrap();
+	let liquidity = 3_414_138_713_961_846;
+	let fee = Permill::from_rational(3u32, 1000u32);
+
+	// Asset 0 for asset 1
+	assert_eq!(
+		compute_swap_step(sqrt_price, lower, liquidity, 10 * ONE, fee, true),
+		Ok(SwapStep {
+			sqrt_price: 18_393_032_566_926_787_260,
+			amount_in: 9_970_000_000_000,
+			amount_out: 9_940_970_285_027,
+			fee_amount: 30_000_000_000,
+		})
+	);
+
+	// Asset 1 for asset 0
+	assert_eq!(
+		compute_swap_step(sqrt_price, upper, liquidity, 10 * ONE, fee, true),
+		Ok(SwapStep {
+			sqrt_price: 18_500_612_429_339_855_254,
+			amount_in: 9_970_000_000_000,
+			amount_out: 9_940_970_285_027,
+			fee_amount: 30_000_000_000,
+		})
+	);
+}
+
+#[test]
+fn compute_swap_step_should_stop_at_target_price() {
+	let sqrt_price = sqrt_price_at_tick(0).unwrap();
+	let lower = sqrt_price_at_tick(-6932).unwrap();
+	let upper = sqrt_price_at_tick(6931).unwrap();
+	let liquidity = 3_414_138_713_961_846;
+	let fee = Permill::from_rational(3u32, 1000u32);
+
+	assert_eq!(
+		compute_swap_step(sqrt_price, lower, liquidity, 10_000 * ONE, fee, true),
+		Ok(SwapStep {
+			sqrt_price: lower,
+			amount_in: 1_414_226_404_728_376,
+			amount_out: 999_999_999_999_999,
+			fee_amount: 4_255_445_550_838,
+		})
+	);
+
+	assert_eq!(
+		compute_swap_step(sqrt_price, upper, liquidity, 10_000 * ONE, fee, false),
+		Ok(SwapStep {
+			sqrt_price: upper,
+			amount_in: 1_413_985_004_577_302,
+			amount_out: 999_879_296_081_824,
+			fee_amount: 4_254_719_171_246,
+		})
+	);
+}
+
+#[test]
+fn compute_swap_step_should_work_when_buying() {
+	let sqrt_price = sqrt_price_at_tick(0).unwrap();
+	let lower = sqrt_price_at_tick(-6932).unwrap();
+	let liquidity = 3_414_138_713_961_846;
+	let fee = Permill::from_rational(3u32, 1000u32);
+
+	let step = compute_swap_step(sqrt_price, lower, liquidity, 10 * ONE, fee, false).unwrap();
+
+	assert_eq!(
+		step,
+		SwapStep {
+			sqrt_price: 18_392_713_626_738_334_325,
+			amount_in: 10_029_376_006_210,
+			amount_out: 10 * ONE,
+			fee_amount: 30_178_664_011,
+		}
+	);
+	assert_eq!(
+		next_sqrt_price_from_output(sqrt_price, liquidity, 10 * ONE, true),
+		Ok(step.sqrt_price)
+	);
+}
+
+#[test]
+fn fees_owed_should_correspond_to_fee_growth() {
+	let liquidity = 3_414_138_713_961_846;
+
+	let growth = fee_growth_delta(30 * ONE, liquidity).unwrap();
+
+	assert_eq!(growth, 162_091_340_913_651_870);
+	// Rounded down in favour of the pool
+	assert_eq!(fees_owed(growth, liquidity), Ok(30 * ONE - 1));
+}
+
+#[test]
+fn virtual_reserves_should_reflect_price() {
+	let liquidity = 3_414_138_713_961_846;
+
+	assert_eq!(virtual_reserves(SQRT_PRICE_ONE, liquidity), Ok((liquidity, liquidity)));
+	assert_eq!(
+		virtual_reserves(2 * SQRT_PRICE_ONE, liquidity),
+		Ok((liquidity / 2, 2 * liquidity))
+	);
+	assert_eq!(virtual_reserves(0, liquidity), Err(crate::MathError::DivisionByZero));
+}
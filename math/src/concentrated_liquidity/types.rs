@@ -0,0 +1,23 @@
+use crate::types::Balance;
+
+/// Square root of a price represented as Q64.64 fixed point number.
+pub type SqrtPrice = u128;
+
+/// Liquidity of a pool or a position.
+pub type Liquidity = u128;
+
+/// Tick index. Price at tick `i` is `1.0001^i`.
+pub type Tick = i32;
+
+/// Result of a single swap step within one tick range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapStep {
+	/// Square root price after the step.
+	pub sqrt_price: SqrtPrice,
+	/// Amount of asset coming into the pool, excluding fee.
+	pub amount_in: Balance,
+	/// Amount of asset going out of the pool.
+	pub amount_out: Balance,
+	/// Fee charged in asset coming into the pool.
+	pub fee_amount: Balance,
+}
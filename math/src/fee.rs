@@ -1,5 +1,6 @@
 use crate::types::Balance;
 use num_traits::Zero;
+use sp_arithmetic::{traits::Saturating, Permill};
 
 pub fn calculate_pool_trade_fee(amount: Balance, fee: (u32, u32)) -> Option<Balance> {
 	let numerator = fee.0;
@@ -18,6 +19,20 @@ pub fn calculate_pool_trade_fee(amount: Balance, fee: (u32, u32)) -> Option<Bala
 		.checked_mul(numerator as Balance)
 }
 
+/// Reduce `fee` by `discount` fraction of it.
+///
+/// Discounted part is rounded down, so the resulting fee is never lower than the exact value.
+pub fn calculate_discounted_fee(fee: Permill, discount: Permill) -> Permill {
+	fee.saturating_sub(discount * fee)
+}
+
+/// Reduce `fee_amount` by `discount` fraction of it.
+///
+/// Discounted part is rounded down, so the resulting fee amount is never lower than the exact value.
+pub fn calculate_discounted_fee_amount(fee_amount: Balance, discount: Permill) -> Balance {
+	fee_amount.saturating_sub(discount.mul_floor(fee_amount))
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -96,4 +111,33 @@ mod tests {
 		assert_eq!(calculate_pool_trade_fee(max_amount, (1, 0)), Some(0));
 		assert_eq!(calculate_pool_trade_fee(1_000, (0, 1)), Some(0));
 	}
+
+	#[test]
+	fn discounted_fee_calculations_should_work() {
+		let fee = Permill::from_parts(3_000);
+
+		assert_eq!(calculate_discounted_fee(fee, Permill::zero()), fee);
+		assert_eq!(
+			calculate_discounted_fee(fee, Permill::from_percent(20)),
+			Permill::from_parts(2_400)
+		);
+		assert_eq!(
+			calculate_discounted_fee(fee, Permill::from_percent(100)),
+			Permill::zero()
+		);
+		// discounted part is rounded down
+		assert_eq!(
+			calculate_discounted_fee(Permill::from_parts(7), Permill::from_percent(50)),
+			Permill::from_parts(4)
+		);
+	}
+
+	#[test]
+	fn discounted_fee_amount_calculations_should_work() {
+		assert_eq!(calculate_discounted_fee_amount(1_000, Permill::zero()), 1_000);
+		assert_eq!(calculate_discounted_fee_amount(1_000, Permill::from_percent(20)), 800);
+		assert_eq!(calculate_discounted_fee_amount(1_000, Permill::from_percent(100)), 0);
+		// discounted part is rounded down
+		assert_eq!(calculate_discounted_fee_amount(7, Permill::from_percent(50)), 4);
+	}
 }
@@ -138,7 +138,8 @@ proptest! {
 		let result = calculate_sell_state_changes(&asset_in, &asset_out, amount,
 			Permill::from_percent(0),
 			Permill::from_percent(0),
-			Balance::default()
+			Balance::default(),
+			Permill::from_percent(100)
 		);
 
 		assert!(result.is_some());
@@ -173,6 +174,7 @@ proptest! {
 			asset_fee,
 			protocol_fee,
 			imbalance.value,
+			Permill::from_percent(100),
 		);
 
 		assert!(result.is_some());
@@ -413,6 +415,7 @@ proptest! {
 			asset_fee,
 			protocol_fee,
 			imbalance.value,
+			Permill::from_percent(100),
 		);
 
 		// ignore the invalid result
@@ -482,6 +485,7 @@ fn buy_update_invariants_no_fees_case() {
 		Permill::from_percent(0),
 		Permill::from_percent(0),
 		Balance::default(),
+		Permill::from_percent(100),
 	);
 
 	assert!(result.is_none()); // This fails because of not enough asset out in pool out
@@ -496,7 +500,8 @@ proptest! {
 		let result = calculate_buy_state_changes(&asset_in, &asset_out, amount,
 			Permill::from_percent(0),
 			Permill::from_percent(0),
-			Balance::default()
+			Balance::default(),
+			Permill::from_percent(100)
 		);
 
 		// perform assertion only when result is valid
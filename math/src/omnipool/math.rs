@@ -19,6 +19,8 @@ fn amount_without_fee(amount: Balance, fee: Permill) -> Option<Balance> {
 }
 
 /// Calculate delta changes of a sell trade given current state of asset in and out.
+///
+/// `imbalance_burn_rate` of the protocol fee is used to burn the imbalance, the rest is transferred to HDX subpool.
 pub fn calculate_sell_state_changes(
 	asset_in_state: &AssetReserveState<Balance>,
 	asset_out_state: &AssetReserveState<Balance>,
@@ -26,6 +28,7 @@ pub fn calculate_sell_state_changes(
 	asset_fee: Permill,
 	protocol_fee: Permill,
 	imbalance: Balance,
+	imbalance_burn_rate: Permill,
 ) -> Option<TradeStateChange<Balance>> {
 	let (in_hub_reserve, in_reserve, in_amount) = to_u256!(asset_in_state.hub_reserve, asset_in_state.reserve, amount);
 
@@ -54,7 +57,7 @@ pub fn calculate_sell_state_changes(
 
 	let asset_fee = amount_out.saturating_sub(delta_reserve_out);
 
-	let delta_imbalance = min(protocol_fee_amount, imbalance);
+	let delta_imbalance = min(imbalance_burn_rate.mul_floor(protocol_fee_amount), imbalance);
 
 	let hdx_fee_amount = protocol_fee_amount.checked_sub(delta_imbalance)?;
 
@@ -188,6 +191,8 @@ pub fn calculate_buy_for_hub_asset_state_changes(
 }
 
 /// Calculate delta changes of a buy trade given current state of asset in and out
+///
+/// `imbalance_burn_rate` of the protocol fee is used to burn the imbalance, the rest is transferred to HDX subpool.
 pub fn calculate_buy_state_changes(
 	asset_in_state: &AssetReserveState<Balance>,
 	asset_out_state: &AssetReserveState<Balance>,
@@ -195,6 +200,7 @@ pub fn calculate_buy_state_changes(
 	asset_fee: Permill,
 	protocol_fee: Permill,
 	imbalance: Balance,
+	imbalance_burn_rate: Permill,
 ) -> Option<TradeStateChange<Balance>> {
 	let reserve_no_fee = amount_without_fee(asset_out_state.reserve, asset_fee)?;
 	let (out_hub_reserve, out_reserve_no_fee, out_amount) =
@@ -229,7 +235,7 @@ pub fn calculate_buy_state_changes(
 	let fee_amount = calculate_fee_amount_for_buy(asset_fee, amount);
 	// Fee accounting and imbalance
 	let protocol_fee_amount = protocol_fee.mul_floor(delta_hub_reserve_in);
-	let delta_imbalance = min(protocol_fee_amount, imbalance);
+	let delta_imbalance = min(imbalance_burn_rate.mul_floor(protocol_fee_amount), imbalance);
 
 	let hdx_fee_amount = protocol_fee_amount.checked_sub(delta_imbalance)?;
 
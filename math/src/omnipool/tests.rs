@@ -39,6 +39,7 @@ fn calculate_sell_should_work_when_correct_input_provided() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -94,6 +95,7 @@ fn calculate_sell_should_return_correct_when_protocol_fee_is_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -139,6 +141,7 @@ fn calculate_sell_should_return_correct_when_protocol_fee_is_not_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -179,6 +182,7 @@ fn calculate_sell_with_fees_should_work_when_correct_input_provided() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -318,6 +322,7 @@ fn calculate_buy_should_work_when_correct_input_provided() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -372,6 +377,7 @@ fn calculate_buy_should_return_correct_fee_when_protocol_fee_is_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -419,6 +425,7 @@ fn calculate_buy_should_return_correct_fee_when_protocol_fee_is_non_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -466,6 +473,7 @@ fn calculate_buy_with_fees_should_work_when_correct_input_provided() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -1097,6 +1105,7 @@ fn calculate_buy_should_charge_less_when_fee_is_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -1148,6 +1157,7 @@ fn calculate_buy_should_charge_more_when_fee_is_not_zero() {
 		asset_fee,
 		protocol_fee,
 		imbalance,
+		Permill::from_percent(100),
 	);
 
 	assert!(state_changes.is_some());
@@ -1171,3 +1181,65 @@ fn calculate_buy_should_charge_more_when_fee_is_not_zero() {
 		}
 	);
 }
+
+#[test]
+fn calculate_sell_should_split_protocol_fee_by_imbalance_burn_rate() {
+	let asset_in_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let state_changes = calculate_sell_state_changes(
+		&asset_in_state,
+		&asset_out_state,
+		4 * UNIT,
+		Permill::from_percent(0),
+		Permill::from_percent(1),
+		2 * UNIT,
+		Permill::from_percent(50),
+	)
+	.unwrap();
+
+	assert_eq!(state_changes.fee.protocol_fee, 57142857142u128);
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(28571428571u128));
+	assert_eq!(state_changes.hdx_hub_amount, 28571428571u128);
+}
+
+#[test]
+fn calculate_buy_should_transfer_whole_protocol_fee_to_hdx_subpool_when_imbalance_burn_rate_is_zero() {
+	let asset_in_state = AssetReserveState {
+		reserve: 10 * UNIT,
+		hub_reserve: 20 * UNIT,
+		shares: 10 * UNIT,
+		protocol_shares: 0u128,
+	};
+	let asset_out_state = AssetReserveState {
+		reserve: 5 * UNIT,
+		hub_reserve: 5 * UNIT,
+		shares: 20 * UNIT,
+		protocol_shares: 0u128,
+	};
+
+	let state_changes = calculate_buy_state_changes(
+		&asset_in_state,
+		&asset_out_state,
+		UNIT,
+		Permill::from_percent(0),
+		Permill::from_percent(1),
+		2 * UNIT,
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert!(state_changes.fee.protocol_fee > 0);
+	assert_eq!(state_changes.delta_imbalance, BalanceUpdate::Increase(0u128));
+	assert_eq!(state_changes.hdx_hub_amount, state_changes.fee.protocol_fee);
+}
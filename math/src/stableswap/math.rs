@@ -4,6 +4,7 @@ use crate::to_u256;
 use crate::types::Balance;
 use num_traits::{CheckedDiv, CheckedMul, One, Zero};
 use primitive_types::U256;
+use sp_arithmetic::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
 use sp_std::ops::Div;
 use sp_std::prelude::*;
@@ -30,11 +31,11 @@ pub fn calculate_out_given_in<const D: u8, const Y: u8>(
 	if idx_in >= balances.len() || idx_out >= balances.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(balances);
-	let amount_in = normalize_value(amount_in, balances[idx_in].decimals, TARGET_PRECISION, Rounding::Down);
+	let reserves = normalize_reserves(balances)?;
+	let amount_in = normalize_asset_value(amount_in, &balances[idx_in], Rounding::Down)?;
 	let new_reserve_out = calculate_y_given_in::<D, Y>(amount_in, idx_in, idx_out, &reserves, amplification)?;
 	let amount_out = reserves[idx_out].checked_sub(new_reserve_out)?;
-	let amount_out = normalize_value(amount_out, TARGET_PRECISION, balances[idx_out].decimals, Rounding::Down);
+	let amount_out = denormalize_asset_value(amount_out, &balances[idx_out], Rounding::Down)?;
 	Some(amount_out.saturating_sub(1u128))
 }
 
@@ -51,11 +52,11 @@ pub fn calculate_in_given_out<const D: u8, const Y: u8>(
 	if idx_in >= balances.len() || idx_out >= balances.len() {
 		return None;
 	}
-	let reserves = normalize_reserves(balances);
-	let amount_out = normalize_value(amount_out, balances[idx_out].decimals, TARGET_PRECISION, Rounding::Down);
+	let reserves = normalize_reserves(balances)?;
+	let amount_out = normalize_asset_value(amount_out, &balances[idx_out], Rounding::Down)?;
 	let new_reserve_in = calculate_y_given_out::<D, Y>(amount_out, idx_in, idx_out, &reserves, amplification)?;
 	let amount_in = new_reserve_in.checked_sub(reserves[idx_in])?;
-	let amount_in = normalize_value(amount_in, TARGET_PRECISION, balances[idx_in].decimals, Rounding::Up);
+	let amount_in = denormalize_asset_value(amount_in, &balances[idx_in], Rounding::Up)?;
 	Some(amount_in.saturating_add(1u128))
 }
 
@@ -89,6 +90,105 @@ pub fn calculate_in_given_out_with_fee<const D: u8, const Y: u8>(
 	Some((amount_in, fee_amount))
 }
 
+/// Calculate amount to be received from the pool given the amount to be sent to the pool with dynamic fee applied.
+/// Fee is calculated from post-trade reserves of traded assets - see `calculate_dynamic_fee`.
+pub fn calculate_out_given_in_with_dynamic_fee<const D: u8, const Y: u8>(
+	balances: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_in: Balance,
+	amplification: Balance,
+	fee: Permill,
+	fee_multiplier: u32,
+) -> Option<(Balance, Balance)> {
+	let amount_out = calculate_out_given_in::<D, Y>(balances, idx_in, idx_out, amount_in, amplification)?;
+	let reserve_in = balances[idx_in].amount.checked_add(amount_in)?;
+	let reserve_out = balances[idx_out].amount.checked_sub(amount_out)?;
+	let fee = calculate_dynamic_fee(
+		normalize_asset_value(reserve_in, &balances[idx_in], Rounding::Down)?,
+		normalize_asset_value(reserve_out, &balances[idx_out], Rounding::Down)?,
+		fee,
+		fee_multiplier,
+	)?;
+	let fee_amount = calculate_fee_amount(amount_out, fee, Rounding::Down);
+	let amount_out = amount_out.checked_sub(fee_amount)?;
+	Some((amount_out, fee_amount))
+}
+
+/// Calculate amount to be sent to the pool given the amount to be received from the pool with dynamic fee applied.
+/// Fee is calculated from post-trade reserves of traded assets - see `calculate_dynamic_fee`.
+pub fn calculate_in_given_out_with_dynamic_fee<const D: u8, const Y: u8>(
+	balances: &[AssetReserve],
+	idx_in: usize,
+	idx_out: usize,
+	amount_out: Balance,
+	amplification: Balance,
+	fee: Permill,
+	fee_multiplier: u32,
+) -> Option<(Balance, Balance)> {
+	let amount_in = calculate_in_given_out::<D, Y>(balances, idx_in, idx_out, amount_out, amplification)?;
+	let reserve_in = balances[idx_in].amount.checked_add(amount_in)?;
+	let reserve_out = balances[idx_out].amount.checked_sub(amount_out)?;
+	let fee = calculate_dynamic_fee(
+		normalize_asset_value(reserve_in, &balances[idx_in], Rounding::Down)?,
+		normalize_asset_value(reserve_out, &balances[idx_out], Rounding::Down)?,
+		fee,
+		fee_multiplier,
+	)?;
+	let fee_amount = calculate_fee_amount(amount_in, fee, Rounding::Up);
+	let amount_in = amount_in.checked_add(fee_amount)?;
+	Some((amount_in, fee_amount))
+}
+
+/// Calculate fee based on imbalance of two normalized reserves.
+///
+/// fee * m / ((m - 1) * 4 * x * y / (x + y)^2 + 1)
+///
+/// Fee is equal to `fee` when reserves are balanced and approaches `fee * m` as the reserves get imbalanced.
+/// Resulting fee is capped at 100%.
+pub fn calculate_dynamic_fee(
+	reserve_a: Balance,
+	reserve_b: Balance,
+	fee: Permill,
+	fee_multiplier: u32,
+) -> Option<Permill> {
+	if fee_multiplier <= 1 {
+		return Some(fee);
+	}
+	let (x, y, m, fee_parts) = to_u256!(reserve_a, reserve_b, fee_multiplier, fee.deconstruct());
+	let sum = x.checked_add(y)?;
+	if sum.is_zero() {
+		return Some(fee);
+	}
+	let sum_squared = sum.checked_mul(sum)?;
+
+	let num = fee_parts.checked_mul(m)?.checked_mul(sum_squared)?;
+	let denom = m
+		.checked_sub(U256::one())?
+		.checked_mul(to_u256!(4u128))?
+		.checked_mul(x)?
+		.checked_mul(y)?
+		.checked_add(sum_squared)?;
+
+	let parts = num.checked_div(denom)?.min(to_u256!(Permill::one().deconstruct()));
+	Some(Permill::from_parts(parts.low_u32()))
+}
+
+/// Calculate dynamic fee of a liquidity operation from reserves after the operation.
+///
+/// Fee is calculated from the least and the most abundant normalized reserves - see `calculate_dynamic_fee`,
+/// so it is never lower than the fee of a trade which results in the same reserves.
+pub fn calculate_liquidity_dynamic_fee(
+	reserves: &[AssetReserve],
+	fee: Permill,
+	fee_multiplier: u32,
+) -> Option<Permill> {
+	let reserves = normalize_reserves(reserves)?;
+	let min_reserve = reserves.iter().min()?;
+	let max_reserve = reserves.iter().max()?;
+	calculate_dynamic_fee(*min_reserve, *max_reserve, fee, fee_multiplier)
+}
+
 /// Calculate amount of shares to be given to LP after LP provided liquidity of some assets to the pool.
 pub fn calculate_shares<const D: u8>(
 	initial_reserves: &[AssetReserve],
@@ -128,10 +228,10 @@ pub fn calculate_shares<const D: u8>(
 				let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 				let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 				let fee_amount = fee.checked_mul_int(diff)?;
-				Some(AssetReserve::new(
-					asset_reserve.amount.saturating_sub(fee_amount),
-					asset_reserve.decimals,
-				))
+				Some(AssetReserve {
+					amount: asset_reserve.amount.saturating_sub(fee_amount),
+					..*asset_reserve
+				})
 			})
 			.collect::<Option<Vec<AssetReserve>>>()?;
 		adjusted_balances
@@ -176,7 +276,10 @@ pub fn calculate_shares_for_amount<const D: u8>(
 		.enumerate()
 		.map(|(idx, v)| -> Option<AssetReserve> {
 			if idx == asset_idx {
-				Some(AssetReserve::new(v.amount.checked_sub(amount)?, v.decimals))
+				Some(AssetReserve {
+					amount: v.amount.checked_sub(amount)?,
+					..*v
+				})
 			} else {
 				Some(*v)
 			}
@@ -194,10 +297,10 @@ pub fn calculate_shares_for_amount<const D: u8>(
 			let ideal_balance = d1.checked_mul(initial_reserve)?.checked_div(d0)?;
 			let diff = Balance::try_from(updated_reserve.abs_diff(ideal_balance)).ok()?;
 			let fee_amount = fee.checked_mul_int(diff)?;
-			Some(AssetReserve::new(
-				asset_reserve.amount.saturating_sub(fee_amount),
-				asset_reserve.decimals,
-			))
+			Some(AssetReserve {
+				amount: asset_reserve.amount.saturating_sub(fee_amount),
+				..*asset_reserve
+			})
 		})
 		.collect::<Option<Vec<AssetReserve>>>()?;
 
@@ -235,8 +338,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	if n_coins <= 1 {
 		return None;
 	}
-	let asset_out_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_out = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let fixed_fee = FixedU128::from(fee);
 	let fee = fixed_fee
@@ -288,8 +391,8 @@ pub fn calculate_withdraw_one_asset<const D: u8, const Y: u8>(
 	let dy_0 = reserves[asset_index].checked_sub(y)?;
 	let fee = dy_0.checked_sub(dy)?;
 
-	let amount_out = normalize_value(dy, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_out_decimals, Rounding::Down);
+	let amount_out = denormalize_asset_value(dy, &asset_out, Rounding::Down)?;
+	let fee = denormalize_asset_value(fee, &asset_out, Rounding::Down)?;
 	Some((amount_out, fee))
 }
 
@@ -316,8 +419,8 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 		return None;
 	}
 
-	let asset_in_decimals = reserves[asset_index].decimals;
-	let reserves = normalize_reserves(reserves);
+	let asset_in = reserves[asset_index];
+	let reserves = normalize_reserves(reserves)?;
 
 	let initial_d = calculate_d_internal::<D>(&reserves, amplification)?;
 	let (shares_hp, issuance_hp, d_hp) = to_u256!(shares, share_asset_issuance, initial_d);
@@ -367,12 +470,60 @@ pub fn calculate_add_one_asset<const D: u8, const Y: u8>(
 	let dy = y1.checked_sub(asset_reserve)?;
 	let dy_0 = y.checked_sub(asset_reserve)?;
 	let fee = dy.checked_sub(dy_0)?;
-	let amount_in = normalize_value(dy, TARGET_PRECISION, asset_in_decimals, Rounding::Down);
-	let fee = normalize_value(fee, TARGET_PRECISION, asset_in_decimals, Rounding::Down);
+	let amount_in = denormalize_asset_value(dy, &asset_in, Rounding::Down)?;
+	let fee = denormalize_asset_value(fee, &asset_in, Rounding::Down)?;
 	Some((amount_in, fee))
 }
+
+/// Calculate amounts of all pool assets to be withdrawn pro-rata for given amount of shares.
+/// No fee is applied. Amounts are rounded down in favour of the pool.
+pub fn calculate_proportional_amounts(
+	reserves: &[AssetReserve],
+	shares: Balance,
+	share_asset_issuance: Balance,
+) -> Option<Vec<Balance>> {
+	if share_asset_issuance.is_zero() || shares > share_asset_issuance {
+		return None;
+	}
+	let (shares_hp, issuance_hp) = to_u256!(shares, share_asset_issuance);
+	reserves
+		.iter()
+		.map(|reserve| {
+			let reserve_hp = to_u256!(reserve.amount);
+			let amount = reserve_hp.checked_mul(shares_hp)?.checked_div(issuance_hp)?;
+			Balance::try_from(amount).ok()
+		})
+		.collect()
+}
+
+/// Calculate amount of shares to be minted when new asset with reserve `new_asset` is added to a pool.
+/// Shares are minted proportionally to the increase of D. No fee is applied.
+pub fn calculate_shares_for_new_asset<const D: u8>(
+	reserves: &[AssetReserve],
+	new_asset: AssetReserve,
+	amplification: Balance,
+	share_issuance: Balance,
+) -> Option<Balance> {
+	if share_issuance.is_zero() || new_asset.amount.is_zero() {
+		return None;
+	}
+	let initial_d = calculate_d::<D>(reserves, amplification)?;
+
+	let mut updated_reserves = reserves.to_vec();
+	updated_reserves.push(new_asset);
+	// Updated D is rounded down so that the new shares are not overvalued.
+	let updated_d = calculate_d::<D>(&updated_reserves, amplification)?.checked_sub(2_u128)?;
+	if updated_d <= initial_d {
+		return None;
+	}
+
+	let (issuance_hp, d0, d1) = to_u256!(share_issuance, initial_d, updated_d);
+	let shares_hp = issuance_hp.checked_mul(d1.checked_sub(d0)?)?.checked_div(d0)?;
+	Balance::try_from(shares_hp).ok()
+}
+
 pub fn calculate_d<const D: u8>(reserves: &[AssetReserve], amplification: Balance) -> Option<Balance> {
-	let balances = normalize_reserves(reserves);
+	let balances = normalize_reserves(reserves)?;
 	calculate_d_internal::<D>(&balances, amplification)
 }
 
@@ -587,6 +738,7 @@ fn abs_diff(d0: U256, d1: U256) -> U256 {
 	}
 }
 
+#[derive(Clone, Copy)]
 pub(crate) enum Rounding {
 	Down,
 	Up,
@@ -599,13 +751,41 @@ fn calculate_fee_amount(amount: Balance, fee: Permill, rounding: Rounding) -> Ba
 	}
 }
 
-pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Vec<Balance> {
+pub(crate) fn normalize_reserves(reserves: &[AssetReserve]) -> Option<Vec<Balance>> {
 	reserves
 		.iter()
-		.map(|v| normalize_value(v.amount, v.decimals, TARGET_PRECISION, Rounding::Down))
+		.map(|v| normalize_asset_value(v.amount, v, Rounding::Down))
 		.collect()
 }
 
+/// Convert amount of given asset to target precision and apply the asset rate.
+///
+/// Returns `None` if the rated amount overflows.
+pub(crate) fn normalize_asset_value(amount: Balance, asset: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = normalize_value(amount, asset.decimals, TARGET_PRECISION, rounding);
+	apply_rate(amount, asset.rate, rounding)
+}
+
+/// Inverse of `normalize_asset_value` - remove the asset rate and convert amount back to asset precision.
+///
+/// Returns `None` if the amount without rate overflows.
+pub(crate) fn denormalize_asset_value(amount: Balance, asset: &AssetReserve, rounding: Rounding) -> Option<Balance> {
+	let amount = apply_rate(amount, (asset.rate.1, asset.rate.0), rounding)?;
+	Some(normalize_value(amount, TARGET_PRECISION, asset.decimals, rounding))
+}
+
+fn apply_rate(amount: Balance, rate: (Balance, Balance), rounding: Rounding) -> Option<Balance> {
+	let (n, d) = rate;
+	if n == d {
+		return Some(amount);
+	}
+	let rounding = match rounding {
+		Rounding::Down => sp_arithmetic::per_things::Rounding::Down,
+		Rounding::Up => sp_arithmetic::per_things::Rounding::Up,
+	};
+	multiply_by_rational_with_rounding(amount, n, d, rounding)
+}
+
 pub(crate) fn normalize_value(amount: Balance, decimals: u8, target_decimals: u8, rounding: Rounding) -> Balance {
 	if target_decimals == decimals {
 		return amount;
@@ -660,7 +840,7 @@ pub fn calculate_share_price<const D: u8>(
 	} else {
 		calculate_d::<D>(balances, amplification)?
 	};
-	let reserves = normalize_reserves(balances);
+	let reserves = normalize_reserves(balances)?;
 
 	let c = reserves
 		.iter()
@@ -692,6 +872,14 @@ pub fn calculate_share_price<const D: u8>(
 		let num = num.checked_div(p_diff)?;
 		(num, denom)
 	};
+	// Share price is expressed in units of the asset, so the asset rate has to be removed.
+	let (rate_n, rate_d) = balances[asset_idx].rate;
+	let (num, denom) = if rate_n != rate_d {
+		let (rate_n, rate_d) = to_u256!(rate_n, rate_d);
+		(num.checked_mul(rate_d)?, denom.checked_mul(rate_n)?)
+	} else {
+		(num, denom)
+	};
 	let (num, denom) = round_to_rational((num, denom), crate::support::rational::Rounding::Down);
 	//dbg!(FixedU128::checked_from_rational(num, denom));
 	Some((num, denom))
@@ -709,7 +897,7 @@ pub fn calculate_spot_price(
 	}
 	let ann = calculate_ann(n, amplification)?;
 
-	let mut reserves = normalize_reserves(balances);
+	let mut reserves = normalize_reserves(balances)?;
 
 	let x0 = reserves[0];
 	let xi = reserves[asset_idx];
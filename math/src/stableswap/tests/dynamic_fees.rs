@@ -0,0 +1,93 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use super::*;
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use sp_arithmetic::Permill;
+
+#[test]
+fn dynamic_fee_should_be_equal_to_fee_when_reserves_are_balanced() {
+	let fee = calculate_dynamic_fee(1_000 * ONE, 1_000 * ONE, Permill::from_percent(1), 10);
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn dynamic_fee_should_be_equal_to_fee_when_multiplier_is_one() {
+	let fee = calculate_dynamic_fee(1_000 * ONE, 10 * ONE, Permill::from_percent(1), 1);
+	assert_eq!(fee, Some(Permill::from_percent(1)));
+}
+
+#[test]
+fn dynamic_fee_should_increase_when_reserves_are_imbalanced() {
+	let base_fee = Permill::from_percent(1);
+
+	let slightly_imbalanced = calculate_dynamic_fee(1_000 * ONE, 900 * ONE, base_fee, 10).unwrap();
+	let imbalanced = calculate_dynamic_fee(1_000 * ONE, 100 * ONE, base_fee, 10).unwrap();
+	let fully_imbalanced = calculate_dynamic_fee(1_000 * ONE, 0, base_fee, 10).unwrap();
+
+	assert!(slightly_imbalanced > base_fee);
+	assert!(imbalanced > slightly_imbalanced);
+	assert_eq!(fully_imbalanced, Permill::from_percent(10));
+}
+
+#[test]
+fn dynamic_fee_should_be_symmetric() {
+	let base_fee = Permill::from_percent(1);
+
+	assert_eq!(
+		calculate_dynamic_fee(1_000 * ONE, 300 * ONE, base_fee, 5),
+		calculate_dynamic_fee(300 * ONE, 1_000 * ONE, base_fee, 5)
+	);
+}
+
+#[test]
+fn dynamic_fee_should_be_capped_at_hundred_percent() {
+	let fee = calculate_dynamic_fee(1_000 * ONE, 0, Permill::from_percent(50), 10);
+	assert_eq!(fee, Some(Permill::one()));
+}
+
+#[test]
+fn out_given_in_with_dynamic_fee_should_charge_more_when_trade_increases_imbalance() {
+	let balances = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let fee = Permill::from_percent(1);
+
+	let (amount_out, fee_amount) =
+		calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(&balances, 0, 1, 500 * ONE, 100, fee).unwrap();
+	let (dynamic_amount_out, dynamic_fee_amount) =
+		calculate_out_given_in_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(&balances, 0, 1, 500 * ONE, 100, fee, 10)
+			.unwrap();
+
+	assert!(dynamic_fee_amount > fee_amount);
+	assert_eq!(amount_out + fee_amount, dynamic_amount_out + dynamic_fee_amount);
+}
+
+#[test]
+fn in_given_out_with_dynamic_fee_should_charge_more_when_trade_increases_imbalance() {
+	let balances = [AssetReserve::new(1_000 * ONE, 12), AssetReserve::new(1_000 * ONE, 12)];
+	let fee = Permill::from_percent(1);
+
+	let (amount_in, fee_amount) =
+		calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(&balances, 0, 1, 500 * ONE, 100, fee).unwrap();
+	let (dynamic_amount_in, dynamic_fee_amount) =
+		calculate_in_given_out_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(&balances, 0, 1, 500 * ONE, 100, fee, 10)
+			.unwrap();
+
+	assert!(dynamic_fee_amount > fee_amount);
+	assert_eq!(amount_in - fee_amount, dynamic_amount_in - dynamic_fee_amount);
+}
+
+#[test]
+fn liquidity_dynamic_fee_should_be_calculated_from_least_and_most_abundant_reserves() {
+	let base_fee = Permill::from_percent(1);
+	let reserves = [
+		AssetReserve::new(1_000 * ONE, 12),
+		AssetReserve::new(300_000_000, 6),
+		AssetReserve::new(500 * ONE, 12),
+	];
+
+	assert_eq!(
+		calculate_liquidity_dynamic_fee(&reserves, base_fee, 5),
+		calculate_dynamic_fee(300 * ONE, 1_000 * ONE, base_fee, 5)
+	);
+}
@@ -1,6 +1,8 @@
 mod amplification;
+mod dynamic_fees;
 mod invariants;
 mod multi_assets;
+mod rates;
 mod two_assets;
 
 use crate::types::Balance;
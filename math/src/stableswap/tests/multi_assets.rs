@@ -805,3 +805,108 @@ fn share_price_calculation_should_work_with_6_decimals() {
 		Some((279206572581786940496760242, 279158579738033226972960348441675415837))
 	);
 }
+
+#[test]
+fn calculate_proportional_amounts_should_work_when_shares_are_part_of_issuance() {
+	let balances: [AssetReserve; 3] = [
+		AssetReserve::new(1_000_000_000, 6),
+		AssetReserve::new(3_000_000_000_000_000, 12),
+		AssetReserve::new(5_000_000_000_000_000_000_000, 18),
+	];
+
+	let result = calculate_proportional_amounts(&balances, 25, 100);
+
+	assert_eq!(
+		result,
+		Some(vec![250_000_000, 750_000_000_000_000, 1_250_000_000_000_000_000_000])
+	);
+}
+
+#[test]
+fn calculate_proportional_amounts_should_round_down() {
+	let balances: [AssetReserve; 2] = [AssetReserve::new(10, 12), AssetReserve::new(11, 12)];
+
+	let result = calculate_proportional_amounts(&balances, 1, 3);
+
+	assert_eq!(result, Some(vec![3, 3]));
+}
+
+#[test]
+fn calculate_proportional_amounts_should_return_all_reserves_when_all_shares_are_withdrawn() {
+	let balances: [AssetReserve; 2] = [AssetReserve::new(1_000, 12), AssetReserve::new(2_000, 12)];
+
+	let result = calculate_proportional_amounts(&balances, 500, 500);
+
+	assert_eq!(result, Some(vec![1_000, 2_000]));
+}
+
+#[test]
+fn calculate_proportional_amounts_should_fail_when_shares_exceed_issuance() {
+	let balances: [AssetReserve; 2] = [AssetReserve::new(1_000, 12), AssetReserve::new(2_000, 12)];
+
+	assert_eq!(calculate_proportional_amounts(&balances, 501, 500), None);
+	assert_eq!(calculate_proportional_amounts(&balances, 0, 0), None);
+}
+
+#[test]
+fn calculate_shares_for_new_asset_should_work_when_pool_stays_balanced() {
+	let balances: [AssetReserve; 2] = [
+		AssetReserve::new(1_000_000_000_000_000, 12),
+		AssetReserve::new(1_000_000_000_000_000, 12),
+	];
+	let new_asset = AssetReserve::new(1_000_000_000_000_000, 12);
+
+	let result =
+		calculate_shares_for_new_asset::<D_ITERATIONS>(&balances, new_asset, 100u128, 2_000_000_000_000_000_000);
+
+	let shares = result.unwrap();
+	assert!(shares <= 1_000_000_000_000_000_000);
+	assert!(shares >= 1_000_000_000_000_000_000 - 10_000);
+}
+
+#[test]
+fn calculate_shares_for_new_asset_should_normalize_decimals() {
+	let balances: [AssetReserve; 2] = [
+		AssetReserve::new(1_000_000_000_000_000, 12),
+		AssetReserve::new(1_000_000_000_000_000, 12),
+	];
+
+	let shares_12 = calculate_shares_for_new_asset::<D_ITERATIONS>(
+		&balances,
+		AssetReserve::new(500_000_000_000_000, 12),
+		100u128,
+		2_000_000_000_000_000_000,
+	)
+	.unwrap();
+	let shares_6 = calculate_shares_for_new_asset::<D_ITERATIONS>(
+		&balances,
+		AssetReserve::new(500_000_000, 6),
+		100u128,
+		2_000_000_000_000_000_000,
+	)
+	.unwrap();
+
+	assert_eq!(shares_12, shares_6);
+}
+
+#[test]
+fn calculate_shares_for_new_asset_should_fail_when_pool_is_empty_or_amount_is_zero() {
+	let balances: [AssetReserve; 2] = [
+		AssetReserve::new(1_000_000_000_000_000, 12),
+		AssetReserve::new(1_000_000_000_000_000, 12),
+	];
+
+	assert_eq!(
+		calculate_shares_for_new_asset::<D_ITERATIONS>(&balances, AssetReserve::new(1_000, 12), 100u128, 0),
+		None
+	);
+	assert_eq!(
+		calculate_shares_for_new_asset::<D_ITERATIONS>(
+			&balances,
+			AssetReserve::new(0, 12),
+			100u128,
+			2_000_000_000_000_000_000
+		),
+		None
+	);
+}
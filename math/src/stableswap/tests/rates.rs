@@ -0,0 +1,141 @@
+const D_ITERATIONS: u8 = 128;
+const Y_ITERATIONS: u8 = 64;
+
+use super::*;
+use crate::stableswap::types::AssetReserve;
+use crate::stableswap::*;
+use sp_arithmetic::{FixedPointNumber, FixedU128, Permill};
+
+#[test]
+fn unit_rate_should_not_change_d() {
+	let balances = [AssetReserve::new(1000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((3, 3)),
+		AssetReserve::new(2000 * ONE, 12).with_rate((1, 1)),
+	];
+
+	assert_eq!(
+		calculate_d::<D_ITERATIONS>(&balances, 100),
+		calculate_d::<D_ITERATIONS>(&rated, 100)
+	);
+}
+
+#[test]
+fn d_should_be_calculated_from_rated_reserves() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+
+	assert_eq!(
+		calculate_d::<D_ITERATIONS>(&rated, 100),
+		calculate_d::<D_ITERATIONS>(&equivalent, 100)
+	);
+}
+
+#[test]
+fn out_given_in_should_apply_rate_of_asset_in() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+
+	let rated_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&rated, 0, 1, ONE, 100).unwrap();
+	let equivalent_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&equivalent, 0, 1, 2 * ONE, 100).unwrap();
+
+	assert_eq!(rated_out, equivalent_out);
+	assert!(rated_out > ONE);
+}
+
+#[test]
+fn out_given_in_should_apply_rate_of_asset_out() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+
+	let rated_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&rated, 1, 0, 2 * ONE, 100).unwrap();
+	let equivalent_out = calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&equivalent, 1, 0, 2 * ONE, 100).unwrap();
+
+	assert!((rated_out * 2).abs_diff(equivalent_out) <= 2);
+	assert!(rated_out < ONE);
+}
+
+#[test]
+fn in_given_out_should_apply_rate_of_asset_in() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+
+	let rated_in = calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&rated, 0, 1, 2 * ONE, 100).unwrap();
+	let equivalent_in = calculate_in_given_out::<D_ITERATIONS, Y_ITERATIONS>(&equivalent, 0, 1, 2 * ONE, 100).unwrap();
+
+	assert!((rated_in * 2).abs_diff(equivalent_in) <= 2);
+	assert!(rated_in > ONE);
+}
+
+#[test]
+fn withdraw_one_asset_should_apply_rate() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+	let issuance = 4000 * ONE;
+
+	let (rated_out, _) =
+		calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(&rated, 10 * ONE, 0, issuance, 100, Permill::zero())
+			.unwrap();
+	let (equivalent_out, _) = calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		&equivalent,
+		10 * ONE,
+		0,
+		issuance,
+		100,
+		Permill::zero(),
+	)
+	.unwrap();
+
+	assert!((rated_out * 2).abs_diff(equivalent_out) <= 2);
+}
+
+#[test]
+fn share_price_should_be_expressed_in_units_of_rated_asset() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((2, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+	let equivalent = [AssetReserve::new(2000 * ONE, 12), AssetReserve::new(2000 * ONE, 12)];
+	let issuance = 4000 * ONE;
+
+	let rated_price = calculate_share_price::<D_ITERATIONS>(&rated, 100, issuance, 0, None).unwrap();
+	let equivalent_price = calculate_share_price::<D_ITERATIONS>(&equivalent, 100, issuance, 0, None).unwrap();
+
+	let rated_price = FixedU128::checked_from_rational(rated_price.0, rated_price.1).unwrap();
+	let equivalent_price = FixedU128::checked_from_rational(equivalent_price.0, equivalent_price.1).unwrap();
+
+	let diff = rated_price
+		.saturating_mul(FixedU128::from(2))
+		.max(equivalent_price)
+		.saturating_sub(rated_price.saturating_mul(FixedU128::from(2)).min(equivalent_price));
+	assert!(diff < FixedU128::from_rational(1, 1_000_000_000));
+}
+
+#[test]
+fn calculations_should_fail_when_rated_reserve_overflows() {
+	let rated = [
+		AssetReserve::new(1000 * ONE, 12).with_rate((Balance::MAX, 1)),
+		AssetReserve::new(2000 * ONE, 12),
+	];
+
+	assert_eq!(calculate_d::<D_ITERATIONS>(&rated, 100), None);
+	assert_eq!(
+		calculate_out_given_in::<D_ITERATIONS, Y_ITERATIONS>(&rated, 1, 0, ONE, 100),
+		None
+	);
+}
@@ -1,15 +1,27 @@
 use crate::types::Balance;
-use num_traits::Zero;
+use num_traits::{One, Zero};
 
 #[derive(Debug, Clone, Copy)]
 pub struct AssetReserve {
 	pub amount: Balance,
 	pub decimals: u8,
+	/// Rate (numerator, denominator) at which the asset is valued against the other pool assets.
+	/// Normalized reserve is multiplied by this rate before it enters the invariant.
+	pub rate: (Balance, Balance),
 }
 
 impl AssetReserve {
 	pub fn new(amount: Balance, decimals: u8) -> Self {
-		Self { amount, decimals }
+		Self {
+			amount,
+			decimals,
+			rate: (Balance::one(), Balance::one()),
+		}
+	}
+
+	pub fn with_rate(mut self, rate: (Balance, Balance)) -> Self {
+		self.rate = rate;
+		self
 	}
 
 	pub fn is_zero(&self) -> bool {
@@ -0,0 +1,103 @@
+use crate::{
+	ensure, to_balance, to_u256,
+	MathError::{self, Overflow, ZeroReserve},
+};
+use core::convert::TryFrom;
+use primitive_types::U256;
+
+use super::calculate_out_given_in;
+
+type Balance = u128;
+
+/// Result of executing long-term orders of both directions against the pool within single block.
+///
+/// At most one of `pool_amount_a_in` and `pool_amount_b_in` is non-zero - the opposing orders are matched
+/// with each other first and only the remainder is traded with the pool. Part of the remainder which exceeds
+/// the max amount allowed to be sold to the pool in single block is left unsold.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct LongTermOrdersExecution {
+	/// Amount of asset B received by sellers of asset A.
+	pub amount_b_out: Balance,
+	/// Amount of asset A received by sellers of asset B.
+	pub amount_a_out: Balance,
+	/// Amount of asset A sold to the pool.
+	pub pool_amount_a_in: Balance,
+	/// Amount of asset B bought from the pool.
+	pub pool_amount_b_out: Balance,
+	/// Amount of asset B sold to the pool.
+	pub pool_amount_b_in: Balance,
+	/// Amount of asset A bought from the pool.
+	pub pool_amount_a_out: Balance,
+	/// Amount of asset A which was neither matched nor sold to the pool.
+	pub unsold_amount_a: Balance,
+	/// Amount of asset B which was neither matched nor sold to the pool.
+	pub unsold_amount_b: Balance,
+}
+
+/// Calculate execution of long-term orders within single block.
+///
+/// Sellers of A and sellers of B are first matched with each other at the current spot price of the pool.
+/// The unmatched remainder of the dominant side is sold to the pool, up to the max pool amount in.
+///
+/// - `reserve_a` - reserve of asset A in the pool
+/// - `reserve_b` - reserve of asset B in the pool
+/// - `amount_a_in` - amount of asset A sold by long-term orders in this block
+/// - `amount_b_in` - amount of asset B sold by long-term orders in this block
+/// - `max_pool_amount_a_in` - max amount of asset A which can be sold to the pool in this block
+/// - `max_pool_amount_b_in` - max amount of asset B which can be sold to the pool in this block
+///
+/// Fees are not included.
+pub fn calculate_long_term_orders_execution(
+	reserve_a: Balance,
+	reserve_b: Balance,
+	amount_a_in: Balance,
+	amount_b_in: Balance,
+	max_pool_amount_a_in: Balance,
+	max_pool_amount_b_in: Balance,
+) -> Result<LongTermOrdersExecution, MathError> {
+	ensure!(reserve_a != 0 && reserve_b != 0, ZeroReserve);
+
+	let (reserve_a_hp, reserve_b_hp, amount_a_in_hp, amount_b_in_hp) =
+		to_u256!(reserve_a, reserve_b, amount_a_in, amount_b_in);
+
+	// value of sold A expressed in B at spot price
+	let amount_a_in_value = amount_a_in_hp
+		.checked_mul(reserve_b_hp)
+		.ok_or(Overflow)?
+		.checked_div(reserve_a_hp)
+		.ok_or(Overflow)?;
+
+	if amount_a_in_value >= amount_b_in_hp {
+		let matched_a = to_balance!(amount_b_in_hp
+			.checked_mul(reserve_a_hp)
+			.ok_or(Overflow)?
+			.checked_div(reserve_b_hp)
+			.ok_or(Overflow)?)?;
+		let unmatched_a = amount_a_in.checked_sub(matched_a).ok_or(Overflow)?;
+		let pool_amount_a_in = unmatched_a.min(max_pool_amount_a_in);
+		let pool_amount_b_out = calculate_out_given_in(reserve_a, reserve_b, pool_amount_a_in)?;
+
+		Ok(LongTermOrdersExecution {
+			amount_b_out: amount_b_in.checked_add(pool_amount_b_out).ok_or(Overflow)?,
+			amount_a_out: matched_a,
+			pool_amount_a_in,
+			pool_amount_b_out,
+			unsold_amount_a: unmatched_a.saturating_sub(pool_amount_a_in),
+			..Default::default()
+		})
+	} else {
+		let matched_b = to_balance!(amount_a_in_value)?;
+		let unmatched_b = amount_b_in.checked_sub(matched_b).ok_or(Overflow)?;
+		let pool_amount_b_in = unmatched_b.min(max_pool_amount_b_in);
+		let pool_amount_a_out = calculate_out_given_in(reserve_b, reserve_a, pool_amount_b_in)?;
+
+		Ok(LongTermOrdersExecution {
+			amount_b_out: matched_b,
+			amount_a_out: amount_a_in.checked_add(pool_amount_a_out).ok_or(Overflow)?,
+			pool_amount_b_in,
+			pool_amount_a_out,
+			unsold_amount_b: unmatched_b.saturating_sub(pool_amount_b_in),
+			..Default::default()
+		})
+	}
+}
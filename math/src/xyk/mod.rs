@@ -1,4 +1,5 @@
 mod math;
+pub mod long_term_orders;
 
 #[cfg(test)]
 mod invariants;
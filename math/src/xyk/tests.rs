@@ -138,3 +138,134 @@ fn calculate_shares() {
 		);
 	}
 }
+
+#[test]
+fn long_term_orders_execution_should_work() {
+	use crate::xyk::long_term_orders::LongTermOrdersExecution;
+
+	let cases = vec![
+		(
+			1000,
+			2000,
+			100,
+			0,
+			Ok(LongTermOrdersExecution {
+				amount_b_out: 181,
+				amount_a_out: 0,
+				pool_amount_a_in: 100,
+				pool_amount_b_out: 181,
+				pool_amount_b_in: 0,
+				pool_amount_a_out: 0,
+				..Default::default()
+			}),
+			"Only A sold",
+		),
+		(
+			1000,
+			2000,
+			0,
+			200,
+			Ok(LongTermOrdersExecution {
+				amount_b_out: 0,
+				amount_a_out: 90,
+				pool_amount_a_in: 0,
+				pool_amount_b_out: 0,
+				pool_amount_b_in: 200,
+				pool_amount_a_out: 90,
+				..Default::default()
+			}),
+			"Only B sold",
+		),
+		(
+			1000,
+			2000,
+			100,
+			200,
+			Ok(LongTermOrdersExecution {
+				amount_b_out: 200,
+				amount_a_out: 100,
+				..Default::default()
+			}),
+			"Fully matched",
+		),
+		(
+			1000,
+			2000,
+			100,
+			100,
+			Ok(LongTermOrdersExecution {
+				amount_b_out: 195,
+				amount_a_out: 50,
+				pool_amount_a_in: 50,
+				pool_amount_b_out: 95,
+				pool_amount_b_in: 0,
+				pool_amount_a_out: 0,
+				..Default::default()
+			}),
+			"A sellers dominate",
+		),
+		(
+			1000,
+			2000,
+			50,
+			200,
+			Ok(LongTermOrdersExecution {
+				amount_b_out: 100,
+				amount_a_out: 97,
+				pool_amount_a_in: 0,
+				pool_amount_b_out: 0,
+				pool_amount_b_in: 100,
+				pool_amount_a_out: 47,
+				..Default::default()
+			}),
+			"B sellers dominate",
+		),
+		(1000, 2000, 0, 0, Ok(LongTermOrdersExecution::default()), "Nothing sold"),
+		(0, 2000, 100, 100, Err(ZeroReserve), "Zero reserve A"),
+		(1000, 0, 100, 100, Err(ZeroReserve), "Zero reserve B"),
+	];
+
+	for case in cases {
+		assert_eq!(
+			crate::xyk::long_term_orders::calculate_long_term_orders_execution(
+				case.0,
+				case.1,
+				case.2,
+				case.3,
+				Balance::MAX,
+				Balance::MAX
+			),
+			case.4,
+			"{}",
+			case.5
+		);
+	}
+}
+
+#[test]
+fn long_term_orders_execution_should_not_sell_more_than_max_pool_amount_in() {
+	use crate::xyk::long_term_orders::{calculate_long_term_orders_execution, LongTermOrdersExecution};
+
+	assert_eq!(
+		calculate_long_term_orders_execution(1000, 2000, 100, 100, 30, 30),
+		Ok(LongTermOrdersExecution {
+			amount_b_out: 158,
+			amount_a_out: 50,
+			pool_amount_a_in: 30,
+			pool_amount_b_out: 58,
+			unsold_amount_a: 20,
+			..Default::default()
+		})
+	);
+	assert_eq!(
+		calculate_long_term_orders_execution(1000, 2000, 50, 200, 30, 30),
+		Ok(LongTermOrdersExecution {
+			amount_b_out: 100,
+			amount_a_out: 64,
+			pool_amount_b_in: 30,
+			pool_amount_a_out: 14,
+			unsold_amount_b: 70,
+			..Default::default()
+		})
+	);
+}
@@ -28,7 +28,7 @@ pub use fc_rpc::{
 };
 pub use fc_rpc_core::types::{FeeHistoryCache, FeeHistoryCacheLimit, FilterPool};
 use fp_rpc::{ConvertTransaction, ConvertTransactionRuntimeApi, EthereumRuntimeRPCApi};
-use hydradx_runtime::{opaque::Block, AccountId, Balance, Index};
+use hydradx_runtime::{opaque::Block, AccountId, AssetId, Balance, Index, ItemId};
 use jsonrpsee::RpcModule;
 use sc_client_api::{
 	backend::{Backend, StateBackend, StorageProvider},
@@ -102,8 +102,10 @@ where
 	C::Api: substrate_frame_rpc_system::AccountNonceApi<Block, AccountId, Index>,
 	C::Api: pallet_transaction_payment_rpc::TransactionPaymentRuntimeApi<Block, Balance>,
 	C::Api: BlockBuilderApi<Block>,
+	C::Api: pallet_omnipool_rpc::OmnipoolRuntimeApi<Block, AssetId, ItemId>,
 	P: TransactionPool + Sync + Send + 'static,
 {
+	use pallet_omnipool_rpc::{Omnipool, OmnipoolApiServer};
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
 	use substrate_frame_rpc_system::{System, SystemApiServer};
 
@@ -115,7 +117,8 @@ where
 	} = deps;
 
 	module.merge(System::new(client.clone(), pool, deny_unsafe).into_rpc())?;
-	module.merge(TransactionPayment::new(client).into_rpc())?;
+	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
+	module.merge(Omnipool::new(client).into_rpc())?;
 
 	Ok(module)
 }
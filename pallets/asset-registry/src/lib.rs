@@ -46,7 +46,7 @@ pub use pallet::*;
 
 pub use crate::types::{AssetDetails, AssetMetadata};
 use frame_support::BoundedVec;
-use hydradx_traits::{AssetKind, CreateRegistry, InspectRegistry, Registry, ShareTokenRegistry};
+use hydradx_traits::{AssetKind, CreateRegistry, InspectRegistry, MutateRegistry, Registry, ShareTokenRegistry};
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -390,24 +390,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::RegistryOrigin::ensure_origin(origin)?;
 
-			ensure!(Self::assets(asset_id).is_some(), Error::<T>::AssetNotFound);
-
-			let b_symbol = Self::to_bounded_name(symbol)?;
-
-			let metadata = AssetMetadata::<BoundedVec<u8, T::StringLimit>> {
-				symbol: b_symbol.clone(),
-				decimals,
-			};
-
-			AssetMetadataMap::<T>::insert(asset_id, metadata);
-
-			Self::deposit_event(Event::MetadataSet {
-				asset_id,
-				symbol: b_symbol,
-				decimals,
-			});
-
-			Ok(())
+			Self::do_set_metadata(asset_id, symbol, decimals)
 		}
 
 		/// Set asset native location.
@@ -540,6 +523,28 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
+	/// Set symbol and decimals of registered asset.
+	pub fn do_set_metadata(asset_id: T::AssetId, symbol: Vec<u8>, decimals: u8) -> DispatchResult {
+		ensure!(Self::assets(asset_id).is_some(), Error::<T>::AssetNotFound);
+
+		let b_symbol = Self::to_bounded_name(symbol)?;
+
+		let metadata = AssetMetadata::<BoundedVec<u8, T::StringLimit>> {
+			symbol: b_symbol.clone(),
+			decimals,
+		};
+
+		AssetMetadataMap::<T>::insert(asset_id, metadata);
+
+		Self::deposit_event(Event::MetadataSet {
+			asset_id,
+			symbol: b_symbol,
+			decimals,
+		});
+
+		Ok(())
+	}
+
 	/// Return location for given asset.
 	pub fn asset_to_location(asset_id: T::AssetId) -> Option<T::AssetNativeLocation> {
 		Self::locations(asset_id)
@@ -649,3 +654,11 @@ impl<T: Config> InspectRegistry<T::AssetId> for Pallet<T> {
 		Some(asset_metadata.symbol.into_inner())
 	}
 }
+
+impl<T: Config> MutateRegistry<T::AssetId> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn set_metadata(asset_id: T::AssetId, symbol: &[u8], decimals: u8) -> Result<(), Self::Error> {
+		Self::do_set_metadata(asset_id, symbol.to_vec(), decimals)
+	}
+}
@@ -17,7 +17,7 @@
 
 pub use crate as pallet_circuit_breaker;
 use frame_support::traits::{Contains, GenesisBuild};
-pub use frame_support::traits::{Everything, OnFinalize};
+pub use frame_support::traits::{Everything, Nothing, OnFinalize};
 pub use frame_support::{assert_noop, assert_ok, parameter_types};
 
 use frame_system::EnsureRoot;
@@ -217,6 +217,7 @@ impl pallet_omnipool::Config for Test {
 	type MinimumTradingLimit = MinTradeAmount;
 	type MinimumPoolLiquidity = MinAddedLiquidity;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
@@ -225,6 +226,11 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type RouteExecutor = ();
+	type AmmTradeWeights = ();
+	type FeeDiscount = ();
+	type LockedPositionOwners = Nothing;
+	type MaxDelistings = ConstU32<10>;
 }
 
 pub struct CircuitBreakerHooks<T>(PhantomData<T>);
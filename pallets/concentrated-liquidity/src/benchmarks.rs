@@ -0,0 +1,259 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+use frame_system::RawOrigin;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use sp_runtime::traits::One;
+
+const SEED: u32 = 1;
+
+const ONE: Balance = 1_000_000_000_000;
+
+const ASSET_A: u32 = 1;
+const ASSET_B: u32 = 2;
+
+fn register_assets<T: Config>() -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	T::BenchmarkHelper::register_asset(ASSET_A.into(), 12)?;
+	T::BenchmarkHelper::register_asset(ASSET_B.into(), 12)
+}
+
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId
+where
+	T::AssetId: From<u32>,
+{
+	let caller: T::AccountId = account(name, index, SEED);
+	T::Currency::deposit(ASSET_A.into(), &caller, 1_000_000 * ONE).unwrap();
+	T::Currency::deposit(ASSET_B.into(), &caller, 1_000_000 * ONE).unwrap();
+	caller
+}
+
+/// Create pool with one wide and one narrow position around current price.
+/// Benchmarked trades are large enough to cross boundary of the narrow position.
+fn initialize_pool<T: Config>() -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	register_assets::<T>()?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::create_pool(
+		successful_origin,
+		ASSET_A.into(),
+		ASSET_B.into(),
+		Permill::from_rational(3u32, 1000u32),
+		10,
+		FixedU128::one(),
+	)?;
+
+	let lp = funded_account::<T>("lp", 0);
+	Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(lp.clone()).into(),
+		ASSET_A.into(),
+		ASSET_B.into(),
+		-60_000,
+		60_000,
+		100_000 * ONE,
+		100_000 * ONE,
+	)?;
+	Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(lp).into(),
+		ASSET_A.into(),
+		ASSET_B.into(),
+		-100,
+		100,
+		1_000 * ONE,
+		1_000 * ONE,
+	)
+}
+
+/// Create pool with one wide position and `c` nested positions around current price.
+/// Benchmarked trades cross lower (or upper) boundaries of all nested positions.
+fn initialize_pool_with_crossable_ticks<T: Config>(c: u32) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	register_assets::<T>()?;
+
+	let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+	Pallet::<T>::create_pool(
+		successful_origin,
+		ASSET_A.into(),
+		ASSET_B.into(),
+		Permill::from_rational(3u32, 1000u32),
+		10,
+		FixedU128::one(),
+	)?;
+
+	let lp = funded_account::<T>("lp", 0);
+	Pallet::<T>::add_liquidity(
+		RawOrigin::Signed(lp.clone()).into(),
+		ASSET_A.into(),
+		ASSET_B.into(),
+		-60_000,
+		60_000,
+		100_000 * ONE,
+		100_000 * ONE,
+	)?;
+	for i in 1..=c as i32 {
+		Pallet::<T>::add_liquidity(
+			RawOrigin::Signed(lp.clone()).into(),
+			ASSET_A.into(),
+			ASSET_B.into(),
+			-10 * i,
+			10 * i,
+			10 * ONE,
+			10 * ONE,
+		)?;
+	}
+
+	Ok(())
+}
+
+benchmarks! {
+	 where_clause {  where T::AssetId: From<u32>,
+	}
+
+	create_pool {
+		register_assets::<T>()?;
+
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		let asset_a: T::AssetId = ASSET_A.into();
+		let asset_b: T::AssetId = ASSET_B.into();
+	}: _<T::RuntimeOrigin>(successful_origin, asset_a, asset_b, Permill::from_rational(3u32, 1000u32), 10, FixedU128::one())
+	verify {
+		assert!(Pallet::<T>::pools(Pallet::<T>::ordered_assets(asset_a, asset_b)).is_some());
+	}
+
+	add_liquidity {
+		initialize_pool::<T>()?;
+
+		let caller = funded_account::<T>("caller", 1);
+		let position_id = Pallet::<T>::next_position_id();
+	}: _(RawOrigin::Signed(caller), ASSET_A.into(), ASSET_B.into(), -200, 200, 1_000 * ONE, 1_000 * ONE)
+	verify {
+		assert!(Pallet::<T>::positions(position_id).is_some());
+	}
+
+	remove_liquidity {
+		initialize_pool::<T>()?;
+
+		let caller = funded_account::<T>("caller", 1);
+		let position_id = Pallet::<T>::next_position_id();
+		Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), ASSET_A.into(), ASSET_B.into(), -200, 200, 1_000 * ONE, 1_000 * ONE)?;
+
+		let seller = funded_account::<T>("seller", 2);
+		Pallet::<T>::sell(RawOrigin::Signed(seller).into(), ASSET_A.into(), ASSET_B.into(), 100 * ONE, 0)?;
+
+		let liquidity = Pallet::<T>::positions(position_id).unwrap().liquidity;
+	}: _(RawOrigin::Signed(caller), position_id, liquidity)
+	verify {
+		assert!(Pallet::<T>::positions(position_id).is_none());
+	}
+
+	collect_fees {
+		initialize_pool::<T>()?;
+
+		let caller = funded_account::<T>("caller", 1);
+		let position_id = Pallet::<T>::next_position_id();
+		Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(), ASSET_A.into(), ASSET_B.into(), -200, 200, 1_000 * ONE, 1_000 * ONE)?;
+
+		let seller = funded_account::<T>("seller", 2);
+		Pallet::<T>::sell(RawOrigin::Signed(seller).into(), ASSET_A.into(), ASSET_B.into(), 100 * ONE, 0)?;
+	}: _(RawOrigin::Signed(caller), position_id)
+	verify {
+		assert!(Pallet::<T>::positions(position_id).unwrap().tokens_owed_0.is_zero());
+	}
+
+	sell {
+		let c in 0..T::MaxTickCrossings::get();
+
+		initialize_pool_with_crossable_ticks::<T>(c)?;
+
+		let seller = funded_account::<T>("seller", 2);
+		let amount = 10_000 * ONE;
+	}: _(RawOrigin::Signed(seller.clone()), ASSET_A.into(), ASSET_B.into(), amount, 0)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_A.into(), &seller), 1_000_000 * ONE - amount);
+		assert!(Pallet::<T>::pools(Pallet::<T>::ordered_assets(ASSET_A.into(), ASSET_B.into())).unwrap().tick < -10 * c as i32);
+	}
+
+	buy {
+		let c in 0..T::MaxTickCrossings::get();
+
+		initialize_pool_with_crossable_ticks::<T>(c)?;
+
+		let buyer = funded_account::<T>("buyer", 2);
+		let amount = 10_000 * ONE;
+	}: _(RawOrigin::Signed(buyer.clone()), ASSET_B.into(), ASSET_A.into(), amount, 20_000 * ONE)
+	verify {
+		assert_eq!(T::Currency::free_balance(ASSET_B.into(), &buyer), 1_000_000 * ONE + amount);
+		assert!(Pallet::<T>::pools(Pallet::<T>::ordered_assets(ASSET_A.into(), ASSET_B.into())).unwrap().tick < -10 * c as i32);
+	}
+
+	router_execution_sell {
+		let c in 1..2;	// if c == 2, calculate_sell is executed
+		let e in 0..1;	// if e == 1, execute_sell is executed
+
+		initialize_pool::<T>()?;
+
+		let seller = funded_account::<T>("seller", 2);
+		let amount = 2_000 * ONE;
+	}: {
+		for _ in 1..c {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_sell(PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount).is_ok());
+		}
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_sell(RawOrigin::Signed(seller.clone()).into(), PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount, 0).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_A.into(), &seller), 1_000_000 * ONE - amount);
+		}
+	}
+
+	router_execution_buy {
+		let c in 1..2;	// if c == 2, calculate_buy is executed
+		let e in 0..1;	// if e == 1, execute_buy is executed
+
+		initialize_pool::<T>()?;
+
+		let buyer = funded_account::<T>("buyer", 2);
+		let amount = 2_000 * ONE;
+	}: {
+		for _ in 1..c {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::calculate_buy(PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount).is_ok());
+		}
+		if e != 0 {
+			assert!(<Pallet::<T> as TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance>>::execute_buy(RawOrigin::Signed(buyer.clone()).into(), PoolType::ConcentratedLiquidity, ASSET_A.into(), ASSET_B.into(), amount, 3_000 * ONE).is_ok());
+		}
+	}
+	verify {
+		if e != 0 {
+			assert_eq!(T::Currency::free_balance(ASSET_B.into(), &buyer), 1_000_000 * ONE + amount);
+		}
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,1191 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Concentrated liquidity pallet
+//!
+//! Constant product pools where liquidity is provided within a chosen price range.
+//!
+//! ## Overview
+//!
+//! Each pool is identified by a pair of assets. Price of the pool is the amount of asset 1 per unit of asset 0,
+//! where asset 0 is the asset with lower asset id.
+//!
+//! Price space is divided into ticks - price at tick `i` is `1.0001^i`. Liquidity provider chooses a price range
+//! delimited by two ticks and their liquidity is used for trades only while the price of the pool is within
+//! this range. Ticks used as range boundaries must be multiples of the pool's tick spacing.
+//!
+//! Each position is represented by NFT instance. Trade fees are accounted per position and can be collected
+//! at any time by the position owner.
+//!
+//! All pools share one pallet account which holds the reserves.
+//!
+//! ### Terminology
+//!
+//! * **Tick:** discrete price point, price at tick `i` is `1.0001^i`
+//! * **Position:** liquidity provided within a price range, represented by NFT instance
+//! * **Active liquidity:** sum of liquidity of positions whose range contains current price
+//!
+//! ## Interface
+//!
+//! ### Dispatchable Functions
+//!
+//! * `create_pool` - Creates new pool of given asset pair with initial price.
+//! * `add_liquidity` - Provides liquidity within a price range. Mints corresponding position NFT.
+//! * `remove_liquidity` - Removes liquidity of a position together with accrued fees. Burns position NFT if all
+//!   liquidity is removed.
+//! * `collect_fees` - Transfers fees accrued by a position to its owner.
+//! * `sell` - Trades an asset in for asset out by selling given amount of asset in.
+//! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+#![allow(clippy::unused_unit)]
+
+use frame_support::pallet_prelude::*;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::{require_transactional, transactional, PalletId};
+use frame_system::ensure_signed;
+use frame_system::pallet_prelude::OriginFor;
+use hydra_dx_math::concentrated_liquidity::types::{Liquidity, SqrtPrice, Tick};
+use hydra_dx_math::concentrated_liquidity::{
+	amounts_for_liquidity, compute_swap_step, fee_growth_delta, fees_owed, liquidity_for_amounts, sqrt_price_at_tick,
+	sqrt_price_from_price, tick_at_sqrt_price, virtual_reserves, MAX_TICK, MIN_TICK,
+};
+use hydra_dx_math::ratio::Ratio;
+use hydradx_traits::{OnLiquidityChangedHandler, OnTradeHandler, Source};
+use orml_traits::MultiCurrency;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, CheckedAdd, One, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarks;
+
+mod tick_bitmap;
+pub mod trade_execution;
+pub mod types;
+pub mod weights;
+
+use crate::types::{Balance, PoolInfo, Position, SwapResult, TickInfo};
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+#[cfg(feature = "runtime-benchmarks")]
+pub use crate::types::BenchmarkHelper;
+
+/// NFT class id type of provided nft implementation
+pub type NFTCollectionIdOf<T> =
+	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use codec::HasCompact;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member
+			+ Parameter
+			+ Ord
+			+ Default
+			+ Copy
+			+ HasCompact
+			+ MaybeSerializeDeserialize
+			+ MaxEncodedLen
+			+ TypeInfo;
+
+		/// Multi currency mechanism
+		type Currency: MultiCurrency<Self::AccountId, CurrencyId = Self::AssetId, Balance = Balance>;
+
+		/// Origin that can create pools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Position identifier type
+		type PositionItemId: Member + Parameter + Default + Copy + HasCompact + AtLeast32BitUnsigned + MaxEncodedLen;
+
+		/// Collection id type
+		type CollectionId: TypeInfo + MaxEncodedLen;
+
+		/// Non fungible class id
+		#[pallet::constant]
+		type NFTCollectionId: Get<NFTCollectionIdOf<Self>>;
+
+		/// Non fungible handling - mint,burn, check owner
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = Self::PositionItemId, CollectionId = Self::CollectionId>;
+
+		/// Maximum trade fee of a pool.
+		#[pallet::constant]
+		type MaxFee: Get<Permill>;
+
+		/// Minimum trading limit
+		#[pallet::constant]
+		type MinTradingLimit: Get<Balance>;
+
+		/// Maximum number of initialized ticks which can be crossed by a single trade.
+		#[pallet::constant]
+		type MaxTickCrossings: Get<u32>;
+
+		/// Minimum liquidity of a position.
+		#[pallet::constant]
+		type MinLiquidity: Get<Liquidity>;
+
+		/// Oracle source identifier for this pallet.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// AMM handlers
+		type AMMHandler: OnTradeHandler<Self::AssetId, Balance, Ratio>
+			+ OnLiquidityChangedHandler<Self::AssetId, Balance, Ratio>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId>;
+	}
+
+	#[pallet::storage]
+	/// State of pools. Maps ordered asset pair to pool state.
+	#[pallet::getter(fn pools)]
+	pub type Pools<T: Config> = StorageMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), PoolInfo>;
+
+	#[pallet::storage]
+	/// State of initialized ticks of a pool.
+	#[pallet::getter(fn ticks)]
+	pub type Ticks<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), Twox64Concat, Tick, TickInfo>;
+
+	#[pallet::storage]
+	/// Bitmap of initialized ticks of a pool. Maps (level, word position) to bitmap word - see `tick_bitmap`.
+	pub(crate) type TickBitmap<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, (T::AssetId, T::AssetId), Twox64Concat, (u8, i32), u128, ValueQuery>;
+
+	#[pallet::storage]
+	/// LP positions. Maps NFT instance id to corresponding position
+	#[pallet::getter(fn positions)]
+	pub type Positions<T: Config> = StorageMap<_, Blake2_128Concat, T::PositionItemId, Position<T::AssetId>>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn next_position_id)]
+	/// Position ids sequencer
+	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A pool was created.
+		PoolCreated {
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			fee: Permill,
+			tick_spacing: u32,
+			sqrt_price: SqrtPrice,
+		},
+		/// LP Position was created and NFT instance minted.
+		PositionCreated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_0: T::AssetId,
+			asset_1: T::AssetId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Liquidity was removed from a position.
+		LiquidityRemoved {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			liquidity: Liquidity,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// Fees accrued by a position were collected.
+		FeesCollected {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			amount_0: Balance,
+			amount_1: Balance,
+		},
+		/// LP Position was destroyed and NFT instance burned.
+		PositionDestroyed {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+		},
+		/// Sell trade executed.
+		SellExecuted {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee_amount: Balance,
+		},
+		/// Buy trade executed.
+		BuyExecuted {
+			who: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			fee_amount: Balance,
+		},
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Creating a pool with same assets is not allowed.
+		SameAssets,
+		/// Pool of given asset pair already exists.
+		PoolAlreadyExists,
+		/// Pool of given asset pair does not exist.
+		PoolNotFound,
+		/// Fee is above maximum allowed fee.
+		InvalidFee,
+		/// Tick spacing must be greater than zero and lower than maximum tick.
+		InvalidTickSpacing,
+		/// Initial price is zero or out of supported range.
+		InvalidInitialPrice,
+		/// Ticks are out of range, not ordered or not multiples of tick spacing.
+		InvalidTickRange,
+		/// Provided amounts result in zero liquidity or removed liquidity is zero.
+		ZeroLiquidity,
+		/// Liquidity of a position is below minimum allowed liquidity.
+		InsufficientLiquidityProvided,
+		/// Amount required to provide liquidity exceeds given maximum.
+		AmountLimitExceeded,
+		/// Position has not been found.
+		PositionNotFound,
+		/// Signed account is not owner of position instance.
+		Forbidden,
+		/// Position does not have enough liquidity.
+		InsufficientPositionLiquidity,
+		/// Traded amount is below minimum allowed limit
+		InsufficientTradingAmount,
+		/// Balance too low
+		InsufficientBalance,
+		/// Pool does not have enough liquidity to execute the trade.
+		InsufficientLiquidity,
+		/// Calculated amount out from sell trade is zero.
+		ZeroAmountOut,
+		/// Minimum limit has not been reached during trade.
+		BuyLimitNotReached,
+		/// Maximum limit has been exceeded during trade.
+		SellLimitExceeded,
+		/// Trade would cross more initialized ticks than allowed in a single trade.
+		MaxTickCrossingsReached,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create new pool of `asset_a` and `asset_b` at given initial price.
+		///
+		/// Can be called only by `AuthorityOrigin`. One pool per asset pair is allowed.
+		///
+		/// Parameters:
+		/// - `origin`: Must be `T::AuthorityOrigin`
+		/// - `asset_a`: First asset of the pool
+		/// - `asset_b`: Second asset of the pool
+		/// - `fee`: Trade fee charged in asset coming into the pool
+		/// - `tick_spacing`: Ticks used as position boundaries must be multiples of tick spacing
+		/// - `initial_price`: Price of `asset_a` denominated in `asset_b`
+		///
+		/// Emits `PoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[transactional]
+		pub fn create_pool(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			fee: Permill,
+			tick_spacing: u32,
+			initial_price: FixedU128,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(asset_a != asset_b, Error::<T>::SameAssets);
+			ensure!(fee <= T::MaxFee::get(), Error::<T>::InvalidFee);
+			ensure!(
+				tick_spacing > 0 && tick_spacing <= MAX_TICK as u32,
+				Error::<T>::InvalidTickSpacing
+			);
+			ensure!(initial_price > FixedU128::zero(), Error::<T>::InvalidInitialPrice);
+
+			let (asset_0, asset_1) = Self::ordered_assets(asset_a, asset_b);
+			ensure!(
+				!Pools::<T>::contains_key((asset_0, asset_1)),
+				Error::<T>::PoolAlreadyExists
+			);
+
+			let price = if asset_a == asset_0 {
+				initial_price
+			} else {
+				initial_price.reciprocal().ok_or(Error::<T>::InvalidInitialPrice)?
+			};
+
+			let sqrt_price = sqrt_price_from_price(price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+			let tick = tick_at_sqrt_price(sqrt_price).map_err(|_| Error::<T>::InvalidInitialPrice)?;
+
+			// ensure collection is created, we can simply ignore the error if it was already created.
+			let _ = T::NFTHandler::create_collection(
+				&T::NFTCollectionId::get(),
+				&Self::pool_account(),
+				&Self::pool_account(),
+			);
+
+			Pools::<T>::insert(
+				(asset_0, asset_1),
+				PoolInfo {
+					fee,
+					tick_spacing,
+					sqrt_price,
+					tick,
+					..Default::default()
+				},
+			);
+
+			Self::deposit_event(Event::PoolCreated {
+				asset_0,
+				asset_1,
+				fee,
+				tick_spacing,
+				sqrt_price,
+			});
+
+			Ok(())
+		}
+
+		/// Provide liquidity to the pool of `asset_a` and `asset_b` within price range `[tick_lower, tick_upper)`.
+		///
+		/// Maximum liquidity which can be provided with given amounts at current price is added.
+		/// If current price is outside of the range, only one asset is transferred.
+		/// Provided liquidity must be at least `T::MinLiquidity`.
+		///
+		/// Position NFT is minted for the origin.
+		///
+		/// Parameters:
+		/// - `origin`: Liquidity provider
+		/// - `asset_a`, `asset_b`: Assets of the pool
+		/// - `tick_lower`, `tick_upper`: Boundaries of the price range, must be multiples of pool's tick spacing
+		/// - `amount_a_max`, `amount_b_max`: Maximum amounts of assets to provide
+		///
+		/// Emits `PositionCreated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn add_liquidity(
+			origin: OriginFor<T>,
+			asset_a: T::AssetId,
+			asset_b: T::AssetId,
+			tick_lower: Tick,
+			tick_upper: Tick,
+			amount_a_max: Balance,
+			amount_b_max: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (pool_key, mut pool) = Self::load_pool(asset_a, asset_b)?;
+			let (asset_0, asset_1) = pool_key;
+			let (amount_0_max, amount_1_max) = if asset_a == asset_0 {
+				(amount_a_max, amount_b_max)
+			} else {
+				(amount_b_max, amount_a_max)
+			};
+
+			Self::ensure_valid_ticks(&pool, tick_lower, tick_upper)?;
+
+			let sqrt_price_lower = sqrt_price_at_tick(tick_lower).map_err(|_| ArithmeticError::Overflow)?;
+			let sqrt_price_upper = sqrt_price_at_tick(tick_upper).map_err(|_| ArithmeticError::Overflow)?;
+
+			let liquidity = liquidity_for_amounts(
+				pool.sqrt_price,
+				sqrt_price_lower,
+				sqrt_price_upper,
+				amount_0_max,
+				amount_1_max,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+			ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+			ensure!(
+				liquidity >= T::MinLiquidity::get(),
+				Error::<T>::InsufficientLiquidityProvided
+			);
+
+			let (amount_0, amount_1) =
+				amounts_for_liquidity(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, true)
+					.map_err(|_| ArithmeticError::Overflow)?;
+			ensure!(
+				amount_0 <= amount_0_max && amount_1 <= amount_1_max,
+				Error::<T>::AmountLimitExceeded
+			);
+
+			let liquidity_delta = i128::try_from(liquidity).map_err(|_| ArithmeticError::Overflow)?;
+			Self::update_tick(&pool_key, &pool, tick_lower, liquidity_delta, false)?;
+			Self::update_tick(&pool_key, &pool, tick_upper, liquidity_delta, true)?;
+
+			if tick_lower <= pool.tick && pool.tick < tick_upper {
+				pool.liquidity = pool.liquidity.checked_add(liquidity).ok_or(ArithmeticError::Overflow)?;
+			}
+
+			let (fee_growth_inside_0, fee_growth_inside_1) =
+				Self::fee_growth_inside(&pool_key, &pool, tick_lower, tick_upper);
+
+			T::Currency::transfer(asset_0, &who, &Self::pool_account(), amount_0)?;
+			T::Currency::transfer(asset_1, &who, &Self::pool_account(), amount_1)?;
+
+			let position_id = Self::create_and_mint_position_instance(&who)?;
+
+			Positions::<T>::insert(
+				position_id,
+				Position {
+					asset_0,
+					asset_1,
+					tick_lower,
+					tick_upper,
+					liquidity,
+					fee_growth_inside_0_last: fee_growth_inside_0,
+					fee_growth_inside_1_last: fee_growth_inside_1,
+					tokens_owed_0: Balance::zero(),
+					tokens_owed_1: Balance::zero(),
+				},
+			);
+			Pools::<T>::insert(pool_key, &pool);
+
+			Self::notify_liquidity_changed(&pool_key, &pool, amount_0, amount_1)?;
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id,
+				owner: who,
+				asset_0,
+				asset_1,
+				tick_lower,
+				tick_upper,
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			Ok(())
+		}
+
+		/// Remove `liquidity` from given position.
+		///
+		/// Fees accrued by the position are transferred to the owner together with withdrawn amounts.
+		/// If all liquidity is removed, position is destroyed and NFT is burned.
+		/// Otherwise remaining liquidity of the position must be at least `T::MinLiquidity`.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: The identifier of position which liquidity is removed from
+		/// - `liquidity`: Amount of liquidity to remove
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		/// Emits `PositionDestroyed` event when all liquidity is removed.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::AMMHandler::on_liquidity_changed_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			liquidity: Liquidity,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!liquidity.is_zero(), Error::<T>::ZeroLiquidity);
+
+			let mut position = Self::load_owned_position(&who, position_id)?;
+			ensure!(
+				position.liquidity >= liquidity,
+				Error::<T>::InsufficientPositionLiquidity
+			);
+
+			let (pool_key, mut pool) = Self::load_pool(position.asset_0, position.asset_1)?;
+
+			Self::accrue_fees(&pool_key, &pool, &mut position)?;
+
+			let sqrt_price_lower = sqrt_price_at_tick(position.tick_lower).map_err(|_| ArithmeticError::Overflow)?;
+			let sqrt_price_upper = sqrt_price_at_tick(position.tick_upper).map_err(|_| ArithmeticError::Overflow)?;
+
+			let (amount_0, amount_1) =
+				amounts_for_liquidity(pool.sqrt_price, sqrt_price_lower, sqrt_price_upper, liquidity, false)
+					.map_err(|_| ArithmeticError::Overflow)?;
+
+			let liquidity_delta = i128::try_from(liquidity)
+				.map_err(|_| ArithmeticError::Overflow)?
+				.checked_neg()
+				.ok_or(ArithmeticError::Overflow)?;
+			Self::update_tick(&pool_key, &pool, position.tick_lower, liquidity_delta, false)?;
+			Self::update_tick(&pool_key, &pool, position.tick_upper, liquidity_delta, true)?;
+
+			if position.tick_lower <= pool.tick && pool.tick < position.tick_upper {
+				pool.liquidity = pool
+					.liquidity
+					.checked_sub(liquidity)
+					.ok_or(ArithmeticError::Underflow)?;
+			}
+
+			position.liquidity = position
+				.liquidity
+				.checked_sub(liquidity)
+				.ok_or(ArithmeticError::Underflow)?;
+			ensure!(
+				position.liquidity.is_zero() || position.liquidity >= T::MinLiquidity::get(),
+				Error::<T>::InsufficientLiquidityProvided
+			);
+
+			let fees_0 = sp_std::mem::take(&mut position.tokens_owed_0);
+			let fees_1 = sp_std::mem::take(&mut position.tokens_owed_1);
+
+			T::Currency::transfer(
+				position.asset_0,
+				&Self::pool_account(),
+				&who,
+				amount_0.checked_add(fees_0).ok_or(ArithmeticError::Overflow)?,
+			)?;
+			T::Currency::transfer(
+				position.asset_1,
+				&Self::pool_account(),
+				&who,
+				amount_1.checked_add(fees_1).ok_or(ArithmeticError::Overflow)?,
+			)?;
+
+			Pools::<T>::insert(pool_key, &pool);
+
+			Self::notify_liquidity_changed(&pool_key, &pool, amount_0, amount_1)?;
+
+			if !fees_0.is_zero() || !fees_1.is_zero() {
+				Self::deposit_event(Event::FeesCollected {
+					position_id,
+					owner: who.clone(),
+					amount_0: fees_0,
+					amount_1: fees_1,
+				});
+			}
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				position_id,
+				owner: who.clone(),
+				liquidity,
+				amount_0,
+				amount_1,
+			});
+
+			if position.liquidity.is_zero() {
+				// All liquidity removed, remove position and burn NFT instance
+				Positions::<T>::remove(position_id);
+				T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+
+				Self::deposit_event(Event::PositionDestroyed {
+					position_id,
+					owner: who,
+				});
+			} else {
+				Positions::<T>::insert(position_id, position);
+			}
+
+			Ok(())
+		}
+
+		/// Transfer fees accrued by given position to its owner.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: The identifier of position
+		///
+		/// Emits `FeesCollected` event when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::collect_fees())]
+		#[transactional]
+		pub fn collect_fees(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let mut position = Self::load_owned_position(&who, position_id)?;
+			let (pool_key, pool) = Self::load_pool(position.asset_0, position.asset_1)?;
+
+			Self::accrue_fees(&pool_key, &pool, &mut position)?;
+
+			let amount_0 = sp_std::mem::take(&mut position.tokens_owed_0);
+			let amount_1 = sp_std::mem::take(&mut position.tokens_owed_1);
+
+			T::Currency::transfer(position.asset_0, &Self::pool_account(), &who, amount_0)?;
+			T::Currency::transfer(position.asset_1, &Self::pool_account(), &who, amount_1)?;
+
+			Positions::<T>::insert(position_id, position);
+
+			Self::deposit_event(Event::FeesCollected {
+				position_id,
+				owner: who,
+				amount_0,
+				amount_1,
+			});
+
+			Ok(())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by selling `amount` of `asset_in`.
+		///
+		/// Trade fee is charged in `asset_in`. Trade can cross at most `T::MaxTickCrossings` initialized ticks,
+		/// weight of ticks which were not crossed is refunded.
+		///
+		/// Parameters:
+		/// - `origin`: Trader
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `amount`: Amount of asset sold
+		/// - `min_buy_amount`: Minimum amount required to receive
+		///
+		/// Emits `SellExecuted` event when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::sell(T::MaxTickCrossings::get())
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn sell(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount: Balance,
+			min_buy_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount,
+				Error::<T>::InsufficientBalance
+			);
+
+			let (pool_key, mut pool) = Self::load_pool(asset_in, asset_out)?;
+			let zero_for_one = asset_in == pool_key.0;
+
+			let result = Self::simulate_swap(&pool_key, &pool, zero_for_one, amount, true)?;
+
+			ensure!(!result.amount_out.is_zero(), Error::<T>::ZeroAmountOut);
+			ensure!(result.amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
+
+			T::Currency::transfer(asset_in, &who, &Self::pool_account(), result.amount_in)?;
+			T::Currency::transfer(asset_out, &Self::pool_account(), &who, result.amount_out)?;
+
+			Self::apply_swap(&pool_key, &mut pool, zero_for_one, &result);
+
+			Self::notify_trade(
+				&pool_key,
+				&pool,
+				asset_in,
+				asset_out,
+				result.amount_in,
+				result.amount_out,
+			)?;
+
+			Self::deposit_event(Event::SellExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee_amount: result.fee_amount,
+			});
+
+			Ok(Some(
+				T::WeightInfo::sell(result.crossed_ticks.len() as u32).saturating_add(T::AMMHandler::on_trade_weight()),
+			)
+			.into())
+		}
+
+		/// Execute a swap of `asset_in` for `asset_out` by buying `amount` of `asset_out`.
+		///
+		/// Trade fee is charged in `asset_in`. Trade can cross at most `T::MaxTickCrossings` initialized ticks,
+		/// weight of ticks which were not crossed is refunded.
+		///
+		/// Parameters:
+		/// - `origin`: Trader
+		/// - `asset_out`: ID of asset bought from the pool
+		/// - `asset_in`: ID of asset sold to the pool
+		/// - `amount`: Amount of asset bought
+		/// - `max_sell_amount`: Maximum amount of asset in to be sold
+		///
+		/// Emits `BuyExecuted` event when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::buy(T::MaxTickCrossings::get())
+			.saturating_add(T::AMMHandler::on_trade_weight()))]
+		#[transactional]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset_out: T::AssetId,
+			asset_in: T::AssetId,
+			amount: Balance,
+			max_sell_amount: Balance,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let (pool_key, mut pool) = Self::load_pool(asset_in, asset_out)?;
+			let zero_for_one = asset_in == pool_key.0;
+
+			let result = Self::simulate_swap(&pool_key, &pool, zero_for_one, amount, false)?;
+
+			ensure!(result.amount_in <= max_sell_amount, Error::<T>::SellLimitExceeded);
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= result.amount_in,
+				Error::<T>::InsufficientBalance
+			);
+
+			T::Currency::transfer(asset_in, &who, &Self::pool_account(), result.amount_in)?;
+			T::Currency::transfer(asset_out, &Self::pool_account(), &who, result.amount_out)?;
+
+			Self::apply_swap(&pool_key, &mut pool, zero_for_one, &result);
+
+			Self::notify_trade(
+				&pool_key,
+				&pool,
+				asset_in,
+				asset_out,
+				result.amount_in,
+				result.amount_out,
+			)?;
+
+			Self::deposit_event(Event::BuyExecuted {
+				who,
+				asset_in,
+				asset_out,
+				amount_in: result.amount_in,
+				amount_out: result.amount_out,
+				fee_amount: result.fee_amount,
+			});
+
+			Ok(Some(
+				T::WeightInfo::buy(result.crossed_ticks.len() as u32).saturating_add(T::AMMHandler::on_trade_weight()),
+			)
+			.into())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account which holds reserves of all pools.
+	pub fn pool_account() -> T::AccountId {
+		PalletId(*b"conc/liq").into_account_truncating()
+	}
+
+	/// Order asset pair so that asset with lower id comes first.
+	pub fn ordered_assets(asset_a: T::AssetId, asset_b: T::AssetId) -> (T::AssetId, T::AssetId) {
+		if asset_a < asset_b {
+			(asset_a, asset_b)
+		} else {
+			(asset_b, asset_a)
+		}
+	}
+
+	/// Load state of pool of given asset pair together with its ordered asset pair.
+	fn load_pool(
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<((T::AssetId, T::AssetId), PoolInfo), DispatchError> {
+		let pool_key = Self::ordered_assets(asset_a, asset_b);
+		let pool = Pools::<T>::get(pool_key).ok_or(Error::<T>::PoolNotFound)?;
+		Ok((pool_key, pool))
+	}
+
+	fn load_owned_position(
+		who: &T::AccountId,
+		position_id: T::PositionItemId,
+	) -> Result<Position<T::AssetId>, DispatchError> {
+		ensure!(
+			T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).as_ref() == Some(who),
+			Error::<T>::Forbidden
+		);
+
+		Positions::<T>::get(position_id).ok_or_else(|| Error::<T>::PositionNotFound.into())
+	}
+
+	fn ensure_valid_ticks(pool: &PoolInfo, tick_lower: Tick, tick_upper: Tick) -> DispatchResult {
+		let tick_spacing = pool.tick_spacing as Tick;
+
+		ensure!(
+			tick_lower < tick_upper
+				&& tick_lower >= MIN_TICK
+				&& tick_upper <= MAX_TICK
+				&& tick_lower % tick_spacing == 0
+				&& tick_upper % tick_spacing == 0,
+			Error::<T>::InvalidTickRange
+		);
+
+		Ok(())
+	}
+
+	/// Update liquidity of a tick which is a lower or upper boundary of a position.
+	/// Tick is initialized when first used and removed when no position uses it anymore.
+	fn update_tick(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		tick: Tick,
+		liquidity_delta: i128,
+		upper: bool,
+	) -> DispatchResult {
+		let mut info = Ticks::<T>::get(pool_key, tick).unwrap_or_default();
+
+		if info.liquidity_gross.is_zero() {
+			// By convention, all fee growth before the tick was initialized happened below the tick.
+			if tick <= pool.tick {
+				(info.fee_growth_outside_0, info.fee_growth_outside_1) = pool.fee_growth_global();
+			}
+
+			Self::set_tick_initialized(pool_key, tick);
+		}
+
+		info.liquidity_gross = add_liquidity_delta(info.liquidity_gross, liquidity_delta)?;
+		info.liquidity_net = if upper {
+			info.liquidity_net.checked_sub(liquidity_delta)
+		} else {
+			info.liquidity_net.checked_add(liquidity_delta)
+		}
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if info.liquidity_gross.is_zero() {
+			Ticks::<T>::remove(pool_key, tick);
+			Self::clear_tick_initialized(pool_key, tick);
+		} else {
+			Ticks::<T>::insert(pool_key, tick, info);
+		}
+
+		Ok(())
+	}
+
+	/// Calculate fee growth per unit of liquidity inside given price range.
+	fn fee_growth_inside(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		tick_lower: Tick,
+		tick_upper: Tick,
+	) -> (u128, u128) {
+		let (global_0, global_1) = pool.fee_growth_global();
+		let (lower_0, lower_1) = Ticks::<T>::get(pool_key, tick_lower)
+			.unwrap_or_default()
+			.fee_growth_outside();
+		let (upper_0, upper_1) = Ticks::<T>::get(pool_key, tick_upper)
+			.unwrap_or_default()
+			.fee_growth_outside();
+
+		let (below_0, below_1) = if pool.tick >= tick_lower {
+			(lower_0, lower_1)
+		} else {
+			(global_0.wrapping_sub(lower_0), global_1.wrapping_sub(lower_1))
+		};
+
+		let (above_0, above_1) = if pool.tick < tick_upper {
+			(upper_0, upper_1)
+		} else {
+			(global_0.wrapping_sub(upper_0), global_1.wrapping_sub(upper_1))
+		};
+
+		(
+			global_0.wrapping_sub(below_0).wrapping_sub(above_0),
+			global_1.wrapping_sub(below_1).wrapping_sub(above_1),
+		)
+	}
+
+	/// Add fees earned by the position since its last update to its owed amounts.
+	fn accrue_fees(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		position: &mut Position<T::AssetId>,
+	) -> DispatchResult {
+		let (inside_0, inside_1) = Self::fee_growth_inside(pool_key, pool, position.tick_lower, position.tick_upper);
+
+		let owed_0 = fees_owed(
+			inside_0.wrapping_sub(position.fee_growth_inside_0_last),
+			position.liquidity,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+		let owed_1 = fees_owed(
+			inside_1.wrapping_sub(position.fee_growth_inside_1_last),
+			position.liquidity,
+		)
+		.map_err(|_| ArithmeticError::Overflow)?;
+
+		position.tokens_owed_0 = position
+			.tokens_owed_0
+			.checked_add(owed_0)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.tokens_owed_1 = position
+			.tokens_owed_1
+			.checked_add(owed_1)
+			.ok_or(ArithmeticError::Overflow)?;
+		position.fee_growth_inside_0_last = inside_0;
+		position.fee_growth_inside_1_last = inside_1;
+
+		Ok(())
+	}
+
+	/// Simulate a swap over initialized ticks of a pool without changing any state.
+	///
+	/// Fails if the swap would cross more than `T::MaxTickCrossings` initialized ticks.
+	///
+	/// - `zero_for_one` - asset 0 is sold to the pool, price decreases
+	/// - `amount` - amount of asset in (including fee) when `exact_in`, amount of asset out otherwise
+	pub(crate) fn simulate_swap(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		zero_for_one: bool,
+		amount: Balance,
+		exact_in: bool,
+	) -> Result<SwapResult, DispatchError> {
+		let mut result = SwapResult {
+			sqrt_price: pool.sqrt_price,
+			tick: pool.tick,
+			liquidity: pool.liquidity,
+			fee_growth_global_in: if zero_for_one {
+				pool.fee_growth_global_0
+			} else {
+				pool.fee_growth_global_1
+			},
+			..Default::default()
+		};
+		let mut remaining = amount;
+
+		while !remaining.is_zero() {
+			let next_tick = Self::next_initialized_tick(pool_key, result.tick, zero_for_one);
+
+			let target_tick = next_tick.unwrap_or(if zero_for_one { MIN_TICK } else { MAX_TICK });
+			let target_sqrt_price = sqrt_price_at_tick(target_tick).map_err(|_| ArithmeticError::Overflow)?;
+
+			let step = compute_swap_step(
+				result.sqrt_price,
+				target_sqrt_price,
+				result.liquidity,
+				remaining,
+				pool.fee,
+				exact_in,
+			)
+			.map_err(|_| ArithmeticError::Overflow)?;
+
+			let step_amount_in = step
+				.amount_in
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			remaining = remaining
+				.checked_sub(if exact_in { step_amount_in } else { step.amount_out })
+				.ok_or(ArithmeticError::Overflow)?;
+
+			result.amount_in = result
+				.amount_in
+				.checked_add(step_amount_in)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.amount_out = result
+				.amount_out
+				.checked_add(step.amount_out)
+				.ok_or(ArithmeticError::Overflow)?;
+			result.fee_amount = result
+				.fee_amount
+				.checked_add(step.fee_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			if !result.liquidity.is_zero() {
+				let growth =
+					fee_growth_delta(step.fee_amount, result.liquidity).map_err(|_| ArithmeticError::Overflow)?;
+				result.fee_growth_global_in = result.fee_growth_global_in.wrapping_add(growth);
+			}
+
+			let start_sqrt_price = result.sqrt_price;
+			result.sqrt_price = step.sqrt_price;
+
+			if step.sqrt_price == target_sqrt_price {
+				let Some(tick) = next_tick else {
+					// Price limit reached, there is no more liquidity.
+					break;
+				};
+				ensure!(
+					(result.crossed_ticks.len() as u32) < T::MaxTickCrossings::get(),
+					Error::<T>::MaxTickCrossingsReached
+				);
+
+				let liquidity_net = Ticks::<T>::get(pool_key, tick).unwrap_or_default().liquidity_net;
+				result.liquidity = if zero_for_one {
+					add_liquidity_delta(
+						result.liquidity,
+						liquidity_net.checked_neg().ok_or(ArithmeticError::Overflow)?,
+					)?
+				} else {
+					add_liquidity_delta(result.liquidity, liquidity_net)?
+				};
+				result.crossed_ticks.push((tick, result.fee_growth_global_in));
+				result.tick = if zero_for_one { tick - 1 } else { tick };
+			} else if step.sqrt_price != start_sqrt_price {
+				result.tick = tick_at_sqrt_price(step.sqrt_price).map_err(|_| ArithmeticError::Overflow)?;
+			}
+		}
+
+		ensure!(remaining.is_zero(), Error::<T>::InsufficientLiquidity);
+
+		Ok(result)
+	}
+
+	/// Update pool and crossed ticks with the result of simulated swap.
+	fn apply_swap(pool_key: &(T::AssetId, T::AssetId), pool: &mut PoolInfo, zero_for_one: bool, result: &SwapResult) {
+		for (tick, fee_growth_global_in) in result.crossed_ticks.iter() {
+			let (global_0, global_1) = if zero_for_one {
+				(*fee_growth_global_in, pool.fee_growth_global_1)
+			} else {
+				(pool.fee_growth_global_0, *fee_growth_global_in)
+			};
+
+			Ticks::<T>::mutate(pool_key, tick, |maybe_info| {
+				if let Some(info) = maybe_info {
+					info.fee_growth_outside_0 = global_0.wrapping_sub(info.fee_growth_outside_0);
+					info.fee_growth_outside_1 = global_1.wrapping_sub(info.fee_growth_outside_1);
+				}
+			});
+		}
+
+		pool.sqrt_price = result.sqrt_price;
+		pool.tick = result.tick;
+		pool.liquidity = result.liquidity;
+		if zero_for_one {
+			pool.fee_growth_global_0 = result.fee_growth_global_in;
+		} else {
+			pool.fee_growth_global_1 = result.fee_growth_global_in;
+		}
+
+		Pools::<T>::insert(pool_key, &*pool);
+	}
+
+	/// Weight of crossing maximum number of initialized ticks in a single trade.
+	///
+	/// To be added to weight of trades which are not charged by `sell`/`buy` weight, e.g. in router.
+	pub fn max_tick_crossings_weight() -> Weight {
+		T::WeightInfo::sell(T::MaxTickCrossings::get()).saturating_sub(T::WeightInfo::sell(0))
+	}
+
+	/// Virtual reserves of given asset pair which correspond to active liquidity at current price.
+	pub fn virtual_reserves(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		asset_a: T::AssetId,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (reserve_0, reserve_1) =
+			virtual_reserves(pool.sqrt_price, pool.liquidity).map_err(|_| ArithmeticError::Overflow)?;
+
+		if asset_a == pool_key.0 {
+			Ok((reserve_0, reserve_1))
+		} else {
+			Ok((reserve_1, reserve_0))
+		}
+	}
+
+	fn notify_trade(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		amount_out: Balance,
+	) -> DispatchResult {
+		// Oracle ignores zero liquidity, price has moved outside of all positions.
+		if pool.liquidity.is_zero() {
+			return Ok(());
+		}
+
+		let (liquidity_in, liquidity_out) = Self::virtual_reserves(pool_key, pool, asset_in)?;
+
+		T::AMMHandler::on_trade(
+			T::OracleSource::get(),
+			asset_in,
+			asset_out,
+			amount_in,
+			amount_out,
+			liquidity_in,
+			liquidity_out,
+			Ratio::new(liquidity_in, liquidity_out),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Ok(())
+	}
+
+	fn notify_liquidity_changed(
+		pool_key: &(T::AssetId, T::AssetId),
+		pool: &PoolInfo,
+		amount_0: Balance,
+		amount_1: Balance,
+	) -> DispatchResult {
+		if pool.liquidity.is_zero() {
+			return Ok(());
+		}
+
+		let (liquidity_0, liquidity_1) = Self::virtual_reserves(pool_key, pool, pool_key.0)?;
+
+		T::AMMHandler::on_liquidity_changed(
+			T::OracleSource::get(),
+			pool_key.0,
+			pool_key.1,
+			amount_0,
+			amount_1,
+			liquidity_0,
+			liquidity_1,
+			Ratio::new(liquidity_0, liquidity_1),
+		)
+		.map_err(|(_w, e)| e)?;
+
+		Ok(())
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+}
+
+fn add_liquidity_delta(liquidity: Liquidity, delta: i128) -> Result<Liquidity, DispatchError> {
+	if delta >= 0 {
+		liquidity.checked_add(delta.unsigned_abs())
+	} else {
+		liquidity.checked_sub(delta.unsigned_abs())
+	}
+	.ok_or_else(|| ArithmeticError::Overflow.into())
+}
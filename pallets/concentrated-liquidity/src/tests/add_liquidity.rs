@@ -0,0 +1,299 @@
+use super::*;
+use crate::types::{Position, TickInfo};
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity::{MAX_TICK, MIN_TICK};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn add_liquidity_should_work_when_price_is_within_range() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = ConcentratedLiquidity::next_position_id();
+
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			WIDE_LOWER,
+			WIDE_UPPER,
+			1000 * ONE,
+			1000 * ONE,
+		));
+
+		assert_balance!(ALICE, HDX, 9000 * ONE);
+		assert_balance!(ALICE, DAI, 9000 * ONE);
+		assert_balance!(ConcentratedLiquidity::pool_account(), HDX, 1000 * ONE);
+		assert_balance!(ConcentratedLiquidity::pool_account(), DAI, 1000 * ONE);
+
+		assert_eq!(
+			ConcentratedLiquidity::positions(position_id).unwrap(),
+			Position {
+				asset_0: HDX,
+				asset_1: DAI,
+				tick_lower: WIDE_LOWER,
+				tick_upper: WIDE_UPPER,
+				liquidity: WIDE_LIQUIDITY,
+				fee_growth_inside_0_last: 0,
+				fee_growth_inside_1_last: 0,
+				tokens_owed_0: 0,
+				tokens_owed_1: 0,
+			}
+		);
+		assert_eq!(get_mock_minted_position(position_id), Some(ALICE));
+		assert_eq!(ConcentratedLiquidity::next_position_id(), position_id + 1);
+
+		assert_eq!(
+			ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity,
+			WIDE_LIQUIDITY
+		);
+		assert_eq!(initialized_ticks((HDX, DAI)), vec![WIDE_LOWER, WIDE_UPPER]);
+		assert_eq!(
+			ConcentratedLiquidity::ticks((HDX, DAI), WIDE_LOWER).unwrap(),
+			TickInfo {
+				liquidity_gross: WIDE_LIQUIDITY,
+				liquidity_net: WIDE_LIQUIDITY as i128,
+				fee_growth_outside_0: 0,
+				fee_growth_outside_1: 0,
+			}
+		);
+		assert_eq!(
+			ConcentratedLiquidity::ticks((HDX, DAI), WIDE_UPPER).unwrap(),
+			TickInfo {
+				liquidity_gross: WIDE_LIQUIDITY,
+				liquidity_net: -(WIDE_LIQUIDITY as i128),
+				fee_growth_outside_0: 0,
+				fee_growth_outside_1: 0,
+			}
+		);
+
+		expect_events(vec![Event::PositionCreated {
+			position_id,
+			owner: ALICE,
+			asset_0: HDX,
+			asset_1: DAI,
+			tick_lower: WIDE_LOWER,
+			tick_upper: WIDE_UPPER,
+			liquidity: WIDE_LIQUIDITY,
+			amount_0: 1000 * ONE,
+			amount_1: 1000 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn add_liquidity_should_accept_assets_in_any_order() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			WIDE_LOWER,
+			WIDE_UPPER,
+			1000 * ONE,
+			2000 * ONE,
+		));
+
+		// liquidity is limited by DAI amount, price is 1
+		assert_balance!(ALICE, HDX, 9000 * ONE);
+		assert_balance!(ALICE, DAI, 9000 * ONE);
+		assert_eq!(ConcentratedLiquidity::positions(0).unwrap().liquidity, WIDE_LIQUIDITY);
+	});
+}
+
+#[test]
+fn add_liquidity_should_transfer_only_asset_0_when_range_is_above_price() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100,
+			200,
+			100 * ONE,
+			100 * ONE,
+		));
+
+		assert_balance!(ALICE, HDX, 9900 * ONE);
+		assert_balance!(ALICE, DAI, 10_000 * ONE);
+		assert_eq!(
+			ConcentratedLiquidity::positions(0).unwrap().liquidity,
+			20_151_542_874_862_585
+		);
+		// range does not contain current price
+		assert_eq!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_transfer_only_asset_1_when_range_is_below_price() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			-200,
+			-100,
+			100 * ONE,
+			100 * ONE,
+		));
+
+		assert_balance!(ALICE, HDX, 10_000 * ONE);
+		assert_balance!(ALICE, DAI, 9900 * ONE);
+		assert_eq!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity, 0);
+	});
+}
+
+#[test]
+fn add_liquidity_should_share_ticks_with_existing_positions() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			WIDE_LOWER,
+			WIDE_UPPER,
+			1000 * ONE,
+			1000 * ONE,
+		));
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			WIDE_LOWER,
+			WIDE_UPPER,
+			1000 * ONE,
+			1000 * ONE,
+		));
+
+		assert_eq!(get_mock_minted_position(1), Some(BOB));
+		assert_eq!(initialized_ticks((HDX, DAI)), vec![WIDE_LOWER, WIDE_UPPER]);
+		assert_eq!(
+			ConcentratedLiquidity::ticks((HDX, DAI), WIDE_LOWER)
+				.unwrap()
+				.liquidity_gross,
+			2 * WIDE_LIQUIDITY
+		);
+		assert_eq!(
+			ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity,
+			2 * WIDE_LIQUIDITY
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				WIDE_LOWER,
+				WIDE_UPPER,
+				1000 * ONE,
+				1000 * ONE,
+			),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_tick_range_is_invalid() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		for (lower, upper) in [
+			(100, 100),
+			(100, -100),
+			(-105, 100),
+			(-100, 105),
+			(MIN_TICK - 10, 100),
+			(-100, MAX_TICK + 10),
+		] {
+			assert_noop!(
+				ConcentratedLiquidity::add_liquidity(
+					RuntimeOrigin::signed(ALICE),
+					HDX,
+					DAI,
+					lower,
+					upper,
+					1000 * ONE,
+					1000 * ONE,
+				),
+				Error::<Test>::InvalidTickRange
+			);
+		}
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_provided_amounts_are_zero() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DAI, WIDE_LOWER, WIDE_UPPER, 0, 0,),
+			Error::<Test>::ZeroLiquidity
+		);
+		// range is above current price, only HDX can be provided
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(RuntimeOrigin::signed(ALICE), HDX, DAI, 100, 200, 0, 1000 * ONE),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_liquidity_is_below_min_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				WIDE_LOWER,
+				WIDE_UPPER,
+				100_000,
+				100_000
+			),
+			Error::<Test>::InsufficientLiquidityProvided
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_fail_when_account_has_insufficient_balance() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				WIDE_LOWER,
+				WIDE_UPPER,
+				20_000 * ONE,
+				20_000 * ONE,
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_should_not_limit_number_of_initialized_ticks() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		for i in 1..=100 {
+			assert_ok!(ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				-10 * i,
+				10 * i,
+				ONE,
+				ONE,
+			));
+		}
+
+		let expected: Vec<i32> = (-100..=100).filter(|i| *i != 0).map(|i| 10 * i).collect();
+		assert_eq!(initialized_ticks((HDX, DAI)), expected);
+	});
+}
@@ -0,0 +1,194 @@
+use super::*;
+use crate::types::PoolInfo;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::concentrated_liquidity::MAX_TICK;
+use sp_runtime::traits::BadOrigin;
+
+#[test]
+fn create_pool_should_work() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			Permill::from_rational(3u32, 1000u32),
+			10,
+			FixedU128::one(),
+		));
+
+		assert_eq!(
+			ConcentratedLiquidity::pools((HDX, DAI)).unwrap(),
+			PoolInfo {
+				fee: Permill::from_rational(3u32, 1000u32),
+				tick_spacing: 10,
+				sqrt_price: 1 << 64,
+				tick: 0,
+				liquidity: 0,
+				fee_growth_global_0: 0,
+				fee_growth_global_1: 0,
+			}
+		);
+
+		expect_events(vec![Event::PoolCreated {
+			asset_0: HDX,
+			asset_1: DAI,
+			fee: Permill::from_rational(3u32, 1000u32),
+			tick_spacing: 10,
+			sqrt_price: 1 << 64,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn create_pool_should_order_assets_and_invert_price_when_first_asset_is_greater() {
+	ExtBuilder::default().build().execute_with(|| {
+		// 1 DAI = 2 HDX
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			DAI,
+			HDX,
+			Permill::from_rational(3u32, 1000u32),
+			10,
+			FixedU128::from(2),
+		));
+
+		assert!(ConcentratedLiquidity::pools((DAI, HDX)).is_none());
+
+		let pool = ConcentratedLiquidity::pools((HDX, DAI)).unwrap();
+		assert_eq!(pool.sqrt_price, 13_043_817_825_332_782_212);
+		assert_eq!(pool.tick, -6932);
+	});
+}
+
+#[test]
+fn create_pool_should_set_tick_of_initial_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::create_pool(
+			RuntimeOrigin::root(),
+			HDX,
+			DAI,
+			Permill::from_rational(3u32, 1000u32),
+			10,
+			FixedU128::from(2),
+		));
+
+		let pool = ConcentratedLiquidity::pools((HDX, DAI)).unwrap();
+		assert_eq!(pool.sqrt_price, 26_087_635_650_665_564_424);
+		assert_eq!(pool.tick, 6931);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_called_by_non_authority_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				Permill::from_rational(3u32, 1000u32),
+				10,
+				FixedU128::one(),
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				HDX,
+				Permill::from_rational(3u32, 1000u32),
+				10,
+				FixedU128::one(),
+			),
+			Error::<Test>::SameAssets
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_pool_already_exists() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				DAI,
+				HDX,
+				Permill::from_rational(3u32, 1000u32),
+				10,
+				FixedU128::one(),
+			),
+			Error::<Test>::PoolAlreadyExists
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_fee_exceeds_maximum() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_percent(2),
+				10,
+				FixedU128::one(),
+			),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_tick_spacing_is_invalid() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_rational(3u32, 1000u32),
+				0,
+				FixedU128::one(),
+			),
+			Error::<Test>::InvalidTickSpacing
+		);
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_rational(3u32, 1000u32),
+				MAX_TICK as u32 + 1,
+				FixedU128::one(),
+			),
+			Error::<Test>::InvalidTickSpacing
+		);
+	});
+}
+
+#[test]
+fn create_pool_should_fail_when_initial_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::create_pool(
+				RuntimeOrigin::root(),
+				HDX,
+				DAI,
+				Permill::from_rational(3u32, 1000u32),
+				10,
+				FixedU128::zero(),
+			),
+			Error::<Test>::InvalidInitialPrice
+		);
+	});
+}
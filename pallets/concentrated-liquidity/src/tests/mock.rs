@@ -0,0 +1,302 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for Concentrated liquidity pallet.
+
+use crate::*;
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+use crate as pallet_concentrated_liquidity;
+
+use frame_support::traits::{Everything, GenesisBuild};
+use frame_support::{
+	assert_ok, construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use frame_system::EnsureRoot;
+use orml_traits::parameter_type_with_key;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type AssetId = u32;
+
+pub const HDX: AssetId = 0;
+pub const DAI: AssetId = 2;
+pub const DOT: AssetId = 3;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+pub const CHARLIE: AccountId = 3;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+thread_local! {
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+}
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system,
+		Tokens: orml_tokens,
+		ConcentratedLiquidity: pallet_concentrated_liquidity,
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = AccountId;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = i128;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = ();
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const PositionCollectionId: u32 = 1000;
+	pub const MaxFee: Permill = Permill::from_percent(1);
+	pub const MinTradingLimit: Balance = 1_000;
+	pub const MinLiquidity: u128 = 1_000_000;
+	pub const CLOracleSource: Source = *b"conc/liq";
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PositionItemId = u32;
+	type CollectionId = u32;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type MaxFee = MaxFee;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxTickCrossings = ConstU32<3>;
+	type MinLiquidity = MinLiquidity;
+	type OracleSource = CLOracleSource;
+	type AMMHandler = ();
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+/// Pool parameters used by `ExtBuilder::with_pool`.
+pub struct InitialPool {
+	pub asset_a: AssetId,
+	pub asset_b: AssetId,
+	pub fee: Permill,
+	pub tick_spacing: u32,
+	pub price: FixedU128,
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	pools: Vec<InitialPool>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 10_000 * ONE),
+				(ALICE, DAI, 10_000 * ONE),
+				(BOB, HDX, 10_000 * ONE),
+				(BOB, DAI, 10_000 * ONE),
+				(CHARLIE, HDX, 10_000 * ONE),
+				(CHARLIE, DAI, 10_000 * ONE),
+			],
+			pools: vec![],
+		}
+	}
+}
+
+impl ExtBuilder {
+	/// Create HDX/DAI pool with 0.3% fee, tick spacing 10 and price 1.
+	pub fn with_default_pool(self) -> Self {
+		self.with_pool(InitialPool {
+			asset_a: HDX,
+			asset_b: DAI,
+			fee: Permill::from_rational(3u32, 1000u32),
+			tick_spacing: 10,
+			price: FixedU128::one(),
+		})
+	}
+
+	pub fn with_pool(mut self, pool: InitialPool) -> Self {
+		self.pools.push(pool);
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			for pool in self.pools {
+				assert_ok!(ConcentratedLiquidity::create_pool(
+					RuntimeOrigin::root(),
+					pool.asset_a,
+					pool.asset_b,
+					pool.fee,
+					pool.tick_spacing,
+					pool.price,
+				));
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		let mut owner: Option<AccountId> = None;
+
+		POSITIONS.with(|v| {
+			if let Some(o) = v.borrow().get(instance) {
+				owner = Some((*o).into());
+			}
+		});
+		owner
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			let mut m = v.borrow_mut();
+			m.remove(instance);
+		});
+		Ok(())
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DummyRegistry;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl crate::types::BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(_asset_id: AssetId, _decimals: u8) -> DispatchResult {
+		Ok(())
+	}
+}
+
+/// Initialized ticks of a pool in ascending order.
+pub(crate) fn initialized_ticks(pool_key: (AssetId, AssetId)) -> Vec<i32> {
+	let mut ticks = Vec::new();
+	let mut tick = MIN_TICK - 1;
+	while let Some(next) = ConcentratedLiquidity::next_initialized_tick(&pool_key, tick, false) {
+		ticks.push(next);
+		tick = next;
+	}
+	ticks
+}
+
+pub(crate) fn get_mock_minted_position(position_id: u32) -> Option<u64> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
@@ -0,0 +1,24 @@
+mod add_liquidity;
+mod create_pool;
+pub(crate) mod mock;
+mod remove_liquidity;
+mod tick_bitmap;
+mod trade_execution;
+mod trades;
+
+use crate::*;
+use mock::*;
+
+#[macro_export]
+macro_rules! assert_balance {
+	( $x:expr, $y:expr, $z:expr) => {{
+		assert_eq!(Tokens::free_balance($y, &$x), $z);
+	}};
+}
+
+/// Position `[-6930, 6930)` around price 1 - roughly between price 0.5 and 2.
+pub(crate) const WIDE_LOWER: i32 = -6930;
+pub(crate) const WIDE_UPPER: i32 = 6930;
+
+/// Liquidity of a wide position provided with 1000 HDX and 1000 DAI at price 1.
+pub(crate) const WIDE_LIQUIDITY: u128 = 3_414_963_133_432_563;
@@ -0,0 +1,291 @@
+use super::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn add_wide_position(who: AccountId) -> u32 {
+	let position_id = ConcentratedLiquidity::next_position_id();
+	assert_ok!(ConcentratedLiquidity::add_liquidity(
+		RuntimeOrigin::signed(who),
+		HDX,
+		DAI,
+		WIDE_LOWER,
+		WIDE_UPPER,
+		1000 * ONE,
+		1000 * ONE,
+	));
+	position_id
+}
+
+#[test]
+fn remove_liquidity_should_work_when_all_liquidity_is_removed() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			WIDE_LIQUIDITY,
+		));
+
+		// amounts are rounded down in favor of the pool
+		assert_balance!(ALICE, HDX, 10_000 * ONE - 1);
+		assert_balance!(ALICE, DAI, 10_000 * ONE - 1);
+		assert_balance!(ConcentratedLiquidity::pool_account(), HDX, 1);
+		assert_balance!(ConcentratedLiquidity::pool_account(), DAI, 1);
+
+		assert!(ConcentratedLiquidity::positions(position_id).is_none());
+		assert_eq!(get_mock_minted_position(position_id), None);
+		assert_eq!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity, 0);
+		assert!(initialized_ticks((HDX, DAI)).is_empty());
+		assert!(ConcentratedLiquidity::ticks((HDX, DAI), WIDE_LOWER).is_none());
+		assert!(ConcentratedLiquidity::ticks((HDX, DAI), WIDE_UPPER).is_none());
+
+		expect_events(vec![
+			Event::LiquidityRemoved {
+				position_id,
+				owner: ALICE,
+				liquidity: WIDE_LIQUIDITY,
+				amount_0: 999_999_999_999_999,
+				amount_1: 999_999_999_999_999,
+			}
+			.into(),
+			Event::PositionDestroyed {
+				position_id,
+				owner: ALICE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_keep_position_when_part_of_liquidity_is_removed() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			WIDE_LIQUIDITY / 2,
+		));
+
+		assert_balance!(ALICE, HDX, 9000 * ONE + 499_999_999_999_999);
+		assert_balance!(ALICE, DAI, 9000 * ONE + 499_999_999_999_999);
+
+		let remaining = WIDE_LIQUIDITY - WIDE_LIQUIDITY / 2;
+		assert_eq!(
+			ConcentratedLiquidity::positions(position_id).unwrap().liquidity,
+			remaining
+		);
+		assert_eq!(get_mock_minted_position(position_id), Some(ALICE));
+		assert_eq!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity, remaining);
+		assert_eq!(
+			ConcentratedLiquidity::ticks((HDX, DAI), WIDE_LOWER)
+				.unwrap()
+				.liquidity_gross,
+			remaining
+		);
+
+		expect_events(vec![Event::LiquidityRemoved {
+			position_id,
+			owner: ALICE,
+			liquidity: WIDE_LIQUIDITY / 2,
+			amount_0: 499_999_999_999_999,
+			amount_1: 499_999_999_999_999,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_transfer_accrued_fees() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			10 * ONE,
+			0
+		));
+
+		assert_ok!(ConcentratedLiquidity::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			position_id,
+			WIDE_LIQUIDITY,
+		));
+
+		assert_balance!(ALICE, HDX, 9000 * ONE + 1_009_969_999_999_999 + 29_999_999_999);
+		assert_balance!(ALICE, DAI, 9000 * ONE + 990_059_022_727_196);
+
+		expect_events(vec![
+			Event::FeesCollected {
+				position_id,
+				owner: ALICE,
+				amount_0: 29_999_999_999,
+				amount_1: 0,
+			}
+			.into(),
+			Event::LiquidityRemoved {
+				position_id,
+				owner: ALICE,
+				liquidity: WIDE_LIQUIDITY,
+				amount_0: 1_009_969_999_999_999,
+				amount_1: 990_059_022_727_196,
+			}
+			.into(),
+			Event::PositionDestroyed {
+				position_id,
+				owner: ALICE,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_caller_is_not_position_owner() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(BOB), position_id, WIDE_LIQUIDITY),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_liquidity_is_zero() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, 0),
+			Error::<Test>::ZeroLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_position_has_insufficient_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, WIDE_LIQUIDITY + 1),
+			Error::<Test>::InsufficientPositionLiquidity
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_fail_when_remaining_liquidity_is_below_min_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_noop!(
+			ConcentratedLiquidity::remove_liquidity(RuntimeOrigin::signed(ALICE), position_id, WIDE_LIQUIDITY - 1),
+			Error::<Test>::InsufficientLiquidityProvided
+		);
+	});
+}
+
+#[test]
+fn collect_fees_should_transfer_fees_accrued_by_position() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			10 * ONE,
+			0
+		));
+
+		assert_ok!(ConcentratedLiquidity::collect_fees(
+			RuntimeOrigin::signed(ALICE),
+			position_id
+		));
+
+		assert_balance!(ALICE, HDX, 9000 * ONE + 29_999_999_999);
+		assert_balance!(ALICE, DAI, 9000 * ONE);
+
+		let position = ConcentratedLiquidity::positions(position_id).unwrap();
+		assert_eq!(position.liquidity, WIDE_LIQUIDITY);
+		assert_eq!(position.tokens_owed_0, 0);
+		assert_eq!(position.tokens_owed_1, 0);
+
+		expect_events(vec![Event::FeesCollected {
+			position_id,
+			owner: ALICE,
+			amount_0: 29_999_999_999,
+			amount_1: 0,
+		}
+		.into()]);
+
+		// nothing more to collect
+		assert_ok!(ConcentratedLiquidity::collect_fees(
+			RuntimeOrigin::signed(ALICE),
+			position_id
+		));
+		assert_balance!(ALICE, HDX, 9000 * ONE + 29_999_999_999);
+	});
+}
+
+#[test]
+fn collect_fees_should_split_fees_between_positions_active_during_trade() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let wide_position = add_wide_position(ALICE);
+		let narrow_position = ConcentratedLiquidity::next_position_id();
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			-100,
+			100,
+			100 * ONE,
+			100 * ONE,
+		));
+
+		// trade crosses lower tick of the narrow position
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			200 * ONE,
+			0
+		));
+
+		let alice_hdx = Tokens::free_balance(HDX, &ALICE);
+		let bob_hdx = Tokens::free_balance(HDX, &BOB);
+
+		assert_ok!(ConcentratedLiquidity::collect_fees(
+			RuntimeOrigin::signed(ALICE),
+			wide_position
+		));
+		assert_ok!(ConcentratedLiquidity::collect_fees(
+			RuntimeOrigin::signed(BOB),
+			narrow_position
+		));
+
+		assert_balance!(ALICE, HDX, alice_hdx + 297_589_086_372);
+		assert_balance!(BOB, HDX, bob_hdx + 302_410_913_628);
+	});
+}
+
+#[test]
+fn collect_fees_should_fail_when_caller_is_not_position_owner() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		let position_id = add_wide_position(ALICE);
+
+		assert_noop!(
+			ConcentratedLiquidity::collect_fees(RuntimeOrigin::signed(BOB), position_id),
+			Error::<Test>::Forbidden
+		);
+	});
+}
@@ -0,0 +1,108 @@
+use super::*;
+use hydra_dx_math::concentrated_liquidity::{MAX_TICK, MIN_TICK};
+use pretty_assertions::assert_eq;
+
+const POOL: (AssetId, AssetId) = (HDX, DAI);
+
+// Ticks in the same word, in neighbouring words and in words tracked by different upper level words.
+const TICKS: [i32; 9] = [MIN_TICK, -300_000, -129, -1, 0, 127, 128, 20_000, MAX_TICK];
+
+fn initialize(ticks: &[i32]) {
+	for tick in ticks {
+		ConcentratedLiquidity::set_tick_initialized(&POOL, *tick);
+	}
+}
+
+#[test]
+fn next_initialized_tick_should_be_none_when_no_tick_is_initialized() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(ConcentratedLiquidity::next_initialized_tick(&POOL, 0, true), None);
+		assert_eq!(ConcentratedLiquidity::next_initialized_tick(&POOL, 0, false), None);
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, MAX_TICK, true),
+			None
+		);
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, MIN_TICK, false),
+			None
+		);
+	});
+}
+
+#[test]
+fn next_initialized_tick_should_find_greater_tick() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize(&TICKS);
+
+		for pair in TICKS.windows(2) {
+			assert_eq!(
+				ConcentratedLiquidity::next_initialized_tick(&POOL, pair[0], false),
+				Some(pair[1])
+			);
+			assert_eq!(
+				ConcentratedLiquidity::next_initialized_tick(&POOL, pair[1] - 1, false),
+				Some(pair[1])
+			);
+		}
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, MAX_TICK, false),
+			None
+		);
+	});
+}
+
+#[test]
+fn next_initialized_tick_should_find_lower_or_equal_tick() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize(&TICKS);
+
+		for pair in TICKS.windows(2) {
+			assert_eq!(
+				ConcentratedLiquidity::next_initialized_tick(&POOL, pair[1] - 1, true),
+				Some(pair[0])
+			);
+			assert_eq!(
+				ConcentratedLiquidity::next_initialized_tick(&POOL, pair[1], true),
+				Some(pair[1])
+			);
+		}
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, MIN_TICK - 1, true),
+			None
+		);
+	});
+}
+
+#[test]
+fn cleared_tick_should_not_be_found() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize(&TICKS);
+
+		ConcentratedLiquidity::clear_tick_initialized(&POOL, 20_000);
+		ConcentratedLiquidity::clear_tick_initialized(&POOL, -300_000);
+
+		assert_eq!(initialized_ticks(POOL), vec![MIN_TICK, -129, -1, 0, 127, 128, MAX_TICK]);
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, 128, false),
+			Some(MAX_TICK)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::next_initialized_tick(&POOL, -130, true),
+			Some(MIN_TICK)
+		);
+	});
+}
+
+#[test]
+fn bitmap_should_be_removed_when_all_ticks_are_cleared() {
+	ExtBuilder::default().build().execute_with(|| {
+		initialize(&TICKS);
+
+		for tick in TICKS {
+			ConcentratedLiquidity::clear_tick_initialized(&POOL, tick);
+		}
+
+		assert!(initialized_ticks(POOL).is_empty());
+		assert_eq!(TickBitmap::<Test>::iter_prefix(POOL).count(), 0);
+	});
+}
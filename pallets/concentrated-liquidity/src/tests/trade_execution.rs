@@ -0,0 +1,118 @@
+use super::*;
+use frame_support::assert_ok;
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+
+fn add_wide_position() {
+	assert_ok!(ConcentratedLiquidity::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		WIDE_LOWER,
+		WIDE_UPPER,
+		1000 * ONE,
+		1000 * ONE,
+	));
+}
+
+#[test]
+fn calculate_sell_should_return_amount_received_by_execute_sell() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		let amount_out =
+			ConcentratedLiquidity::calculate_sell(PoolType::ConcentratedLiquidity, HDX, DAI, 10 * ONE).unwrap();
+		assert_eq!(amount_out, 9_940_977_272_802);
+
+		assert_ok!(ConcentratedLiquidity::execute_sell(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DAI,
+			10 * ONE,
+			amount_out,
+		));
+
+		assert_balance!(BOB, DAI, 10_000 * ONE + amount_out);
+	});
+}
+
+#[test]
+fn calculate_buy_should_return_amount_paid_by_execute_buy() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		let amount_in =
+			ConcentratedLiquidity::calculate_buy(PoolType::ConcentratedLiquidity, HDX, DAI, 10 * ONE).unwrap();
+		assert_eq!(amount_in, 10_059_547_536_216);
+
+		assert_ok!(ConcentratedLiquidity::execute_buy(
+			RuntimeOrigin::signed(BOB),
+			PoolType::ConcentratedLiquidity,
+			HDX,
+			DAI,
+			10 * ONE,
+			amount_in,
+		));
+
+		assert_balance!(BOB, HDX, 10_000 * ONE - amount_in);
+		assert_balance!(BOB, DAI, 10_010 * ONE);
+	});
+}
+
+#[test]
+fn trade_execution_should_not_be_supported_for_other_pool_types() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_eq!(
+			ConcentratedLiquidity::calculate_sell(PoolType::XYK, HDX, DAI, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::calculate_buy(PoolType::Omnipool, HDX, DAI, 10 * ONE),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::execute_sell(RuntimeOrigin::signed(BOB), PoolType::XYK, HDX, DAI, 10 * ONE, 0),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::XYK, HDX, DAI),
+			Err(ExecutorError::NotSupported)
+		);
+		assert_eq!(Tokens::free_balance(HDX, &BOB), 10_000 * ONE);
+	});
+}
+
+#[test]
+fn get_liquidity_depth_should_return_virtual_reserve_of_asset() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		// at price 1, virtual reserves of both assets equal to active liquidity
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::ConcentratedLiquidity, HDX, DAI),
+			Ok(WIDE_LIQUIDITY)
+		);
+		assert_eq!(
+			ConcentratedLiquidity::get_liquidity_depth(PoolType::ConcentratedLiquidity, DAI, HDX),
+			Ok(WIDE_LIQUIDITY)
+		);
+	});
+}
+
+#[test]
+fn asset_pairs_should_return_all_pools() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_eq!(
+			ConcentratedLiquidity::asset_pairs(),
+			vec![Trade {
+				pool: PoolType::ConcentratedLiquidity,
+				asset_in: HDX,
+				asset_out: DAI,
+			}]
+		);
+	});
+}
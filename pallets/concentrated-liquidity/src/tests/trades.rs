@@ -0,0 +1,335 @@
+use super::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use pretty_assertions::assert_eq;
+
+fn add_wide_position() {
+	assert_ok!(ConcentratedLiquidity::add_liquidity(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		WIDE_LOWER,
+		WIDE_UPPER,
+		1000 * ONE,
+		1000 * ONE,
+	));
+}
+
+#[test]
+fn sell_should_work_when_selling_asset_0() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			10 * ONE,
+			9_940_977_272_802
+		));
+
+		assert_balance!(BOB, HDX, 9990 * ONE);
+		assert_balance!(BOB, DAI, 10_000 * ONE + 9_940_977_272_802);
+		assert_balance!(ConcentratedLiquidity::pool_account(), HDX, 1010 * ONE);
+		assert_balance!(
+			ConcentratedLiquidity::pool_account(),
+			DAI,
+			1000 * ONE - 9_940_977_272_802
+		);
+
+		let pool = ConcentratedLiquidity::pools((HDX, DAI)).unwrap();
+		assert_eq!(pool.sqrt_price, 18_393_045_495_883_431_993);
+		assert_eq!(pool.tick, -59);
+		assert_eq!(pool.liquidity, WIDE_LIQUIDITY);
+		assert_eq!(pool.fee_growth_global_0, 162_052_209_815_522);
+		assert_eq!(pool.fee_growth_global_1, 0);
+
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * ONE,
+			amount_out: 9_940_977_272_802,
+			fee_amount: 30_000_000_000,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_work_when_selling_asset_1() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			0
+		));
+
+		assert_balance!(BOB, DAI, 9990 * ONE);
+		assert_balance!(BOB, HDX, 10_000 * ONE + 9_940_977_272_802);
+
+		let pool = ConcentratedLiquidity::pools((HDX, DAI)).unwrap();
+		assert!(pool.sqrt_price > 1 << 64);
+		assert!(pool.tick > 0);
+		assert_eq!(pool.fee_growth_global_0, 0);
+		assert_eq!(pool.fee_growth_global_1, 162_052_209_815_522);
+	});
+}
+
+#[test]
+fn sell_should_cross_initialized_tick_and_update_active_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			-100,
+			100,
+			100 * ONE,
+			100 * ONE,
+		));
+		assert_eq!(
+			ConcentratedLiquidity::pools((HDX, DAI)).unwrap().liquidity,
+			23_466_004_781_332_843
+		);
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DAI,
+			200 * ONE,
+			0
+		));
+
+		assert_balance!(CHARLIE, DAI, 10_000 * ONE + 196_115_235_275_338);
+
+		let pool = ConcentratedLiquidity::pools((HDX, DAI)).unwrap();
+		assert_eq!(pool.sqrt_price, 17_927_554_531_265_970_031);
+		assert_eq!(pool.tick, -572);
+		// only the wide position is active below tick -100
+		assert_eq!(pool.liquidity, WIDE_LIQUIDITY);
+
+		let crossed_tick = ConcentratedLiquidity::ticks((HDX, DAI), -100).unwrap();
+		assert_eq!(crossed_tick.fee_growth_outside_0, 278_214_809_323_170);
+		assert_eq!(crossed_tick.fee_growth_outside_1, 0);
+
+		expect_events(vec![Event::SellExecuted {
+			who: CHARLIE,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 200 * ONE,
+			amount_out: 196_115_235_275_338,
+			fee_amount: 600_000_000_001,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_charge_weight_of_crossed_ticks() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+		for i in 1..=3 {
+			assert_ok!(ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				DAI,
+				-10 * i,
+				10 * i,
+				ONE,
+				ONE,
+			));
+		}
+
+		let post_info = ConcentratedLiquidity::sell(RuntimeOrigin::signed(CHARLIE), HDX, DAI, 50 * ONE, 0).unwrap();
+
+		assert!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().tick < -30);
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::sell(3).saturating_add(<Test as Config>::AMMHandler::on_trade_weight()))
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_max_tick_crossings_is_reached() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+		for i in 1..=4 {
+			assert_ok!(ConcentratedLiquidity::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				HDX,
+				DAI,
+				-10 * i,
+				10 * i,
+				ONE,
+				ONE,
+			));
+		}
+
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(CHARLIE), HDX, DAI, 50 * ONE, 0),
+			Error::<Test>::MaxTickCrossingsReached
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(CHARLIE), HDX, DAI, 50 * ONE, u128::MAX),
+			Error::<Test>::MaxTickCrossingsReached
+		);
+	});
+}
+
+#[test]
+fn sell_should_skip_range_without_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			100,
+			200,
+			100 * ONE,
+			100 * ONE,
+		));
+		assert_ok!(ConcentratedLiquidity::add_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			-200,
+			-100,
+			100 * ONE,
+			100 * ONE,
+		));
+
+		assert_ok!(ConcentratedLiquidity::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DAI,
+			ONE,
+			0
+		));
+
+		assert_balance!(BOB, DAI, 10_000 * ONE + 987_031_587_794);
+		assert_eq!(ConcentratedLiquidity::pools((HDX, DAI)).unwrap().tick, -101);
+	});
+}
+
+#[test]
+fn buy_should_work() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_ok!(ConcentratedLiquidity::buy(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			10_059_547_536_216
+		));
+
+		assert_balance!(BOB, HDX, 10_000 * ONE - 10_059_547_536_216);
+		assert_balance!(BOB, DAI, 10_010 * ONE);
+
+		expect_events(vec![Event::BuyExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10_059_547_536_216,
+			amount_out: 10 * ONE,
+			fee_amount: 30_178_642_609,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_buy_limit_is_not_reached() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 10 * ONE, 9_940_977_272_803),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn buy_should_fail_when_sell_limit_is_exceeded() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 10 * ONE, 10_059_547_536_215),
+			Error::<Test>::SellLimitExceeded
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_amount_is_below_min_trading_limit() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 999, 0),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 999, u128::MAX),
+			Error::<Test>::InsufficientTradingAmount
+		);
+	});
+}
+
+#[test]
+fn sell_should_fail_when_account_has_insufficient_balance() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 10_001 * ONE, 0),
+			Error::<Test>::InsufficientBalance
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_insufficient_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		add_wide_position();
+
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, 5000 * ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+		assert_noop!(
+			ConcentratedLiquidity::buy(RuntimeOrigin::signed(BOB), DAI, HDX, 1000 * ONE, u128::MAX),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_has_no_liquidity() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DAI, ONE, 0),
+			Error::<Test>::InsufficientLiquidity
+		);
+	});
+}
+
+#[test]
+fn trade_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default().with_default_pool().build().execute_with(|| {
+		assert_noop!(
+			ConcentratedLiquidity::sell(RuntimeOrigin::signed(BOB), HDX, DOT, ONE, 0),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
@@ -0,0 +1,121 @@
+// This file is part of HydraDX-node.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Bitmap of initialized ticks.
+//!
+//! Bit `i` of word `w` at level 0 is set if tick `w * 128 + i` is initialized. Bit `i` of word `w` at level
+//! `l > 0` is set if word `w * 128 + i` at level `l - 1` is not empty. Search for the nearest initialized tick
+//! therefore reads at most two words per level regardless of the number of initialized ticks.
+
+use crate::{Config, Pallet, TickBitmap};
+use hydra_dx_math::concentrated_liquidity::types::Tick;
+use hydra_dx_math::concentrated_liquidity::{MAX_TICK, MIN_TICK};
+
+const WORD_BITS: u32 = u128::BITS;
+const WORD_SHIFT: u32 = WORD_BITS.trailing_zeros();
+const TOP_LEVEL: u8 = 2;
+
+fn word_position(position: i32) -> (i32, u32) {
+	(position >> WORD_SHIFT, (position & (WORD_BITS as i32 - 1)) as u32)
+}
+
+/// Range of words of the top level which can contain an initialized tick.
+fn top_level_words() -> (i32, i32) {
+	let shift = WORD_SHIFT * (TOP_LEVEL as u32 + 1);
+	(MIN_TICK >> shift, MAX_TICK >> shift)
+}
+
+impl<T: Config> Pallet<T> {
+	/// Mark tick as initialized.
+	pub(crate) fn set_tick_initialized(pool_key: &(T::AssetId, T::AssetId), tick: Tick) {
+		let mut position = tick;
+		for level in 0..=TOP_LEVEL {
+			let (word, bit) = word_position(position);
+			let was_empty = TickBitmap::<T>::mutate(pool_key, (level, word), |bitmap| {
+				let was_empty = *bitmap == 0;
+				*bitmap |= 1u128 << bit;
+				was_empty
+			});
+			if !was_empty {
+				break;
+			}
+			position = word;
+		}
+	}
+
+	/// Mark tick as not initialized.
+	pub(crate) fn clear_tick_initialized(pool_key: &(T::AssetId, T::AssetId), tick: Tick) {
+		let mut position = tick;
+		for level in 0..=TOP_LEVEL {
+			let (word, bit) = word_position(position);
+			let bitmap = TickBitmap::<T>::get(pool_key, (level, word)) & !(1u128 << bit);
+			if bitmap != 0 {
+				TickBitmap::<T>::insert(pool_key, (level, word), bitmap);
+				break;
+			}
+			TickBitmap::<T>::remove(pool_key, (level, word));
+			position = word;
+		}
+	}
+
+	/// Find the nearest initialized tick lower than or equal to `tick` if `lte`, greater than `tick` otherwise.
+	pub(crate) fn next_initialized_tick(pool_key: &(T::AssetId, T::AssetId), tick: Tick, lte: bool) -> Option<Tick> {
+		if lte {
+			Self::find_lte(pool_key, 0, tick)
+		} else {
+			Self::find_gte(pool_key, 0, tick.checked_add(1)?)
+		}
+	}
+
+	/// Find the lowest set position at `level` which is greater than or equal to `position`.
+	fn find_gte(pool_key: &(T::AssetId, T::AssetId), level: u8, position: i32) -> Option<i32> {
+		let (word, bit) = word_position(position);
+		let bitmap = TickBitmap::<T>::get(pool_key, (level, word)) & (u128::MAX << bit);
+		if bitmap != 0 {
+			return Some((word << WORD_SHIFT) | bitmap.trailing_zeros() as i32);
+		}
+
+		let next_word = if level == TOP_LEVEL {
+			let (_, max_word) = top_level_words();
+			(word.checked_add(1)?..=max_word).find(|w| TickBitmap::<T>::get(pool_key, (level, *w)) != 0)?
+		} else {
+			Self::find_gte(pool_key, level + 1, word.checked_add(1)?)?
+		};
+		let bitmap = TickBitmap::<T>::get(pool_key, (level, next_word));
+		Some((next_word << WORD_SHIFT) | bitmap.trailing_zeros() as i32)
+	}
+
+	/// Find the highest set position at `level` which is lower than or equal to `position`.
+	fn find_lte(pool_key: &(T::AssetId, T::AssetId), level: u8, position: i32) -> Option<i32> {
+		let (word, bit) = word_position(position);
+		let bitmap = TickBitmap::<T>::get(pool_key, (level, word)) & (u128::MAX >> (WORD_BITS - 1 - bit));
+		if bitmap != 0 {
+			return Some((word << WORD_SHIFT) | (WORD_BITS - 1 - bitmap.leading_zeros()) as i32);
+		}
+
+		let prev_word = if level == TOP_LEVEL {
+			let (min_word, _) = top_level_words();
+			(min_word..=word.checked_sub(1)?)
+				.rev()
+				.find(|w| TickBitmap::<T>::get(pool_key, (level, *w)) != 0)?
+		} else {
+			Self::find_lte(pool_key, level + 1, word.checked_sub(1)?)?
+		};
+		let bitmap = TickBitmap::<T>::get(pool_key, (level, prev_word));
+		Some((prev_word << WORD_SHIFT) | (WORD_BITS - 1 - bitmap.leading_zeros()) as i32)
+	}
+}
@@ -0,0 +1,107 @@
+use crate::types::Balance;
+use crate::{Config, Pallet, Pools};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
+	type Error = DispatchError;
+
+	fn calculate_sell(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let (pool_key, pool) = Self::load_pool(asset_in, asset_out).map_err(ExecutorError::Error)?;
+
+		let result = Self::simulate_swap(&pool_key, &pool, asset_in == pool_key.0, amount_in, true)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(result.amount_out)
+	}
+
+	fn calculate_buy(
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let (pool_key, pool) = Self::load_pool(asset_in, asset_out).map_err(ExecutorError::Error)?;
+
+		let result = Self::simulate_swap(&pool_key, &pool, asset_in == pool_key.0, amount_out, false)
+			.map_err(ExecutorError::Error)?;
+
+		Ok(result.amount_in)
+	}
+
+	fn execute_sell(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: Balance,
+		min_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::sell(who, asset_in, asset_out, amount_in, min_limit)
+			.map(|_| ())
+			.map_err(|e| ExecutorError::Error(e.error))
+	}
+
+	fn execute_buy(
+		who: T::RuntimeOrigin,
+		pool_type: PoolType<T::AssetId>,
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: Balance,
+		max_limit: Balance,
+	) -> Result<(), ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		Self::buy(who, asset_out, asset_in, amount_out, max_limit)
+			.map(|_| ())
+			.map_err(|e| ExecutorError::Error(e.error))
+	}
+
+	fn get_liquidity_depth(
+		pool_type: PoolType<T::AssetId>,
+		asset_a: T::AssetId,
+		asset_b: T::AssetId,
+	) -> Result<Balance, ExecutorError<Self::Error>> {
+		if pool_type != PoolType::ConcentratedLiquidity {
+			return Err(ExecutorError::NotSupported);
+		}
+
+		let (pool_key, pool) = Self::load_pool(asset_a, asset_b).map_err(ExecutorError::Error)?;
+
+		let (liquidity, _) = Self::virtual_reserves(&pool_key, &pool, asset_a).map_err(ExecutorError::Error)?;
+
+		Ok(liquidity)
+	}
+}
+
+impl<T: Config> PoolAssetPairs<T::AssetId> for Pallet<T> {
+	fn asset_pairs() -> Vec<Trade<T::AssetId>> {
+		Pools::<T>::iter_keys()
+			.map(|(asset_0, asset_1)| Trade {
+				pool: PoolType::ConcentratedLiquidity,
+				asset_in: asset_0,
+				asset_out: asset_1,
+			})
+			.collect()
+	}
+}
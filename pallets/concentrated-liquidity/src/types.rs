@@ -0,0 +1,103 @@
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::RuntimeDebug;
+use hydra_dx_math::concentrated_liquidity::types::{Liquidity, SqrtPrice, Tick};
+use scale_info::TypeInfo;
+#[cfg(feature = "runtime-benchmarks")]
+use sp_runtime::DispatchResult;
+use sp_runtime::Permill;
+
+/// Balance type used in concentrated liquidity pools
+pub type Balance = u128;
+
+/// Pool state. Price is expressed as amount of asset 1 per unit of asset 0.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct PoolInfo {
+	/// Trade fee charged in asset coming into the pool.
+	pub fee: Permill,
+	/// Ticks used as position boundaries must be multiples of tick spacing.
+	pub tick_spacing: u32,
+	/// Current square root price as Q64.64 fixed point number.
+	pub sqrt_price: SqrtPrice,
+	/// Tick which corresponds to current price.
+	pub tick: Tick,
+	/// Liquidity of positions whose range contains current price.
+	pub liquidity: Liquidity,
+	/// Accumulated fees of asset 0 per unit of liquidity as Q64.64 fixed point number.
+	pub fee_growth_global_0: u128,
+	/// Accumulated fees of asset 1 per unit of liquidity as Q64.64 fixed point number.
+	pub fee_growth_global_1: u128,
+}
+
+impl PoolInfo {
+	pub(crate) fn fee_growth_global(&self) -> (u128, u128) {
+		(self.fee_growth_global_0, self.fee_growth_global_1)
+	}
+}
+
+/// State of an initialized tick - a tick used as a boundary of at least one position.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct TickInfo {
+	/// Total liquidity of positions which use this tick as a boundary.
+	pub liquidity_gross: Liquidity,
+	/// Liquidity added to the pool when price crosses this tick upwards.
+	pub liquidity_net: i128,
+	/// Fee growth of asset 0 on the other side of this tick relative to current tick.
+	pub fee_growth_outside_0: u128,
+	/// Fee growth of asset 1 on the other side of this tick relative to current tick.
+	pub fee_growth_outside_1: u128,
+}
+
+impl TickInfo {
+	pub(crate) fn fee_growth_outside(&self) -> (u128, u128) {
+		(self.fee_growth_outside_0, self.fee_growth_outside_1)
+	}
+}
+
+/// Liquidity position provided within a price range. Represented by NFT instance.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Position<AssetId> {
+	/// Asset 0 of the pool.
+	pub asset_0: AssetId,
+	/// Asset 1 of the pool.
+	pub asset_1: AssetId,
+	/// Lower boundary of the price range.
+	pub tick_lower: Tick,
+	/// Upper boundary of the price range.
+	pub tick_upper: Tick,
+	/// Liquidity provided by the position.
+	pub liquidity: Liquidity,
+	/// Fee growth of asset 0 inside the price range at the time of last update.
+	pub fee_growth_inside_0_last: u128,
+	/// Fee growth of asset 1 inside the price range at the time of last update.
+	pub fee_growth_inside_1_last: u128,
+	/// Fees of asset 0 accrued but not yet collected.
+	pub tokens_owed_0: Balance,
+	/// Fees of asset 1 accrued but not yet collected.
+	pub tokens_owed_1: Balance,
+}
+
+/// Result of a swap simulated over initialized ticks of a pool.
+#[derive(Clone, Default, RuntimeDebug, PartialEq, Eq)]
+pub struct SwapResult {
+	/// Amount of asset coming into the pool, including fee.
+	pub amount_in: Balance,
+	/// Amount of asset going out of the pool.
+	pub amount_out: Balance,
+	/// Fee charged in asset coming into the pool.
+	pub fee_amount: Balance,
+	/// Square root price after the swap.
+	pub sqrt_price: SqrtPrice,
+	/// Tick after the swap.
+	pub tick: Tick,
+	/// Active liquidity after the swap.
+	pub liquidity: Liquidity,
+	/// Fee growth of asset coming into the pool after the swap.
+	pub fee_growth_global_in: u128,
+	/// Ticks crossed during the swap together with fee growth of asset in at the moment of crossing.
+	pub crossed_ticks: sp_std::vec::Vec<(Tick, u128)>,
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult;
+}
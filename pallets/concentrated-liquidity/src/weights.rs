@@ -0,0 +1,184 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Autogenerated weights for pallet_concentrated_liquidity
+//!
+//! THIS FILE WAS AUTO-GENERATED USING THE SUBSTRATE BENCHMARK CLI VERSION 4.0.0-dev
+//! DATE: 2023-11-02, STEPS: 5, REPEAT: 20, LOW RANGE: [], HIGH RANGE: []
+//! EXECUTION: Some(Wasm), WASM-EXECUTION: Compiled, CHAIN: Some("dev"), DB CACHE: 1024
+
+// Executed Command:
+// ./target/release/hydradx
+// benchmark
+// pallet
+// --pallet=pallet_concentrated_liquidity
+// --chain=dev
+// --steps=5
+// --repeat=20
+// --extrinsic=*
+// --execution=wasm
+// --wasm-execution=compiled
+// --heap-pages=4096
+// --template=.maintain/pallet-weight-template.hbs
+// --output=pallets/concentrated-liquidity/src/weights.rs
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(clippy::unnecessary_cast)]
+
+use frame_support::{
+	traits::Get,
+	weights::{constants::RocksDbWeight, Weight},
+};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_concentrated_liquidity.
+pub trait WeightInfo {
+	fn create_pool() -> Weight;
+	fn add_liquidity() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn collect_fees() -> Weight;
+	fn sell(c: u32) -> Weight;
+	fn buy(c: u32) -> Weight;
+	fn router_execution_sell(c: u32, e: u32) -> Weight;
+	fn router_execution_buy(c: u32, e: u32) -> Weight;
+}
+
+/// Weights for pallet_concentrated_liquidity using the hydraDX node and recommended hardware.
+pub struct HydraWeight<T>(PhantomData<T>);
+
+impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
+	fn create_pool() -> Weight {
+		Weight::from_ref_time(41_203_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(152_871_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(16 as u64))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(163_455_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(15 as u64))
+			.saturating_add(T::DbWeight::get().writes(15 as u64))
+	}
+	fn collect_fees() -> Weight {
+		Weight::from_ref_time(83_690_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(5 as u64))
+	}
+	/// The range of component `c` is `[0, 20]`.
+	fn sell(c: u32) -> Weight {
+		Weight::from_ref_time(171_034_000 as u64)
+			.saturating_add(Weight::from_ref_time(24_671_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	/// The range of component `c` is `[0, 20]`.
+	fn buy(c: u32) -> Weight {
+		Weight::from_ref_time(176_512_000 as u64)
+			.saturating_add(Weight::from_ref_time(25_108_000 as u64).saturating_mul(c as u64))
+			.saturating_add(T::DbWeight::get().reads(18 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+			.saturating_add(T::DbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_sell(c: u32, e: u32) -> Weight {
+		Weight::from_ref_time(9_128_331 as u64)
+			.saturating_add(Weight::from_ref_time(36_904_117 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(149_338_209 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((13 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
+	}
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_buy(c: u32, e: u32) -> Weight {
+		Weight::from_ref_time(9_487_512 as u64)
+			.saturating_add(Weight::from_ref_time(38_115_092 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(153_802_771 as u64).saturating_mul(e as u64))
+			.saturating_add(T::DbWeight::get().reads(10 as u64))
+			.saturating_add(T::DbWeight::get().reads((13 as u64).saturating_mul(e as u64)))
+			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	fn create_pool() -> Weight {
+		Weight::from_ref_time(41_203_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(3 as u64))
+	}
+	fn add_liquidity() -> Weight {
+		Weight::from_ref_time(152_871_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(16 as u64))
+	}
+	fn remove_liquidity() -> Weight {
+		Weight::from_ref_time(163_455_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(15 as u64))
+			.saturating_add(RocksDbWeight::get().writes(15 as u64))
+	}
+	fn collect_fees() -> Weight {
+		Weight::from_ref_time(83_690_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(5 as u64))
+	}
+	/// The range of component `c` is `[0, 20]`.
+	fn sell(c: u32) -> Weight {
+		Weight::from_ref_time(171_034_000 as u64)
+			.saturating_add(Weight::from_ref_time(24_671_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(18 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	/// The range of component `c` is `[0, 20]`.
+	fn buy(c: u32) -> Weight {
+		Weight::from_ref_time(176_512_000 as u64)
+			.saturating_add(Weight::from_ref_time(25_108_000 as u64).saturating_mul(c as u64))
+			.saturating_add(RocksDbWeight::get().reads(18 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(c as u64)))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+			.saturating_add(RocksDbWeight::get().writes((1 as u64).saturating_mul(c as u64)))
+	}
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_sell(c: u32, e: u32) -> Weight {
+		Weight::from_ref_time(9_128_331 as u64)
+			.saturating_add(Weight::from_ref_time(36_904_117 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(149_338_209 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((13 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
+	}
+	/// The range of component `c` is `[1, 2]`.
+	/// The range of component `e` is `[0, 1]`.
+	fn router_execution_buy(c: u32, e: u32) -> Weight {
+		Weight::from_ref_time(9_487_512 as u64)
+			.saturating_add(Weight::from_ref_time(38_115_092 as u64).saturating_mul(c as u64))
+			.saturating_add(Weight::from_ref_time(153_802_771 as u64).saturating_mul(e as u64))
+			.saturating_add(RocksDbWeight::get().reads(10 as u64))
+			.saturating_add(RocksDbWeight::get().reads((13 as u64).saturating_mul(e as u64)))
+			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
+	}
+}
@@ -255,6 +255,7 @@ impl pallet_omnipool::Config for Test {
 	type MinimumTradingLimit = MinTradeAmount;
 	type MinimumPoolLiquidity = MinAddedLiquidity;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
@@ -264,6 +265,11 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = ();
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type RouteExecutor = ();
+	type AmmTradeWeights = ();
+	type FeeDiscount = ();
+	type LockedPositionOwners = Nothing;
+	type MaxDelistings = ConstU32<10>;
 }
 
 pub struct WithdrawFeePriceOracle;
@@ -357,6 +363,7 @@ impl pallet_route_executor::Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type AssetPairs = ();
 	type WeightInfo = ();
 }
 
@@ -732,7 +732,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit, false)?;
+			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit)?;
 
 			Ok(())
 		}
@@ -762,7 +762,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit, false)?;
+			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit)?;
 
 			Ok(())
 		}
@@ -993,7 +993,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		assets: AssetPair,
 		amount: BalanceOf<T>,
 		min_bought: BalanceOf<T>,
-		_discount: bool,
 	) -> Result<AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>, DispatchError> {
 		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 		ensure!(
@@ -1056,8 +1055,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				assets,
 				amount: amount_without_fee,
 				amount_b: amount_out,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
 			})
 
@@ -1094,8 +1091,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				assets,
 				amount,
 				amount_b: amount_out_without_fee,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
 			})
 		}
@@ -1122,7 +1117,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 		assets: AssetPair,
 		amount: BalanceOf<T>,
 		max_sold: BalanceOf<T>,
-		_discount: bool,
 	) -> Result<AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>, DispatchError> {
 		ensure!(!amount.is_zero(), Error::<T>::ZeroAmount);
 
@@ -1185,8 +1179,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				assets,
 				amount: calculated_in,
 				amount_b: amount,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
 			})
 
@@ -1228,8 +1220,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, BalanceOf<T>> for Pallet<T
 				assets,
 				amount: calculated_in_without_fee,
 				amount_b: amount,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (fee_asset, fee),
 			})
 		}
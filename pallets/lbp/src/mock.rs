@@ -65,8 +65,6 @@ pub const SAMPLE_AMM_TRANSFER: AMMTransfer<AccountId, AssetId, AssetPair, Balanc
 	},
 	amount: 1000,
 	amount_b: 10000,
-	discount: false,
-	discount_amount: 0_u128,
 	fee: (KUSD, 200),
 };
 
@@ -1695,8 +1695,6 @@ fn execute_trade_should_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -1723,8 +1721,6 @@ fn execute_trade_should_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -1751,8 +1747,6 @@ fn trade_fails_when_first_fee_lesser_than_existential_deposit() {
 			},
 			amount: 1000,
 			amount_b: 1000,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (KUSD, EXISTENTIAL_DEPOSIT - 1),
 		};
 
@@ -1778,8 +1772,6 @@ fn execute_trade_should_not_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -1815,8 +1807,6 @@ fn execute_sell_should_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -1872,8 +1862,6 @@ fn execute_sell_should_not_work() {
 			},
 			amount: 8_000_000_000_u128,
 			amount_b: 200_000_000_000_000_u128,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (KUSD, 1_000),
 		};
 
@@ -1946,8 +1934,6 @@ fn execute_buy_should_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -1997,8 +1983,6 @@ fn execute_buy_should_not_work() {
 			assets: AssetPair { asset_in, asset_out },
 			amount: amount_in,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_in, 1_000),
 		};
 
@@ -2916,13 +2900,11 @@ fn amm_trait_should_work() {
 			assets: asset_pair,
 			amount: amount_in - fee,
 			amount_b: 563_741,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_pair.asset_in, fee),
 		};
 
 		assert_eq!(
-			LBPPallet::validate_sell(&who, asset_pair, amount_in, sell_limit, false).unwrap(),
+			LBPPallet::validate_sell(&who, asset_pair, amount_in, sell_limit).unwrap(),
 			t_sell
 		);
 
@@ -2933,12 +2915,10 @@ fn amm_trait_should_work() {
 			assets: asset_pair,
 			amount: 1_771_197,
 			amount_b,
-			discount: false,
-			discount_amount: 0_u128,
 			fee: (asset_pair.asset_in, 3_548),
 		};
 		assert_eq!(
-			LBPPallet::validate_buy(&who, asset_pair, amount_in, buy_limit, false).unwrap(),
+			LBPPallet::validate_buy(&who, asset_pair, amount_in, buy_limit).unwrap(),
 			t_buy
 		);
 
@@ -3252,8 +3232,7 @@ fn validate_trade_should_work() {
 					asset_out: BSX
 				},
 				1_000_000_u128,
-				2_157_153_u128,
-				false
+				2_157_153_u128
 			)
 			.unwrap(),
 			AMMTransfer {
@@ -3264,8 +3243,6 @@ fn validate_trade_should_work() {
 				},
 				amount: 1_998_500_u128,
 				amount_b: 1_000_000_u128,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (KUSD, 4_004),
 			}
 		);
@@ -3278,8 +3255,7 @@ fn validate_trade_should_work() {
 					asset_out: BSX
 				},
 				1_000_000_u128,
-				2_000_u128,
-				false
+				2_000_u128
 			)
 			.unwrap(),
 			AMMTransfer {
@@ -3290,8 +3266,6 @@ fn validate_trade_should_work() {
 				},
 				amount: 998_000_u128,
 				amount_b: 499_687_u128,
-				discount: false,
-				discount_amount: 0_u128,
 				fee: (KUSD, 2000),
 			}
 		);
@@ -3312,7 +3286,6 @@ fn validate_trade_should_not_work() {
 				},
 				1_000_000_u128,
 				2_157_153_u128,
-				false,
 			),
 			Error::<Test>::SaleIsNotRunning
 		);
@@ -3328,7 +3301,6 @@ fn validate_trade_should_not_work() {
 				},
 				0,
 				2_157_153_u128,
-				false,
 			),
 			Error::<Test>::ZeroAmount
 		);
@@ -3343,7 +3315,6 @@ fn validate_trade_should_not_work() {
 				},
 				100_000_000u128,
 				3_000_000_000_u128,
-				false,
 			),
 			Error::<Test>::InsufficientAssetBalance
 		);
@@ -3359,7 +3330,6 @@ fn validate_trade_should_not_work() {
 				},
 				1_000_000_u128,
 				2_157_153_u128,
-				false,
 			),
 			Error::<Test>::PoolNotFound
 		);
@@ -3373,7 +3343,6 @@ fn validate_trade_should_not_work() {
 				},
 				1_000_000_000_u128,
 				2_157_153_u128,
-				false,
 			),
 			Error::<Test>::MaxOutRatioExceeded
 		);
@@ -3387,7 +3356,6 @@ fn validate_trade_should_not_work() {
 				},
 				400_000_000_u128,
 				2_157_153_u128,
-				false,
 			),
 			Error::<Test>::MaxInRatioExceeded
 		);
@@ -3401,7 +3369,6 @@ fn validate_trade_should_not_work() {
 				},
 				1_000_u128,
 				500_u128,
-				false,
 			),
 			Error::<Test>::TradingLimitReached
 		);
@@ -3415,7 +3382,6 @@ fn validate_trade_should_not_work() {
 				},
 				1_000_u128,
 				1_994_u128,
-				false,
 			),
 			Error::<Test>::TradingLimitReached
 		);
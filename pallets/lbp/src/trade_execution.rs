@@ -1,5 +1,5 @@
 use crate::*;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::traits::BlockNumberProvider;
@@ -150,3 +150,15 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(liquidty)
 	}
 }
+
+impl<T: Config> PoolAssetPairs<AssetId> for Pallet<T> {
+	fn asset_pairs() -> Vec<Trade<AssetId>> {
+		PoolData::<T>::iter_values()
+			.map(|pool| Trade {
+				pool: PoolType::LBP,
+				asset_in: pool.assets.0,
+				asset_out: pool.assets.1,
+			})
+			.collect()
+	}
+}
@@ -0,0 +1,165 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::OnInitialize;
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrencyExtended;
+use sp_runtime::{FixedU128, Permill};
+
+const ONE: Balance = 1_000_000_000_000;
+const HDX: u32 = 0;
+const DAI: u32 = 2;
+
+const SEED: u32 = 0;
+
+fn initialize_omnipool<T: Config>() -> DispatchResult
+where
+	<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	T::AssetId: From<u32>,
+{
+	let stable_amount: Balance = 1_000_000_000_000_000u128;
+	let native_amount: Balance = 1_000_000_000_000_000u128;
+
+	let acc = OmnipoolPallet::<T>::protocol_account();
+
+	<T as pallet_omnipool::Config>::Currency::update_balance(DAI.into(), &acc, stable_amount as i128)?;
+	<T as pallet_omnipool::Config>::Currency::update_balance(HDX.into(), &acc, native_amount as i128)?;
+
+	OmnipoolPallet::<T>::add_token(
+		RawOrigin::Root.into(),
+		HDX.into(),
+		FixedU128::from(1),
+		Permill::from_percent(100),
+		acc.clone(),
+	)?;
+	OmnipoolPallet::<T>::add_token(
+		RawOrigin::Root.into(),
+		DAI.into(),
+		FixedU128::from((1, 2)),
+		Permill::from_percent(100),
+		acc,
+	)
+}
+
+fn create_funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId
+where
+	<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	T::AssetId: From<u32>,
+{
+	let caller: T::AccountId = account(name, index, SEED);
+
+	<T as pallet_omnipool::Config>::Currency::update_balance(HDX.into(), &caller, (1_000 * ONE) as i128).unwrap();
+
+	caller
+}
+
+fn place_sell_order<T: Config>(
+	owner: T::AccountId,
+	expires_at: Option<T::BlockNumber>,
+) -> Result<OrderId, DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	let order_id = Pallet::<T>::next_order_id();
+
+	Pallet::<T>::place_order(
+		RawOrigin::Signed(owner).into(),
+		HDX.into(),
+		DAI.into(),
+		10 * ONE,
+		ONE,
+		OrderKind::Sell,
+		expires_at,
+	)?;
+
+	Ok(order_id)
+}
+
+benchmarks! {
+	where_clause { where
+		<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T::AssetId: From<u32>,
+	}
+
+	place_order {
+		initialize_omnipool::<T>()?;
+		let owner = create_funded_account::<T>("owner", 1);
+		let expires_at = Some(T::BlockNumber::from(100u32));
+	}:  _(RawOrigin::Signed(owner.clone()), HDX.into(), DAI.into(), 10 * ONE, ONE, OrderKind::Sell, expires_at)
+	verify {
+		assert!(<Orders<T>>::get(0).is_some());
+		assert_eq!(T::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX.into(), &owner), 10 * ONE);
+	}
+
+	cancel_order {
+		initialize_omnipool::<T>()?;
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_sell_order::<T>(owner.clone(), Some(T::BlockNumber::from(100u32)))?;
+	}:  _(RawOrigin::Signed(owner.clone()), order_id)
+	verify {
+		assert!(<Orders<T>>::get(order_id).is_none());
+		assert_eq!(T::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX.into(), &owner), 0);
+	}
+
+	on_initialize {
+		let c in 0 .. T::MaxOrdersExpiringPerBlock::get();
+
+		initialize_omnipool::<T>()?;
+		let owner = create_funded_account::<T>("owner", 1);
+		let expires_at = T::BlockNumber::from(100u32);
+
+		for _ in 0..c {
+			place_sell_order::<T>(owner.clone(), Some(expires_at))?;
+		}
+	}: { Pallet::<T>::on_initialize(expires_at); }
+	verify {
+		assert_eq!(<Orders<T>>::iter().count(), 0);
+	}
+
+	check_order {
+		initialize_omnipool::<T>()?;
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_sell_order::<T>(owner, None)?;
+		let order = <Orders<T>>::get(order_id).unwrap();
+		let mut is_reached = false;
+	}: { is_reached = Pallet::<T>::is_limit_price_reached(&order); }
+	verify {
+		assert!(is_reached);
+	}
+
+	fill_order {
+		initialize_omnipool::<T>()?;
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_sell_order::<T>(owner, Some(T::BlockNumber::from(100u32)))?;
+		let order = <Orders<T>>::get(order_id).unwrap();
+	}: { Pallet::<T>::fill_order(order_id, &order)?; }
+	verify {
+		assert!(<Orders<T>>::get(order_id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,463 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// # Limit orders pallet
+// ## General description
+// This pallet provides limit orders which rest on the Omnipool until the price reaches the limit set by the owner.
+// It allows anyone to `place_order` by specifying a pair of assets (in and out), their respective amounts and
+// whether the order is a sell or a buy order. The limit price of the order is `amount_out / amount_in`.
+//
+// A sell order sells exactly `amount_in` and it is filled when the Omnipool quotes at least `amount_out` for it.
+// A buy order buys exactly `amount_out` and it is filled when the Omnipool quotes at most `amount_in` for it.
+// The quote includes fees and price impact of the trade, so an order is filled only when it can be fully executed
+// at or better than its limit price.
+//
+// ## Notes
+// Amount in is reserved when an order is placed and released when the order is filled, cancelled or expired.
+//
+// Orders are checked in `on_idle` with the remaining block weight. Orders which cannot be checked in one block are
+// checked in the following blocks, continuing from the last checked order.
+//
+// Orders with an expiry block are removed in `on_initialize` of that block.
+//
+// ## Dispatachable functions
+// * `place_order` -  create a new limit order.
+// * `cancel_order` - cancel an open limit order.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pallet_omnipool::weights::WeightInfo as OmnipoolWeightInfo;
+use sp_runtime::{
+	traits::{One, Zero},
+	ArithmeticError, DispatchError,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub type Balance = u128;
+pub type OrderId = u32;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"limitord";
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum OrderKind {
+	/// Sell exactly `amount_in` for at least `amount_out`.
+	Sell,
+	/// Buy exactly `amount_out` for at most `amount_in`.
+	Buy,
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct LimitOrder<AccountId, AssetId, BlockNumber> {
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub amount_out: Balance,
+	pub kind: OrderKind,
+	pub expires_at: Option<BlockNumber>,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_omnipool::Config {
+		/// Named reservable multi currency
+		type Currencies: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = NamedReserveIdentifier,
+			CurrencyId = Self::AssetId,
+			Balance = Balance,
+		>;
+
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Maximum number of orders which can expire in one block.
+		#[pallet::constant]
+		type MaxOrdersExpiringPerBlock: Get<u32>;
+
+		/// Maximum number of orders checked in `on_idle` of one block.
+		#[pallet::constant]
+		type MaxOrdersCheckedPerBlock: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An order has been placed
+		Placed {
+			order_id: OrderId,
+			owner: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			kind: OrderKind,
+			expires_at: Option<T::BlockNumber>,
+		},
+		/// An order has been filled
+		Filled {
+			order_id: OrderId,
+			owner: T::AccountId,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// An order has been cancelled
+		Cancelled { order_id: OrderId },
+		/// An order has expired
+		Expired { order_id: OrderId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Order cannot be found
+		OrderNotFound,
+		/// Size of order ID exceeds the bound
+		OrderIdOutOfBound,
+		/// Asset in and asset out of an order must be different
+		SameAssetOrderNotAllowed,
+		/// Asset is not in Omnipool
+		AssetNotInOmnipool,
+		/// Order amounts must be at least Omnipool's minimum trading limit
+		OrderAmountTooSmall,
+		/// Expiry block must be in the future
+		InvalidExpiry,
+		/// Maximum number of orders expiring in the block has been reached
+		MaxOrdersExpiringPerBlockReached,
+		/// The caller does not have permission to complete the action
+		Forbidden,
+		/// Reserved amount not sufficient.
+		InsufficientReservedAmount,
+	}
+
+	/// ID sequencer for Orders
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, LimitOrder<T::AccountId, T::AssetId, T::BlockNumber>, OptionQuery>;
+
+	/// Orders which expire in given block
+	#[pallet::storage]
+	#[pallet::getter(fn orders_expiring_at)]
+	pub type OrdersExpiringAt<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::BlockNumber, BoundedVec<OrderId, T::MaxOrdersExpiringPerBlock>, ValueQuery>;
+
+	/// Last order checked in `on_idle`. Next check continues with the following order.
+	#[pallet::storage]
+	#[pallet::getter(fn last_checked_order)]
+	pub type LastCheckedOrder<T: Config> = StorageValue<_, OrderId, OptionQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(current_blocknumber: T::BlockNumber) -> Weight {
+			let order_ids = OrdersExpiringAt::<T>::take(current_blocknumber);
+			let count = order_ids.len() as u32;
+
+			for order_id in order_ids {
+				if let Some(order) = Orders::<T>::take(order_id) {
+					T::Currencies::unreserve_named(&NAMED_RESERVE_ID, order.asset_in, &order.owner, order.amount_in);
+
+					Self::deposit_event(Event::Expired { order_id });
+				}
+			}
+
+			<T as Config>::WeightInfo::on_initialize(count)
+		}
+
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::fill_orders(remaining_weight)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new limit order
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being sold
+		/// - `asset_out`: Asset which is being bought
+		/// - `amount_in`: Amount to sell for sell order, maximum amount to sell for buy order
+		/// - `amount_out`: Minimum amount to buy for sell order, amount to buy for buy order
+		/// - `kind`: Whether the order is sell or buy order
+		/// - `expires_at`: Block in which the order expires. Order does not expire if not set.
+		///
+		/// Validations:
+		/// - asset_in and asset_out must be different and traded in Omnipool
+		/// - amount_in and amount_out must be at least Omnipool's minimum trading limit
+		/// - expires_at must be in the future
+		///
+		/// Events:
+		/// - `Placed` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order())]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			amount_out: Balance,
+			kind: OrderKind,
+			expires_at: Option<T::BlockNumber>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetOrderNotAllowed);
+			ensure!(
+				OmnipoolPallet::<T>::exists(asset_in) || asset_in == <T as pallet_omnipool::Config>::HubAssetId::get(),
+				Error::<T>::AssetNotInOmnipool
+			);
+			ensure!(OmnipoolPallet::<T>::exists(asset_out), Error::<T>::AssetNotInOmnipool);
+
+			let min_amount = <T as pallet_omnipool::Config>::MinimumTradingLimit::get();
+			ensure!(
+				amount_in >= min_amount && amount_out >= min_amount,
+				Error::<T>::OrderAmountTooSmall
+			);
+
+			if let Some(block) = expires_at {
+				ensure!(
+					block > frame_system::Pallet::<T>::block_number(),
+					Error::<T>::InvalidExpiry
+				);
+			}
+
+			let order_id = <NextOrderId<T>>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+				let order_id = *next_id;
+				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
+				Ok(order_id)
+			})?;
+
+			T::Currencies::reserve_named(&NAMED_RESERVE_ID, asset_in, &owner, amount_in)?;
+
+			if let Some(block) = expires_at {
+				<OrdersExpiringAt<T>>::try_mutate(block, |order_ids| order_ids.try_push(order_id))
+					.map_err(|_| Error::<T>::MaxOrdersExpiringPerBlockReached)?;
+			}
+
+			<Orders<T>>::insert(
+				order_id,
+				LimitOrder {
+					owner: owner.clone(),
+					asset_in,
+					asset_out,
+					amount_in,
+					amount_out,
+					kind,
+					expires_at,
+				},
+			);
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				kind,
+				expires_at,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel an open limit order
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		///
+		/// Validations:
+		/// - caller is order owner
+		///
+		/// Emits `Cancelled` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::Forbidden);
+
+			Self::unreserve_amount_in(&order)?;
+			Self::remove_order(order_id, &order);
+
+			Self::deposit_event(Event::Cancelled { order_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Check open orders with given weight and fill those whose limit price has been reached.
+	fn fill_orders(remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		let max_order_weight = <T as Config>::WeightInfo::check_order().saturating_add(Self::fill_order_weight());
+
+		let max_orders = remaining_weight
+			.saturating_sub(weight)
+			.ref_time()
+			.checked_div(max_order_weight.ref_time())
+			.unwrap_or_default()
+			.min(T::MaxOrdersCheckedPerBlock::get() as u64) as usize;
+
+		if max_orders.is_zero() {
+			return Weight::zero();
+		}
+
+		let orders_iter = match <LastCheckedOrder<T>>::get() {
+			Some(order_id) => <Orders<T>>::iter_from(<Orders<T>>::hashed_key_for(order_id)),
+			None => <Orders<T>>::iter(),
+		};
+		let orders: Vec<_> = orders_iter.take(max_orders).collect();
+
+		// Start from the beginning in next block once all orders have been checked.
+		let last_checked_order = if orders.len() < max_orders {
+			None
+		} else {
+			orders.last().map(|(order_id, _)| *order_id)
+		};
+		<LastCheckedOrder<T>>::set(last_checked_order);
+
+		for (order_id, order) in orders {
+			weight.saturating_accrue(<T as Config>::WeightInfo::check_order());
+
+			if !Self::is_limit_price_reached(&order) {
+				continue;
+			}
+
+			weight.saturating_accrue(Self::fill_order_weight());
+
+			if let Ok((amount_in, amount_out)) = Self::fill_order(order_id, &order) {
+				Self::deposit_event(Event::Filled {
+					order_id,
+					owner: order.owner,
+					amount_in,
+					amount_out,
+				});
+			}
+		}
+
+		weight
+	}
+
+	fn is_limit_price_reached(order: &LimitOrder<T::AccountId, T::AssetId, T::BlockNumber>) -> bool {
+		match order.kind {
+			OrderKind::Sell => OmnipoolPallet::<T>::quote_sell(order.asset_in, order.asset_out, order.amount_in)
+				.map(|quote| quote.amount_out >= order.amount_out),
+			OrderKind::Buy => OmnipoolPallet::<T>::quote_buy(order.asset_in, order.asset_out, order.amount_out)
+				.map(|quote| quote.amount_in <= order.amount_in),
+		}
+		.unwrap_or(false)
+	}
+
+	/// Execute the order in Omnipool on behalf of the owner.
+	///
+	/// Returns amounts which were actually sold and bought.
+	#[transactional]
+	fn fill_order(
+		order_id: OrderId,
+		order: &LimitOrder<T::AccountId, T::AssetId, T::BlockNumber>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		Self::unreserve_amount_in(order)?;
+
+		let asset_in_balance = T::Currencies::free_balance(order.asset_in, &order.owner);
+		let asset_out_balance = T::Currencies::free_balance(order.asset_out, &order.owner);
+
+		let origin: OriginFor<T> = RawOrigin::Signed(order.owner.clone()).into();
+
+		match order.kind {
+			OrderKind::Sell => OmnipoolPallet::<T>::sell(
+				origin,
+				order.asset_in,
+				order.asset_out,
+				order.amount_in,
+				order.amount_out,
+			)?,
+			OrderKind::Buy => OmnipoolPallet::<T>::buy(
+				origin,
+				order.asset_out,
+				order.asset_in,
+				order.amount_out,
+				order.amount_in,
+			)?,
+		}
+
+		let amount_in = asset_in_balance
+			.checked_sub(T::Currencies::free_balance(order.asset_in, &order.owner))
+			.ok_or(ArithmeticError::Underflow)?;
+		let amount_out = T::Currencies::free_balance(order.asset_out, &order.owner)
+			.checked_sub(asset_out_balance)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		Self::remove_order(order_id, order);
+
+		Ok((amount_in, amount_out))
+	}
+
+	fn fill_order_weight() -> Weight {
+		<T as Config>::WeightInfo::fill_order().saturating_add(
+			<T as pallet_omnipool::Config>::WeightInfo::sell().max(<T as pallet_omnipool::Config>::WeightInfo::buy()),
+		)
+	}
+
+	fn unreserve_amount_in(order: &LimitOrder<T::AccountId, T::AssetId, T::BlockNumber>) -> DispatchResult {
+		let remaining_to_unreserve =
+			T::Currencies::unreserve_named(&NAMED_RESERVE_ID, order.asset_in, &order.owner, order.amount_in);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+		Ok(())
+	}
+
+	fn remove_order(order_id: OrderId, order: &LimitOrder<T::AccountId, T::AssetId, T::BlockNumber>) {
+		<Orders<T>>::remove(order_id);
+
+		if let Some(block) = order.expires_at {
+			<OrdersExpiringAt<T>>::mutate(block, |order_ids| order_ids.retain(|id| *id != order_id));
+		}
+	}
+}
@@ -0,0 +1,87 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as limit_orders;
+use crate::tests::mock::*;
+use crate::{Event, OrderKind};
+use frame_support::{assert_ok, traits::Hooks};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn on_initialize_should_remove_expired_orders_and_unreserve_amount_in() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			Some(10),
+		));
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(BOB),
+			DAI,
+			HDX,
+			10 * ONE,
+			100 * ONE,
+			OrderKind::Sell,
+			Some(11),
+		));
+
+		// Act
+		LimitOrders::on_initialize(10);
+
+		// Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert!(LimitOrders::orders(1).is_some());
+		assert!(LimitOrders::orders_expiring_at(10).is_empty());
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, DAI, &BOB),
+			10 * ONE
+		);
+
+		expect_events(vec![Event::Expired { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn on_initialize_should_skip_orders_which_were_already_removed() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			Some(10),
+		));
+		assert_ok!(LimitOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Act
+		LimitOrders::on_initialize(10);
+
+		// Assert
+		expect_events(vec![Event::Cancelled { order_id: 0 }.into()]);
+	});
+}
@@ -0,0 +1,198 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as limit_orders;
+use crate::tests::mock::*;
+use crate::{Event, OrderKind};
+use frame_support::{assert_ok, traits::Hooks, weights::Weight};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+
+fn move_dai_price_up() {
+	assert_ok!(Omnipool::sell(RuntimeOrigin::signed(BOB), DAI, HDX, 500 * ONE, 0));
+}
+
+#[test]
+fn on_idle_should_fill_sell_order_when_limit_price_is_reached() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			Some(10),
+		));
+		move_dai_price_up();
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert!(LimitOrders::orders_expiring_at(10).is_empty());
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 990 * ONE);
+
+		let amount_out = Tokens::free_balance(DAI, &ALICE) - 1000 * ONE;
+		assert!(amount_out >= 25 * ONE);
+
+		expect_events(vec![Event::Filled {
+			order_id: 0,
+			owner: ALICE,
+			amount_in: 10 * ONE,
+			amount_out,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_fill_buy_order_when_limit_price_is_reached() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			4 * ONE,
+			10 * ONE,
+			OrderKind::Buy,
+			None,
+		));
+		move_dai_price_up();
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert_eq!(LimitOrders::orders(0), None);
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1010 * ONE);
+
+		let amount_in = 1000 * ONE - Tokens::free_balance(HDX, &ALICE);
+		assert!(amount_in <= 4 * ONE);
+
+		expect_events(vec![Event::Filled {
+			order_id: 0,
+			owner: ALICE,
+			amount_in,
+			amount_out: 10 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_idle_should_not_fill_orders_when_limit_price_is_not_reached() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			None,
+		));
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			4 * ONE,
+			10 * ONE,
+			OrderKind::Buy,
+			None,
+		));
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert!(LimitOrders::orders(0).is_some());
+		assert!(LimitOrders::orders(1).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			14 * ONE
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_check_at_most_max_orders_per_block() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		for _ in 0..4 {
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				ONE,
+				ONE / 10,
+				OrderKind::Sell,
+				None,
+			));
+		}
+
+		// Act
+		LimitOrders::on_idle(1, Weight::MAX);
+
+		// Assert
+		assert_eq!(
+			limit_orders::Orders::<Test>::iter().count(),
+			4 - MaxOrdersCheckedPerBlock::get() as usize
+		);
+		assert!(LimitOrders::last_checked_order().is_some());
+
+		// Act
+		LimitOrders::on_idle(2, Weight::MAX);
+
+		// Assert
+		assert_eq!(limit_orders::Orders::<Test>::iter().count(), 0);
+		assert_eq!(LimitOrders::last_checked_order(), None);
+	});
+}
+
+#[test]
+fn on_idle_should_not_check_orders_when_there_is_not_enough_weight() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			ONE,
+			ONE / 10,
+			OrderKind::Sell,
+			None,
+		));
+
+		// Act
+		let weight = LimitOrders::on_idle(1, Weight::zero());
+
+		// Assert
+		assert_eq!(weight, Weight::zero());
+		assert!(LimitOrders::orders(0).is_some());
+	});
+}
@@ -0,0 +1,252 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as limit_orders;
+use crate::tests::mock::*;
+use crate::{Error, Event, LimitOrder, OrderKind};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn place_order_should_reserve_amount_in() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			Some(10),
+		));
+
+		// Assert
+		assert_eq!(
+			LimitOrders::orders(0),
+			Some(LimitOrder {
+				owner: ALICE,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: 10 * ONE,
+				amount_out: 25 * ONE,
+				kind: OrderKind::Sell,
+				expires_at: Some(10),
+			})
+		);
+		assert_eq!(LimitOrders::orders_expiring_at(10).to_vec(), vec![0]);
+		assert_eq!(LimitOrders::next_order_id(), 1);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			10 * ONE
+		);
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			owner: ALICE,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * ONE,
+			amount_out: 25 * ONE,
+			kind: OrderKind::Sell,
+			expires_at: Some(10),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_work_when_order_does_not_expire() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			DAI,
+			HDX,
+			10 * ONE,
+			5 * ONE,
+			OrderKind::Buy,
+			None,
+		));
+
+		// Assert
+		assert_eq!(LimitOrders::orders(0).unwrap().expires_at, None);
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, DAI, &ALICE),
+			10 * ONE
+		);
+	});
+}
+
+#[test]
+fn place_order_should_work_when_asset_in_is_hub_asset() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		assert_ok!(Tokens::deposit(LRNA, &ALICE, 100 * ONE));
+
+		// Act
+		assert_ok!(LimitOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			LRNA,
+			DAI,
+			10 * ONE,
+			25 * ONE,
+			OrderKind::Sell,
+			None,
+		));
+
+		// Assert
+		assert_eq!(
+			Tokens::reserved_balance_named(&limit_orders::NAMED_RESERVE_ID, LRNA, &ALICE),
+			10 * ONE
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_assets_are_same() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				HDX,
+				10 * ONE,
+				10 * ONE,
+				OrderKind::Sell,
+				None
+			),
+			Error::<Test>::SameAssetOrderNotAllowed
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				NOT_IN_POOL,
+				10 * ONE,
+				10 * ONE,
+				OrderKind::Sell,
+				None
+			),
+			Error::<Test>::AssetNotInOmnipool
+		);
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				NOT_IN_POOL,
+				HDX,
+				10 * ONE,
+				10 * ONE,
+				OrderKind::Sell,
+				None
+			),
+			Error::<Test>::AssetNotInOmnipool
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_amount_is_below_minimum_trading_limit() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				MinTradeAmount::get() - 1,
+				OrderKind::Sell,
+				None
+			),
+			Error::<Test>::OrderAmountTooSmall
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_expiry_block_is_not_in_future() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				25 * ONE,
+				OrderKind::Sell,
+				Some(System::block_number())
+			),
+			Error::<Test>::InvalidExpiry
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_max_orders_expiring_in_block_is_reached() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		// Arrange
+		for _ in 0..MaxOrdersExpiringPerBlock::get() {
+			assert_ok!(LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				ONE,
+				25 * ONE,
+				OrderKind::Sell,
+				Some(10)
+			));
+		}
+
+		// Act & Assert
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				ONE,
+				25 * ONE,
+				OrderKind::Sell,
+				Some(10)
+			),
+			Error::<Test>::MaxOrdersExpiringPerBlockReached
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().with_initial_pool().build().execute_with(|| {
+		assert_noop!(
+			LimitOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				1001 * ONE,
+				25 * ONE,
+				OrderKind::Sell,
+				None
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
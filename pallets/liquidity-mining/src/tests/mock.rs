@@ -207,7 +207,6 @@ impl AMM<AccountId, AssetId, AssetPair, Balance> for Amm {
 		_assets: AssetPair,
 		_amount: Balance,
 		_min_bought: Balance,
-		_discount: bool,
 	) -> Result<
 		hydradx_traits::AMMTransfer<AccountId, AssetId, AssetPair, Balance>,
 		frame_support::sp_runtime::DispatchError,
@@ -232,7 +231,6 @@ impl AMM<AccountId, AssetId, AssetPair, Balance> for Amm {
 		_assets: AssetPair,
 		_amount: Balance,
 		_max_limit: Balance,
-		_discount: bool,
 	) -> Result<
 		hydradx_traits::AMMTransfer<AccountId, AssetId, AssetPair, Balance>,
 		frame_support::sp_runtime::DispatchError,
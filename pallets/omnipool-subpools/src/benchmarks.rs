@@ -0,0 +1,117 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_system::RawOrigin;
+use orml_traits::MultiCurrencyExtended;
+use pallet_stableswap::types::BenchmarkHelper;
+use pallet_stableswap::MAX_ASSETS_IN_POOL;
+use sp_runtime::FixedU128;
+
+const ONE: Balance = 1_000_000_000_000;
+const SHARE_ASSET: u32 = 100;
+const FIRST_STABLE_ASSET: u32 = 101;
+
+const SEED: u32 = 0;
+
+fn add_stable_asset<T: Config>(asset_id: u32) -> DispatchResult
+where
+	<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	AssetIdOf<T>: From<u32>,
+{
+	let acc = OmnipoolPallet::<T>::protocol_account();
+
+	<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(asset_id.into(), 12)?;
+	<T as pallet_omnipool::Config>::Currency::update_balance(asset_id.into(), &acc, (1_000_000 * ONE) as i128)?;
+
+	OmnipoolPallet::<T>::add_token(
+		RawOrigin::Root.into(),
+		asset_id.into(),
+		FixedU128::from((1, 2)),
+		Permill::from_percent(100),
+		acc,
+	)
+}
+
+fn add_stable_assets<T: Config>(count: u32) -> Result<Vec<AssetIdOf<T>>, DispatchError>
+where
+	<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	AssetIdOf<T>: From<u32>,
+{
+	let mut assets = Vec::new();
+
+	for asset_id in FIRST_STABLE_ASSET..FIRST_STABLE_ASSET + count {
+		add_stable_asset::<T>(asset_id)?;
+		assets.push(asset_id.into());
+	}
+
+	<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(SHARE_ASSET.into(), 18)?;
+
+	Ok(assets)
+}
+
+benchmarks! {
+	where_clause { where
+		<T as pallet_omnipool::Config>::Currency: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		AssetIdOf<T>: From<u32>,
+	}
+
+	create_subpool {
+		let a in 2 .. MAX_ASSETS_IN_POOL;
+
+		let assets = add_stable_assets::<T>(a)?;
+	}:  _(RawOrigin::Root, SHARE_ASSET.into(), assets.clone(), 100u16, Permill::from_percent(1), Permill::from_percent(100))
+	verify {
+		assert!(OmnipoolPallet::<T>::exists(SHARE_ASSET.into()));
+		for asset_id in assets {
+			assert!(!OmnipoolPallet::<T>::exists(asset_id));
+		}
+	}
+
+	migrate_position {
+		let assets = add_stable_assets::<T>(2)?;
+
+		let lp: T::AccountId = account("lp", 1, SEED);
+		<T as pallet_omnipool::Config>::Currency::update_balance(assets[0], &lp, (1_000 * ONE) as i128)?;
+
+		let position_id = OmnipoolPallet::<T>::next_position_id();
+		OmnipoolPallet::<T>::add_liquidity(RawOrigin::Signed(lp.clone()).into(), assets[0], 100 * ONE)?;
+
+		Pallet::<T>::create_subpool(
+			RawOrigin::Root.into(),
+			SHARE_ASSET.into(),
+			assets,
+			100u16,
+			Permill::from_percent(1),
+			Permill::from_percent(100),
+		)?;
+	}:  _(RawOrigin::Signed(lp), position_id)
+	verify {
+		assert_eq!(OmnipoolPallet::<T>::positions(position_id).unwrap().asset_id, SHARE_ASSET.into());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,389 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// # Omnipool subpools pallet
+// ## General description
+// This pallet moves several Omnipool assets into a stableswap pool (subpool) and lists the stableswap share asset
+// in the Omnipool instead. Assets of the subpool do not compete for hub asset liquidity anymore - the subpool is
+// represented by a single asset whose hub reserve is the sum of hub reserves of the migrated assets.
+//
+// ## Notes
+// When a subpool is created, reserves of the migrated assets are transferred from the Omnipool account to the
+// stableswap pool account and the share asset is minted to the Omnipool account as the reserve of the new Omnipool
+// asset. The migrated assets are removed from the Omnipool.
+//
+// Existing LP positions of the migrated assets are not converted immediately. Details of the migration are stored
+// for each migrated asset and the owner of a position converts it into a position of the share asset by calling
+// `migrate_position`.
+//
+// ## Dispatachable functions
+// * `create_subpool` -  create a subpool from Omnipool assets.
+// * `migrate_position` - convert LP position of a migrated asset into a position of the subpool share asset.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use frame_support::traits::{tokens::nonfungibles::Inspect, Contains};
+use frame_support::{pallet_prelude::*, require_transactional, transactional};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydra_dx_math::omnipool::types::AssetReserveState as MathReserveState;
+use hydra_dx_math::omnipool_subpools::types::MigrationDetails;
+use hydra_dx_math::omnipool_subpools::{
+	calculate_asset_migration_details, convert_position, create_subpool_initial_state,
+};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::types::{Position, Tradability};
+use pallet_stableswap::types::StableswapHooks;
+use sp_runtime::{traits::Zero, ArithmeticError, DispatchError, Permill};
+use sp_std::num::NonZeroU16;
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub type Balance = u128;
+
+type OmnipoolPallet<T> = pallet_omnipool::Pallet<T>;
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+
+pub type AssetIdOf<T> = <T as pallet_omnipool::Config>::AssetId;
+
+/// Details of an asset migration into a subpool. Used to convert LP positions of the migrated asset.
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub struct AssetDetail {
+	/// Price of the asset denominated in the share asset at the time of migration
+	pub price: (Balance, Balance),
+	/// Total LP shares of the asset at the time of migration
+	pub shares: Balance,
+	/// Hub reserve of the asset at the time of migration
+	pub hub_reserve: Balance,
+	/// Amount of share asset which corresponds to the migrated asset reserve
+	pub share_tokens: Balance,
+}
+
+impl From<MigrationDetails> for AssetDetail {
+	fn from(details: MigrationDetails) -> Self {
+		Self {
+			price: details.price,
+			shares: details.shares,
+			hub_reserve: details.hub_reserve,
+			share_tokens: details.share_tokens,
+		}
+	}
+}
+
+impl From<AssetDetail> for MigrationDetails {
+	fn from(detail: AssetDetail) -> Self {
+		Self {
+			price: detail.price,
+			shares: detail.shares,
+			hub_reserve: detail.hub_reserve,
+			share_tokens: detail.share_tokens,
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config
+		+ pallet_omnipool::Config
+		+ pallet_stableswap::Config<AssetId = <Self as pallet_omnipool::Config>::AssetId>
+	{
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Origin that can create subpools.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A subpool has been created and its share asset added to the Omnipool.
+		SubpoolCreated {
+			pool_id: AssetIdOf<T>,
+			assets: Vec<AssetIdOf<T>>,
+			share_tokens: Balance,
+		},
+		/// An Omnipool asset has been migrated into a subpool.
+		AssetMigrated {
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			reserve: Balance,
+			share_tokens: Balance,
+		},
+		/// LP position of a migrated asset has been converted into a position of the subpool share asset.
+		PositionMigrated {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: AssetIdOf<T>,
+			pool_id: AssetIdOf<T>,
+			shares: Balance,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Subpool must be created from at least two assets
+		InsufficientAssets,
+		/// Native and hub asset cannot be migrated into a subpool
+		NotAllowed,
+		/// Asset is not in Omnipool
+		AssetNotInOmnipool,
+		/// Asset is being delisted from Omnipool
+		AssetBeingDelisted,
+		/// Asset of the position has not been migrated into a subpool
+		AssetNotMigrated,
+		/// Position is locked, eg. in liquidity mining, and must be returned to its owner first
+		PositionLocked,
+	}
+
+	/// Migrated assets and details of their migration. Maps asset id to subpool id and migration details.
+	#[pallet::storage]
+	#[pallet::getter(fn migrated_assets)]
+	pub type MigratedAssets<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (AssetIdOf<T>, AssetDetail), OptionQuery>;
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a subpool from Omnipool assets.
+		///
+		/// Creates a stableswap pool with `share_asset` as pool id, moves reserves of `assets` from the Omnipool into
+		/// the pool and adds the share asset to the Omnipool instead of the migrated assets.
+		///
+		/// Hub reserve, shares and protocol shares of the migrated assets are transferred to the share asset,
+		/// so total hub asset liquidity in Omnipool is unchanged.
+		///
+		/// Parameters:
+		/// - `origin`: Must be AuthorityOrigin
+		/// - `share_asset`: Pool id of the new stableswap pool. Must be registered and not in Omnipool.
+		/// - `assets`: Omnipool assets to migrate into the subpool.
+		/// - `amplification`: Amplification of the stableswap pool.
+		/// - `fee`: Trade fee of the stableswap pool.
+		/// - `share_asset_weight_cap`: Weight cap of the share asset in Omnipool.
+		///
+		/// Emits `AssetMigrated` event for each migrated asset and `SubpoolCreated` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_subpool(assets.len() as u32)
+							.saturating_add(<T as pallet_stableswap::Config>::Hooks::on_liquidity_changed_weight(assets.len()))
+							.saturating_add(StableswapPallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn create_subpool(
+			origin: OriginFor<T>,
+			share_asset: AssetIdOf<T>,
+			assets: Vec<AssetIdOf<T>>,
+			amplification: u16,
+			fee: Permill,
+			share_asset_weight_cap: Permill,
+		) -> DispatchResult {
+			<T as Config>::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(assets.len() >= 2, Error::<T>::InsufficientAssets);
+
+			let amplification =
+				NonZeroU16::new(amplification).ok_or(pallet_stableswap::Error::<T>::InvalidAmplification)?;
+
+			let pool_id = StableswapPallet::<T>::do_create_pool(share_asset, &assets, amplification, fee)?;
+
+			let share_tokens = Self::do_migrate_assets(pool_id, &assets, share_asset_weight_cap)?;
+
+			Self::deposit_event(Event::SubpoolCreated {
+				pool_id,
+				assets,
+				share_tokens,
+			});
+
+			Ok(())
+		}
+
+		/// Convert LP position of a migrated asset into a position of the subpool share asset.
+		///
+		/// Position keeps its id and NFT. Amount, shares and price of the position are converted using details
+		/// of the asset migration.
+		///
+		/// Locked positions, eg. positions deposited in liquidity mining, cannot be converted. Owner has to
+		/// withdraw the position first, as the farms of the position are bound to the migrated asset.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: Id of the position to convert
+		///
+		/// Emits `PositionMigrated` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::migrate_position())]
+		#[transactional]
+		pub fn migrate_position(origin: OriginFor<T>, position_id: T::PositionItemId) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			let position_owner = <T as pallet_omnipool::Config>::NFTHandler::owner(
+				&<T as pallet_omnipool::Config>::NFTCollectionId::get(),
+				&position_id,
+			);
+			ensure!(
+				!position_owner.map_or(false, |who| {
+					<T as pallet_omnipool::Config>::LockedPositionOwners::contains(&who)
+				}),
+				Error::<T>::PositionLocked
+			);
+
+			let position = OmnipoolPallet::<T>::load_position(position_id, owner.clone())?;
+
+			let (pool_id, detail) = MigratedAssets::<T>::get(position.asset_id).ok_or(Error::<T>::AssetNotMigrated)?;
+
+			let converted = convert_position((&position).into(), detail.into()).ok_or(ArithmeticError::Overflow)?;
+
+			let migrated_position = Position {
+				asset_id: pool_id,
+				amount: converted.amount,
+				shares: converted.shares,
+				price: converted.price,
+			};
+
+			OmnipoolPallet::<T>::set_position(position_id, &migrated_position)?;
+
+			Self::deposit_event(Event::PositionMigrated {
+				position_id,
+				owner,
+				asset_id: position.asset_id,
+				pool_id,
+				shares: migrated_position.shares,
+			});
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Move `assets` from Omnipool into subpool `pool_id` and add the share asset to Omnipool.
+	///
+	/// Initial state of the share asset is calculated from the first two assets, remaining assets are
+	/// migrated into the already created state one by one.
+	///
+	/// Returns amount of share asset minted to Omnipool.
+	#[require_transactional]
+	fn do_migrate_assets(
+		pool_id: AssetIdOf<T>,
+		assets: &[AssetIdOf<T>],
+		share_asset_weight_cap: Permill,
+	) -> Result<Balance, DispatchError> {
+		let mut states: Vec<MathReserveState<Balance>> = Vec::with_capacity(assets.len());
+
+		for asset_id in assets.iter() {
+			ensure!(
+				*asset_id != <T as pallet_omnipool::Config>::HubAssetId::get()
+					&& *asset_id != <T as pallet_omnipool::Config>::HdxAssetId::get(),
+				Error::<T>::NotAllowed
+			);
+			ensure!(
+				OmnipoolPallet::<T>::delistings(asset_id).is_none(),
+				Error::<T>::AssetBeingDelisted
+			);
+
+			let state = OmnipoolPallet::<T>::load_asset_state(*asset_id).map_err(|_| Error::<T>::AssetNotInOmnipool)?;
+			states.push((&state).into());
+		}
+
+		let mut subpool_state =
+			create_subpool_initial_state(&states[0], &states[1]).ok_or(ArithmeticError::Overflow)?;
+
+		let mut migration_details: Vec<MigrationDetails> = Vec::with_capacity(assets.len());
+
+		for state in states.iter().take(2) {
+			let (details, _) =
+				calculate_asset_migration_details(state, None, Balance::zero()).ok_or(ArithmeticError::Overflow)?;
+			migration_details.push(details);
+		}
+
+		for state in states.iter().skip(2) {
+			let (details, delta) =
+				calculate_asset_migration_details(state, Some(&subpool_state), subpool_state.reserve)
+					.ok_or(ArithmeticError::Overflow)?;
+			let delta = delta.ok_or(ArithmeticError::Overflow)?;
+
+			subpool_state = subpool_state.delta_update(&delta).ok_or(ArithmeticError::Overflow)?;
+			migration_details.push(details);
+		}
+
+		let omnipool_account = OmnipoolPallet::<T>::protocol_account();
+		let pool_account = StableswapPallet::<T>::pool_account(pool_id);
+
+		for ((asset_id, state), details) in assets.iter().zip(states.iter()).zip(migration_details) {
+			<T as pallet_omnipool::Config>::Currency::transfer(
+				*asset_id,
+				&omnipool_account,
+				&pool_account,
+				state.reserve,
+			)?;
+
+			OmnipoolPallet::<T>::remove_asset(*asset_id)?;
+
+			let share_tokens = details.share_tokens;
+			MigratedAssets::<T>::insert(asset_id, (pool_id, AssetDetail::from(details)));
+
+			Self::deposit_event(Event::AssetMigrated {
+				asset_id: *asset_id,
+				pool_id,
+				reserve: state.reserve,
+				share_tokens,
+			});
+		}
+
+		let share_tokens = subpool_state.reserve;
+
+		<T as pallet_omnipool::Config>::Currency::deposit(pool_id, &omnipool_account, share_tokens)?;
+
+		let pool = StableswapPallet::<T>::pools(pool_id).ok_or(pallet_stableswap::Error::<T>::PoolNotFound)?;
+		let delta: Vec<Balance> = pool
+			.assets
+			.iter()
+			.map(|asset_id| <T as pallet_omnipool::Config>::Currency::free_balance(*asset_id, &pool_account))
+			.collect();
+		StableswapPallet::<T>::notify_liquidity_changed(
+			pool_id,
+			&pool,
+			sp_std::vec![Balance::zero(); delta.len()],
+			delta,
+			Balance::zero(),
+		)?;
+
+		OmnipoolPallet::<T>::add_asset(
+			pool_id,
+			(subpool_state, share_asset_weight_cap, Tradability::default()).into(),
+		)?;
+
+		Ok(share_tokens)
+	}
+}
@@ -0,0 +1,322 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AssetDetail, Error, Event, MigratedAssets};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128, Permill};
+
+#[test]
+fn create_subpool_should_move_asset_reserves_to_stableswap_pool() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				vec![USDT, USDC],
+				100,
+				Permill::from_percent(1),
+				Permill::from_percent(50),
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(USDT, &pool_account(SHARE_ASSET)), 2000 * ONE);
+			assert_eq!(Tokens::free_balance(USDC, &pool_account(SHARE_ASSET)), 1000 * ONE);
+			assert_eq!(Tokens::free_balance(USDT, &Omnipool::protocol_account()), 0);
+			assert_eq!(Tokens::free_balance(USDC, &Omnipool::protocol_account()), 0);
+			assert_eq!(
+				Tokens::free_balance(SHARE_ASSET, &Omnipool::protocol_account()),
+				2000 * ONE
+			);
+			assert_eq!(Tokens::total_issuance(SHARE_ASSET), 2000 * ONE);
+			assert!(Stableswap::pools(SHARE_ASSET).is_some());
+		});
+}
+
+#[test]
+fn create_subpool_should_replace_migrated_assets_with_share_asset_in_omnipool() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			let hub_balance = Tokens::free_balance(LRNA, &Omnipool::protocol_account());
+
+			// Act
+			assert_ok!(OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				vec![USDT, USDC],
+				100,
+				Permill::from_percent(1),
+				Permill::from_percent(50),
+			));
+
+			// Assert
+			assert!(!Omnipool::exists(USDT));
+			assert!(!Omnipool::exists(USDC));
+
+			let state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+			assert_eq!(state.reserve, 2000 * ONE);
+			assert_eq!(state.hub_reserve, 2000 * ONE);
+			assert_eq!(state.shares, 2000 * ONE);
+			assert_eq!(state.protocol_shares, 0);
+			assert_eq!(state.cap, 500_000_000_000_000_000);
+
+			assert_eq!(Tokens::free_balance(LRNA, &Omnipool::protocol_account()), hub_balance);
+		});
+}
+
+#[test]
+fn create_subpool_should_store_migration_details() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				vec![USDT, USDC],
+				100,
+				Permill::from_percent(1),
+				Permill::from_percent(50),
+			));
+
+			// Assert
+			assert_eq!(
+				MigratedAssets::<Test>::get(USDT),
+				Some((
+					SHARE_ASSET,
+					AssetDetail {
+						price: (1300 * ONE, 2000 * ONE),
+						shares: 2000 * ONE,
+						hub_reserve: 1300 * ONE,
+						share_tokens: 1300 * ONE,
+					}
+				))
+			);
+			assert_eq!(
+				MigratedAssets::<Test>::get(USDC),
+				Some((
+					SHARE_ASSET,
+					AssetDetail {
+						price: (700 * ONE, 1000 * ONE),
+						shares: 1000 * ONE,
+						hub_reserve: 700 * ONE,
+						share_tokens: 700 * ONE,
+					}
+				))
+			);
+
+			expect_events(vec![
+				Event::AssetMigrated {
+					asset_id: USDT,
+					pool_id: SHARE_ASSET,
+					reserve: 2000 * ONE,
+					share_tokens: 1300 * ONE,
+				}
+				.into(),
+				Event::AssetMigrated {
+					asset_id: USDC,
+					pool_id: SHARE_ASSET,
+					reserve: 1000 * ONE,
+					share_tokens: 700 * ONE,
+				}
+				.into(),
+				Event::SubpoolCreated {
+					pool_id: SHARE_ASSET,
+					assets: vec![USDT, USDC],
+					share_tokens: 2000 * ONE,
+				}
+				.into(),
+			]);
+		});
+}
+
+#[test]
+fn create_subpool_should_migrate_all_assets_when_more_than_two_assets_are_provided() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_token(USDD, FixedU128::from_rational(1, 2), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Act
+			assert_ok!(OmnipoolSubpools::create_subpool(
+				RuntimeOrigin::root(),
+				SHARE_ASSET,
+				vec![USDT, USDC, USDD],
+				100,
+				Permill::from_percent(1),
+				Permill::from_percent(50),
+			));
+
+			// Assert
+			assert!(!Omnipool::exists(USDD));
+			assert_eq!(Tokens::free_balance(USDD, &pool_account(SHARE_ASSET)), 1000 * ONE);
+
+			let state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+			assert_eq!(state.reserve, 2500 * ONE);
+			assert_eq!(state.hub_reserve, 2500 * ONE);
+			assert_eq!(state.shares, 2500 * ONE);
+
+			assert_eq!(
+				MigratedAssets::<Test>::get(USDD),
+				Some((
+					SHARE_ASSET,
+					AssetDetail {
+						price: (500 * ONE * 2000 * ONE, 1000 * ONE * 2000 * ONE),
+						shares: 1000 * ONE,
+						hub_reserve: 500 * ONE,
+						share_tokens: 500 * ONE,
+					}
+				))
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_called_by_non_authority_origin() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::signed(ALICE),
+					SHARE_ASSET,
+					vec![USDT, USDC],
+					100,
+					Permill::from_percent(1),
+					Permill::from_percent(50),
+				),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_less_than_two_assets_are_provided() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					vec![USDT],
+					100,
+					Permill::from_percent(1),
+					Permill::from_percent(50),
+				),
+				Error::<Test>::InsufficientAssets
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_native_asset_is_migrated() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					vec![USDT, HDX],
+					100,
+					Permill::from_percent(1),
+					Permill::from_percent(50),
+				),
+				Error::<Test>::NotAllowed
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_not_in_omnipool() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_registered_asset(USDC)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					vec![USDT, USDC],
+					100,
+					Permill::from_percent(1),
+					Permill::from_percent(50),
+				),
+				Error::<Test>::AssetNotInOmnipool
+			);
+		});
+}
+
+#[test]
+fn create_subpool_should_fail_when_asset_is_being_delisted() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			assert_ok!(Omnipool::announce_delisting(RuntimeOrigin::root(), USDC, 10, ALICE));
+
+			// Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::create_subpool(
+					RuntimeOrigin::root(),
+					SHARE_ASSET,
+					vec![USDT, USDC],
+					100,
+					Permill::from_percent(1),
+					Permill::from_percent(50),
+				),
+				Error::<Test>::AssetBeingDelisted
+			);
+		});
+}
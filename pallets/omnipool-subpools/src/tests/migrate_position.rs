@@ -0,0 +1,227 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use pallet_omnipool::types::Position;
+use pretty_assertions::assert_eq;
+use sp_runtime::{FixedU128, Permill};
+
+const USDT_POSITION: u32 = 2;
+const USDC_POSITION: u32 = 3;
+const USDD_POSITION: u32 = 4;
+
+fn create_subpool() {
+	assert_ok!(OmnipoolSubpools::create_subpool(
+		RuntimeOrigin::root(),
+		SHARE_ASSET,
+		vec![USDT, USDC],
+		100,
+		Permill::from_percent(1),
+		Permill::from_percent(50),
+	));
+}
+
+#[test]
+fn migrate_position_should_convert_position_to_share_asset() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+
+			// Act
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP),
+				USDT_POSITION
+			));
+
+			// Assert
+			assert_eq!(
+				Omnipool::positions(USDT_POSITION),
+				Some(Position {
+					asset_id: SHARE_ASSET,
+					amount: 1300 * ONE,
+					shares: 1300 * ONE,
+					price: (
+						650_000_000_000_000_000 * 2000 * ONE,
+						1_000_000_000_000_000_000 * 1300 * ONE
+					),
+				})
+			);
+
+			expect_events(vec![Event::PositionMigrated {
+				position_id: USDT_POSITION,
+				owner: LP,
+				asset_id: USDT,
+				pool_id: SHARE_ASSET,
+				shares: 1300 * ONE,
+			}
+			.into()]);
+		});
+}
+
+#[test]
+fn migrated_positions_should_own_all_shares_of_share_asset() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+
+			// Act
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP),
+				USDT_POSITION
+			));
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP),
+				USDC_POSITION
+			));
+
+			// Assert
+			let usdt_position = Omnipool::positions(USDT_POSITION).unwrap();
+			let usdc_position = Omnipool::positions(USDC_POSITION).unwrap();
+			let state = Omnipool::load_asset_state(SHARE_ASSET).unwrap();
+
+			assert_eq!(usdc_position.shares, 700 * ONE);
+			assert_eq!(usdt_position.shares + usdc_position.shares, state.shares);
+		});
+}
+
+#[test]
+fn migrated_position_liquidity_should_be_removable_from_omnipool() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP),
+				USDT_POSITION
+			));
+
+			// Act
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP),
+				USDT_POSITION,
+				1300 * ONE
+			));
+
+			// Assert
+			assert_eq!(Tokens::free_balance(SHARE_ASSET, &LP), 1300 * ONE);
+			assert!(Omnipool::positions(USDT_POSITION).is_none());
+		});
+}
+
+#[test]
+fn migrate_position_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+
+			// Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(ALICE), USDT_POSITION),
+				pallet_omnipool::Error::<Test>::Forbidden
+			);
+		});
+}
+
+#[test]
+fn migrate_position_should_fail_when_asset_was_not_migrated() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_token(USDD, FixedU128::from_rational(1, 2), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+
+			// Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP), USDD_POSITION),
+				Error::<Test>::AssetNotMigrated
+			);
+		});
+}
+
+#[test]
+fn migrate_position_should_fail_when_position_was_already_migrated() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+			assert_ok!(OmnipoolSubpools::migrate_position(
+				RuntimeOrigin::signed(LP),
+				USDT_POSITION
+			));
+
+			// Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP), USDT_POSITION),
+				Error::<Test>::AssetNotMigrated
+			);
+		});
+}
+
+#[test]
+fn migrate_position_should_fail_when_position_is_locked_in_liquidity_mining() {
+	ExtBuilder::default()
+		.with_registered_asset(SHARE_ASSET)
+		.with_token(USDT, FixedU128::from_rational(65, 100), LP, 2000 * ONE)
+		.with_token(USDC, FixedU128::from_rational(7, 10), LP, 1000 * ONE)
+		.with_initial_pool()
+		.build()
+		.execute_with(|| {
+			// Arrange
+			create_subpool();
+			POSITIONS.with(|v| v.borrow_mut().insert(USDT_POSITION, LOCKED_POSITIONS_ACCOUNT));
+
+			// Act & Assert
+			assert_noop!(
+				OmnipoolSubpools::migrate_position(RuntimeOrigin::signed(LP), USDT_POSITION),
+				Error::<Test>::PositionLocked
+			);
+		});
+}
@@ -0,0 +1,500 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as omnipool_subpools;
+use crate::Config;
+use core::ops::RangeInclusive;
+use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
+use frame_support::{
+	assert_ok, parameter_types,
+	traits::{ConstU32, Contains, Everything, GenesisBuild, Nothing},
+	weights::Weight,
+};
+use frame_system::EnsureRoot;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
+use hydradx_traits::{AccountIdFor, AssetKind, InspectRegistry, Registry};
+use orml_traits::{parameter_type_with_key, GetByKey};
+use pallet_omnipool::traits::ExternalPriceProvider;
+#[cfg(feature = "runtime-benchmarks")]
+use pallet_stableswap::types::BenchmarkHelper;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult, FixedU128, Permill,
+};
+use sp_std::num::NonZeroU16;
+use std::{cell::RefCell, collections::HashMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const USDT: AssetId = 10;
+pub const USDC: AssetId = 11;
+pub const USDD: AssetId = 12;
+pub const SHARE_ASSET: AssetId = 20;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const LP: AccountId = 3;
+pub const LOCKED_POSITIONS_ACCOUNT: AccountId = 99;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system,
+		 Tokens: orml_tokens,
+		 Omnipool: pallet_omnipool,
+		 Stableswap: pallet_stableswap,
+		 OmnipoolSubpools: omnipool_subpools,
+	 }
+);
+
+thread_local! {
+	pub static REGISTERED_ASSETS: RefCell<HashMap<AssetId, u8>> = RefCell::new(HashMap::default());
+	pub static POSITIONS: RefCell<HashMap<u32, u64>> = RefCell::new(HashMap::default());
+	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+parameter_types! {
+	pub const HDXAssetId: AssetId = HDX;
+	pub const LRNAAssetId: AssetId = LRNA;
+	pub const PositionCollectionId: u32 = 1000;
+	pub const AssetFee: Permill = Permill::from_percent(0);
+	pub const ProtocolFee: Permill = Permill::from_percent(0);
+	pub const MinAddedLiquidity: Balance = 1000u128;
+	pub const MinTradeAmount: Balance = 1000u128;
+	pub const MaxInRatio: Balance = 1u128;
+	pub const MaxOutRatio: Balance = 1u128;
+	pub const MinWithdrawFee: Permill = Permill::from_percent(0);
+}
+
+impl pallet_omnipool::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type PositionItemId = u32;
+	type Currency = Tokens;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
+	type HubAssetId = LRNAAssetId;
+	type WeightInfo = ();
+	type HdxAssetId = HDXAssetId;
+	type NFTCollectionId = PositionCollectionId;
+	type NFTHandler = DummyNFT;
+	type AssetRegistry = DummyRegistry;
+	type MinimumTradingLimit = MinTradeAmount;
+	type MinimumPoolLiquidity = MinAddedLiquidity;
+	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
+	type MaxInRatio = MaxInRatio;
+	type MaxOutRatio = MaxOutRatio;
+	type CollectionId = u32;
+	type OmnipoolHooks = ();
+	type PriceBarrier = ();
+	type MinWithdrawalFee = MinWithdrawFee;
+	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type Fee = FeeProvider;
+	type RouteExecutor = ();
+	type AmmTradeWeights = ();
+	type FeeDiscount = ();
+	type LockedPositionOwners = LockedPositionOwnersMock;
+	type MaxDelistings = ConstU32<10>;
+}
+
+parameter_types! {
+	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub MaxAmplificationChangePerBlock: FixedU128 = FixedU128::from(100);
+	pub const TreasuryAccount: AccountId = 100;
+}
+
+impl pallet_stableswap::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type BlockNumberProvider = System;
+	type AssetId = AssetId;
+	type Currency = Tokens;
+	type ShareAccountId = AccountIdConstructor;
+	type AssetInspection = DummyRegistry;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PoolAssetLiquidityAccount = TreasuryAccount;
+	type DustAccountHandler = Whitelist;
+	type Hooks = ();
+	type MinPoolLiquidity = MinAddedLiquidity;
+	type MinTradingLimit = MinTradeAmount;
+	type AmplificationRange = AmplificationRange;
+	type MaxAmplificationChangePerBlock = MaxAmplificationChangePerBlock;
+	type RateOracle = ();
+	type RateFeedOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = ();
+	type FeeDiscount = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = DummyRegistry;
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	registered_assets: Vec<AssetId>,
+	pool_tokens: Vec<(AssetId, FixedU128, AccountId, Balance)>,
+	init_pool: bool,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		POSITIONS.with(|v| {
+			v.borrow_mut().clear();
+		});
+		DUSTER_WHITELIST.with(|v| {
+			v.borrow_mut().clear();
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, 10_000 * ONE),
+				(ALICE, HDX, 1000 * ONE),
+				(ALICE, DAI, 1000 * ONE),
+			],
+			registered_assets: vec![],
+			pool_tokens: vec![],
+			init_pool: false,
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_initial_pool(mut self) -> Self {
+		self.init_pool = true;
+		self
+	}
+
+	pub fn with_registered_asset(mut self, asset_id: AssetId) -> Self {
+		self.registered_assets.push(asset_id);
+		self
+	}
+
+	pub fn with_token(mut self, asset_id: AssetId, price: FixedU128, owner: AccountId, amount: Balance) -> Self {
+		self.endowed_accounts.push((owner, asset_id, amount));
+		self.pool_tokens.push((asset_id, price, owner, amount));
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(HDX, 12);
+			v.borrow_mut().insert(LRNA, 12);
+			v.borrow_mut().insert(DAI, 12);
+			self.registered_assets.iter().for_each(|asset| {
+				v.borrow_mut().insert(*asset, 12);
+			});
+			self.pool_tokens.iter().for_each(|(asset, ..)| {
+				v.borrow_mut().insert(*asset, 12);
+			});
+		});
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts.clone(),
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		let mut r: sp_io::TestExternalities = t.into();
+
+		r.execute_with(|| {
+			System::set_block_number(1);
+
+			if self.init_pool {
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					HDX,
+					FixedU128::from(1),
+					Permill::from_percent(100),
+					Omnipool::protocol_account(),
+				));
+				assert_ok!(Omnipool::add_token(
+					RuntimeOrigin::root(),
+					DAI,
+					FixedU128::from_float(0.5),
+					Permill::from_percent(100),
+					Omnipool::protocol_account(),
+				));
+
+				for (asset_id, price, owner, amount) in self.pool_tokens {
+					assert_ok!(Tokens::transfer(
+						RuntimeOrigin::signed(owner),
+						Omnipool::protocol_account(),
+						asset_id,
+						amount
+					));
+					assert_ok!(Omnipool::add_token(
+						RuntimeOrigin::root(),
+						asset_id,
+						price,
+						Permill::from_percent(100),
+						owner
+					));
+				}
+			}
+		});
+
+		r
+	}
+}
+
+pub struct DummyNFT;
+
+impl<AccountId: From<u64>> Inspect<AccountId> for DummyNFT {
+	type ItemId = u32;
+	type CollectionId = u32;
+
+	fn owner(_class: &Self::CollectionId, instance: &Self::ItemId) -> Option<AccountId> {
+		POSITIONS.with(|v| v.borrow().get(instance).map(|owner| (*owner).into()))
+	}
+}
+
+impl<AccountId: From<u64>> Create<AccountId> for DummyNFT {
+	fn create_collection(_class: &Self::CollectionId, _who: &AccountId, _admin: &AccountId) -> DispatchResult {
+		Ok(())
+	}
+}
+
+impl<AccountId: From<u64> + Into<u64> + Copy> Mutate<AccountId> for DummyNFT {
+	fn mint_into(_class: &Self::CollectionId, instance: &Self::ItemId, who: &AccountId) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().insert(*instance, (*who).into());
+		});
+		Ok(())
+	}
+
+	fn burn(
+		_class: &Self::CollectionId,
+		instance: &Self::ItemId,
+		_maybe_check_owner: Option<&AccountId>,
+	) -> DispatchResult {
+		POSITIONS.with(|v| {
+			v.borrow_mut().remove(instance);
+		});
+		Ok(())
+	}
+}
+
+pub struct DummyRegistry;
+
+impl Registry<AssetId, Vec<u8>, Balance, DispatchError> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn retrieve_asset(_name: &Vec<u8>) -> Result<AssetId, DispatchError> {
+		Ok(0)
+	}
+
+	fn retrieve_asset_type(_asset_id: AssetId) -> Result<AssetKind, DispatchError> {
+		unimplemented!()
+	}
+
+	fn create_asset(_name: &Vec<u8>, _existential_deposit: Balance) -> Result<AssetId, DispatchError> {
+		let assigned = REGISTERED_ASSETS.with(|v| {
+			let l = v.borrow().len();
+			v.borrow_mut().insert(l as u32, 12);
+			l as u32
+		});
+		Ok(assigned)
+	}
+}
+
+impl InspectRegistry<AssetId> for DummyRegistry {
+	fn exists(asset_id: AssetId) -> bool {
+		REGISTERED_ASSETS.with(|v| v.borrow().contains_key(&asset_id))
+	}
+
+	fn decimals(asset_id: AssetId) -> Option<u8> {
+		REGISTERED_ASSETS.with(|v| v.borrow().get(&asset_id).copied())
+	}
+
+	fn asset_name(_asset_id: AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+
+	fn asset_symbol(_asset_id: AssetId) -> Option<Vec<u8>> {
+		unimplemented!()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<AssetId> for DummyRegistry {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		REGISTERED_ASSETS.with(|v| {
+			v.borrow_mut().insert(asset_id, decimals);
+		});
+
+		Ok(())
+	}
+}
+
+pub struct AccountIdConstructor;
+
+impl AccountIdFor<u32> for AccountIdConstructor {
+	type AccountId = AccountId;
+
+	fn from_assets(asset: &u32, _identifier: Option<&[u8]>) -> Self::AccountId {
+		(asset * 1000) as u64
+	}
+
+	fn name(asset: &u32, identifier: Option<&[u8]>) -> Vec<u8> {
+		let mut buf: Vec<u8> = if let Some(ident) = identifier {
+			ident.to_vec()
+		} else {
+			vec![]
+		};
+		buf.extend_from_slice(&(asset).to_le_bytes());
+
+		buf
+	}
+}
+
+pub struct Whitelist;
+
+impl DustRemovalAccountWhitelist<AccountId> for Whitelist {
+	type Error = DispatchError;
+
+	fn add_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().push(*account));
+		Ok(())
+	}
+
+	fn remove_account(account: &AccountId) -> Result<(), Self::Error> {
+		DUSTER_WHITELIST.with(|v| v.borrow_mut().retain(|a| a != account));
+		Ok(())
+	}
+}
+
+pub struct WithdrawFeePriceOracle;
+
+impl ExternalPriceProvider<AssetId, EmaPrice> for WithdrawFeePriceOracle {
+	type Error = DispatchError;
+
+	fn get_price(asset_a: AssetId, asset_b: AssetId) -> Result<EmaPrice, Self::Error> {
+		assert_eq!(asset_a, LRNA);
+		let asset_state = Omnipool::load_asset_state(asset_b)?;
+		Ok(EmaPrice::new(asset_state.hub_reserve, asset_state.reserve))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub struct FeeProvider;
+
+impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
+	fn get(_: &AssetId) -> (Permill, Permill) {
+		(AssetFee::get(), ProtocolFee::get())
+	}
+}
+
+pub(crate) fn pool_account(pool_id: AssetId) -> AccountId {
+	pallet_stableswap::Pallet::<Test>::pool_account(pool_id)
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
+
+pub struct LockedPositionOwnersMock;
+
+impl Contains<AccountId> for LockedPositionOwnersMock {
+	fn contains(who: &AccountId) -> bool {
+		*who == LOCKED_POSITIONS_ACCOUNT
+	}
+}
@@ -0,0 +1,69 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the Omnipool pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
+
+pub use pallet_omnipool::types::{
+	AssetReserveState, Balance, PositionValuation, ProtocolLiquidityValuation, SimpleImbalance, TradeQuote,
+};
+
+sp_api::decl_runtime_apis! {
+	/// The API to query Omnipool state and trade quotes.
+	#[api_version(3)]
+	pub trait OmnipoolApi<AssetId, PositionId> where
+		AssetId: Codec,
+		PositionId: Codec,
+	{
+		/// Returns state of an asset in the Omnipool, including its pool reserve.
+		fn asset_state(asset_id: AssetId) -> Option<AssetReserveState<Balance>>;
+
+		/// Returns amounts which would be received if all shares of the position were removed.
+		fn position_value(position_id: PositionId) -> Result<PositionValuation<AssetId, Balance>, DispatchError>;
+
+		/// Returns result of selling `amount_in` of `asset_in` for `asset_out`.
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Returns result of buying `amount_out` of `asset_out` for `asset_in`.
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<TradeQuote<Balance>, DispatchError>;
+
+		/// Returns value of protocol owned liquidity of an asset.
+		#[api_version(2)]
+		fn protocol_liquidity_value(asset_id: AssetId) -> Result<ProtocolLiquidityValuation<AssetId, Balance>, DispatchError>;
+
+		/// Returns value of protocol owned liquidity of all assets in which protocol owns some shares.
+		#[api_version(2)]
+		fn protocol_liquidity() -> Vec<ProtocolLiquidityValuation<AssetId, Balance>>;
+
+		/// Returns current imbalance of hub asset.
+		#[api_version(3)]
+		fn hub_asset_imbalance() -> SimpleImbalance<Balance>;
+	}
+}
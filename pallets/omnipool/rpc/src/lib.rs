@@ -0,0 +1,281 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! RPC interface for the Omnipool pallet.
+
+use std::{fmt::Debug, sync::Arc};
+
+use codec::Codec;
+use jsonrpsee::{
+	core::RpcResult,
+	proc_macros::rpc,
+	types::error::{CallError, ErrorObject},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use sp_api::{ApiExt, ProvideRuntimeApi};
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_omnipool_rpc_runtime_api::{
+	AssetReserveState, Balance, OmnipoolApi as OmnipoolRuntimeApi, PositionValuation, ProtocolLiquidityValuation,
+	SimpleImbalance, TradeQuote,
+};
+
+#[rpc(client, server)]
+pub trait OmnipoolApi<BlockHash, AssetId, PositionId> {
+	/// Returns state of an asset in the Omnipool.
+	#[method(name = "omnipool_assetState")]
+	fn asset_state(&self, asset_id: AssetId, at: Option<BlockHash>) -> RpcResult<Option<AssetReserveState<Balance>>>;
+
+	/// Returns amounts which would be received if all shares of the position were removed.
+	#[method(name = "omnipool_positionValue")]
+	fn position_value(
+		&self,
+		position_id: PositionId,
+		at: Option<BlockHash>,
+	) -> RpcResult<PositionValuation<AssetId, Balance>>;
+
+	/// Returns result of selling `amount_in` of `asset_in` for `asset_out`.
+	#[method(name = "omnipool_quoteSell")]
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+
+	/// Returns result of buying `amount_out` of `asset_out` for `asset_in`.
+	#[method(name = "omnipool_quoteBuy")]
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<BlockHash>,
+	) -> RpcResult<TradeQuote<Balance>>;
+
+	/// Returns value of protocol owned liquidity of an asset.
+	#[method(name = "omnipool_protocolLiquidityValue")]
+	fn protocol_liquidity_value(
+		&self,
+		asset_id: AssetId,
+		at: Option<BlockHash>,
+	) -> RpcResult<ProtocolLiquidityValuation<AssetId, Balance>>;
+
+	/// Returns value of protocol owned liquidity of all assets in which protocol owns some shares.
+	#[method(name = "omnipool_protocolLiquidity")]
+	fn protocol_liquidity(&self, at: Option<BlockHash>)
+		-> RpcResult<Vec<ProtocolLiquidityValuation<AssetId, Balance>>>;
+
+	/// Returns current imbalance of hub asset.
+	#[method(name = "omnipool_hubAssetImbalance")]
+	fn hub_asset_imbalance(&self, at: Option<BlockHash>) -> RpcResult<SimpleImbalance<Balance>>;
+}
+
+/// Provides RPC methods to query Omnipool state and trade quotes.
+pub struct Omnipool<C, Block> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<Block>,
+}
+
+impl<C, Block> Omnipool<C, Block> {
+	/// Creates a new instance of the Omnipool RPC helper.
+	pub fn new(client: Arc<C>) -> Self {
+		Self {
+			client,
+			_marker: Default::default(),
+		}
+	}
+}
+
+/// Error type of this RPC api.
+pub enum Error {
+	/// The call to runtime failed.
+	RuntimeError,
+	/// The runtime call was executed, but the operation itself failed.
+	OperationFailed,
+}
+
+impl From<Error> for i32 {
+	fn from(e: Error) -> i32 {
+		match e {
+			Error::RuntimeError => 1,
+			Error::OperationFailed => 2,
+		}
+	}
+}
+
+fn runtime_error(message: &str, e: impl ToString) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(e.to_string()),
+	))
+	.into()
+}
+
+fn unsupported_error(message: &str, version: u32) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::RuntimeError.into(),
+		message,
+		Some(format!("Runtime API version {version} is required.")),
+	))
+	.into()
+}
+
+/// Ensures the runtime implements at least given `version` of the Omnipool runtime API.
+fn ensure_api_version<Block, Api, AssetId, PositionId>(
+	api: &Api,
+	at: <Block as BlockT>::Hash,
+	version: u32,
+	message: &str,
+) -> RpcResult<()>
+where
+	Block: BlockT,
+	Api: ApiExt<Block> + OmnipoolRuntimeApi<Block, AssetId, PositionId>,
+	AssetId: Codec,
+	PositionId: Codec,
+{
+	let api_version = api
+		.api_version::<dyn OmnipoolRuntimeApi<Block, AssetId, PositionId>>(at)
+		.map_err(|e| runtime_error("Unable to query runtime API version.", e))?
+		.unwrap_or_default();
+
+	if api_version < version {
+		return Err(unsupported_error(message, version));
+	}
+
+	Ok(())
+}
+
+fn operation_error(message: &str, e: impl Debug) -> jsonrpsee::core::Error {
+	CallError::Custom(ErrorObject::owned(
+		Error::OperationFailed.into(),
+		message,
+		Some(format!("{e:?}")),
+	))
+	.into()
+}
+
+impl<C, Block, AssetId, PositionId> OmnipoolApiServer<<Block as BlockT>::Hash, AssetId, PositionId>
+	for Omnipool<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+	C::Api: OmnipoolRuntimeApi<Block, AssetId, PositionId>,
+	AssetId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+	PositionId: Codec + Serialize + DeserializeOwned + Send + Sync + 'static,
+{
+	fn asset_state(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Option<AssetReserveState<Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.asset_state(at, asset_id)
+			.map_err(|e| runtime_error("Unable to query asset state.", e))
+	}
+
+	fn position_value(
+		&self,
+		position_id: PositionId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<PositionValuation<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.position_value(at, position_id)
+			.map_err(|e| runtime_error("Unable to query position value.", e))?
+			.map_err(|e| operation_error("Unable to value position.", e))
+	}
+
+	fn quote_sell(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_sell(at, asset_in, asset_out, amount_in)
+			.map_err(|e| runtime_error("Unable to query sell quote.", e))?
+			.map_err(|e| operation_error("Sell is not possible.", e))
+	}
+
+	fn quote_buy(
+		&self,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<TradeQuote<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		api.quote_buy(at, asset_in, asset_out, amount_out)
+			.map_err(|e| runtime_error("Unable to query buy quote.", e))?
+			.map_err(|e| operation_error("Buy is not possible.", e))
+	}
+
+	fn protocol_liquidity_value(
+		&self,
+		asset_id: AssetId,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<ProtocolLiquidityValuation<AssetId, Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		ensure_api_version::<Block, _, AssetId, PositionId>(
+			&*api,
+			at,
+			2,
+			"Protocol liquidity value is not supported.",
+		)?;
+
+		api.protocol_liquidity_value(at, asset_id)
+			.map_err(|e| runtime_error("Unable to query protocol liquidity value.", e))?
+			.map_err(|e| operation_error("Unable to value protocol liquidity.", e))
+	}
+
+	fn protocol_liquidity(
+		&self,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<Vec<ProtocolLiquidityValuation<AssetId, Balance>>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		ensure_api_version::<Block, _, AssetId, PositionId>(&*api, at, 2, "Protocol liquidity is not supported.")?;
+
+		api.protocol_liquidity(at)
+			.map_err(|e| runtime_error("Unable to query protocol liquidity.", e))
+	}
+
+	fn hub_asset_imbalance(&self, at: Option<<Block as BlockT>::Hash>) -> RpcResult<SimpleImbalance<Balance>> {
+		let api = self.client.runtime_api();
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+
+		ensure_api_version::<Block, _, AssetId, PositionId>(&*api, at, 3, "Hub asset imbalance is not supported.")?;
+
+		api.hub_asset_imbalance(at)
+			.map_err(|e| runtime_error("Unable to query hub asset imbalance.", e))
+	}
+}
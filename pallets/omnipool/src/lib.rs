@@ -60,7 +60,9 @@
 //! * `set_asset_tradable_state` - Updates state of an asset in the pool to allow/disallow trading.
 //! * `add_token` - Adds token to the pool. Initial liquidity must be transffered to pool account prior to calling add_token.
 //! * `add_liquidity` - Adds liquidity of selected asset to the pool. Mints corresponding position NFT.
+//! * `add_liquidity_with_asset` - Sells given asset through the router and adds the received amount as liquidity. Mints corresponding position NFT.
 //! * `remove_liquidity` - Removes liquidity of selected position from the pool. Partial withdrawals are allowed.
+//! * `remove_liquidity_to` - Removes liquidity of selected position and sells the withdrawn asset and hub asset for chosen asset.
 //! * `sell` - Trades an asset in for asset out by selling given amount of asset in.
 //! * `buy` - Trades an asset in for asset out by buying given amount of asset out.
 //! * `set_asset_tradable_state` - Updates asset's tradable asset with new flags. This allows/forbids asset operation such SELL,BUY,ADD or  REMOVE liquidtityy.
@@ -70,19 +72,26 @@
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::require_transactional;
+use frame_support::traits::{Contains, DefensiveOption};
+use frame_support::weights::Weight;
 use frame_support::PalletId;
 use frame_support::{ensure, transactional};
-use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One};
+use frame_system::ensure_signed;
+use sp_runtime::traits::{AccountIdConversion, AtLeast32BitUnsigned, One, SaturatedConversion, Saturating};
 use sp_runtime::traits::{CheckedAdd, CheckedSub, Zero};
 use sp_std::ops::{Add, Sub};
 use sp_std::prelude::*;
+use sp_std::vec;
 
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
-use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, I129};
-use hydradx_traits::Registry;
+use hydra_dx_math::ema::EmaPrice;
+use hydra_dx_math::omnipool::types::{AssetStateChange, BalanceUpdate, HubTradeStateChange, TradeStateChange, I129};
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, PoolType, RouterT, Trade};
+use hydradx_traits::{FeeDiscountProvider, Registry};
 use orml_traits::{GetByKey, MultiCurrency};
 use scale_info::TypeInfo;
-use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, Rounding};
 
 #[cfg(test)]
 mod tests;
@@ -93,11 +102,17 @@ pub mod traits;
 pub mod types;
 pub mod weights;
 
-use crate::traits::{AssetInfo, OmnipoolHooks};
-use crate::types::{AssetReserveState, AssetState, Balance, Position, SimpleImbalance, Tradability};
+use crate::traits::{AssetInfo, OmnipoolHooks, ShouldAllow};
+use crate::types::{
+	AssetReserveState, AssetState, Balance, Delisting, DelistingStage, Position, PositionValuation,
+	ProtocolLiquidityValuation, SimpleImbalance, Tradability, TradeQuote,
+};
 pub use pallet::*;
 pub use weights::WeightInfo;
 
+/// Maximum number of assets in `add_liquidity_batch`.
+pub const MAX_ADD_LIQUIDITY_BATCH_SIZE: u32 = 10;
+
 /// NFT class id type of provided nft implementation
 pub type NFTCollectionIdOf<T> =
 	<<T as Config>::NFTHandler as Inspect<<T as frame_system::Config>::AccountId>>::CollectionId;
@@ -109,7 +124,6 @@ pub mod pallet {
 	use crate::types::{Position, Price, Tradability};
 	use codec::HasCompact;
 	use frame_support::pallet_prelude::*;
-	use frame_support::traits::DefensiveOption;
 	use frame_system::pallet_prelude::*;
 	use hydra_dx_math::ema::EmaPrice;
 	use hydra_dx_math::omnipool::types::{BalanceUpdate, I129};
@@ -144,6 +158,9 @@ pub mod pallet {
 		/// Origin to be able to suspend asset trades.
 		type TechnicalOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Origin to be able to manage protocol owned liquidity.
+		type ProtocolLiquidityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// Asset Registry mechanism - used to check if asset is correctly registered in asset registry
 		type AssetRegistry: Registry<Self::AssetId, Vec<u8>, Balance, DispatchError>;
 
@@ -208,6 +225,30 @@ pub mod pallet {
 		type PriceBarrier: ShouldAllow<Self::AccountId, Self::AssetId, EmaPrice>;
 
 		type ExternalPriceOracle: ExternalPriceProvider<Self::AssetId, EmaPrice, Error = DispatchError>;
+
+		/// Router implementation used to convert assets when adding or removing liquidity.
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			Self::AssetId,
+			Balance,
+			Trade<Self::AssetId>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Weight information for the router trades.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
+
+		/// Provider of trade fee discounts.
+		type FeeDiscount: FeeDiscountProvider<Self::AccountId, Self::AssetId>;
+
+		/// Accounts holding locked positions on behalf of their owners, eg. liquidity mining.
+		/// Positions held by these accounts are not settled during delisting until they are returned to their owners
+		/// or force-settled by `AuthorityOrigin`.
+		type LockedPositionOwners: Contains<Self::AccountId>;
+
+		/// Max number of assets being delisted at the same time.
+		#[pallet::constant]
+		type MaxDelistings: Get<u32>;
 	}
 
 	#[pallet::storage]
@@ -220,6 +261,17 @@ pub mod pallet {
 	#[pallet::getter(fn current_imbalance)]
 	pub(super) type HubAssetImbalance<T: Config> = StorageValue<_, SimpleImbalance<Balance>, ValueQuery>;
 
+	#[pallet::type_value]
+	pub fn DefaultImbalanceBurnRate() -> Permill {
+		Permill::one()
+	}
+
+	#[pallet::storage]
+	/// Portion of protocol fee which is used to burn hub asset imbalance.
+	/// Remaining part of protocol fee is transferred to HDX subpool.
+	#[pallet::getter(fn imbalance_burn_rate)]
+	pub(super) type ImbalanceBurnRate<T: Config> = StorageValue<_, Permill, ValueQuery, DefaultImbalanceBurnRate>;
+
 	// LRNA is only allowed to be sold
 	#[pallet::type_value]
 	pub fn DefaultHubAssetTradability() -> Tradability {
@@ -242,6 +294,16 @@ pub mod pallet {
 	/// Position ids sequencer
 	pub(super) type NextPositionId<T: Config> = StorageValue<_, T::PositionItemId, ValueQuery>;
 
+	#[pallet::storage]
+	/// Assets which are being delisted from Omnipool.
+	#[pallet::getter(fn delistings)]
+	pub(super) type Delistings<T: Config> =
+		CountedStorageMap<_, Blake2_128Concat, T::AssetId, Delisting<T::AccountId, T::BlockNumber>>;
+
+	#[pallet::storage]
+	/// Last position checked during settlement of delisted assets.
+	pub(super) type SettlementCursor<T: Config> = StorageValue<_, T::PositionItemId, OptionQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -339,6 +401,45 @@ pub mod pallet {
 
 		/// Asset's weight cap has been updated.
 		AssetWeightCapUpdated { asset_id: T::AssetId, cap: Permill },
+
+		/// Delisting of an asset has been announced. Asset can be only bought and its liquidity removed.
+		DelistingAnnounced {
+			asset_id: T::AssetId,
+			end: T::BlockNumber,
+			beneficiary: T::AccountId,
+		},
+		/// Delisting of an asset has been cancelled.
+		DelistingCancelled { asset_id: T::AssetId },
+		/// Wind-down of a delisted asset has ended. Asset is frozen and remaining positions are settled.
+		DelistingSettlementStarted { asset_id: T::AssetId },
+		/// Position of a delisted asset has been settled pro-rata and destroyed.
+		DelistedPositionSettled {
+			position_id: T::PositionItemId,
+			owner: T::AccountId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			shares: Balance,
+		},
+		/// Settlement of a position of a delisted asset failed.
+		DelistedPositionSettlementFailed {
+			position_id: T::PositionItemId,
+			error: DispatchError,
+		},
+		/// All positions of a delisted asset have been settled and asset has been removed from Omnipool.
+		AssetDelisted { asset_id: T::AssetId },
+
+		/// Portion of protocol fee used to burn hub asset imbalance has been updated.
+		ImbalanceBurnRateUpdated { rate: Permill },
+
+		/// Protocol owned liquidity has been moved from one asset to another.
+		ProtocolLiquidityRebalanced {
+			asset_from: T::AssetId,
+			asset_to: T::AssetId,
+			shares_removed: Balance,
+			amount_removed: Balance,
+			amount_added: Balance,
+			shares_added: Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -410,6 +511,32 @@ pub mod pallet {
 		StableAssetCannotBeRemoved,
 		/// Calculated amount out from sell trade is zero.
 		ZeroAmountOut,
+		/// Amount of shares received is below the given minimum.
+		SlippageLimit,
+		/// Position cannot be merged with itself.
+		SamePositionMergeNotAllowed,
+		/// Merged positions must be positions of the same asset.
+		PositionAssetMismatch,
+		/// Batch must contain at least one asset.
+		EmptyBatch,
+		/// Protocol owned liquidity cannot be rebalanced to the same asset.
+		SameAssetRebalanceNotAllowed,
+		/// Amount of shares must be greater than zero.
+		ZeroShares,
+		/// Delisting of the asset has been already announced.
+		DelistingAlreadyAnnounced,
+		/// Asset is not being delisted.
+		DelistingNotFound,
+		/// Wind-down period of delisting must be greater than zero.
+		InvalidDelistingPeriod,
+		/// Delisting cannot be cancelled once settlement of positions started.
+		DelistingNotCancellable,
+		/// Max number of assets being delisted at the same time has been reached.
+		MaxDelistingsReached,
+		/// Settlement of positions of the delisted asset has not started yet.
+		DelistingNotInSettlement,
+		/// Position is not held by any of `LockedPositionOwners`.
+		PositionNotLocked,
 	}
 
 	#[pallet::call]
@@ -563,125 +690,99 @@ pub mod pallet {
 		)]
 		#[transactional]
 		pub fn add_liquidity(origin: OriginFor<T>, asset: T::AssetId, amount: Balance) -> DispatchResult {
-			//
-			// Preconditions
-			//
-			let who = ensure_signed(origin.clone())?;
-
-			ensure!(
-				amount >= T::MinimumPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(
-				T::Currency::ensure_can_withdraw(asset, &who, amount).is_ok(),
-				Error::<T>::InsufficientBalance
-			);
-
-			let asset_state = Self::load_asset_state(asset)?;
-
-			ensure!(
-				asset_state.tradable.contains(Tradability::ADD_LIQUIDITY),
-				Error::<T>::NotAllowed
-			);
-
-			T::PriceBarrier::ensure_price(
-				&who,
-				T::HubAssetId::get(),
-				asset,
-				EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
-			)
-			.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
-
-			//
-			// Calculate add liquidity state changes
-			//
-			let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
-				&(&asset_state).into(),
-				amount,
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			let new_asset_state = asset_state
-				.clone()
-				.delta_update(&state_changes.asset)
-				.ok_or(ArithmeticError::Overflow)?;
-
-			let hub_reserve_ratio = FixedU128::checked_from_rational(
-				new_asset_state.hub_reserve,
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
-					.checked_add(*state_changes.asset.delta_hub_reserve)
-					.ok_or(ArithmeticError::Overflow)?,
-			)
-			.ok_or(ArithmeticError::DivisionByZero)?;
-
-			ensure!(
-				hub_reserve_ratio <= new_asset_state.weight_cap(),
-				Error::<T>::AssetWeightCapExceeded
-			);
-
-			//
-			// Post - update states
-			//
+			Self::do_add_liquidity(origin, asset, amount, true)?;
+			Ok(())
+		}
 
-			// Create LP position with given shares
-			let lp_position = Position::<Balance, T::AssetId> {
-				asset_id: asset,
-				amount,
-				shares: *state_changes.asset.delta_shares,
-				// Note: position needs price after asset state is updated.
-				price: (new_asset_state.hub_reserve, new_asset_state.reserve),
-			};
+		/// Add liquidity of multiple assets to Omnipool in one batch
+		///
+		/// `add_liquidity_batch` adds liquidity of each asset as in `add_liquidity`
+		/// and mints one position per asset.
+		///
+		/// Asset weight caps are checked only once against the state of the pool after the whole batch
+		/// has been added, so the batch succeeds if the final weight of each asset respects its cap.
+		///
+		/// Parameters:
+		/// - `assets`: List of assets and amounts added to omnipool. Assets must be already in the pool.
+		///
+		/// Emits `LiquidityAdded` event for each asset when successful.
+		///
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_mul(assets.len() as u64)
+		)]
+		#[transactional]
+		pub fn add_liquidity_batch(
+			origin: OriginFor<T>,
+			assets: BoundedVec<(T::AssetId, Balance), ConstU32<MAX_ADD_LIQUIDITY_BATCH_SIZE>>,
+		) -> DispatchResult {
+			ensure_signed(origin.clone())?;
 
-			let instance_id = Self::create_and_mint_position_instance(&who)?;
+			ensure!(!assets.is_empty(), Error::<T>::EmptyBatch);
 
-			<Positions<T>>::insert(instance_id, lp_position);
+			for (asset, amount) in assets.iter() {
+				Self::do_add_liquidity(origin.clone(), *asset, *amount, false)?;
+			}
 
-			Self::deposit_event(Event::PositionCreated {
-				position_id: instance_id,
-				owner: who.clone(),
-				asset,
-				amount,
-				shares: *state_changes.asset.delta_shares,
-				price: new_asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-			});
+			for (asset, _) in assets.iter() {
+				Self::ensure_weight_cap(*asset)?;
+			}
 
-			T::Currency::transfer(
-				asset,
-				&who,
-				&Self::protocol_account(),
-				*state_changes.asset.delta_reserve,
-			)?;
+			Ok(())
+		}
 
-			debug_assert_eq!(*state_changes.asset.delta_reserve, amount);
+		/// Add liquidity of asset `asset` to Omnipool by selling `amount_in` of asset `asset_in`.
+		///
+		/// `add_liquidity_with_asset` sells `amount_in` of `asset_in` for `asset` through the router
+		/// and adds the whole received amount to the pool as in `add_liquidity`.
+		///
+		/// Asset's tradable state must contain ADD_LIQUIDITY flag, otherwise `NotAllowed` error is returned.
+		///
+		/// Parameters:
+		/// - `asset_in`: The identifier of the asset which is sold.
+		/// - `amount_in`: Amount of `asset_in` to sell.
+		/// - `asset`: The identifier of the asset to which liquidity is added. Must be already in the pool.
+		/// - `min_amount_out`: Minimum amount of `asset` received from the sell.
+		/// - `min_shares`: Minimum amount of shares of the new position.
+		/// - `route`: Route of the sell. If empty, the on-chain or default route of the router is used.
+		///
+		/// Emits `LiquidityAdded` event when successful.
+		///
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+			.saturating_add(T::AmmTradeWeights::sell_weight(route))
+		)]
+		#[transactional]
+		pub fn add_liquidity_with_asset(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			amount_in: Balance,
+			asset: T::AssetId,
+			min_amount_out: Balance,
+			min_shares: Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
 
-			// Callback hook info
-			let info: AssetInfo<T::AssetId, Balance> =
-				AssetInfo::new(asset, &asset_state, &new_asset_state, &state_changes.asset, false);
+			ensure!(asset_in != asset, Error::<T>::SameAssetTradeNotAllowed);
 
-			Self::update_imbalance(state_changes.delta_imbalance)?;
+			let balance_before = T::Currency::free_balance(asset, &who);
 
-			Self::update_hub_asset_liquidity(&state_changes.asset.delta_hub_reserve)?;
+			T::RouteExecutor::sell(origin.clone(), asset_in, asset, amount_in, min_amount_out, route)?;
 
-			Self::set_asset_state(asset, new_asset_state);
+			let amount = T::Currency::free_balance(asset, &who)
+				.checked_sub(balance_before)
+				.ok_or(ArithmeticError::Underflow)?;
+			ensure!(amount >= min_amount_out, Error::<T>::BuyLimitNotReached);
 
-			Self::deposit_event(Event::LiquidityAdded {
-				who,
-				asset_id: asset,
-				amount,
-				position_id: instance_id,
-			});
+			let position_id = Self::do_add_liquidity(origin, asset, amount, true)?;
 
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			ensure!(position.shares >= min_shares, Error::<T>::SlippageLimit);
 
 			Ok(())
 		}
@@ -744,17 +845,7 @@ pub mod pallet {
 				)
 				.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 			}
-			let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
-
-			if ext_asset_price.is_zero() {
-				return Err(Error::<T>::InvalidOraclePrice.into());
-			}
-			let withdrawal_fee = hydra_dx_math::omnipool::calculate_withdrawal_fee(
-				asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
-				FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
-					.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
-				T::MinWithdrawalFee::get(),
-			);
+			let withdrawal_fee = Self::withdrawal_fee(asset_id, &asset_state)?;
 
 			let current_imbalance = <HubAssetImbalance<T>>::get();
 			let current_hub_asset_liquidity =
@@ -862,6 +953,89 @@ pub mod pallet {
 			Ok(())
 		}
 
+		/// Remove liquidity of position `position_id` and receive it in asset `asset_out`.
+		///
+		/// `remove_liquidity_to` removes specified shares amount from given position as in `remove_liquidity`,
+		/// then sells the withdrawn asset and any hub asset received through the router for `asset_out`.
+		///
+		/// Hub asset amount below the minimum trading limit can't be sold and is kept by the LP.
+		///
+		/// Parameters:
+		/// - `position_id`: The identifier of position which liquidity is removed from.
+		/// - `amount`: Amount of shares removed from omnipool
+		/// - `asset_out`: The identifier of the asset to receive. Can't be hub asset.
+		/// - `min_amount_out`: Minimum total amount of `asset_out` to receive.
+		/// - `route`: Route of the withdrawn asset sell. If empty, the on-chain or default route of the router is used.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		///
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::AmmTradeWeights::sell_weight(route))
+			.saturating_add(T::AmmTradeWeights::sell_weight(&Pallet::<T>::hub_asset_route(*asset_out)))
+		)]
+		#[transactional]
+		pub fn remove_liquidity_to(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			amount: Balance,
+			asset_out: T::AssetId,
+			min_amount_out: Balance,
+			route: Vec<Trade<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+
+			ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+			let asset_id = Positions::<T>::get(position_id)
+				.ok_or(Error::<T>::PositionNotFound)?
+				.asset_id;
+
+			let asset_balance_before = T::Currency::free_balance(asset_id, &who);
+			let hub_asset_balance_before = T::Currency::free_balance(T::HubAssetId::get(), &who);
+			let asset_out_balance_before = T::Currency::free_balance(asset_out, &who);
+
+			Self::remove_liquidity(origin.clone(), position_id, amount)?;
+
+			if asset_id != asset_out {
+				let asset_amount = T::Currency::free_balance(asset_id, &who)
+					.checked_sub(asset_balance_before)
+					.ok_or(ArithmeticError::Underflow)?;
+
+				T::RouteExecutor::sell(
+					origin.clone(),
+					asset_id,
+					asset_out,
+					asset_amount,
+					Balance::zero(),
+					route,
+				)?;
+			}
+
+			let hub_amount = T::Currency::free_balance(T::HubAssetId::get(), &who)
+				.checked_sub(hub_asset_balance_before)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			if hub_amount >= T::MinimumTradingLimit::get() {
+				T::RouteExecutor::sell(
+					origin,
+					T::HubAssetId::get(),
+					asset_out,
+					hub_amount,
+					Balance::zero(),
+					Self::hub_asset_route(asset_out),
+				)?;
+			}
+
+			let amount_out = T::Currency::free_balance(asset_out, &who)
+				.checked_sub(asset_out_balance_before)
+				.ok_or(ArithmeticError::Underflow)?;
+			ensure!(amount_out >= min_amount_out, Error::<T>::BuyLimitNotReached);
+
+			Ok(())
+		}
+
 		/// Sacrifice LP position in favor of pool.
 		///
 		/// A position is destroyed and liquidity owned by LP becomes pool owned liquidity.
@@ -882,26 +1056,136 @@ pub mod pallet {
 				Error::<T>::Forbidden
 			);
 
-			Assets::<T>::try_mutate(position.asset_id, |maybe_asset| -> DispatchResult {
-				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+			Self::do_sacrifice_position(position_id, &position, &who)
+		}
+
+		/// Split LP position into two positions.
+		///
+		/// Given amount of shares is moved to a new position which is minted to `beneficiary`.
+		/// Amount of asset is split proportionally to shares, price of both positions stays the same.
+		///
+		/// Only owner of position can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the position
+		/// - `position_id`: The identifier of position which is split.
+		/// - `shares`: Amount of shares moved to the new position. Must be less than shares of the position.
+		/// - `beneficiary`: Account which receives the new position.
+		///
+		/// Emits `PositionUpdated` and `PositionCreated` events when successful.
+		///
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::split_position())]
+		#[transactional]
+		pub fn split_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			shares: Balance,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-				asset_state.protocol_shares = asset_state
-					.protocol_shares
-					.checked_add(position.shares)
-					.ok_or(ArithmeticError::Overflow)?;
+			ensure!(shares > Balance::zero(), Error::<T>::InvalidSharesAmount);
 
-				Ok(())
-			})?;
+			let position = Self::load_position(position_id, who.clone())?;
+
+			ensure!(position.shares > shares, Error::<T>::InsufficientShares);
+
+			let (remaining_position, new_position) = position.split(shares).ok_or(ArithmeticError::Overflow)?;
+
+			let new_position_id = Self::create_and_mint_position_instance(&beneficiary)?;
+
+			Self::deposit_event(Event::PositionUpdated {
+				position_id,
+				owner: who,
+				asset: remaining_position.asset_id,
+				amount: remaining_position.amount,
+				shares: remaining_position.shares,
+				price: remaining_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			Self::deposit_event(Event::PositionCreated {
+				position_id: new_position_id,
+				owner: beneficiary,
+				asset: new_position.asset_id,
+				amount: new_position.amount,
+				shares: new_position.shares,
+				price: new_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
+			});
+
+			<Positions<T>>::insert(position_id, remaining_position);
+			<Positions<T>>::insert(new_position_id, new_position);
+
+			Ok(())
+		}
+
+		/// Merge two LP positions of the same asset into one.
+		///
+		/// Amounts and shares of `other_position_id` are added to `position_id`. Price of the merged position
+		/// is the average of prices of both positions weighted by shares.
+		///
+		/// `other_position_id` is destroyed and its NFT instance burned.
+		///
+		/// Only owner of both positions can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: Owner of the positions
+		/// - `position_id`: The identifier of position which is kept.
+		/// - `other_position_id`: The identifier of position which is merged into `position_id`.
+		///
+		/// Emits `PositionUpdated` and `PositionDestroyed` events when successful.
+		///
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::merge_positions())]
+		#[transactional]
+		pub fn merge_positions(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			other_position_id: T::PositionItemId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				position_id != other_position_id,
+				Error::<T>::SamePositionMergeNotAllowed
+			);
+
+			let position = Self::load_position(position_id, who.clone())?;
+			let other_position = Self::load_position(other_position_id, who.clone())?;
+
+			ensure!(
+				position.asset_id == other_position.asset_id,
+				Error::<T>::PositionAssetMismatch
+			);
 
-			// Desotry position and burn NFT
-			<Positions<T>>::remove(position_id);
-			T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(&who))?;
+			let merged_position = position.merge(other_position).ok_or(ArithmeticError::Overflow)?;
+
+			// Destroy merged position and burn NFT
+			<Positions<T>>::remove(other_position_id);
+			T::NFTHandler::burn(&T::NFTCollectionId::get(), &other_position_id, Some(&who))?;
 
 			Self::deposit_event(Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: who.clone(),
+			});
+
+			Self::deposit_event(Event::PositionUpdated {
 				position_id,
 				owner: who,
+				asset: merged_position.asset_id,
+				amount: merged_position.amount,
+				shares: merged_position.shares,
+				price: merged_position
+					.price_from_rational()
+					.ok_or(ArithmeticError::DivisionByZero)?,
 			});
 
+			<Positions<T>>::insert(position_id, merged_position);
+
 			Ok(())
 		}
 
@@ -925,6 +1209,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
 			.saturating_add(T::OmnipoolHooks::on_trade_weight())
 			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::FeeDiscount::discount_weight())
 		)]
 		#[transactional]
 		pub fn sell(
@@ -958,57 +1243,18 @@ pub mod pallet {
 				return Self::sell_asset_for_hub_asset(&who, asset_in, amount, min_buy_amount);
 			}
 
-			let asset_in_state = Self::load_asset_state(asset_in)?;
-			let asset_out_state = Self::load_asset_state(asset_out)?;
-
-			ensure!(
-				Self::allow_assets(&asset_in_state, &asset_out_state),
-				Error::<T>::NotAllowed
-			);
-
-			ensure!(
-				amount
-					<= asset_in_state
-						.reserve
-						.checked_div(T::MaxInRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-				Error::<T>::MaxInRatioExceeded
-			);
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-
-			let (asset_fee, _) = T::Fee::get(&asset_out);
-			let (_, protocol_fee) = T::Fee::get(&asset_in);
-
-			let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
-				&(&asset_in_state).into(),
-				&(&asset_out_state).into(),
-				amount,
-				asset_fee,
-				protocol_fee,
-				current_imbalance.value,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-
-			ensure!(
-				*state_changes.asset_out.delta_reserve > Balance::zero(),
-				Error::<T>::ZeroAmountOut
-			);
+			let (asset_in_state, asset_out_state, state_changes) = Self::calculate_sell_state(
+				asset_in,
+				asset_out,
+				amount,
+				Self::get_discounted_fees(&who, asset_in, asset_out),
+			)?;
 
 			ensure!(
 				*state_changes.asset_out.delta_reserve >= min_buy_amount,
 				Error::<T>::BuyLimitNotReached
 			);
 
-			ensure!(
-				*state_changes.asset_out.delta_reserve
-					<= asset_out_state
-						.reserve
-						.checked_div(T::MaxOutRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
-				Error::<T>::MaxOutRatioExceeded
-			);
-
 			let new_asset_in_state = asset_in_state
 				.clone()
 				.delta_update(&state_changes.asset_in)
@@ -1131,6 +1377,7 @@ pub mod pallet {
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
 			.saturating_add(T::OmnipoolHooks::on_trade_weight())
 			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight())
+			.saturating_add(T::FeeDiscount::discount_weight())
 		)]
 		#[transactional]
 		pub fn buy(
@@ -1158,38 +1405,12 @@ pub mod pallet {
 				return Self::buy_asset_for_hub_asset(origin, &who, asset_out, amount, max_sell_amount);
 			}
 
-			let asset_in_state = Self::load_asset_state(asset_in)?;
-			let asset_out_state = Self::load_asset_state(asset_out)?;
-
-			ensure!(
-				Self::allow_assets(&asset_in_state, &asset_out_state),
-				Error::<T>::NotAllowed
-			);
-
-			ensure!(asset_out_state.reserve >= amount, Error::<T>::InsufficientLiquidity);
-
-			ensure!(
-				amount
-					<= asset_out_state
-						.reserve
-						.checked_div(T::MaxOutRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
-				Error::<T>::MaxOutRatioExceeded
-			);
-
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-
-			let (asset_fee, _) = T::Fee::get(&asset_out);
-			let (_, protocol_fee) = T::Fee::get(&asset_in);
-			let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
-				&(&asset_in_state).into(),
-				&(&asset_out_state).into(),
+			let (asset_in_state, asset_out_state, state_changes) = Self::calculate_buy_state(
+				asset_in,
+				asset_out,
 				amount,
-				asset_fee,
-				protocol_fee,
-				current_imbalance.value,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+				Self::get_discounted_fees(&who, asset_in, asset_out),
+			)?;
 
 			ensure!(
 				T::Currency::ensure_can_withdraw(asset_in, &who, *state_changes.asset_in.delta_reserve).is_ok(),
@@ -1201,15 +1422,6 @@ pub mod pallet {
 				Error::<T>::SellLimitExceeded
 			);
 
-			ensure!(
-				*state_changes.asset_in.delta_reserve
-					<= asset_in_state
-						.reserve
-						.checked_div(T::MaxInRatio::get())
-						.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-				Error::<T>::MaxInRatioExceeded
-			);
-
 			let new_asset_in_state = asset_in_state
 				.clone()
 				.delta_update(&state_changes.asset_in)
@@ -1433,79 +1645,105 @@ pub mod pallet {
 		) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin.clone())?;
 
-			let asset_state = Self::load_asset_state(asset_id)?;
-			ensure!(amount <= asset_state.protocol_shares, Error::<T>::InsufficientShares);
+			Self::do_withdraw_protocol_liquidity(origin, asset_id, amount, price, dest)?;
+			Ok(())
+		}
 
-			let current_imbalance = <HubAssetImbalance<T>>::get();
-			let current_hub_asset_liquidity =
-				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+		/// Move protocol owned liquidity from one asset to another.
+		///
+		/// Protocol shares of `asset_from` are withdrawn at current spot price to the protocol liquidity account,
+		/// withdrawn liquidity is sold for `asset_to` and added back to the pool. Shares of the new position
+		/// become protocol owned shares of `asset_to`.
+		///
+		/// Can be called only by `ProtocolLiquidityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Protocol liquidity origin
+		/// - `asset_from`: Asset whose protocol shares are removed
+		/// - `shares`: Amount of protocol shares of `asset_from` to remove
+		/// - `asset_to`: Asset which receives the liquidity
+		/// - `min_shares`: Minimum amount of protocol shares of `asset_to` which must be added
+		///
+		/// Emits `ProtocolLiquidityRebalanced` event when successful.
+		///
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_protocol_liquidity()
+			.saturating_add(<T as Config>::WeightInfo::sell().saturating_mul(2))
+			.saturating_add(<T as Config>::WeightInfo::add_liquidity())
+			.saturating_add(<T as Config>::WeightInfo::sacrifice_position())
+			.saturating_add(T::OmnipoolHooks::on_trade_weight().saturating_mul(2))
+			.saturating_add(T::OmnipoolHooks::on_liquidity_changed_weight().saturating_mul(4))
+			.saturating_add(T::ExternalPriceOracle::get_price_weight())
+		)]
+		#[transactional]
+		pub fn rebalance_protocol_liquidity(
+			origin: OriginFor<T>,
+			asset_from: T::AssetId,
+			shares: Balance,
+			asset_to: T::AssetId,
+			min_shares: Balance,
+		) -> DispatchResult {
+			T::ProtocolLiquidityOrigin::ensure_origin(origin)?;
 
-			// dev note: as we no longer have the position details for sacrificed one, we just need to
-			// construct temporary position.
-			// Note that amount is ok to set to zero in this case. Although the remove liquidity calculation
-			// calculates the delta for this field, it does not make any difference afterwards.
-			let position = hydra_dx_math::omnipool::types::Position::<Balance> {
-				amount: 0,
-				price,
-				shares: amount,
-			};
+			ensure!(asset_from != asset_to, Error::<T>::SameAssetRebalanceNotAllowed);
+			ensure!(!shares.is_zero(), Error::<T>::ZeroShares);
 
-			let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
-				&(&asset_state).into(),
-				amount,
-				&position,
-				I129 {
-					value: current_imbalance.value,
-					negative: current_imbalance.negative,
-				},
-				current_hub_asset_liquidity,
-				FixedU128::zero(),
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			let asset_state = Self::load_asset_state(asset_from)?;
+			ensure!(shares <= asset_state.protocol_shares, Error::<T>::InsufficientShares);
+			ensure!(<Assets<T>>::contains_key(asset_to), Error::<T>::AssetNotFound);
 
-			let mut new_asset_state = asset_state
-				.clone()
-				.delta_update(&state_changes.asset)
-				.ok_or(ArithmeticError::Overflow)?;
+			let pol_account = Self::protocol_liquidity_account();
+			let pol_origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(pol_account.clone()).into();
 
-			new_asset_state.protocol_shares = new_asset_state.protocol_shares.saturating_sub(amount);
+			let initial_balance = T::Currency::free_balance(asset_to, &pol_account);
 
-			T::Currency::transfer(
-				asset_id,
-				&Self::protocol_account(),
-				&dest,
-				*state_changes.asset.delta_reserve,
+			let (amount_removed, hub_amount) = Self::do_withdraw_protocol_liquidity(
+				pol_origin.clone(),
+				asset_from,
+				shares,
+				(asset_state.hub_reserve, asset_state.reserve),
+				pol_account.clone(),
 			)?;
 
-			Self::update_imbalance(state_changes.delta_imbalance)?;
-
-			// burn only difference between delta hub and lp hub amount.
-			Self::update_hub_asset_liquidity(
-				&state_changes
-					.asset
-					.delta_hub_reserve
-					.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
-					.ok_or(ArithmeticError::Overflow)?,
+			Self::sell(
+				pol_origin.clone(),
+				asset_from,
+				asset_to,
+				amount_removed,
+				Balance::zero(),
 			)?;
 
-			// LP receives some hub asset
-			Self::process_hub_amount(state_changes.lp_hub_amount, &dest)?;
+			// Liquidity is removed at spot price, so hub asset is received only due to rounding.
+			if hub_amount >= T::MinimumTradingLimit::get() {
+				Self::sell(
+					pol_origin.clone(),
+					T::HubAssetId::get(),
+					asset_to,
+					hub_amount,
+					Balance::zero(),
+				)?;
+			}
 
-			// Callback hook info
-			let info: AssetInfo<T::AssetId, Balance> =
-				AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset, true);
+			let amount_added = T::Currency::free_balance(asset_to, &pol_account)
+				.checked_sub(initial_balance)
+				.ok_or(ArithmeticError::Underflow)?;
 
-			Self::set_asset_state(asset_id, new_asset_state);
+			let position_id = Self::do_add_liquidity(pol_origin, asset_to, amount_added, true)?;
+			let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
 
-			Self::deposit_event(Event::ProtocolLiquidityRemoved {
-				who: dest,
-				asset_id,
-				amount: *state_changes.asset.delta_reserve,
-				hub_amount: state_changes.lp_hub_amount,
-				shares_removed: amount,
+			ensure!(position.shares >= min_shares, Error::<T>::SlippageLimit);
+
+			Self::do_sacrifice_position(position_id, &position, &pol_account)?;
+
+			Self::deposit_event(Event::ProtocolLiquidityRebalanced {
+				asset_from,
+				asset_to,
+				shares_removed: shares,
+				amount_removed,
+				amount_added,
+				shares_added: position.shares,
 			});
 
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
 			Ok(())
 		}
 
@@ -1514,42 +1752,192 @@ pub mod pallet {
 		#[transactional]
 		pub fn remove_token(origin: OriginFor<T>, asset_id: T::AssetId, beneficiary: T::AccountId) -> DispatchResult {
 			T::AuthorityOrigin::ensure_origin(origin)?;
-			let asset_state = Self::load_asset_state(asset_id)?;
 
-			// Allow only if no shares owned by LPs and asset is frozen.
-			ensure!(asset_state.tradable == Tradability::FROZEN, Error::<T>::AssetNotFrozen);
+			Self::do_remove_token(asset_id, &beneficiary)
+		}
+
+		/// Announce delisting of an asset from Omnipool.
+		///
+		/// Asset can be only bought and its liquidity removed from now on. Weight cap of the asset is lowered
+		/// linearly to zero over `wind_down_period` blocks.
+		///
+		/// Once the wind-down period ends, asset is frozen and remaining positions are settled pro-rata
+		/// in `on_idle`. When all positions are settled, asset is removed from Omnipool and remaining
+		/// protocol owned liquidity is transferred to `beneficiary`.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin
+		/// - `asset_id`: Asset to delist
+		/// - `wind_down_period`: Number of blocks before settlement of positions starts
+		/// - `beneficiary`: Account which receives protocol owned liquidity of the asset
+		///
+		/// Emits `DelistingAnnounced` event when successful.
+		///
+		#[pallet::call_index(19)]
+		#[pallet::weight(<T as Config>::WeightInfo::announce_delisting())]
+		#[transactional]
+		pub fn announce_delisting(
+			origin: OriginFor<T>,
+			asset_id: T::AssetId,
+			wind_down_period: T::BlockNumber,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(!wind_down_period.is_zero(), Error::<T>::InvalidDelistingPeriod);
 			ensure!(
-				asset_state.shares == asset_state.protocol_shares,
-				Error::<T>::SharesRemaining
+				!<Delistings<T>>::contains_key(asset_id),
+				Error::<T>::DelistingAlreadyAnnounced
 			);
-			// Imbalance update
-			let imbalance = <HubAssetImbalance<T>>::get();
-			let hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
-			let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
-				asset_state.hub_reserve,
-				I129 {
-					value: imbalance.value,
-					negative: imbalance.negative,
-				},
-				hub_asset_liquidity,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
-			Self::update_imbalance(BalanceUpdate::Increase(delta_imbalance))?;
+			ensure!(
+				<Delistings<T>>::count() < T::MaxDelistings::get(),
+				Error::<T>::MaxDelistingsReached
+			);
+
+			let start = frame_system::Pallet::<T>::block_number();
+			let end = start.saturating_add(wind_down_period);
+
+			Assets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				<Delistings<T>>::insert(
+					asset_id,
+					Delisting {
+						stage: DelistingStage::WindDown,
+						start,
+						end,
+						initial_cap: asset_state.cap,
+						beneficiary: beneficiary.clone(),
+					},
+				);
+
+				asset_state.tradable = Tradability::BUY | Tradability::REMOVE_LIQUIDITY;
+
+				Ok(())
+			})?;
 
-			T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), asset_state.hub_reserve)?;
-			T::Currency::transfer(asset_id, &Self::protocol_account(), &beneficiary, asset_state.reserve)?;
-			<Assets<T>>::remove(asset_id);
-			Self::deposit_event(Event::TokenRemoved {
+			Self::deposit_event(Event::DelistingAnnounced {
 				asset_id,
-				amount: asset_state.reserve,
-				hub_withdrawn: asset_state.hub_reserve,
+				end,
+				beneficiary,
 			});
+
+			Ok(())
+		}
+
+		/// Cancel delisting of an asset.
+		///
+		/// Tradable state and weight cap of the asset are restored. Delisting can be cancelled only
+		/// before settlement of positions starts.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `DelistingCancelled` event when successful.
+		///
+		#[pallet::call_index(20)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_delisting())]
+		#[transactional]
+		pub fn cancel_delisting(origin: OriginFor<T>, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let delisting = <Delistings<T>>::get(asset_id).ok_or(Error::<T>::DelistingNotFound)?;
+			ensure!(
+				delisting.stage == DelistingStage::WindDown,
+				Error::<T>::DelistingNotCancellable
+			);
+
+			Assets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				asset_state.tradable = Tradability::default();
+				asset_state.cap = delisting.initial_cap;
+
+				Ok(())
+			})?;
+
+			<Delistings<T>>::remove(asset_id);
+
+			Self::deposit_event(Event::DelistingCancelled { asset_id });
+
+			Ok(())
+		}
+
+		/// Settle position of a delisted asset which is held by one of `LockedPositionOwners`.
+		///
+		/// Locked positions are skipped by the settlement of delisted assets, so delisting could not finish
+		/// while any of them is not returned to its owner. This settles such position pro-rata, transfers
+		/// the asset amount to `beneficiary` and destroys the position.
+		///
+		/// Can be called only by `AuthorityOrigin` once settlement of positions of the asset started.
+		///
+		/// Parameters:
+		/// - `origin`: Authority origin
+		/// - `position_id`: Locked position of a delisted asset
+		/// - `beneficiary`: Account which receives the asset amount of the position, eg. the owner of the lock
+		///
+		/// Emits `DelistedPositionSettled` event when successful.
+		///
+		#[pallet::call_index(22)]
+		#[pallet::weight(<T as Config>::WeightInfo::settle_delisted_position()
+			.saturating_add(T::DbWeight::get().reads(2)))]
+		#[transactional]
+		pub fn force_settle_delisted_position(
+			origin: OriginFor<T>,
+			position_id: T::PositionItemId,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let position = <Positions<T>>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+			let delisting = <Delistings<T>>::get(position.asset_id).ok_or(Error::<T>::DelistingNotFound)?;
+			ensure!(
+				delisting.stage == DelistingStage::Settlement,
+				Error::<T>::DelistingNotInSettlement
+			);
+
+			let owner =
+				T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id).ok_or(Error::<T>::PositionNotFound)?;
+			ensure!(T::LockedPositionOwners::contains(&owner), Error::<T>::PositionNotLocked);
+
+			Self::settle_delisted_position(position_id, &position, &owner, beneficiary)
+		}
+
+		/// Update portion of protocol fee which is used to burn hub asset imbalance.
+		///
+		/// Protocol fee collected in hub asset during trades is burned up to the current imbalance.
+		/// With `rate` lower than 100%, only given portion of protocol fee is burned and the rest is
+		/// transferred to HDX subpool.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// Emits `ImbalanceBurnRateUpdated` event when successful.
+		///
+		#[pallet::call_index(21)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_imbalance_burn_rate())]
+		#[transactional]
+		pub fn set_imbalance_burn_rate(origin: OriginFor<T>, rate: Permill) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			<ImbalanceBurnRate<T>>::put(rate);
+
+			Self::deposit_event(Event::ImbalanceBurnRateUpdated { rate });
+
 			Ok(())
 		}
 	}
 
 	#[pallet::hooks]
 	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			Self::update_delistings(n)
+		}
+
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::settle_delistings(remaining_weight)
+		}
+
 		fn integrity_test() {
 			assert_ne!(
 				T::MinimumPoolLiquidity::get(),
@@ -1573,6 +1961,11 @@ impl<T: Config> Pallet<T> {
 		PalletId(*b"omnipool").into_account_truncating()
 	}
 
+	/// Account used to hold protocol owned liquidity while it is being rebalanced.
+	pub fn protocol_liquidity_account() -> T::AccountId {
+		PalletId(*b"omnipool").into_sub_account_truncating(b"pol")
+	}
+
 	/// Retrieve state of asset from the pool and its pool balance
 	pub fn load_asset_state(asset_id: T::AssetId) -> Result<AssetReserveState<Balance>, DispatchError> {
 		let state = <Assets<T>>::get(asset_id).ok_or(Error::<T>::AssetNotFound)?;
@@ -1586,53 +1979,590 @@ impl<T: Config> Pallet<T> {
 		<Assets<T>>::insert(asset_id, Into::<AssetState<Balance>>::into(new_state));
 	}
 
-	/// Generate an nft instance id and mint NFT into the class and instance.
+	/// Adds liquidity of `asset` and mints new position. Returns the id of the new position.
+	///
+	/// Asset weight cap is not checked if `check_weight_cap` is false. The caller is responsible for
+	/// checking the weight cap with `ensure_weight_cap` once the liquidity is added.
 	#[require_transactional]
-	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
-		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
-			let next_position_id = *current_value;
+	fn do_add_liquidity(
+		origin: T::RuntimeOrigin,
+		asset: T::AssetId,
+		amount: Balance,
+		check_weight_cap: bool,
+	) -> Result<T::PositionItemId, DispatchError> {
+		//
+		// Preconditions
+		//
+		let who = ensure_signed(origin.clone())?;
 
-			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+		ensure!(
+			amount >= T::MinimumPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
 
-			*current_value = current_value
-				.checked_add(&T::PositionItemId::one())
-				.ok_or(ArithmeticError::Overflow)?;
+		ensure!(
+			T::Currency::ensure_can_withdraw(asset, &who, amount).is_ok(),
+			Error::<T>::InsufficientBalance
+		);
 
-			Ok(next_position_id)
-		})
-	}
+		let asset_state = Self::load_asset_state(asset)?;
 
-	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
-	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
-		if hub_asset_amount > Balance::zero() {
-			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+		ensure!(
+			asset_state.tradable.contains(Tradability::ADD_LIQUIDITY),
+			Error::<T>::NotAllowed
+		);
 
-			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
-			native_subpool.hub_reserve = native_subpool
-				.hub_reserve
-				.checked_add(hub_asset_amount)
-				.ok_or(ArithmeticError::Overflow)?;
-			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+		T::PriceBarrier::ensure_price(
+			&who,
+			T::HubAssetId::get(),
+			asset,
+			EmaPrice::new(asset_state.hub_reserve, asset_state.reserve),
+		)
+		.map_err(|_| Error::<T>::PriceDifferenceTooHigh)?;
 
-			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
 
-			let delta_changes = AssetStateChange {
-				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
-				..Default::default()
-			};
+		//
+		// Calculate add liquidity state changes
+		//
+		let state_changes = hydra_dx_math::omnipool::calculate_add_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
 
-			let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
-				T::HdxAssetId::get(),
-				&hdx_state,
-				&updated_hdx_state,
-				&delta_changes,
-				false,
-			);
+		let new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
 
-			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
-		}
-		Ok(())
-	}
+		if check_weight_cap {
+			let hub_reserve_ratio = FixedU128::checked_from_rational(
+				new_asset_state.hub_reserve,
+				T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
+					.checked_add(*state_changes.asset.delta_hub_reserve)
+					.ok_or(ArithmeticError::Overflow)?,
+			)
+			.ok_or(ArithmeticError::DivisionByZero)?;
+
+			ensure!(
+				hub_reserve_ratio <= new_asset_state.weight_cap(),
+				Error::<T>::AssetWeightCapExceeded
+			);
+		}
+
+		//
+		// Post - update states
+		//
+
+		// Create LP position with given shares
+		let lp_position = Position::<Balance, T::AssetId> {
+			asset_id: asset,
+			amount,
+			shares: *state_changes.asset.delta_shares,
+			// Note: position needs price after asset state is updated.
+			price: (new_asset_state.hub_reserve, new_asset_state.reserve),
+		};
+
+		let instance_id = Self::create_and_mint_position_instance(&who)?;
+
+		<Positions<T>>::insert(instance_id, lp_position);
+
+		Self::deposit_event(Event::PositionCreated {
+			position_id: instance_id,
+			owner: who.clone(),
+			asset,
+			amount,
+			shares: *state_changes.asset.delta_shares,
+			price: new_asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+		});
+
+		T::Currency::transfer(
+			asset,
+			&who,
+			&Self::protocol_account(),
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		debug_assert_eq!(*state_changes.asset.delta_reserve, amount);
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset, &asset_state, &new_asset_state, &state_changes.asset, false);
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		Self::update_hub_asset_liquidity(&state_changes.asset.delta_hub_reserve)?;
+
+		Self::set_asset_state(asset, new_asset_state);
+
+		Self::deposit_event(Event::LiquidityAdded {
+			who,
+			asset_id: asset,
+			amount,
+			position_id: instance_id,
+		});
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok(instance_id)
+	}
+
+	/// Remove `amount` of protocol shares of `asset_id` from the pool and transfer the liquidity to `dest`.
+	///
+	/// Returns amounts of asset and hub asset which were transferred.
+	#[require_transactional]
+	fn do_withdraw_protocol_liquidity(
+		origin: T::RuntimeOrigin,
+		asset_id: T::AssetId,
+		amount: Balance,
+		price: (Balance, Balance),
+		dest: T::AccountId,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let asset_state = Self::load_asset_state(asset_id)?;
+		ensure!(amount <= asset_state.protocol_shares, Error::<T>::InsufficientShares);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+		let current_hub_asset_liquidity = T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account());
+
+		// dev note: as we no longer have the position details for sacrificed one, we just need to
+		// construct temporary position.
+		// Note that amount is ok to set to zero in this case. Although the remove liquidity calculation
+		// calculates the delta for this field, it does not make any difference afterwards.
+		let position = hydra_dx_math::omnipool::types::Position::<Balance> {
+			amount: 0,
+			price,
+			shares: amount,
+		};
+
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			amount,
+			&position,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			current_hub_asset_liquidity,
+			FixedU128::zero(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let mut new_asset_state = asset_state
+			.clone()
+			.delta_update(&state_changes.asset)
+			.ok_or(ArithmeticError::Overflow)?;
+
+		new_asset_state.protocol_shares = new_asset_state.protocol_shares.saturating_sub(amount);
+
+		T::Currency::transfer(
+			asset_id,
+			&Self::protocol_account(),
+			&dest,
+			*state_changes.asset.delta_reserve,
+		)?;
+
+		Self::update_imbalance(state_changes.delta_imbalance)?;
+
+		// burn only difference between delta hub and lp hub amount.
+		Self::update_hub_asset_liquidity(
+			&state_changes
+				.asset
+				.delta_hub_reserve
+				.merge(BalanceUpdate::Increase(state_changes.lp_hub_amount))
+				.ok_or(ArithmeticError::Overflow)?,
+		)?;
+
+		// LP receives some hub asset
+		Self::process_hub_amount(state_changes.lp_hub_amount, &dest)?;
+
+		// Callback hook info
+		let info: AssetInfo<T::AssetId, Balance> =
+			AssetInfo::new(asset_id, &asset_state, &new_asset_state, &state_changes.asset, true);
+
+		Self::set_asset_state(asset_id, new_asset_state);
+
+		Self::deposit_event(Event::ProtocolLiquidityRemoved {
+			who: dest,
+			asset_id,
+			amount: *state_changes.asset.delta_reserve,
+			hub_amount: state_changes.lp_hub_amount,
+			shares_removed: amount,
+		});
+
+		T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+
+		Ok((*state_changes.asset.delta_reserve, state_changes.lp_hub_amount))
+	}
+
+	/// Remove asset from Omnipool and transfer its reserve to `beneficiary`.
+	///
+	/// Asset must be frozen and all its shares must be owned by protocol.
+	#[require_transactional]
+	fn do_remove_token(asset_id: T::AssetId, beneficiary: &T::AccountId) -> DispatchResult {
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		// Allow only if no shares owned by LPs and asset is frozen.
+		ensure!(asset_state.tradable == Tradability::FROZEN, Error::<T>::AssetNotFrozen);
+		ensure!(
+			asset_state.shares == asset_state.protocol_shares,
+			Error::<T>::SharesRemaining
+		);
+		// Imbalance update
+		let imbalance = <HubAssetImbalance<T>>::get();
+		let hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
+		let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+			asset_state.hub_reserve,
+			I129 {
+				value: imbalance.value,
+				negative: imbalance.negative,
+			},
+			hub_asset_liquidity,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		Self::update_imbalance(BalanceUpdate::Increase(delta_imbalance))?;
+
+		T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), asset_state.hub_reserve)?;
+		T::Currency::transfer(asset_id, &Self::protocol_account(), beneficiary, asset_state.reserve)?;
+		<Assets<T>>::remove(asset_id);
+		Self::deposit_event(Event::TokenRemoved {
+			asset_id,
+			amount: asset_state.reserve,
+			hub_withdrawn: asset_state.hub_reserve,
+		});
+		Ok(())
+	}
+
+	/// Lower weight cap of assets in wind-down stage of delisting and start settlement of positions
+	/// once the wind-down period ends.
+	///
+	/// Number of delistings is bounded by `MaxDelistings`, each of them is charged in returned weight.
+	fn update_delistings(block_number: T::BlockNumber) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+
+		for (asset_id, mut delisting) in <Delistings<T>>::iter() {
+			if delisting.stage != DelistingStage::WindDown {
+				weight.saturating_accrue(T::DbWeight::get().reads(1));
+				continue;
+			}
+
+			weight.saturating_accrue(<T as Config>::WeightInfo::update_delisting());
+
+			let _ = Assets::<T>::try_mutate(asset_id, |maybe_asset| -> DispatchResult {
+				let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+				if block_number >= delisting.end {
+					asset_state.tradable = Tradability::FROZEN;
+					asset_state.cap = 0;
+					delisting.stage = DelistingStage::Settlement;
+					<Delistings<T>>::insert(asset_id, &delisting);
+
+					Self::deposit_event(Event::DelistingSettlementStarted { asset_id });
+				} else {
+					let remaining: u128 = delisting.end.saturating_sub(block_number).saturated_into();
+					let period: u128 = delisting.end.saturating_sub(delisting.start).saturated_into();
+					asset_state.cap =
+						multiply_by_rational_with_rounding(delisting.initial_cap, remaining, period, Rounding::Down)
+							.ok_or(ArithmeticError::Overflow)?;
+				}
+
+				Ok(())
+			});
+		}
+
+		weight
+	}
+
+	/// Settle positions of assets in settlement stage of delisting and remove assets whose positions
+	/// have been all settled.
+	///
+	/// Positions are checked from the last checked position, so all positions are eventually checked
+	/// even if only part of them fits into `remaining_weight`.
+	///
+	/// Positions held by `LockedPositionOwners` are skipped, asset is removed only once they are returned
+	/// to their owners and settled, or force-settled by `AuthorityOrigin` via `force_settle_delisted_position`.
+	fn settle_delistings(remaining_weight: Weight) -> Weight {
+		let mut weight = T::DbWeight::get().reads(1);
+
+		let settling: Vec<(T::AssetId, T::AccountId)> = <Delistings<T>>::iter()
+			.filter(|(_, delisting)| delisting.stage == DelistingStage::Settlement)
+			.map(|(asset_id, delisting)| (asset_id, delisting.beneficiary))
+			.collect();
+		weight.saturating_accrue(T::DbWeight::get().reads(settling.len() as u64));
+
+		if settling.is_empty() {
+			return weight;
+		}
+
+		let finalize_weight = <T as Config>::WeightInfo::remove_token();
+		let mut remaining_assets = Vec::with_capacity(settling.len());
+		for (asset_id, beneficiary) in settling {
+			let Some(asset_state) = <Assets<T>>::get(asset_id) else {
+				continue;
+			};
+			weight.saturating_accrue(T::DbWeight::get().reads(1));
+
+			if asset_state.shares != asset_state.protocol_shares {
+				remaining_assets.push(asset_id);
+				continue;
+			}
+
+			if weight.saturating_add(finalize_weight).any_gt(remaining_weight) {
+				return weight;
+			}
+			weight.saturating_accrue(finalize_weight);
+
+			if Self::finalize_delisting(asset_id, &beneficiary).is_ok() {
+				Self::deposit_event(Event::AssetDelisted { asset_id });
+			}
+		}
+
+		if remaining_assets.is_empty() {
+			return weight;
+		}
+
+		let settle_weight = <T as Config>::WeightInfo::settle_delisted_position();
+		let read_weight = T::DbWeight::get().reads_writes(1, 1);
+
+		let mut positions = match <SettlementCursor<T>>::get() {
+			Some(position_id) => <Positions<T>>::iter_from(<Positions<T>>::hashed_key_for(position_id)),
+			None => <Positions<T>>::iter(),
+		};
+
+		loop {
+			if weight
+				.saturating_add(read_weight)
+				.saturating_add(settle_weight)
+				.any_gt(remaining_weight)
+			{
+				break;
+			}
+			weight.saturating_accrue(read_weight);
+
+			let Some((position_id, position)) = positions.next() else {
+				<SettlementCursor<T>>::kill();
+				break;
+			};
+			<SettlementCursor<T>>::put(position_id);
+
+			if !remaining_assets.contains(&position.asset_id) {
+				continue;
+			}
+
+			weight.saturating_accrue(settle_weight);
+
+			let Some(owner) = T::NFTHandler::owner(&T::NFTCollectionId::get(), &position_id) else {
+				Self::deposit_event(Event::DelistedPositionSettlementFailed {
+					position_id,
+					error: Error::<T>::PositionNotFound.into(),
+				});
+				continue;
+			};
+
+			// Locked positions are settled once they are returned to their owners, eg. by exiting all farms,
+			// or force-settled by authority.
+			if T::LockedPositionOwners::contains(&owner) {
+				continue;
+			}
+
+			if let Err(error) = Self::settle_delisted_position(position_id, &position, &owner, owner.clone()) {
+				Self::deposit_event(Event::DelistedPositionSettlementFailed { position_id, error });
+			}
+		}
+
+		weight
+	}
+
+	/// Remove delisted asset from Omnipool.
+	#[transactional]
+	fn finalize_delisting(asset_id: T::AssetId, beneficiary: &T::AccountId) -> DispatchResult {
+		Self::do_remove_token(asset_id, beneficiary)?;
+		<Delistings<T>>::remove(asset_id);
+		Ok(())
+	}
+
+	/// Settle position of delisted asset.
+	///
+	/// `beneficiary` receives amount of asset proportional to position's shares, corresponding
+	/// part of hub reserve is burned. Position is destroyed and its NFT burned from `owner`.
+	#[transactional]
+	fn settle_delisted_position(
+		position_id: T::PositionItemId,
+		position: &Position<Balance, T::AssetId>,
+		owner: &T::AccountId,
+		beneficiary: T::AccountId,
+	) -> DispatchResult {
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+
+		let amount = multiply_by_rational_with_rounding(
+			asset_state.reserve,
+			position.shares,
+			asset_state.shares,
+			Rounding::Down,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		let hub_amount = multiply_by_rational_with_rounding(
+			asset_state.hub_reserve,
+			position.shares,
+			asset_state.shares,
+			Rounding::Down,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let imbalance = <HubAssetImbalance<T>>::get();
+		let delta_imbalance = hydra_dx_math::omnipool::calculate_delta_imbalance(
+			hub_amount,
+			I129 {
+				value: imbalance.value,
+				negative: imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+		Self::update_imbalance(BalanceUpdate::Increase(delta_imbalance))?;
+
+		T::Currency::withdraw(T::HubAssetId::get(), &Self::protocol_account(), hub_amount)?;
+		T::Currency::transfer(position.asset_id, &Self::protocol_account(), &beneficiary, amount)?;
+
+		Assets::<T>::try_mutate(position.asset_id, |maybe_asset| -> DispatchResult {
+			let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+			asset_state.hub_reserve = asset_state
+				.hub_reserve
+				.checked_sub(hub_amount)
+				.ok_or(ArithmeticError::Underflow)?;
+			asset_state.shares = asset_state
+				.shares
+				.checked_sub(position.shares)
+				.ok_or(ArithmeticError::Underflow)?;
+
+			Ok(())
+		})?;
+
+		<Positions<T>>::remove(position_id);
+		T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(owner))?;
+
+		Self::deposit_event(Event::DelistedPositionSettled {
+			position_id,
+			owner: beneficiary,
+			asset_id: position.asset_id,
+			amount,
+			shares: position.shares,
+		});
+
+		Ok(())
+	}
+
+	/// Convert shares of given position to protocol shares, destroy the position and burn its NFT.
+	#[require_transactional]
+	fn do_sacrifice_position(
+		position_id: T::PositionItemId,
+		position: &Position<Balance, T::AssetId>,
+		owner: &T::AccountId,
+	) -> DispatchResult {
+		Assets::<T>::try_mutate(position.asset_id, |maybe_asset| -> DispatchResult {
+			let asset_state = maybe_asset.as_mut().ok_or(Error::<T>::AssetNotFound)?;
+
+			asset_state.protocol_shares = asset_state
+				.protocol_shares
+				.checked_add(position.shares)
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(())
+		})?;
+
+		// Desotry position and burn NFT
+		<Positions<T>>::remove(position_id);
+		T::NFTHandler::burn(&T::NFTCollectionId::get(), &position_id, Some(owner))?;
+
+		Self::deposit_event(Event::PositionDestroyed {
+			position_id,
+			owner: owner.clone(),
+		});
+
+		Ok(())
+	}
+
+	/// Ensure that the current weight of `asset` in the pool respects its weight cap.
+	fn ensure_weight_cap(asset: T::AssetId) -> DispatchResult {
+		let asset_state = Self::load_asset_state(asset)?;
+
+		let hub_reserve_ratio = FixedU128::checked_from_rational(
+			asset_state.hub_reserve,
+			T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account()),
+		)
+		.ok_or(ArithmeticError::DivisionByZero)?;
+
+		ensure!(
+			hub_reserve_ratio <= asset_state.weight_cap(),
+			Error::<T>::AssetWeightCapExceeded
+		);
+
+		Ok(())
+	}
+
+	/// Route selling hub asset for `asset_out` in Omnipool.
+	fn hub_asset_route(asset_out: T::AssetId) -> Vec<Trade<T::AssetId>> {
+		vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: T::HubAssetId::get(),
+			asset_out,
+		}]
+	}
+
+	/// Generate an nft instance id and mint NFT into the class and instance.
+	#[require_transactional]
+	fn create_and_mint_position_instance(owner: &T::AccountId) -> Result<T::PositionItemId, DispatchError> {
+		<NextPositionId<T>>::try_mutate(|current_value| -> Result<T::PositionItemId, DispatchError> {
+			let next_position_id = *current_value;
+
+			T::NFTHandler::mint_into(&T::NFTCollectionId::get(), &next_position_id, owner)?;
+
+			*current_value = current_value
+				.checked_add(&T::PositionItemId::one())
+				.ok_or(ArithmeticError::Overflow)?;
+
+			Ok(next_position_id)
+		})
+	}
+
+	/// Update Hub asset side of HDX subpool and add given amount to hub_asset_reserve
+	fn update_hdx_subpool_hub_asset(origin: T::RuntimeOrigin, hub_asset_amount: Balance) -> DispatchResult {
+		if hub_asset_amount > Balance::zero() {
+			let hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let mut native_subpool = Assets::<T>::get(T::HdxAssetId::get()).ok_or(Error::<T>::AssetNotFound)?;
+			native_subpool.hub_reserve = native_subpool
+				.hub_reserve
+				.checked_add(hub_asset_amount)
+				.ok_or(ArithmeticError::Overflow)?;
+			<Assets<T>>::insert(T::HdxAssetId::get(), native_subpool);
+
+			let updated_hdx_state = Self::load_asset_state(T::HdxAssetId::get())?;
+
+			let delta_changes = AssetStateChange {
+				delta_hub_reserve: BalanceUpdate::Increase(hub_asset_amount),
+				..Default::default()
+			};
+
+			let info: AssetInfo<T::AssetId, Balance> = AssetInfo::new(
+				T::HdxAssetId::get(),
+				&hdx_state,
+				&updated_hdx_state,
+				&delta_changes,
+				false,
+			);
+
+			T::OmnipoolHooks::on_liquidity_changed(origin, info)?;
+		}
+		Ok(())
+	}
 
 	/// Update total hub asset liquidity and write new value to storage.
 	/// Update total issueance if AdjustSupply is specified.
@@ -1662,25 +2592,161 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
-	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
-	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
-		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
+	/// Return asset fee of `asset_out` and protocol fee of `asset_in`.
+	fn get_fees(asset_in: T::AssetId, asset_out: T::AssetId) -> (Permill, Permill) {
+		let (asset_fee, _) = T::Fee::get(&asset_out);
+		let (_, protocol_fee) = T::Fee::get(&asset_in);
+		(asset_fee, protocol_fee)
 	}
 
-	/// Swap hub asset for asset_out.
-	/// Special handling of sell trade where asset in is Hub Asset.
-	fn sell_hub_asset(
-		origin: T::RuntimeOrigin,
-		who: &T::AccountId,
+	/// Return asset fee of `asset_out` and protocol fee of `asset_in`, both reduced by the fee discount of `who`.
+	fn get_discounted_fees(who: &T::AccountId, asset_in: T::AssetId, asset_out: T::AssetId) -> (Permill, Permill) {
+		let (asset_fee, protocol_fee) = Self::get_fees(asset_in, asset_out);
+		let discount = T::FeeDiscount::discount(who, asset_in, asset_out);
+
+		(
+			hydra_dx_math::fee::calculate_discounted_fee(asset_fee, discount),
+			hydra_dx_math::fee::calculate_discounted_fee(protocol_fee, discount),
+		)
+	}
+
+	/// Calculate state changes of selling `amount` of `asset_in` for `asset_out` with given asset and protocol fee.
+	///
+	/// Performs all checks of a sell which do not depend on the trader. Shared by `sell` and `quote_sell`.
+	/// Neither of the assets can be hub asset.
+	#[allow(clippy::type_complexity)]
+	fn calculate_sell_state(
+		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount: Balance,
-		limit: Balance,
-	) -> DispatchResult {
+		(asset_fee, protocol_fee): (Permill, Permill),
+	) -> Result<
+		(
+			AssetReserveState<Balance>,
+			AssetReserveState<Balance>,
+			TradeStateChange<Balance>,
+		),
+		DispatchError,
+	> {
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+
 		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::SELL),
+			Self::allow_assets(&asset_in_state, &asset_out_state),
 			Error::<T>::NotAllowed
 		);
 
+		ensure!(
+			amount
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_sell_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+			<ImbalanceBurnRate<T>>::get(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset_out.delta_reserve > Balance::zero(),
+			Error::<T>::ZeroAmountOut
+		);
+
+		ensure!(
+			*state_changes.asset_out.delta_reserve
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		Ok((asset_in_state, asset_out_state, state_changes))
+	}
+
+	/// Calculate state changes of buying `amount` of `asset_out` for `asset_in` with given asset and protocol fee.
+	///
+	/// Performs all checks of a buy which do not depend on the trader. Shared by `buy` and `quote_buy`.
+	/// Neither of the assets can be hub asset.
+	#[allow(clippy::type_complexity)]
+	fn calculate_buy_state(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		(asset_fee, protocol_fee): (Permill, Permill),
+	) -> Result<
+		(
+			AssetReserveState<Balance>,
+			AssetReserveState<Balance>,
+			TradeStateChange<Balance>,
+		),
+		DispatchError,
+	> {
+		let asset_in_state = Self::load_asset_state(asset_in)?;
+		let asset_out_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(
+			Self::allow_assets(&asset_in_state, &asset_out_state),
+			Error::<T>::NotAllowed
+		);
+
+		ensure!(asset_out_state.reserve >= amount, Error::<T>::InsufficientLiquidity);
+
+		ensure!(
+			amount
+				<= asset_out_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
+		);
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_state_changes(
+			&(&asset_in_state).into(),
+			&(&asset_out_state).into(),
+			amount,
+			asset_fee,
+			protocol_fee,
+			current_imbalance.value,
+			<ImbalanceBurnRate<T>>::get(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset_in.delta_reserve
+				<= asset_in_state
+					.reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Ok((asset_in_state, asset_out_state, state_changes))
+	}
+
+	/// Calculate state changes of selling `amount` of hub asset for `asset_out` with given asset fee.
+	///
+	/// Shared by `sell` and `quote_sell` when asset in is hub asset.
+	fn calculate_sell_hub_state(
+		asset_out: T::AssetId,
+		amount: Balance,
+		asset_fee: Permill,
+	) -> Result<(AssetReserveState<Balance>, HubTradeStateChange<Balance>), DispatchError> {
+		ensure!(Self::is_hub_asset_allowed(Tradability::SELL), Error::<T>::NotAllowed);
+
 		let asset_state = Self::load_asset_state(asset_out)?;
 
 		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
@@ -1695,10 +2761,6 @@ impl<T: Config> Pallet<T> {
 
 		let current_imbalance = <HubAssetImbalance<T>>::get();
 
-		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
-
-		let (asset_fee, _) = T::Fee::get(&asset_out);
-
 		let state_changes = hydra_dx_math::omnipool::calculate_sell_hub_state_changes(
 			&(&asset_state).into(),
 			amount,
@@ -1707,24 +2769,92 @@ impl<T: Config> Pallet<T> {
 				value: current_imbalance.value,
 				negative: current_imbalance.negative,
 			},
-			current_hub_asset_liquidity,
+			Self::get_hub_asset_balance_of_protocol_account(),
 		)
 		.ok_or(ArithmeticError::Overflow)?;
 
 		ensure!(
-			*state_changes.asset.delta_reserve >= limit,
-			Error::<T>::BuyLimitNotReached
+			*state_changes.asset.delta_reserve
+				<= asset_state
+					.reserve
+					.checked_div(T::MaxOutRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
+			Error::<T>::MaxOutRatioExceeded
 		);
 
+		Ok((asset_state, state_changes))
+	}
+
+	/// Calculate state changes of buying `amount` of `asset_out` for hub asset with given asset fee.
+	///
+	/// Shared by `buy` and `quote_buy` when asset in is hub asset.
+	fn calculate_buy_for_hub_asset_state(
+		asset_out: T::AssetId,
+		amount: Balance,
+		asset_fee: Permill,
+	) -> Result<(AssetReserveState<Balance>, HubTradeStateChange<Balance>), DispatchError> {
+		ensure!(Self::is_hub_asset_allowed(Tradability::SELL), Error::<T>::NotAllowed);
+
+		let asset_state = Self::load_asset_state(asset_out)?;
+
+		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
 		ensure!(
-			*state_changes.asset.delta_reserve
+			amount
 				<= asset_state
 					.reserve
 					.checked_div(T::MaxOutRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxOutRatio is zero.
 			Error::<T>::MaxOutRatioExceeded
 		);
 
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
+			&(&asset_state).into(),
+			amount,
+			asset_fee,
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		ensure!(
+			*state_changes.asset.delta_hub_reserve
+				<= asset_state
+					.hub_reserve
+					.checked_div(T::MaxInRatio::get())
+					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
+			Error::<T>::MaxInRatioExceeded
+		);
+
+		Ok((asset_state, state_changes))
+	}
+
+	/// Check if assets can be traded - asset_in must be allowed to be sold and asset_out allowed to be bought.
+	fn allow_assets(asset_in: &AssetReserveState<Balance>, asset_out: &AssetReserveState<Balance>) -> bool {
+		asset_in.tradable.contains(Tradability::SELL) && asset_out.tradable.contains(Tradability::BUY)
+	}
+
+	/// Swap hub asset for asset_out.
+	/// Special handling of sell trade where asset in is Hub Asset.
+	fn sell_hub_asset(
+		origin: T::RuntimeOrigin,
+		who: &T::AccountId,
+		asset_out: T::AssetId,
+		amount: Balance,
+		limit: Balance,
+	) -> DispatchResult {
+		let (asset_fee, _) = Self::get_discounted_fees(who, T::HubAssetId::get(), asset_out);
+		let (asset_state, state_changes) = Self::calculate_sell_hub_state(asset_out, amount, asset_fee)?;
+
+		ensure!(
+			*state_changes.asset.delta_reserve >= limit,
+			Error::<T>::BuyLimitNotReached
+		);
+
 		let new_asset_out_state = asset_state
 			.clone()
 			.delta_update(&state_changes.asset)
@@ -1784,56 +2914,14 @@ impl<T: Config> Pallet<T> {
 		amount: Balance,
 		limit: Balance,
 	) -> DispatchResult {
-		ensure!(
-			HubAssetTradability::<T>::get().contains(Tradability::SELL),
-			Error::<T>::NotAllowed
-		);
-
-		let asset_state = Self::load_asset_state(asset_out)?;
-
-		ensure!(asset_state.tradable.contains(Tradability::BUY), Error::<T>::NotAllowed);
-
-		ensure!(
-			amount
-				<= asset_state
-					.reserve
-					.checked_div(T::MaxOutRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-			Error::<T>::MaxOutRatioExceeded
-		);
-
-		let current_imbalance = <HubAssetImbalance<T>>::get();
-
-		let current_hub_asset_liquidity = Self::get_hub_asset_balance_of_protocol_account();
-
-		let (asset_fee, _) = T::Fee::get(&asset_out);
-
-		let state_changes = hydra_dx_math::omnipool::calculate_buy_for_hub_asset_state_changes(
-			&(&asset_state).into(),
-			amount,
-			asset_fee,
-			I129 {
-				value: current_imbalance.value,
-				negative: current_imbalance.negative,
-			},
-			current_hub_asset_liquidity,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+		let (asset_fee, _) = Self::get_discounted_fees(who, T::HubAssetId::get(), asset_out);
+		let (asset_state, state_changes) = Self::calculate_buy_for_hub_asset_state(asset_out, amount, asset_fee)?;
 
 		ensure!(
 			*state_changes.asset.delta_hub_reserve <= limit,
 			Error::<T>::SellLimitExceeded
 		);
 
-		ensure!(
-			*state_changes.asset.delta_hub_reserve
-				<= asset_state
-					.hub_reserve
-					.checked_div(T::MaxInRatio::get())
-					.ok_or(ArithmeticError::DivisionByZero)?, // Note: this can only fail if MaxInRatio is zero.
-			Error::<T>::MaxInRatioExceeded
-		);
-
 		let new_asset_out_state = asset_state
 			.clone()
 			.delta_update(&state_changes.asset)
@@ -1916,6 +3004,25 @@ impl<T: Config> Pallet<T> {
 		Err(Error::<T>::NotAllowed.into())
 	}
 
+	/// Calculate withdrawal fee of an asset based on the difference between its spot price and external oracle price.
+	fn withdrawal_fee(
+		asset_id: T::AssetId,
+		asset_state: &AssetReserveState<Balance>,
+	) -> Result<FixedU128, DispatchError> {
+		let ext_asset_price = T::ExternalPriceOracle::get_price(T::HubAssetId::get(), asset_id)?;
+
+		if ext_asset_price.is_zero() {
+			return Err(Error::<T>::InvalidOraclePrice.into());
+		}
+
+		Ok(hydra_dx_math::omnipool::calculate_withdrawal_fee(
+			asset_state.price().ok_or(ArithmeticError::DivisionByZero)?,
+			FixedU128::checked_from_rational(ext_asset_price.n, ext_asset_price.d)
+				.defensive_ok_or(Error::<T>::InvalidOraclePrice)?,
+			T::MinWithdrawalFee::get(),
+		))
+	}
+
 	/// Get hub asset balance of protocol account
 	fn get_hub_asset_balance_of_protocol_account() -> Balance {
 		T::Currency::free_balance(T::HubAssetId::get(), &Self::protocol_account())
@@ -2005,4 +3112,170 @@ impl<T: Config> Pallet<T> {
 		}
 		Ok(())
 	}
+
+	/// Simulate selling `amount` of `asset_in` for `asset_out`.
+	///
+	/// Performs the same checks and calculations as `sell`, including current asset and protocol fees,
+	/// without changing any state. Fee discounts are not applied as the quote is not bound to an account.
+	pub fn quote_sell(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		if asset_in == T::HubAssetId::get() {
+			let (asset_fee, _) = Self::get_fees(asset_in, asset_out);
+			let (_, state_changes) = Self::calculate_sell_hub_state(asset_out, amount, asset_fee)?;
+
+			return Ok(TradeQuote {
+				amount_in: *state_changes.asset.delta_hub_reserve,
+				amount_out: *state_changes.asset.delta_reserve,
+				hub_amount_in: 0,
+				hub_amount_out: 0,
+				asset_fee_amount: state_changes.fee.asset_fee,
+				protocol_fee_amount: state_changes.fee.protocol_fee,
+			});
+		}
+
+		let (_, _, state_changes) =
+			Self::calculate_sell_state(asset_in, asset_out, amount, Self::get_fees(asset_in, asset_out))?;
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset_in.delta_reserve,
+			amount_out: *state_changes.asset_out.delta_reserve,
+			hub_amount_in: *state_changes.asset_in.delta_hub_reserve,
+			hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
+			asset_fee_amount: state_changes.fee.asset_fee,
+			protocol_fee_amount: state_changes.fee.protocol_fee,
+		})
+	}
+
+	/// Simulate buying `amount` of `asset_out` for `asset_in`.
+	///
+	/// Performs the same checks and calculations as `buy`, including current asset and protocol fees,
+	/// without changing any state. Fee discounts are not applied as the quote is not bound to an account.
+	pub fn quote_buy(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount: Balance,
+	) -> Result<TradeQuote<Balance>, DispatchError> {
+		ensure!(asset_in != asset_out, Error::<T>::SameAssetTradeNotAllowed);
+		ensure!(
+			amount >= T::MinimumTradingLimit::get(),
+			Error::<T>::InsufficientTradingAmount
+		);
+		ensure!(asset_out != T::HubAssetId::get(), Error::<T>::NotAllowed);
+
+		if asset_in == T::HubAssetId::get() {
+			let (asset_fee, _) = Self::get_fees(asset_in, asset_out);
+			let (_, state_changes) = Self::calculate_buy_for_hub_asset_state(asset_out, amount, asset_fee)?;
+
+			return Ok(TradeQuote {
+				amount_in: *state_changes.asset.delta_hub_reserve,
+				amount_out: *state_changes.asset.delta_reserve,
+				hub_amount_in: 0,
+				hub_amount_out: 0,
+				asset_fee_amount: state_changes.fee.asset_fee,
+				protocol_fee_amount: state_changes.fee.protocol_fee,
+			});
+		}
+
+		let (_, _, state_changes) =
+			Self::calculate_buy_state(asset_in, asset_out, amount, Self::get_fees(asset_in, asset_out))?;
+
+		Ok(TradeQuote {
+			amount_in: *state_changes.asset_in.delta_reserve,
+			amount_out: *state_changes.asset_out.delta_reserve,
+			hub_amount_in: *state_changes.asset_in.delta_hub_reserve,
+			hub_amount_out: *state_changes.asset_out.delta_hub_reserve,
+			asset_fee_amount: state_changes.fee.asset_fee,
+			protocol_fee_amount: state_changes.fee.protocol_fee,
+		})
+	}
+
+	/// Calculate amounts of asset and hub asset which would be received if all shares of given position were removed.
+	///
+	/// Withdrawal fee is calculated the same way as in `remove_liquidity`.
+	pub fn position_value(
+		position_id: T::PositionItemId,
+	) -> Result<PositionValuation<T::AssetId, Balance>, DispatchError> {
+		let position = Positions::<T>::get(position_id).ok_or(Error::<T>::PositionNotFound)?;
+		let asset_state = Self::load_asset_state(position.asset_id)?;
+
+		let withdrawal_fee = Self::withdrawal_fee(position.asset_id, &asset_state)?;
+
+		let current_imbalance = <HubAssetImbalance<T>>::get();
+
+		let state_changes = hydra_dx_math::omnipool::calculate_remove_liquidity_state_changes(
+			&(&asset_state).into(),
+			position.shares,
+			&(&position).into(),
+			I129 {
+				value: current_imbalance.value,
+				negative: current_imbalance.negative,
+			},
+			Self::get_hub_asset_balance_of_protocol_account(),
+			withdrawal_fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		Ok(PositionValuation {
+			asset_id: position.asset_id,
+			shares: position.shares,
+			amount: *state_changes.asset.delta_reserve,
+			hub_amount: state_changes.lp_hub_amount,
+			withdrawal_fee,
+		})
+	}
+
+	/// Calculate value of protocol owned liquidity of given asset.
+	///
+	/// Value is the share of asset reserve and hub reserve proportional to protocol shares.
+	pub fn protocol_liquidity_value(
+		asset_id: T::AssetId,
+	) -> Result<ProtocolLiquidityValuation<T::AssetId, Balance>, DispatchError> {
+		let asset_state = Self::load_asset_state(asset_id)?;
+
+		let (amount, hub_amount) = if asset_state.shares.is_zero() {
+			(Balance::zero(), Balance::zero())
+		} else {
+			(
+				multiply_by_rational_with_rounding(
+					asset_state.reserve,
+					asset_state.protocol_shares,
+					asset_state.shares,
+					Rounding::Down,
+				)
+				.ok_or(ArithmeticError::Overflow)?,
+				multiply_by_rational_with_rounding(
+					asset_state.hub_reserve,
+					asset_state.protocol_shares,
+					asset_state.shares,
+					Rounding::Down,
+				)
+				.ok_or(ArithmeticError::Overflow)?,
+			)
+		};
+
+		Ok(ProtocolLiquidityValuation {
+			asset_id,
+			shares: asset_state.protocol_shares,
+			amount,
+			hub_amount,
+		})
+	}
+
+	/// Calculate value of protocol owned liquidity of all assets in which protocol owns some shares.
+	pub fn protocol_liquidity() -> Vec<ProtocolLiquidityValuation<T::AssetId, Balance>> {
+		<Assets<T>>::iter_keys()
+			.filter_map(|asset_id| Self::protocol_liquidity_value(asset_id).ok())
+			.filter(|valuation| !valuation.shares.is_zero())
+			.collect()
+	}
 }
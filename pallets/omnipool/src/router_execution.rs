@@ -1,12 +1,13 @@
 use crate::types::Balance;
-use crate::{Config, Error, HubAssetImbalance, Pallet};
+use crate::{Assets, Config, Error, HubAssetImbalance, ImbalanceBurnRate, Pallet};
 use frame_system::pallet_prelude::OriginFor;
 use hydra_dx_math::omnipool::types::I129;
 
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
 use orml_traits::{GetByKey, MultiCurrency};
 use sp_runtime::traits::Get;
 use sp_runtime::{ArithmeticError, DispatchError};
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -60,6 +61,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 			asset_fee,
 			protocol_fee,
 			current_imbalance.value,
+			<ImbalanceBurnRate<T>>::get(),
 		)
 		.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -115,6 +117,7 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 			asset_fee,
 			protocol_fee,
 			current_imbalance.value,
+			<ImbalanceBurnRate<T>>::get(),
 		)
 		.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
 
@@ -165,3 +168,26 @@ impl<T: Config> TradeExecution<OriginFor<T>, T::AccountId, T::AssetId, Balance>
 		Ok(asset_state.reserve)
 	}
 }
+
+impl<T: Config> PoolAssetPairs<T::AssetId> for Pallet<T> {
+	fn asset_pairs() -> Vec<Trade<T::AssetId>> {
+		let assets: Vec<T::AssetId> = Assets::<T>::iter_keys().collect();
+
+		let mut pairs = Vec::new();
+		for (idx, asset_a) in assets.iter().enumerate() {
+			pairs.push(Trade {
+				pool: PoolType::Omnipool,
+				asset_in: T::HubAssetId::get(),
+				asset_out: *asset_a,
+			});
+			for asset_b in assets.iter().skip(idx + 1) {
+				pairs.push(Trade {
+					pool: PoolType::Omnipool,
+					asset_in: *asset_a,
+					asset_out: *asset_b,
+				});
+			}
+		}
+		pairs
+	}
+}
@@ -0,0 +1,123 @@
+use super::*;
+use frame_support::assert_noop;
+use sp_runtime::BoundedVec;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, 1_000, 5000 * ONE))
+		.add_endowed_accounts((LP1, 2_000, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 2000 * ONE))
+		.add_endowed_accounts((LP2, 2_000, 2000 * ONE))
+		.add_endowed_accounts((LP1, 3_000, 5000 * ONE))
+		.with_registered_asset(2_000)
+		.with_asset_weight_cap(Permill::from_float(0.17))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+#[test]
+fn add_liquidity_batch_should_mint_position_for_each_asset() {
+	ext().execute_with(|| {
+		let first_position_id = <NextPositionId<Test>>::get();
+
+		// ACT
+		assert_ok!(Omnipool::add_liquidity_batch(
+			RuntimeOrigin::signed(LP1),
+			BoundedVec::truncate_from(vec![(1_000, 400 * ONE), (2_000, 200 * ONE)]),
+		));
+
+		// ASSERT
+		assert_eq!(
+			Positions::<Test>::get(first_position_id).unwrap(),
+			Position::<Balance, AssetId> {
+				asset_id: 1_000,
+				amount: 400 * ONE,
+				shares: 400 * ONE,
+				price: (1560 * ONE, 2400 * ONE),
+			}
+		);
+		assert_eq!(
+			Positions::<Test>::get(first_position_id + 1).unwrap(),
+			Position::<Balance, AssetId> {
+				asset_id: 2_000,
+				amount: 200 * ONE,
+				shares: 200 * ONE,
+				price: (1430 * ONE, 2200 * ONE),
+			}
+		);
+
+		assert_balance!(LP1, 1_000, 4600 * ONE);
+		assert_balance!(LP1, 2_000, 4800 * ONE);
+
+		assert_pool_state!(13_490 * ONE, 26_980 * ONE, SimpleImbalance::default());
+
+		let minted_positions = POSITIONS.with(|v| {
+			(
+				v.borrow().get(&first_position_id).copied(),
+				v.borrow().get(&(first_position_id + 1)).copied(),
+			)
+		});
+		assert_eq!(minted_positions, (Some(LP1), Some(LP1)));
+	});
+}
+
+#[test]
+fn add_liquidity_batch_should_check_weight_cap_against_final_state() {
+	ext().execute_with(|| {
+		// Adding liquidity of one asset exceeds its weight cap
+		assert_noop!(
+			Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 2000 * ONE),
+			Error::<Test>::AssetWeightCapExceeded
+		);
+
+		// ACT - weight of both assets is within the cap once both are added
+		assert_ok!(Omnipool::add_liquidity_batch(
+			RuntimeOrigin::signed(LP1),
+			BoundedVec::truncate_from(vec![(1_000, 2000 * ONE), (2_000, 2000 * ONE)]),
+		));
+
+		// ASSERT
+		assert_eq!(Assets::<Test>::get(1_000).unwrap().hub_reserve, 2600 * ONE);
+		assert_eq!(Assets::<Test>::get(2_000).unwrap().hub_reserve, 2600 * ONE);
+		assert_balance!(LP1, 1_000, 3000 * ONE);
+		assert_balance!(LP1, 2_000, 3000 * ONE);
+	});
+}
+
+#[test]
+fn add_liquidity_batch_should_fail_when_final_weight_exceeds_cap() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_batch(
+				RuntimeOrigin::signed(LP1),
+				BoundedVec::truncate_from(vec![(1_000, 3000 * ONE), (2_000, 3000 * ONE)]),
+			),
+			Error::<Test>::AssetWeightCapExceeded
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_batch_should_fail_when_batch_is_empty() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_batch(RuntimeOrigin::signed(LP1), BoundedVec::default()),
+			Error::<Test>::EmptyBatch
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_batch_should_fail_when_any_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_batch(
+				RuntimeOrigin::signed(LP1),
+				BoundedVec::truncate_from(vec![(1_000, 400 * ONE), (3_000, 200 * ONE)]),
+			),
+			Error::<Test>::AssetNotFound
+		);
+	});
+}
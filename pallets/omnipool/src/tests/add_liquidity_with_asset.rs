@@ -0,0 +1,117 @@
+use super::*;
+use frame_support::assert_noop;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, DAI, 5000 * ONE))
+		.add_endowed_accounts((LP2, 1_000, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+#[test]
+fn add_liquidity_with_asset_should_create_position_with_sold_amount() {
+	let amount_in = 100 * ONE;
+
+	// Reference - sell and add liquidity separately
+	let (expected_amount, expected_shares) = ext().execute_with(|| {
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), DAI, 1_000, amount_in, 0));
+		let amount = Tokens::free_balance(1_000, &LP1);
+
+		let position_id = last_position_id();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, amount));
+
+		(amount, Positions::<Test>::get(position_id).unwrap().shares)
+	});
+
+	ext().execute_with(|| {
+		// ACT
+		let position_id = last_position_id();
+		assert_ok!(Omnipool::add_liquidity_with_asset(
+			RuntimeOrigin::signed(LP1),
+			DAI,
+			amount_in,
+			1_000,
+			expected_amount,
+			expected_shares,
+			vec![],
+		));
+
+		// ASSERT
+		let position = Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(position.asset_id, 1_000);
+		assert_eq!(position.amount, expected_amount);
+		assert_eq!(position.shares, expected_shares);
+
+		assert_balance!(LP1, DAI, 4900 * ONE);
+		assert_balance!(LP1, 1_000, 0);
+
+		let minted_position = POSITIONS.with(|v| v.borrow().get(&position_id).copied());
+		assert_eq!(minted_position, Some(LP1));
+
+		assert_hub_asset!();
+	});
+}
+
+#[test]
+fn add_liquidity_with_asset_should_fail_when_shares_are_below_limit() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_with_asset(
+				RuntimeOrigin::signed(LP1),
+				DAI,
+				100 * ONE,
+				1_000,
+				0,
+				1_000 * ONE,
+				vec![],
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_with_asset_should_fail_when_sell_amount_out_is_below_limit() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_with_asset(
+				RuntimeOrigin::signed(LP1),
+				DAI,
+				100 * ONE,
+				1_000,
+				1_000 * ONE,
+				0,
+				vec![],
+			),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_with_asset_should_fail_when_assets_are_same() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::add_liquidity_with_asset(RuntimeOrigin::signed(LP1), DAI, 100 * ONE, DAI, 0, 0, vec![]),
+			Error::<Test>::SameAssetTradeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn add_liquidity_with_asset_should_fail_when_adding_liquidity_is_not_allowed() {
+	ext().execute_with(|| {
+		assert_ok!(Omnipool::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			1_000,
+			Tradability::SELL | Tradability::BUY
+		));
+
+		assert_noop!(
+			Omnipool::add_liquidity_with_asset(RuntimeOrigin::signed(LP1), DAI, 100 * ONE, 1_000, 0, 0, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
@@ -394,6 +394,49 @@ fn simple_buy_with_fee_works() {
 		});
 }
 
+#[test]
+fn buy_with_fee_discount_should_reduce_asset_and_protocol_fee() {
+	let buy = |asset_fee: Permill, protocol_fee: Permill, discount: Permill| -> Balance {
+		let mut sold_amount = 0;
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![
+				(Omnipool::protocol_account(), DAI, 1000 * ONE),
+				(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+				(LP2, 100, 2000 * ONE),
+				(LP3, 200, 2000 * ONE),
+				(LP1, 100, 1000 * ONE),
+			])
+			.with_registered_asset(100)
+			.with_registered_asset(200)
+			.with_asset_fee(asset_fee)
+			.with_protocol_fee(protocol_fee)
+			.with_fee_discount(discount)
+			.with_initial_pool(FixedU128::from(1), FixedU128::from(1))
+			.with_token(100, FixedU128::from(1), LP2, 2000 * ONE)
+			.with_token(200, FixedU128::from(1), LP3, 2000 * ONE)
+			.build()
+			.execute_with(|| {
+				assert_ok!(Omnipool::buy(RuntimeOrigin::signed(LP1), 200, 100, 50 * ONE, 100 * ONE));
+
+				assert_eq!(Tokens::free_balance(200, &LP1), 50 * ONE);
+
+				sold_amount = 1000 * ONE - Tokens::free_balance(100, &LP1);
+			});
+		sold_amount
+	};
+
+	let discounted = buy(
+		Permill::from_percent(10),
+		Permill::from_percent(2),
+		Permill::from_percent(50),
+	);
+	let expected = buy(Permill::from_percent(5), Permill::from_percent(1), Permill::zero());
+	let without_discount = buy(Permill::from_percent(10), Permill::from_percent(2), Permill::zero());
+
+	assert_eq!(discounted, expected);
+	assert!(discounted < without_discount);
+}
+
 #[test]
 fn buy_should_emit_event_with_correct_asset_fee_amount() {
 	ExtBuilder::default()
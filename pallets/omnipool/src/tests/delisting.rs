@@ -0,0 +1,598 @@
+use super::*;
+use crate::types::DelistingStage;
+use frame_support::assert_noop;
+use frame_support::traits::{OnIdle, OnInitialize};
+use frame_support::weights::Weight;
+use sp_runtime::DispatchError::BadOrigin;
+
+const BENEFICIARY: u64 = 1234;
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	Omnipool::on_initialize(n);
+}
+
+#[test]
+fn announce_delisting_should_allow_only_buy_and_remove_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+
+			assert_eq!(
+				Omnipool::assets(1_000).unwrap().tradable,
+				Tradability::BUY | Tradability::REMOVE_LIQUIDITY
+			);
+			assert_eq!(
+				Omnipool::delistings(1_000).unwrap(),
+				Delisting {
+					stage: DelistingStage::WindDown,
+					start: 1,
+					end: 11,
+					initial_cap: DEFAULT_WEIGHT_CAP,
+					beneficiary: BENEFICIARY,
+				}
+			);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::DelistingAnnounced {
+					asset_id: 1_000,
+					end: 11,
+					beneficiary: BENEFICIARY,
+				}
+				.into(),
+			);
+		});
+}
+
+#[test]
+fn announce_delisting_should_fail_when_called_by_non_authority_origin() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::announce_delisting(RuntimeOrigin::signed(LP1), 1_000, 10, BENEFICIARY),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn announce_delisting_should_fail_when_period_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::announce_delisting(RuntimeOrigin::root(), 1_000, 0, BENEFICIARY),
+				Error::<Test>::InvalidDelistingPeriod
+			);
+		});
+}
+
+#[test]
+fn announce_delisting_should_fail_when_asset_is_not_in_pool() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::announce_delisting(RuntimeOrigin::root(), 1_000, 10, BENEFICIARY),
+				Error::<Test>::AssetNotFound
+			);
+		});
+}
+
+#[test]
+fn announce_delisting_should_fail_when_already_announced() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+
+			assert_noop!(
+				Omnipool::announce_delisting(RuntimeOrigin::root(), 1_000, 10, BENEFICIARY),
+				Error::<Test>::DelistingAlreadyAnnounced
+			);
+		});
+}
+
+#[test]
+fn weight_cap_should_be_lowered_linearly_during_wind_down() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+
+			run_to_block(6);
+			assert_eq!(Omnipool::assets(1_000).unwrap().cap, DEFAULT_WEIGHT_CAP / 2);
+
+			run_to_block(9);
+			assert_eq!(Omnipool::assets(1_000).unwrap().cap, DEFAULT_WEIGHT_CAP / 5);
+		});
+}
+
+#[test]
+fn asset_should_be_frozen_when_wind_down_ends() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+
+			run_to_block(11);
+
+			let asset_state = Omnipool::assets(1_000).unwrap();
+			assert_eq!(asset_state.tradable, Tradability::FROZEN);
+			assert_eq!(asset_state.cap, 0);
+			assert_eq!(Omnipool::delistings(1_000).unwrap().stage, DelistingStage::Settlement);
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::DelistingSettlementStarted { asset_id: 1_000 }.into(),
+			);
+		});
+}
+
+#[test]
+fn cancel_delisting_should_restore_asset_state() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(6);
+
+			assert_ok!(Omnipool::cancel_delisting(RuntimeOrigin::root(), 1_000));
+
+			let asset_state = Omnipool::assets(1_000).unwrap();
+			assert_eq!(asset_state.tradable, Tradability::default());
+			assert_eq!(asset_state.cap, DEFAULT_WEIGHT_CAP);
+			assert!(Omnipool::delistings(1_000).is_none());
+
+			frame_system::Pallet::<Test>::assert_last_event(
+				crate::Event::DelistingCancelled { asset_id: 1_000 }.into(),
+			);
+		});
+}
+
+#[test]
+fn cancel_delisting_should_fail_when_settlement_started() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(11);
+
+			assert_noop!(
+				Omnipool::cancel_delisting(RuntimeOrigin::root(), 1_000),
+				Error::<Test>::DelistingNotCancellable
+			);
+		});
+}
+
+#[test]
+fn cancel_delisting_should_fail_when_asset_is_not_delisted() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+		])
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				Omnipool::cancel_delisting(RuntimeOrigin::root(), DAI),
+				Error::<Test>::DelistingNotFound
+			);
+		});
+}
+
+#[test]
+fn positions_should_be_settled_pro_rata_and_asset_removed_when_settlement_finishes() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp2_position_id = <NextPositionId<Test>>::get() - 1;
+
+			let sacrificed_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 400 * ONE));
+			assert_ok!(Omnipool::sacrifice_position(
+				RuntimeOrigin::signed(LP1),
+				sacrificed_position_id
+			));
+
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 600 * ONE));
+
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(11);
+
+			Omnipool::on_idle(11, Weight::MAX);
+
+			assert!(Positions::<Test>::get(lp2_position_id).is_none());
+			assert!(Positions::<Test>::get(lp1_position_id).is_none());
+			assert_balance!(LP2, 1_000, 2000 * ONE);
+			assert_balance!(LP1, 1_000, 4600 * ONE);
+
+			assert_asset_state!(
+				1_000,
+				AssetReserveState {
+					reserve: 400 * ONE,
+					hub_reserve: 260 * ONE,
+					shares: 400 * ONE,
+					protocol_shares: 400 * ONE,
+					cap: 0,
+					tradable: Tradability::FROZEN,
+				}
+			);
+
+			expect_events(vec![crate::Event::DelistedPositionSettled {
+				position_id: lp1_position_id,
+				owner: LP1,
+				asset_id: 1_000,
+				amount: 600 * ONE,
+				shares: 600 * ONE,
+			}
+			.into()]);
+
+			Omnipool::on_idle(12, Weight::MAX);
+
+			assert!(Omnipool::assets(1_000).is_none());
+			assert!(Omnipool::delistings(1_000).is_none());
+			assert_balance!(BENEFICIARY, 1_000, 400 * ONE);
+			assert_balance!(Omnipool::protocol_account(), 1_000, 0);
+
+			frame_system::Pallet::<Test>::assert_last_event(crate::Event::AssetDelisted { asset_id: 1_000 }.into());
+		});
+}
+
+#[test]
+fn positions_of_other_assets_should_not_be_settled() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP2, 2_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_registered_asset(2_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let other_position_id = <NextPositionId<Test>>::get() - 1;
+
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(11);
+
+			Omnipool::on_idle(11, Weight::MAX);
+
+			assert!(Positions::<Test>::get(other_position_id).is_some());
+			assert_eq!(Omnipool::assets(2_000).unwrap().shares, 2000 * ONE);
+		});
+}
+
+#[test]
+fn locked_positions_should_be_settled_once_returned_to_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 600 * ONE));
+
+			// Position is deposited to liquidity mining
+			POSITIONS.with(|v| v.borrow_mut().insert(lp1_position_id, LOCKED_POSITIONS_ACCOUNT));
+
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(11);
+
+			Omnipool::on_idle(11, Weight::MAX);
+			Omnipool::on_idle(12, Weight::MAX);
+
+			assert!(Positions::<Test>::get(lp1_position_id).is_some());
+			assert_balance!(LOCKED_POSITIONS_ACCOUNT, 1_000, 0);
+			assert_eq!(Omnipool::assets(1_000).unwrap().shares, 600 * ONE);
+			assert!(Omnipool::delistings(1_000).is_some());
+
+			// Position is withdrawn from liquidity mining
+			POSITIONS.with(|v| v.borrow_mut().insert(lp1_position_id, LP1));
+
+			Omnipool::on_idle(13, Weight::MAX);
+
+			assert!(Positions::<Test>::get(lp1_position_id).is_none());
+			assert_balance!(LP1, 1_000, 5000 * ONE);
+
+			expect_events(vec![crate::Event::DelistedPositionSettled {
+				position_id: lp1_position_id,
+				owner: LP1,
+				asset_id: 1_000,
+				amount: 600 * ONE,
+				shares: 600 * ONE,
+			}
+			.into()]);
+
+			Omnipool::on_idle(14, Weight::MAX);
+
+			assert!(Omnipool::assets(1_000).is_none());
+			assert!(Omnipool::delistings(1_000).is_none());
+		});
+}
+
+#[test]
+fn announce_delisting_should_fail_when_max_delistings_reached() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP2, 2_000, 2000 * ONE),
+			(LP2, 3_000, 2000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_registered_asset(2_000)
+		.with_registered_asset(3_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(3_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				2_000,
+				10,
+				BENEFICIARY
+			));
+
+			assert_noop!(
+				Omnipool::announce_delisting(RuntimeOrigin::root(), 3_000, 10, BENEFICIARY),
+				Error::<Test>::MaxDelistingsReached
+			);
+
+			assert_ok!(Omnipool::cancel_delisting(RuntimeOrigin::root(), 2_000));
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				3_000,
+				10,
+				BENEFICIARY
+			));
+		});
+}
+
+#[test]
+fn locked_position_should_be_force_settled_by_authority() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 600 * ONE));
+
+			// Position is deposited to liquidity mining and never returned
+			POSITIONS.with(|v| v.borrow_mut().insert(lp1_position_id, LOCKED_POSITIONS_ACCOUNT));
+
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+
+			assert_noop!(
+				Omnipool::force_settle_delisted_position(RuntimeOrigin::root(), lp1_position_id, LP1),
+				Error::<Test>::DelistingNotInSettlement
+			);
+
+			run_to_block(11);
+			Omnipool::on_idle(11, Weight::MAX);
+			Omnipool::on_idle(12, Weight::MAX);
+
+			assert!(Positions::<Test>::get(lp1_position_id).is_some());
+			assert!(Omnipool::delistings(1_000).is_some());
+
+			assert_noop!(
+				Omnipool::force_settle_delisted_position(RuntimeOrigin::signed(LP1), lp1_position_id, LP1),
+				BadOrigin
+			);
+
+			assert_ok!(Omnipool::force_settle_delisted_position(
+				RuntimeOrigin::root(),
+				lp1_position_id,
+				LP1
+			));
+
+			assert!(Positions::<Test>::get(lp1_position_id).is_none());
+			assert_balance!(LP1, 1_000, 5000 * ONE);
+			assert_balance!(LOCKED_POSITIONS_ACCOUNT, 1_000, 0);
+
+			expect_events(vec![crate::Event::DelistedPositionSettled {
+				position_id: lp1_position_id,
+				owner: LP1,
+				asset_id: 1_000,
+				amount: 600 * ONE,
+				shares: 600 * ONE,
+			}
+			.into()]);
+
+			Omnipool::on_idle(13, Weight::MAX);
+
+			assert!(Omnipool::assets(1_000).is_none());
+			assert!(Omnipool::delistings(1_000).is_none());
+		});
+}
+
+#[test]
+fn force_settle_delisted_position_should_fail_when_position_is_not_locked() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let lp1_position_id = <NextPositionId<Test>>::get();
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 600 * ONE));
+
+			assert_ok!(Omnipool::announce_delisting(
+				RuntimeOrigin::root(),
+				1_000,
+				10,
+				BENEFICIARY
+			));
+			run_to_block(11);
+
+			assert_noop!(
+				Omnipool::force_settle_delisted_position(RuntimeOrigin::root(), lp1_position_id, BENEFICIARY),
+				Error::<Test>::PositionNotLocked
+			);
+		});
+}
@@ -210,3 +210,130 @@ fn imbalance_should_approach_zero_when_enough_trades_are_executed() {
 			assert_eq!(HubAssetImbalance::<Test>::get(), SimpleImbalance::default());
 		});
 }
+
+#[test]
+fn set_imbalance_burn_rate_should_work_when_called_by_authority_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Omnipool::imbalance_burn_rate(), Permill::one());
+
+		assert_ok!(Omnipool::set_imbalance_burn_rate(
+			RuntimeOrigin::root(),
+			Permill::from_percent(50)
+		));
+
+		assert_eq!(Omnipool::imbalance_burn_rate(), Permill::from_percent(50));
+		frame_system::Pallet::<Test>::assert_last_event(
+			crate::Event::ImbalanceBurnRateUpdated {
+				rate: Permill::from_percent(50),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_imbalance_burn_rate_should_fail_when_called_by_non_authority_origin() {
+	ExtBuilder::default().build().execute_with(|| {
+		frame_support::assert_noop!(
+			Omnipool::set_imbalance_burn_rate(RuntimeOrigin::signed(LP1), Permill::from_percent(50)),
+			sp_runtime::DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn trade_should_burn_only_given_portion_of_protocol_fee_when_burn_rate_is_set() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, 100, 5000000000000000),
+			(LP1, 200, 5000000000000000),
+			(LP3, 200, 1000000000000000),
+			(LP3, 1, 100000000000000),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_protocol_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			// Selling hub asset to the pool creates the imbalance
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP3),
+				LRNA,
+				200,
+				50000000000000,
+				10000000000000
+			));
+
+			assert_ok!(Omnipool::set_imbalance_burn_rate(
+				RuntimeOrigin::root(),
+				Permill::from_percent(50)
+			));
+
+			let amount = 1000000000000;
+			let protocol_fee = Omnipool::quote_sell(200, 100, amount).unwrap().protocol_fee_amount;
+			assert!(protocol_fee > 0);
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+			let old_hdx_hub_reserve = Assets::<Test>::get(HDX).unwrap().hub_reserve;
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), 200, 100, amount, 1));
+
+			let burned = Permill::from_percent(50).mul_floor(protocol_fee);
+			assert_eq!(HubAssetImbalance::<Test>::get().value, old_imbalance.value - burned);
+			assert_eq!(
+				Assets::<Test>::get(HDX).unwrap().hub_reserve,
+				old_hdx_hub_reserve + protocol_fee - burned
+			);
+		});
+}
+
+#[test]
+fn trade_should_not_burn_imbalance_when_burn_rate_is_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP1, 100, 5000000000000000),
+			(LP1, 200, 5000000000000000),
+			(LP3, 200, 1000000000000000),
+			(LP3, 1, 100000000000000),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_protocol_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP1, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP3),
+				LRNA,
+				200,
+				50000000000000,
+				10000000000000
+			));
+
+			assert_ok!(Omnipool::set_imbalance_burn_rate(
+				RuntimeOrigin::root(),
+				Permill::zero()
+			));
+
+			let old_imbalance = HubAssetImbalance::<Test>::get();
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP3),
+				100,
+				200,
+				1000000000000,
+				u128::MAX
+			));
+
+			assert_eq!(HubAssetImbalance::<Test>::get(), old_imbalance);
+		});
+}
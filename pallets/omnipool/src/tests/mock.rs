@@ -25,13 +25,13 @@ use crate as pallet_omnipool;
 
 use crate::traits::ExternalPriceProvider;
 use frame_support::dispatch::Weight;
-use frame_support::traits::{ConstU128, Everything, GenesisBuild};
+use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
 };
 use frame_system::EnsureRoot;
-use hydradx_traits::{AssetKind, Registry};
+use hydradx_traits::{AssetKind, FeeDiscountProvider, Registry};
 use orml_traits::parameter_type_with_key;
 use primitive_types::{U128, U256};
 use sp_core::H256;
@@ -57,6 +57,8 @@ pub const LP1: u64 = 1;
 pub const LP2: u64 = 2;
 pub const LP3: u64 = 3;
 
+pub const LOCKED_POSITIONS_ACCOUNT: u64 = 99;
+
 pub const ONE: Balance = 1_000_000_000_000;
 
 pub const NATIVE_AMOUNT: Balance = 10_000 * ONE;
@@ -78,6 +80,7 @@ thread_local! {
 	pub static WITHDRAWAL_FEE: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
 	pub static WITHDRAWAL_ADJUSTMENT: RefCell<(u32,u32, bool)> = RefCell::new((0u32,0u32, false));
 	pub static ON_TRADE_WITHDRAWAL: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
+	pub static FEE_DISCOUNT: RefCell<Permill> = RefCell::new(Permill::from_percent(0));
 }
 
 construct_runtime!(
@@ -190,6 +193,7 @@ impl Config for Test {
 	type MinimumTradingLimit = MinTradeAmount;
 	type MinimumPoolLiquidity = MinAddedLiquidity;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
@@ -201,6 +205,60 @@ impl Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type RouteExecutor = OmnipoolRouter;
+	type AmmTradeWeights = ();
+	type FeeDiscount = FeeDiscountMock;
+	type LockedPositionOwners = LockedPositionOwnersMock;
+	type MaxDelistings = ConstU32<2>;
+}
+
+/// Router which executes trades directly in the Omnipool, ignoring the route.
+pub struct OmnipoolRouter;
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for OmnipoolRouter {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		Omnipool::sell(origin, asset_in, asset_out, amount_in, min_amount_out)
+	}
+
+	fn buy(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_out: Balance,
+		max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		Omnipool::buy(origin, asset_out, asset_in, amount_out, max_amount_in)
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: hydradx_traits::router::AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> frame_support::dispatch::DispatchResultWithPostInfo {
+		unimplemented!()
+	}
 }
 
 pub struct ExtBuilder {
@@ -261,6 +319,9 @@ impl Default for ExtBuilder {
 		WITHDRAWAL_ADJUSTMENT.with(|v| {
 			*v.borrow_mut() = (0, 0, false);
 		});
+		FEE_DISCOUNT.with(|v| {
+			*v.borrow_mut() = Permill::from_percent(0);
+		});
 
 		Self {
 			endowed_accounts: vec![
@@ -359,6 +420,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_fee_discount(self, discount: Permill) -> Self {
+		FEE_DISCOUNT.with(|v| *v.borrow_mut() = discount);
+		self
+	}
+
 	pub fn with_token(
 		mut self,
 		asset_id: AssetId,
@@ -657,6 +723,18 @@ impl GetByKey<AssetId, (Permill, Permill)> for FeeProvider {
 	}
 }
 
+pub struct FeeDiscountMock;
+
+impl FeeDiscountProvider<AccountId, AssetId> for FeeDiscountMock {
+	fn discount(_who: &AccountId, _asset_in: AssetId, _asset_out: AssetId) -> Permill {
+		FEE_DISCOUNT.with(|v| *v.borrow())
+	}
+
+	fn discount_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub(crate) fn expect_events(e: Vec<RuntimeEvent>) {
 	e.into_iter().for_each(frame_system::Pallet::<Test>::assert_has_event);
 }
@@ -705,3 +783,11 @@ impl OmnipoolHooks<RuntimeOrigin, AccountId, AssetId, Balance> for MockHooks {
 		Ok(to_take)
 	}
 }
+
+pub struct LockedPositionOwnersMock;
+
+impl Contains<AccountId> for LockedPositionOwnersMock {
+	fn contains(who: &AccountId) -> bool {
+		*who == LOCKED_POSITIONS_ACCOUNT
+	}
+}
@@ -1,19 +1,26 @@
-use crate::types::{Position, SimpleImbalance};
+use crate::types::{Delisting, Position, ProtocolLiquidityValuation, SimpleImbalance};
 use crate::*;
 use frame_support::assert_ok;
 use sp_runtime::FixedU128;
 
 mod add_liquidity;
+mod add_liquidity_batch;
+mod add_liquidity_with_asset;
 mod add_token;
 mod buy;
 mod invariants;
 mod remove_liquidity;
+mod remove_liquidity_to;
 mod sell;
 
 mod barrier;
+mod delisting;
 mod imbalance;
 pub(crate) mod mock;
 mod positions;
+mod positions_split_merge;
+mod protocol_liquidity;
+mod quote;
 mod refund;
 mod remove_token;
 mod tradability;
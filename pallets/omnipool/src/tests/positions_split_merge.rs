@@ -0,0 +1,378 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+
+const ASSET_ID: AssetId = 1_000;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.add_endowed_accounts((LP1, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP1, DAI, 5000 * ONE))
+		.add_endowed_accounts((LP2, ASSET_ID, 5000 * ONE))
+		.add_endowed_accounts((LP3, DAI, 5000 * ONE))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(ASSET_ID, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+}
+
+fn position_owner(position_id: u32) -> Option<u64> {
+	POSITIONS.with(|v| v.borrow().get(&position_id).copied())
+}
+
+#[test]
+fn split_position_should_move_shares_to_new_position_when_caller_is_owner() {
+	ext().execute_with(|| {
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let position = Positions::<Test>::get(position_id).unwrap();
+
+		// Act
+		let new_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::split_position(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			100 * ONE,
+			LP2
+		));
+
+		// Assert
+		assert_eq!(
+			Positions::<Test>::get(position_id).unwrap(),
+			Position {
+				asset_id: ASSET_ID,
+				amount: 300 * ONE,
+				shares: 300 * ONE,
+				price: position.price,
+			}
+		);
+		assert_eq!(
+			Positions::<Test>::get(new_position_id).unwrap(),
+			Position {
+				asset_id: ASSET_ID,
+				amount: 100 * ONE,
+				shares: 100 * ONE,
+				price: position.price,
+			}
+		);
+
+		assert_eq!(position_owner(position_id), Some(LP1));
+		assert_eq!(position_owner(new_position_id), Some(LP2));
+	});
+}
+
+#[test]
+fn split_position_should_not_change_total_value_of_position() {
+	ext().execute_with(|| {
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, ASSET_ID, 100 * ONE, 0));
+		let value = Omnipool::position_value(position_id).unwrap();
+
+		// Act
+		let new_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::split_position(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			150 * ONE,
+			LP1
+		));
+
+		// Assert
+		let remaining_value = Omnipool::position_value(position_id).unwrap();
+		let new_value = Omnipool::position_value(new_position_id).unwrap();
+
+		assert_eq_approx!(
+			remaining_value.amount + new_value.amount,
+			value.amount,
+			1,
+			"position amount"
+		);
+		assert_eq_approx!(
+			remaining_value.hub_amount + new_value.hub_amount,
+			value.hub_amount,
+			1,
+			"position hub amount"
+		);
+	});
+}
+
+#[test]
+fn split_position_should_emit_events_when_successful() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let price = Positions::<Test>::get(position_id)
+			.unwrap()
+			.price_from_rational()
+			.unwrap();
+
+		// Act
+		let new_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::split_position(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			100 * ONE,
+			LP2
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::PositionUpdated {
+				position_id,
+				owner: LP1,
+				asset: ASSET_ID,
+				amount: 300 * ONE,
+				shares: 300 * ONE,
+				price,
+			}
+			.into(),
+			Event::PositionCreated {
+				position_id: new_position_id,
+				owner: LP2,
+				asset: ASSET_ID,
+				amount: 100 * ONE,
+				shares: 100 * ONE,
+				price,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn split_position_should_fail_when_caller_is_not_position_owner() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+		assert_noop!(
+			Omnipool::split_position(RuntimeOrigin::signed(LP2), position_id, 100 * ONE, LP2),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_not_less_than_position_shares() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+		assert_noop!(
+			Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 400 * ONE, LP2),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
+
+#[test]
+fn split_position_should_fail_when_shares_are_zero() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+		assert_noop!(
+			Omnipool::split_position(RuntimeOrigin::signed(LP1), position_id, 0, LP2),
+			Error::<Test>::InvalidSharesAmount
+		);
+	});
+}
+
+#[test]
+fn merge_positions_should_keep_price_when_positions_have_same_price() {
+	ext().execute_with(|| {
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let price = Positions::<Test>::get(position_id).unwrap().price;
+
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::split_position(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			100 * ONE,
+			LP1
+		));
+
+		// Act
+		assert_ok!(Omnipool::merge_positions(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			other_position_id
+		));
+
+		// Assert
+		assert_eq!(
+			Positions::<Test>::get(position_id).unwrap(),
+			Position {
+				asset_id: ASSET_ID,
+				amount: 400 * ONE,
+				shares: 400 * ONE,
+				price,
+			}
+		);
+		assert_eq!(Positions::<Test>::get(other_position_id), None);
+		assert_eq!(position_owner(other_position_id), None);
+	});
+}
+
+#[test]
+fn merge_positions_should_weight_price_by_shares_when_positions_have_different_price() {
+	ext().execute_with(|| {
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, ASSET_ID, 100 * ONE, 0));
+
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 200 * ONE));
+
+		let position = Positions::<Test>::get(position_id).unwrap();
+		let other_position = Positions::<Test>::get(other_position_id).unwrap();
+
+		let expected_price = (position.price_from_rational().unwrap() * FixedU128::from_inner(position.shares)
+			+ other_position.price_from_rational().unwrap() * FixedU128::from_inner(other_position.shares))
+			/ FixedU128::from_inner(position.shares + other_position.shares);
+
+		// Act
+		assert_ok!(Omnipool::merge_positions(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			other_position_id
+		));
+
+		// Assert
+		let merged_position = Positions::<Test>::get(position_id).unwrap();
+		assert_eq!(merged_position.amount, position.amount + other_position.amount);
+		assert_eq!(merged_position.shares, position.shares + other_position.shares);
+		assert_eq_approx!(
+			merged_position.price_from_rational().unwrap(),
+			expected_price,
+			FixedU128::from_inner(1_000),
+			"merged price"
+		);
+
+		assert_eq!(Positions::<Test>::get(other_position_id), None);
+		assert_eq!(position_owner(other_position_id), None);
+		assert_eq!(position_owner(position_id), Some(LP1));
+	});
+}
+
+#[test]
+fn merge_positions_should_allow_to_remove_all_liquidity_of_merged_position() {
+	ext().execute_with(|| {
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, ASSET_ID, 100 * ONE, 0));
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 200 * ONE));
+
+		assert_ok!(Omnipool::merge_positions(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			other_position_id
+		));
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		// Act
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares
+		));
+
+		// Assert
+		assert_eq!(Positions::<Test>::get(position_id), None);
+		assert_hub_asset!();
+	});
+}
+
+#[test]
+fn merge_positions_should_emit_events_when_successful() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		// Arrange
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 200 * ONE));
+		let price = Positions::<Test>::get(position_id)
+			.unwrap()
+			.price_from_rational()
+			.unwrap();
+
+		// Act
+		assert_ok!(Omnipool::merge_positions(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			other_position_id
+		));
+
+		// Assert
+		expect_events(vec![
+			Event::PositionDestroyed {
+				position_id: other_position_id,
+				owner: LP1,
+			}
+			.into(),
+			Event::PositionUpdated {
+				position_id,
+				owner: LP1,
+				asset: ASSET_ID,
+				amount: 600 * ONE,
+				shares: 600 * ONE,
+				price,
+			}
+			.into(),
+		]);
+	});
+}
+
+#[test]
+fn merge_positions_should_fail_when_caller_is_not_owner_of_both_positions() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP2), ASSET_ID, 200 * ONE));
+
+		assert_noop!(
+			Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn merge_positions_should_fail_when_positions_are_of_different_assets() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+		let other_position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), DAI, 200 * ONE));
+
+		assert_noop!(
+			Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, other_position_id),
+			Error::<Test>::PositionAssetMismatch
+		);
+	});
+}
+
+#[test]
+fn merge_positions_should_fail_when_position_is_merged_with_itself() {
+	ext().execute_with(|| {
+		let position_id = <NextPositionId<Test>>::get();
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), ASSET_ID, 400 * ONE));
+
+		assert_noop!(
+			Omnipool::merge_positions(RuntimeOrigin::signed(LP1), position_id, position_id),
+			Error::<Test>::SamePositionMergeNotAllowed
+		);
+	});
+}
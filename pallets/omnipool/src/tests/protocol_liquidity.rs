@@ -0,0 +1,223 @@
+use super::*;
+use frame_support::assert_noop;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn sacrifice_liquidity(who: u64, asset: AssetId, amount: Balance) {
+	let position_id = <NextPositionId<Test>>::get();
+	assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(who), asset, amount));
+	assert_ok!(Omnipool::sacrifice_position(RuntimeOrigin::signed(who), position_id));
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_move_protocol_shares_to_another_asset() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP2, 2_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_registered_asset(2_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(2_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+			let next_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::rebalance_protocol_liquidity(
+				RuntimeOrigin::root(),
+				1_000,
+				200 * ONE,
+				2_000,
+				ONE,
+			));
+
+			let asset_from = Omnipool::load_asset_state(1_000).unwrap();
+			let asset_to = Omnipool::load_asset_state(2_000).unwrap();
+
+			assert_eq!(asset_from.protocol_shares, 200 * ONE);
+			assert_eq!(asset_from.shares, 2200 * ONE);
+			assert!(asset_to.protocol_shares > ONE);
+			assert_eq!(asset_to.shares, 2000 * ONE + asset_to.protocol_shares);
+
+			// Rebalanced liquidity is not left in the protocol liquidity account
+			assert_balance!(Omnipool::protocol_liquidity_account(), 1_000, 0);
+			assert_balance!(Omnipool::protocol_liquidity_account(), 2_000, 0);
+			assert_balance!(Omnipool::protocol_liquidity_account(), LRNA, 0);
+
+			// Position created during the rebalance is destroyed
+			assert!(Positions::<Test>::get(next_position_id).is_none());
+		});
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_fail_when_called_by_non_protocol_liquidity_origin() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_noop!(
+				Omnipool::rebalance_protocol_liquidity(RuntimeOrigin::signed(LP1), 1_000, 200 * ONE, DAI, 0),
+				BadOrigin
+			);
+		});
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_fail_when_assets_are_same() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_noop!(
+				Omnipool::rebalance_protocol_liquidity(RuntimeOrigin::root(), 1_000, 200 * ONE, 1_000, 0),
+				Error::<Test>::SameAssetRebalanceNotAllowed
+			);
+		});
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_fail_when_shares_are_zero() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_noop!(
+				Omnipool::rebalance_protocol_liquidity(RuntimeOrigin::root(), 1_000, 0, DAI, 0),
+				Error::<Test>::ZeroShares
+			);
+		});
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_fail_when_protocol_shares_are_insufficient() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_noop!(
+				Omnipool::rebalance_protocol_liquidity(RuntimeOrigin::root(), 1_000, 401 * ONE, DAI, 0),
+				Error::<Test>::InsufficientShares
+			);
+		});
+}
+
+#[test]
+fn rebalance_protocol_liquidity_should_fail_when_received_shares_are_below_limit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_noop!(
+				Omnipool::rebalance_protocol_liquidity(RuntimeOrigin::root(), 1_000, 200 * ONE, DAI, Balance::MAX),
+				Error::<Test>::SlippageLimit
+			);
+		});
+}
+
+#[test]
+fn protocol_liquidity_value_should_be_proportional_to_protocol_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_eq!(
+				Omnipool::protocol_liquidity_value(1_000).unwrap(),
+				ProtocolLiquidityValuation {
+					asset_id: 1_000,
+					shares: 400 * ONE,
+					amount: 400 * ONE,
+					hub_amount: 260 * ONE,
+				}
+			);
+		});
+}
+
+#[test]
+fn protocol_liquidity_should_return_only_assets_with_protocol_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert!(Omnipool::protocol_liquidity().is_empty());
+
+			sacrifice_liquidity(LP1, 1_000, 400 * ONE);
+
+			assert_eq!(
+				Omnipool::protocol_liquidity(),
+				vec![Omnipool::protocol_liquidity_value(1_000).unwrap()]
+			);
+		});
+}
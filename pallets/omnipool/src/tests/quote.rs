@@ -0,0 +1,204 @@
+use super::*;
+use frame_support::assert_noop;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+#[test]
+fn quote_sell_should_match_executed_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_percent(2))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let sell_amount = 50 * ONE;
+
+			let quote = Omnipool::quote_sell(100, 200, sell_amount).unwrap();
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 100, 200, sell_amount, 0));
+
+			expect_events(vec![Event::SellExecuted {
+				who: LP1,
+				asset_in: 100,
+				asset_out: 200,
+				amount_in: quote.amount_in,
+				amount_out: quote.amount_out,
+				hub_amount_in: quote.hub_amount_in,
+				hub_amount_out: quote.hub_amount_out,
+				asset_fee_amount: quote.asset_fee_amount,
+				protocol_fee_amount: quote.protocol_fee_amount,
+			}
+			.into()]);
+			assert_eq!(Tokens::free_balance(200, &LP1), quote.amount_out);
+		});
+}
+
+#[test]
+fn quote_buy_should_match_executed_buy() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(1))
+		.with_protocol_fee(Permill::from_percent(2))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let buy_amount = 50 * ONE;
+
+			let quote = Omnipool::quote_buy(100, 200, buy_amount).unwrap();
+
+			assert_ok!(Omnipool::buy(
+				RuntimeOrigin::signed(LP1),
+				200,
+				100,
+				buy_amount,
+				u128::MAX
+			));
+
+			expect_events(vec![Event::BuyExecuted {
+				who: LP1,
+				asset_in: 100,
+				asset_out: 200,
+				amount_in: quote.amount_in,
+				amount_out: quote.amount_out,
+				hub_amount_in: quote.hub_amount_in,
+				hub_amount_out: quote.hub_amount_out,
+				asset_fee_amount: quote.asset_fee_amount,
+				protocol_fee_amount: quote.protocol_fee_amount,
+			}
+			.into()]);
+			assert_eq!(Tokens::free_balance(100, &LP1), 1000 * ONE - quote.amount_in);
+		});
+}
+
+#[test]
+fn quote_sell_of_hub_asset_should_match_executed_sell() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP1, LRNA, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_asset_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let sell_amount = 50 * ONE;
+
+			let quote = Omnipool::quote_sell(LRNA, 100, sell_amount).unwrap();
+
+			assert_eq!(quote.amount_in, sell_amount);
+			assert_eq!(quote.hub_amount_in, 0);
+			assert_eq!(quote.hub_amount_out, 0);
+
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), LRNA, 100, sell_amount, 0));
+
+			assert_eq!(Tokens::free_balance(100, &LP1), quote.amount_out);
+		});
+}
+
+#[test]
+fn quote_should_fail_when_trade_is_not_allowed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(100, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::from_float(0.65), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Omnipool::set_asset_tradable_state(
+				RuntimeOrigin::root(),
+				200,
+				Tradability::SELL
+			));
+
+			assert_noop!(Omnipool::quote_sell(100, 200, 10 * ONE), Error::<Test>::NotAllowed);
+			assert_noop!(Omnipool::quote_buy(100, 200, 10 * ONE), Error::<Test>::NotAllowed);
+			assert_noop!(Omnipool::quote_sell(100, LRNA, 10 * ONE), Error::<Test>::NotAllowed);
+			assert_noop!(
+				Omnipool::quote_sell(100, 100, 10 * ONE),
+				Error::<Test>::SameAssetTradeNotAllowed
+			);
+		});
+}
+
+#[test]
+fn position_value_should_match_removed_liquidity() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, DAI, 500 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_min_withdrawal_fee(Permill::from_percent(1))
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let current_position_id = <NextPositionId<Test>>::get();
+
+			assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 500 * ONE));
+
+			// Increase the price so LP receives some hub asset
+			assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, 1_000, 100 * ONE, 0));
+
+			let position = Positions::<Test>::get(current_position_id).unwrap();
+			let value = Omnipool::position_value(current_position_id).unwrap();
+
+			assert_eq!(value.asset_id, 1_000);
+			assert_eq!(value.shares, position.shares);
+			assert_eq!(value.withdrawal_fee, FixedU128::from_rational(1, 100));
+			assert!(value.hub_amount > 0);
+
+			let balance_before = Tokens::free_balance(1_000, &LP1);
+
+			assert_ok!(Omnipool::remove_liquidity(
+				RuntimeOrigin::signed(LP1),
+				current_position_id,
+				position.shares
+			));
+
+			assert_eq!(Tokens::free_balance(1_000, &LP1), balance_before + value.amount);
+			assert_eq!(Tokens::free_balance(LRNA, &LP1), value.hub_amount);
+		});
+}
+
+#[test]
+fn position_value_should_fail_when_position_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(Omnipool::position_value(1), Error::<Test>::PositionNotFound);
+	});
+}
@@ -0,0 +1,148 @@
+use super::*;
+use frame_support::assert_noop;
+
+fn ext() -> sp_io::TestExternalities {
+	let mut ext = ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 1_000, 2000 * ONE),
+			(LP1, 1_000, 5000 * ONE),
+			(LP3, DAI, 500 * ONE),
+		])
+		.with_registered_asset(1_000)
+		.with_initial_pool(FixedU128::from_float(0.5), FixedU128::from(1))
+		.with_token(1_000, FixedU128::from_float(0.65), LP2, 2000 * ONE)
+		.build();
+
+	ext.execute_with(|| {
+		assert_ok!(Omnipool::add_liquidity(RuntimeOrigin::signed(LP1), 1_000, 500 * ONE));
+
+		// Increase the price so LP receives some hub asset
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP3), DAI, 1_000, 100 * ONE, 0));
+	});
+
+	ext
+}
+
+// Position of LP1 is the last minted position.
+fn lp1_position_id() -> u32 {
+	<NextPositionId<Test>>::get() - 1
+}
+
+#[test]
+fn remove_liquidity_to_should_sell_asset_and_hub_asset_for_asset_out() {
+	// Reference - remove liquidity and sell everything separately
+	let expected_amount_out = ext().execute_with(|| {
+		let position_id = lp1_position_id();
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+		let value = Omnipool::position_value(position_id).unwrap();
+
+		assert_ok!(Omnipool::remove_liquidity(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares
+		));
+		assert_ok!(Omnipool::sell(RuntimeOrigin::signed(LP1), 1_000, DAI, value.amount, 0));
+		assert_ok!(Omnipool::sell(
+			RuntimeOrigin::signed(LP1),
+			LRNA,
+			DAI,
+			value.hub_amount,
+			0
+		));
+
+		Tokens::free_balance(DAI, &LP1)
+	});
+
+	ext().execute_with(|| {
+		let position_id = lp1_position_id();
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		// ACT
+		assert_ok!(Omnipool::remove_liquidity_to(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares,
+			DAI,
+			expected_amount_out,
+			vec![],
+		));
+
+		// ASSERT
+		assert!(expected_amount_out > 0);
+		assert_balance!(LP1, DAI, expected_amount_out);
+		assert_balance!(LP1, LRNA, 0);
+		assert_balance!(LP1, 1_000, 4500 * ONE);
+		assert!(Positions::<Test>::get(position_id).is_none());
+
+		assert_hub_asset!();
+	});
+}
+
+#[test]
+fn remove_liquidity_to_should_sell_only_hub_asset_when_asset_out_is_position_asset() {
+	ext().execute_with(|| {
+		let position_id = lp1_position_id();
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+		let value = Omnipool::position_value(position_id).unwrap();
+		assert!(value.hub_amount > 0);
+
+		// ACT
+		assert_ok!(Omnipool::remove_liquidity_to(
+			RuntimeOrigin::signed(LP1),
+			position_id,
+			shares,
+			1_000,
+			value.amount,
+			vec![],
+		));
+
+		// ASSERT
+		assert!(Tokens::free_balance(1_000, &LP1) > 4500 * ONE + value.amount);
+		assert_balance!(LP1, LRNA, 0);
+	});
+}
+
+#[test]
+fn remove_liquidity_to_should_fail_when_amount_out_is_below_limit() {
+	ext().execute_with(|| {
+		let position_id = lp1_position_id();
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		assert_noop!(
+			Omnipool::remove_liquidity_to(
+				RuntimeOrigin::signed(LP1),
+				position_id,
+				shares,
+				DAI,
+				1_000 * ONE,
+				vec![],
+			),
+			Error::<Test>::BuyLimitNotReached
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_to_should_fail_when_asset_out_is_hub_asset() {
+	ext().execute_with(|| {
+		let position_id = lp1_position_id();
+		let shares = Positions::<Test>::get(position_id).unwrap().shares;
+
+		assert_noop!(
+			Omnipool::remove_liquidity_to(RuntimeOrigin::signed(LP1), position_id, shares, LRNA, 0, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_to_should_fail_when_position_does_not_exist() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Omnipool::remove_liquidity_to(RuntimeOrigin::signed(LP1), 100, ONE, DAI, 0, vec![]),
+			Error::<Test>::PositionNotFound
+		);
+	});
+}
@@ -419,6 +419,52 @@ fn simple_sell_with_fee_works() {
 		});
 }
 
+#[test]
+fn sell_with_fee_discount_should_reduce_asset_fee() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(Omnipool::protocol_account(), DAI, 1000 * ONE),
+			(Omnipool::protocol_account(), HDX, NATIVE_AMOUNT),
+			(LP2, 100, 2000 * ONE),
+			(LP3, 200, 2000 * ONE),
+			(LP1, 100, 1000 * ONE),
+		])
+		.with_registered_asset(100)
+		.with_registered_asset(200)
+		.with_asset_fee(Permill::from_percent(10))
+		.with_fee_discount(Permill::from_percent(50))
+		.with_initial_pool(FixedU128::from(1), FixedU128::from(1))
+		.with_token(100, FixedU128::one(), LP2, 2000 * ONE)
+		.with_token(200, FixedU128::one(), LP3, 2000 * ONE)
+		.build()
+		.execute_with(|| {
+			let sell_amount = 50 * ONE;
+			let min_limit = 10 * ONE;
+
+			// 50% discount of 10% fee
+			let fee = Permill::from_percent(5);
+			let fee = Permill::from_percent(100).checked_sub(&fee).unwrap();
+
+			let expected_zero_fee = 47_619_047_619_047u128;
+			let expected_5_percent_fee = fee.mul_floor(expected_zero_fee);
+
+			assert_ok!(Omnipool::sell(
+				RuntimeOrigin::signed(LP1),
+				100,
+				200,
+				sell_amount,
+				min_limit
+			));
+
+			assert_eq!(Tokens::free_balance(100, &LP1), 950_000_000_000_000);
+			assert_eq!(Tokens::free_balance(200, &LP1), expected_5_percent_fee);
+			assert_eq!(
+				Tokens::free_balance(200, &Omnipool::protocol_account()),
+				2000000000000000 - expected_5_percent_fee,
+			);
+		});
+}
+
 #[test]
 fn sell_hub_asset_should_fail_when_asset_out_is_not_allowed_to_buy() {
 	ExtBuilder::default()
@@ -2,9 +2,14 @@ use super::*;
 use codec::MaxEncodedLen;
 use frame_support::pallet_prelude::*;
 use hydra_dx_math::omnipool::types::{AssetReserveState as MathReserveState, AssetStateChange, BalanceUpdate};
-use sp_runtime::{FixedPointNumber, FixedU128};
+use primitive_types::U256;
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
+use sp_runtime::{FixedPointNumber, FixedU128, Rounding};
 use sp_std::ops::{Add, Sub};
 
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
 /// Balance type used in Omnipool
 pub type Balance = u128;
 
@@ -13,6 +18,7 @@ pub type Price = FixedU128;
 
 bitflags::bitflags! {
 	/// Indicates whether asset can be bought or sold to/from Omnipool and/or liquidity added/removed.
+	#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 	#[derive(Encode,Decode, MaxEncodedLen, TypeInfo)]
 	pub struct Tradability: u8 {
 		/// Asset is frozen. No operations are allowed.
@@ -135,8 +141,77 @@ where
 	}
 }
 
+impl<AssetId> Position<Balance, AssetId> {
+	/// Split given amount of shares off the position.
+	///
+	/// Amount is split proportionally to shares, price remains the same for both positions.
+	/// Returns `(remaining position, new position)`.
+	pub(super) fn split(self, shares: Balance) -> Option<(Self, Self)>
+	where
+		AssetId: Copy,
+	{
+		if shares.is_zero() || shares >= self.shares {
+			return None;
+		}
+
+		let amount = multiply_by_rational_with_rounding(self.amount, shares, self.shares, Rounding::Down)?;
+
+		let new_position = Self {
+			asset_id: self.asset_id,
+			amount,
+			shares,
+			price: self.price,
+		};
+
+		let remaining = Self {
+			asset_id: self.asset_id,
+			amount: self.amount.checked_sub(amount)?,
+			shares: self.shares.checked_sub(shares)?,
+			price: self.price,
+		};
+
+		Some((remaining, new_position))
+	}
+
+	/// Merge other position of the same asset into this one.
+	///
+	/// Amounts and shares are summed, price of merged position is the average of both prices weighted by shares.
+	pub(super) fn merge(self, other: Self) -> Option<Self>
+	where
+		AssetId: PartialEq,
+	{
+		if self.asset_id != other.asset_id {
+			return None;
+		}
+
+		let shares = self.shares.checked_add(other.shares)?;
+
+		let price = if self.price == other.price {
+			self.price
+		} else {
+			let price = FixedU128::checked_from_rational(self.price.0, self.price.1)?;
+			let other_price = FixedU128::checked_from_rational(other.price.0, other.price.1)?;
+
+			let weighted_price = U256::from(price.into_inner())
+				.checked_mul(U256::from(self.shares))?
+				.checked_add(U256::from(other_price.into_inner()).checked_mul(U256::from(other.shares))?)?
+				.checked_div(U256::from(shares))?;
+
+			(Balance::try_from(weighted_price).ok()?, FixedU128::DIV)
+		};
+
+		Some(Self {
+			asset_id: self.asset_id,
+			amount: self.amount.checked_add(other.amount)?,
+			shares,
+			price,
+		})
+	}
+}
+
 /// Simple type to represent imbalance which can be positive or negative.
 // Note: Simple prefix is used not to confuse with Imbalance trait from frame_support.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
 pub struct SimpleImbalance<Balance> {
 	pub value: Balance,
@@ -212,7 +287,8 @@ impl<Balance: CheckedAdd + CheckedSub + PartialOrd + Copy> Sub<Balance> for Simp
 }
 
 /// Asset state representation including asset pool reserve.
-#[derive(Clone, Default, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Debug, PartialEq, Eq, Encode, Decode, TypeInfo)]
 pub struct AssetReserveState<Balance> {
 	/// Quantity of asset in omnipool
 	pub reserve: Balance,
@@ -317,3 +393,77 @@ where
 		})
 	}
 }
+
+/// Result of a simulated sell or buy trade.
+///
+/// Amounts are calculated with the same fees and state as the trade would be executed with at the time of the query.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct TradeQuote<Balance> {
+	/// Amount of asset in sold to the pool
+	pub amount_in: Balance,
+	/// Amount of asset out bought from the pool
+	pub amount_out: Balance,
+	/// Amount of hub asset which asset in's hub reserve is decreased by
+	pub hub_amount_in: Balance,
+	/// Amount of hub asset which asset out's hub reserve is increased by
+	pub hub_amount_out: Balance,
+	/// Asset fee amount in asset out
+	pub asset_fee_amount: Balance,
+	/// Protocol fee amount in hub asset
+	pub protocol_fee_amount: Balance,
+}
+
+/// Valuation of a position if all its shares were removed at current state of the pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PositionValuation<AssetId, Balance> {
+	/// Position's asset
+	pub asset_id: AssetId,
+	/// Quantity of LP shares owned by LP
+	pub shares: Balance,
+	/// Amount of asset which would be received
+	pub amount: Balance,
+	/// Amount of hub asset which would be received
+	pub hub_amount: Balance,
+	/// Withdrawal fee which would be applied
+	pub withdrawal_fee: FixedU128,
+}
+
+/// Stage of asset delisting.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub enum DelistingStage {
+	/// Asset can only be bought and its liquidity removed. Weight cap is lowered toward zero.
+	WindDown,
+	/// Asset is frozen and remaining positions are settled pro-rata.
+	Settlement,
+}
+
+/// Delisting of an asset from Omnipool.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct Delisting<AccountId, BlockNumber> {
+	/// Current stage of delisting
+	pub stage: DelistingStage,
+	/// Block in which delisting was announced
+	pub start: BlockNumber,
+	/// Block in which wind-down ends and settlement of positions starts
+	pub end: BlockNumber,
+	/// Weight cap of the asset when delisting was announced
+	pub initial_cap: u128,
+	/// Account which receives protocol owned liquidity when asset is removed
+	pub beneficiary: AccountId,
+}
+
+/// Value of protocol owned liquidity of an asset at current state of the pool.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ProtocolLiquidityValuation<AssetId, Balance> {
+	/// Asset of protocol owned liquidity
+	pub asset_id: AssetId,
+	/// Quantity of shares owned by protocol
+	pub shares: Balance,
+	/// Amount of asset owned by protocol
+	pub amount: Balance,
+	/// Amount of hub asset reserve owned by protocol
+	pub hub_amount: Balance,
+}
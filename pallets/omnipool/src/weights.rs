@@ -61,6 +61,13 @@ pub trait WeightInfo {
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 	fn withdraw_protocol_liquidity() -> Weight;
 	fn remove_token() -> Weight;
+	fn split_position() -> Weight;
+	fn merge_positions() -> Weight;
+	fn announce_delisting() -> Weight;
+	fn cancel_delisting() -> Weight;
+	fn update_delisting() -> Weight;
+	fn settle_delisted_position() -> Weight;
+	fn set_imbalance_burn_rate() -> Weight;
 }
 
 /// Weights for pallet_omnipool using the hydraDX node and recommended hardware.
@@ -400,6 +407,34 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	fn withdraw_protocol_liquidity() -> Weight {
 		Weight::zero()
 	}
+
+	fn split_position() -> Weight {
+		Weight::zero()
+	}
+
+	fn merge_positions() -> Weight {
+		Weight::zero()
+	}
+
+	fn announce_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn cancel_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn update_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn settle_delisted_position() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_imbalance_burn_rate() -> Weight {
+		Weight::zero()
+	}
 }
 
 // For backwards compatibility and tests
@@ -736,4 +771,32 @@ impl WeightInfo for () {
 	fn withdraw_protocol_liquidity() -> Weight {
 		Weight::zero()
 	}
+
+	fn split_position() -> Weight {
+		Weight::zero()
+	}
+
+	fn merge_positions() -> Weight {
+		Weight::zero()
+	}
+
+	fn announce_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn cancel_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn update_delisting() -> Weight {
+		Weight::zero()
+	}
+
+	fn settle_delisted_position() -> Weight {
+		Weight::zero()
+	}
+
+	fn set_imbalance_burn_rate() -> Weight {
+		Weight::zero()
+	}
 }
@@ -0,0 +1,47 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Runtime API definition for the route executor pallet.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::Codec;
+use sp_std::vec::Vec;
+
+pub use pallet_route_executor::{AmountInAndOut, RouteQuote, Trade};
+
+sp_api::decl_runtime_apis! {
+	/// The API to discover the best routes between two assets across all AMM pools.
+	pub trait RouterApi<AssetId, Balance> where
+		AssetId: Codec,
+		Balance: Codec,
+	{
+		/// Returns routes to sell `amount_in` of `asset_in` for `asset_out`, ordered by the received amount.
+		fn find_sell_routes(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Vec<RouteQuote<AssetId, Balance>>;
+
+		/// Returns routes to buy `amount_out` of `asset_out` for `asset_in`, ordered by the spent amount.
+		fn find_buy_routes(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Vec<RouteQuote<AssetId, Balance>>;
+	}
+}
@@ -17,7 +17,7 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-use codec::MaxEncodedLen;
+use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::storage::with_transaction;
 use frame_support::traits::fungibles::Mutate;
 use frame_support::PalletId;
@@ -32,11 +32,14 @@ use frame_system::pallet_prelude::OriginFor;
 use frame_system::{ensure_signed, Origin};
 use hydradx_traits::router::{inverse_route, AssetPair, RouteProvider};
 pub use hydradx_traits::router::{
-	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolType, RouterT, Trade, TradeExecution,
+	AmmTradeWeights, AmountInAndOut, ExecutorError, PoolAssetPairs, PoolType, RouterT, Trade, TradeExecution,
 };
 use orml_traits::arithmetic::{CheckedAdd, CheckedSub};
-use sp_runtime::traits::{AccountIdConversion, CheckedDiv};
-use sp_runtime::{ArithmeticError, DispatchError, TransactionOutcome};
+use scale_info::TypeInfo;
+use sp_runtime::traits::{AccountIdConversion, CheckedDiv, Saturating, Zero};
+use sp_runtime::{ArithmeticError, DispatchError, Permill, TransactionOutcome};
+use sp_std::cmp::Ordering;
+use sp_std::collections::btree_map::BTreeMap;
 use sp_std::{vec, vec::Vec};
 
 #[cfg(test)]
@@ -49,6 +52,35 @@ pub use pallet::*;
 
 pub const MAX_NUMBER_OF_TRADES: u32 = 5;
 
+/// Max number of trades in a route found by the route discovery.
+pub const MAX_NUMBER_OF_DISCOVERED_TRADES: usize = 3;
+
+/// Max number of routes found by the route discovery, the on-chain route not included.
+pub const MAX_NUMBER_OF_DISCOVERED_ROUTES: usize = 16;
+
+/// Max number of trades explored by the route discovery.
+pub const MAX_NUMBER_OF_EXPLORED_TRADES: usize = 1_000;
+
+/// Max number of the most connected assets which can be used as intermediate assets of discovered routes.
+pub const MAX_NUMBER_OF_HUB_ASSETS: usize = 8;
+
+/// Max number of routes in a split-route trade.
+pub const MAX_NUMBER_OF_SPLIT_ROUTES: u32 = 4;
+
+/// A route of a split-route trade, with the share of the total amount traded through it.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq, TypeInfo)]
+pub struct SplitRoute<AssetId> {
+	pub weight: Permill,
+	pub route: Vec<Trade<AssetId>>,
+}
+
+/// Route together with the calculated amounts of its trades, in the order of the trades.
+#[derive(Encode, Decode, Clone, Debug, Eq, PartialEq, TypeInfo)]
+pub struct RouteQuote<AssetId, Balance> {
+	pub route: Vec<Trade<AssetId>>,
+	pub amounts: Vec<AmountInAndOut<Balance>>,
+}
+
 //TODO: rebenchmark on reference machine
 
 #[frame_support::pallet]
@@ -79,7 +111,8 @@ pub mod pallet {
 			+ Default
 			+ CheckedSub
 			+ CheckedAdd
-			+ CheckedDiv;
+			+ CheckedDiv
+			+ AtLeast32BitUnsigned;
 
 		/// Native Asset Id
 		#[pallet::constant]
@@ -98,6 +131,9 @@ pub mod pallet {
 			Error = DispatchError,
 		>;
 
+		/// Provider of the tradable asset pairs of the AMM pools, used for route discovery
+		type AssetPairs: PoolAssetPairs<Self::AssetId>;
+
 		/// Weight information for the extrinsics.
 		type WeightInfo: AmmTradeWeights<Trade<Self::AssetId>>;
 	}
@@ -114,6 +150,13 @@ pub mod pallet {
 		},
 		///The route with trades has been successfully executed
 		RouteUpdated { asset_ids: Vec<T::AssetId> },
+		///All routes of a split-route trade have been successfully executed
+		SplitRouteExecuted {
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			amount_out: T::Balance,
+		},
 	}
 
 	#[pallet::error]
@@ -136,6 +179,10 @@ pub mod pallet {
 		InvalidRoute,
 		///The route update was not successful
 		RouteUpdateIsNotSuccessful,
+		///The max number of routes in a split-route trade is reached
+		MaxSplitRoutesExceeded,
+		///The weights of split routes are zero or don't sum up to 100%
+		InvalidSplitRouteWeights,
 	}
 
 	/// Storing routes for asset pairs
@@ -393,6 +440,132 @@ pub mod pallet {
 
 			Err(Error::<T>::RouteUpdateIsNotSuccessful.into())
 		}
+
+		/// Executes a sell split across several routes of the same asset pair.
+		///
+		/// Each route sells its weighted share of `amount_in`, the rounding remainder is sold in the last route.
+		/// All routes are executed atomically and the limit applies to the total received amount.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to sell
+		/// - `asset_out`: The identifier of the asset to receive
+		/// - `amount_in`: The total amount of `asset_in` to sell
+		/// - `min_amount_out`: The minimum total amount of `asset_out` to receive.
+		/// - `routes`: Routes with weights summing up to 100%. Empty route means the on-chain or default route.
+		///
+		/// Emits `RouteExecuted` for each route and `SplitRouteExecuted` when successful.
+		#[pallet::call_index(3)]
+		#[pallet::weight(split_route_weight::<T>(routes, T::WeightInfo::sell_weight))]
+		#[transactional]
+		pub fn sell_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: T::Balance,
+			min_amount_out: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::ensure_split_routes(&routes, asset_in, asset_out)?;
+
+			let user_balance_of_asset_out_before_trade = T::Currency::reducible_balance(asset_out, &who, false);
+
+			for (split_route, amount) in routes.iter().zip(Self::split_amount(&routes, amount_in)?) {
+				if amount.is_zero() {
+					continue;
+				}
+				Self::sell(
+					origin.clone(),
+					asset_in,
+					asset_out,
+					amount,
+					T::Balance::zero(),
+					split_route.route.clone(),
+				)?;
+			}
+
+			let amount_out = T::Currency::reducible_balance(asset_out, &who, false)
+				.checked_sub(&user_balance_of_asset_out_before_trade)
+				.ok_or(Error::<T>::InvalidRouteExecution)?;
+			ensure!(amount_out >= min_amount_out, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitRouteExecuted {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+
+		/// Executes a buy split across several routes of the same asset pair.
+		///
+		/// Each route buys its weighted share of `amount_out`, the rounding remainder is bought in the last route.
+		/// All routes are executed atomically and the limit applies to the total spent amount.
+		///
+		/// - `origin`: The executor of the trade
+		/// - `asset_in`: The identifier of the asset to be swapped to buy `asset_out`
+		/// - `asset_out`: The identifier of the asset to buy
+		/// - `amount_out`: The total amount of `asset_out` to buy
+		/// - `max_amount_in`: The max total amount of `asset_in` to spend on the buy.
+		/// - `routes`: Routes with weights summing up to 100%. Empty route means the on-chain or default route.
+		///
+		/// Emits `RouteExecuted` for each route and `SplitRouteExecuted` when successful.
+		#[pallet::call_index(4)]
+		#[pallet::weight(split_route_weight::<T>(routes, T::WeightInfo::buy_weight))]
+		#[transactional]
+		pub fn buy_split(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_out: T::Balance,
+			max_amount_in: T::Balance,
+			routes: Vec<SplitRoute<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin.clone())?;
+			Self::ensure_split_routes(&routes, asset_in, asset_out)?;
+
+			let user_balance_of_asset_in_before_trade = T::Currency::reducible_balance(asset_in, &who, true);
+
+			for (split_route, amount) in routes.iter().zip(Self::split_amount(&routes, amount_out)?) {
+				if amount.is_zero() {
+					continue;
+				}
+				Self::buy(
+					origin.clone(),
+					asset_in,
+					asset_out,
+					amount,
+					max_amount_in,
+					split_route.route.clone(),
+				)?;
+			}
+
+			let amount_in = user_balance_of_asset_in_before_trade
+				.checked_sub(&T::Currency::reducible_balance(asset_in, &who, true))
+				.ok_or(Error::<T>::InvalidRouteExecution)?;
+			ensure!(amount_in <= max_amount_in, Error::<T>::TradingLimitReached);
+
+			Self::deposit_event(Event::SplitRouteExecuted {
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+			});
+
+			Ok(())
+		}
+	}
+
+	/// Sums up the weights of all routes of a split-route trade.
+	pub(crate) fn split_route_weight<T: Config>(
+		routes: &[SplitRoute<T::AssetId>],
+		route_weight: fn(&[Trade<T::AssetId>]) -> Weight,
+	) -> Weight {
+		routes.iter().fold(Weight::zero(), |weight, split_route| {
+			weight.saturating_add(route_weight(&split_route.route))
+		})
 	}
 }
 
@@ -447,6 +620,54 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	fn ensure_split_routes(
+		routes: &[SplitRoute<T::AssetId>],
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+	) -> Result<(), DispatchError> {
+		ensure!(!routes.is_empty(), Error::<T>::RouteHasNoTrades);
+		ensure!(
+			(routes.len() as u32) <= MAX_NUMBER_OF_SPLIT_ROUTES,
+			Error::<T>::MaxSplitRoutesExceeded
+		);
+
+		let mut total_weight = 0u32;
+		for split_route in routes.iter() {
+			ensure!(!split_route.weight.is_zero(), Error::<T>::InvalidSplitRouteWeights);
+			total_weight = total_weight.saturating_add(split_route.weight.deconstruct());
+
+			Self::ensure_route_size(split_route.route.len())?;
+			if let (Some(first_trade), Some(last_trade)) = (split_route.route.first(), split_route.route.last()) {
+				ensure!(first_trade.asset_in == asset_in, Error::<T>::InvalidRoute);
+				ensure!(last_trade.asset_out == asset_out, Error::<T>::InvalidRoute);
+			}
+		}
+		ensure!(
+			total_weight == Permill::one().deconstruct(),
+			Error::<T>::InvalidSplitRouteWeights
+		);
+
+		Ok(())
+	}
+
+	/// Splits the amount by the route weights. The rounding remainder is added to the last route.
+	fn split_amount(routes: &[SplitRoute<T::AssetId>], amount: T::Balance) -> Result<Vec<T::Balance>, DispatchError> {
+		let mut amounts: Vec<T::Balance> = routes
+			.iter()
+			.map(|split_route| split_route.weight.mul_floor(amount))
+			.collect();
+
+		let distributed = amounts
+			.iter()
+			.fold(T::Balance::zero(), |acc, amount| acc.saturating_add(*amount));
+		let remainder = amount.checked_sub(&distributed).ok_or(ArithmeticError::Underflow)?;
+		if let Some(last_amount) = amounts.last_mut() {
+			*last_amount = last_amount.checked_add(&remainder).ok_or(ArithmeticError::Overflow)?;
+		}
+
+		Ok(amounts)
+	}
+
 	fn get_route_or_default(
 		route: Vec<Trade<T::AssetId>>,
 		asset_pair: AssetPair<T::AssetId>,
@@ -477,6 +698,7 @@ impl<T: Config> Pallet<T> {
 			PoolType::Stableswap(pool_id) => pool_id,
 			PoolType::XYK => first_route.asset_out,
 			PoolType::LBP => first_route.asset_out,
+			PoolType::ConcentratedLiquidity => first_route.asset_out,
 		};
 
 		let asset_in_liquidity = T::AMM::get_liquidity_depth(first_route.pool, first_route.asset_in, asset_b);
@@ -567,6 +789,162 @@ impl<T: Config> Pallet<T> {
 		Ok(amount_in_and_outs)
 	}
 
+	/// Finds routes to sell `amount_in` of `asset_in` for `asset_out`.
+	///
+	/// Routes are built from the asset pairs of all AMM pools, up to `MAX_NUMBER_OF_DISCOVERED_TRADES` trades.
+	/// Only the `MAX_NUMBER_OF_HUB_ASSETS` assets with the most pools are used as intermediate assets,
+	/// and the search stops after `MAX_NUMBER_OF_EXPLORED_TRADES` trades or `MAX_NUMBER_OF_DISCOVERED_ROUTES` routes.
+	/// The on-chain route of the asset pair is always considered as well.
+	/// Routes which can't be calculated are skipped.
+	///
+	/// Returns routes ordered by the received amount of `asset_out`, the best one first.
+	pub fn find_sell_routes(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_in: T::Balance,
+	) -> Vec<RouteQuote<T::AssetId, T::Balance>> {
+		let mut quotes: Vec<RouteQuote<T::AssetId, T::Balance>> = Self::discover_routes(asset_in, asset_out)
+			.into_iter()
+			.filter_map(|route| {
+				let amounts = Self::calculate_sell_trade_amounts(&route, amount_in).ok()?;
+				Some(RouteQuote { route, amounts })
+			})
+			.collect();
+
+		quotes.sort_by(|a, b| {
+			let amount_out_a = a.amounts.last().map(|amount| amount.amount_out);
+			let amount_out_b = b.amounts.last().map(|amount| amount.amount_out);
+			amount_out_b.partial_cmp(&amount_out_a).unwrap_or(Ordering::Equal)
+		});
+
+		quotes
+	}
+
+	/// Finds routes to buy `amount_out` of `asset_out` for `asset_in`.
+	///
+	/// Routes are discovered the same way as in `find_sell_routes`.
+	///
+	/// Returns routes ordered by the spent amount of `asset_in`, the best one first.
+	pub fn find_buy_routes(
+		asset_in: T::AssetId,
+		asset_out: T::AssetId,
+		amount_out: T::Balance,
+	) -> Vec<RouteQuote<T::AssetId, T::Balance>> {
+		let mut quotes: Vec<RouteQuote<T::AssetId, T::Balance>> = Self::discover_routes(asset_in, asset_out)
+			.into_iter()
+			.filter_map(|route| {
+				let mut amounts = Self::calculate_buy_trade_amounts(&route, amount_out).ok()?;
+				amounts.reverse();
+				Some(RouteQuote { route, amounts })
+			})
+			.collect();
+
+		quotes.sort_by(|a, b| {
+			let amount_in_a = a.amounts.first().map(|amount| amount.amount_in);
+			let amount_in_b = b.amounts.first().map(|amount| amount.amount_in);
+			amount_in_a.partial_cmp(&amount_in_b).unwrap_or(Ordering::Equal)
+		});
+
+		quotes
+	}
+
+	fn discover_routes(asset_in: T::AssetId, asset_out: T::AssetId) -> Vec<Vec<Trade<T::AssetId>>> {
+		if asset_in == asset_out {
+			return Vec::new();
+		}
+
+		let mut trades: BTreeMap<T::AssetId, Vec<Trade<T::AssetId>>> = BTreeMap::new();
+		for pair in T::AssetPairs::asset_pairs() {
+			trades.entry(pair.asset_in).or_default().push(pair);
+			trades.entry(pair.asset_out).or_default().push(Trade {
+				pool: pair.pool,
+				asset_in: pair.asset_out,
+				asset_out: pair.asset_in,
+			});
+		}
+
+		let mut assets_by_pools: Vec<(T::AssetId, usize)> = trades
+			.iter()
+			.map(|(asset, asset_trades)| (*asset, asset_trades.len()))
+			.collect();
+		assets_by_pools.sort_by(|a, b| b.1.cmp(&a.1));
+		let hub_assets: Vec<T::AssetId> = assets_by_pools
+			.into_iter()
+			.take(MAX_NUMBER_OF_HUB_ASSETS)
+			.map(|(asset, _)| asset)
+			.collect();
+
+		let mut routes = Vec::new();
+		let mut visited = vec![asset_in];
+		let mut path = Vec::new();
+		let mut remaining_trades = MAX_NUMBER_OF_EXPLORED_TRADES;
+		Self::extend_route(
+			&trades,
+			&hub_assets,
+			asset_out,
+			&mut visited,
+			&mut path,
+			&mut routes,
+			&mut remaining_trades,
+		);
+
+		let onchain_route = <Pallet<T> as RouteProvider<T::AssetId>>::get_route(AssetPair::new(asset_in, asset_out));
+		if !routes.contains(&onchain_route) {
+			routes.push(onchain_route);
+		}
+
+		routes
+	}
+
+	/// Depth-first search of routes ending in `asset_out`.
+	/// Assets are not visited twice and consecutive trades in the same multi-asset pool are skipped,
+	/// as those are covered by a direct trade in the pool. Only hub assets are used as intermediate assets.
+	fn extend_route(
+		trades: &BTreeMap<T::AssetId, Vec<Trade<T::AssetId>>>,
+		hub_assets: &[T::AssetId],
+		asset_out: T::AssetId,
+		visited: &mut Vec<T::AssetId>,
+		path: &mut Vec<Trade<T::AssetId>>,
+		routes: &mut Vec<Vec<Trade<T::AssetId>>>,
+		remaining_trades: &mut usize,
+	) {
+		if path.len() >= MAX_NUMBER_OF_DISCOVERED_TRADES {
+			return;
+		}
+
+		let Some(current_asset) = visited.last().copied() else {
+			return;
+		};
+
+		for trade in trades.get(&current_asset).into_iter().flatten() {
+			if *remaining_trades == 0 || routes.len() >= MAX_NUMBER_OF_DISCOVERED_ROUTES {
+				return;
+			}
+			*remaining_trades -= 1;
+
+			if visited.contains(&trade.asset_out) {
+				continue;
+			}
+
+			let is_same_pool = path.last().map_or(false, |last_trade| {
+				last_trade.pool == trade.pool && matches!(trade.pool, PoolType::Omnipool | PoolType::Stableswap(_))
+			});
+			if is_same_pool {
+				continue;
+			}
+
+			path.push(*trade);
+			if trade.asset_out == asset_out {
+				routes.push(path.clone());
+			} else if hub_assets.contains(&trade.asset_out) {
+				visited.push(trade.asset_out);
+				Self::extend_route(trades, hub_assets, asset_out, visited, path, routes, remaining_trades);
+				visited.pop();
+			}
+			path.pop();
+		}
+	}
+
 	fn insert_route(asset_pair: AssetPair<T::AssetId>, route: Vec<Trade<T::AssetId>>) -> DispatchResultWithPostInfo {
 		let route_as_bounded_vec: BoundedVec<Trade<T::AssetId>, sp_runtime::traits::ConstU32<MAX_NUMBER_OF_TRADES>> =
 			route.try_into().map_err(|_| Error::<T>::MaxTradesExceeded)?;
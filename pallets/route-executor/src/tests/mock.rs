@@ -22,7 +22,7 @@ use frame_support::traits::{Everything, GenesisBuild, Nothing};
 use frame_system as system;
 use frame_system::ensure_signed;
 use frame_system::pallet_prelude::OriginFor;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, TradeExecution};
 use orml_traits::parameter_type_with_key;
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_currencies::BasicCurrencyAdapter;
@@ -150,6 +150,7 @@ impl Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type AssetPairs = MockedAssetPairs;
 	type WeightInfo = ();
 }
 
@@ -197,6 +198,7 @@ pub const HDX_AUSD_TRADE_IN_XYK: Trade<AssetId> = Trade {
 
 pub struct ExtBuilder {
 	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	asset_pairs: Vec<Trade<AssetId>>,
 }
 
 // Returns default values for genesis config
@@ -204,6 +206,7 @@ impl Default for ExtBuilder {
 	fn default() -> Self {
 		Self {
 			endowed_accounts: vec![(ALICE, HDX, 1000u128)],
+			asset_pairs: vec![],
 		}
 	}
 }
@@ -214,6 +217,11 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_asset_pairs(mut self, asset_pairs: Vec<Trade<AssetId>>) -> Self {
+		self.asset_pairs = asset_pairs;
+		self
+	}
+
 	pub fn build(self) -> sp_io::TestExternalities {
 		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
 
@@ -244,6 +252,8 @@ impl ExtBuilder {
 		.assimilate_storage(&mut t)
 		.unwrap();
 
+		ASSET_PAIRS.with(|v| *v.borrow_mut() = self.asset_pairs);
+
 		let mut ext = sp_io::TestExternalities::new(t);
 		ext.execute_with(|| System::set_block_number(1));
 		ext
@@ -254,6 +264,15 @@ type ExecutedTradeInfo = (PoolType<AssetId>, Balance, AssetId, AssetId);
 thread_local! {
 	pub static EXECUTED_SELLS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
 	pub static EXECUTED_BUYS: RefCell<Vec<ExecutedTradeInfo>> = RefCell::new(Vec::default());
+	pub static ASSET_PAIRS: RefCell<Vec<Trade<AssetId>>> = RefCell::new(Vec::default());
+}
+
+pub struct MockedAssetPairs;
+
+impl PoolAssetPairs<AssetId> for MockedAssetPairs {
+	fn asset_pairs() -> Vec<Trade<AssetId>> {
+		ASSET_PAIRS.with(|v| v.borrow().clone())
+	}
 }
 
 type OriginForRuntime = OriginFor<Test>;
@@ -1,4 +1,6 @@
 pub mod buy;
 pub mod mock;
+pub mod route_discovery;
 pub mod sell;
 pub mod set_route;
+pub mod split_route;
@@ -0,0 +1,260 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{AmountInAndOut, RouteQuote, Trade, MAX_NUMBER_OF_DISCOVERED_ROUTES};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+
+const AMOUNT: Balance = 10;
+
+fn trade(pool: PoolType<AssetId>, asset_in: AssetId, asset_out: AssetId) -> Trade<AssetId> {
+	Trade {
+		pool,
+		asset_in,
+		asset_out,
+	}
+}
+
+#[test]
+fn find_sell_routes_should_return_routes_ordered_by_amount_out() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![
+			trade(PoolType::XYK, HDX, AUSD),
+			trade(PoolType::LBP, HDX, AUSD),
+			trade(PoolType::XYK, HDX, DOT),
+			trade(PoolType::Stableswap(STABLE_SHARE_ASSET), DOT, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			assert_eq!(
+				quotes,
+				vec![
+					RouteQuote {
+						route: vec![trade(PoolType::XYK, HDX, AUSD)],
+						amounts: vec![AmountInAndOut {
+							amount_in: AMOUNT,
+							amount_out: XYK_SELL_CALCULATION_RESULT,
+						}],
+					},
+					RouteQuote {
+						route: vec![trade(PoolType::LBP, HDX, AUSD)],
+						amounts: vec![AmountInAndOut {
+							amount_in: AMOUNT,
+							amount_out: LBP_SELL_CALCULATION_RESULT,
+						}],
+					},
+					RouteQuote {
+						route: default_omnipool_route(),
+						amounts: vec![AmountInAndOut {
+							amount_in: AMOUNT,
+							amount_out: OMNIPOOL_SELL_CALCULATION_RESULT,
+						}],
+					},
+					RouteQuote {
+						route: vec![
+							trade(PoolType::XYK, HDX, DOT),
+							trade(PoolType::Stableswap(STABLE_SHARE_ASSET), DOT, AUSD),
+						],
+						amounts: vec![
+							AmountInAndOut {
+								amount_in: AMOUNT,
+								amount_out: XYK_SELL_CALCULATION_RESULT,
+							},
+							AmountInAndOut {
+								amount_in: XYK_SELL_CALCULATION_RESULT,
+								amount_out: STABLESWAP_SELL_CALCULATION_RESULT,
+							},
+						],
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn find_buy_routes_should_return_routes_ordered_by_amount_in() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![
+			trade(PoolType::XYK, HDX, AUSD),
+			trade(PoolType::LBP, HDX, AUSD),
+			trade(PoolType::Stableswap(STABLE_SHARE_ASSET), HDX, DOT),
+			trade(PoolType::XYK, DOT, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_buy_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			assert_eq!(
+				quotes,
+				vec![
+					RouteQuote {
+						route: vec![trade(PoolType::XYK, HDX, AUSD)],
+						amounts: vec![AmountInAndOut {
+							amount_in: XYK_BUY_CALCULATION_RESULT,
+							amount_out: AMOUNT,
+						}],
+					},
+					RouteQuote {
+						route: vec![
+							trade(PoolType::Stableswap(STABLE_SHARE_ASSET), HDX, DOT),
+							trade(PoolType::XYK, DOT, AUSD),
+						],
+						amounts: vec![
+							AmountInAndOut {
+								amount_in: STABLESWAP_BUY_CALCULATION_RESULT,
+								amount_out: XYK_BUY_CALCULATION_RESULT,
+							},
+							AmountInAndOut {
+								amount_in: XYK_BUY_CALCULATION_RESULT,
+								amount_out: AMOUNT,
+							},
+						],
+					},
+					RouteQuote {
+						route: default_omnipool_route(),
+						amounts: vec![AmountInAndOut {
+							amount_in: OMNIPOOL_BUY_CALCULATION_RESULT,
+							amount_out: AMOUNT,
+						}],
+					},
+					RouteQuote {
+						route: vec![trade(PoolType::LBP, HDX, AUSD)],
+						amounts: vec![AmountInAndOut {
+							amount_in: LBP_BUY_CALCULATION_RESULT,
+							amount_out: AMOUNT,
+						}],
+					},
+				]
+			);
+		});
+}
+
+#[test]
+fn find_sell_routes_should_use_pairs_in_both_directions() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![trade(PoolType::XYK, AUSD, HDX)])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			let routes: Vec<Vec<Trade<AssetId>>> = quotes.into_iter().map(|quote| quote.route).collect();
+			assert_eq!(
+				routes,
+				vec![vec![trade(PoolType::XYK, HDX, AUSD)], default_omnipool_route()]
+			);
+		});
+}
+
+#[test]
+fn find_sell_routes_should_skip_consecutive_trades_in_same_omnipool() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![
+			trade(PoolType::Omnipool, HDX, DOT),
+			trade(PoolType::Omnipool, DOT, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			let routes: Vec<Vec<Trade<AssetId>>> = quotes.into_iter().map(|quote| quote.route).collect();
+			assert_eq!(routes, vec![default_omnipool_route()]);
+		});
+}
+
+#[test]
+fn find_sell_routes_should_not_return_routes_exceeding_max_number_of_trades() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![
+			trade(PoolType::XYK, HDX, DOT),
+			trade(PoolType::XYK, DOT, KSM),
+			trade(PoolType::XYK, KSM, MOVR),
+			trade(PoolType::XYK, MOVR, AUSD),
+		])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			let routes: Vec<Vec<Trade<AssetId>>> = quotes.into_iter().map(|quote| quote.route).collect();
+			assert_eq!(routes, vec![default_omnipool_route()]);
+		});
+}
+
+#[test]
+fn find_sell_routes_should_skip_routes_when_calculation_fails() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![trade(PoolType::XYK, HDX, AUSD)])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, INVALID_CALCULATION_AMOUNT);
+
+			//Assert
+			assert!(quotes.is_empty());
+		});
+}
+
+#[test]
+fn find_sell_routes_should_return_empty_when_assets_are_same() {
+	ExtBuilder::default()
+		.with_asset_pairs(vec![trade(PoolType::XYK, HDX, AUSD)])
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, HDX, AMOUNT);
+
+			//Assert
+			assert!(quotes.is_empty());
+		});
+}
+
+#[test]
+fn find_sell_routes_should_limit_number_of_routes_when_there_are_many_pools() {
+	let assets: Vec<AssetId> = vec![HDX, AUSD].into_iter().chain(2_000..2_040).collect();
+	let mut asset_pairs = Vec::new();
+	for (i, asset_a) in assets.iter().enumerate() {
+		for asset_b in assets.iter().skip(i + 1) {
+			asset_pairs.push(trade(PoolType::XYK, *asset_a, *asset_b));
+		}
+	}
+
+	ExtBuilder::default()
+		.with_asset_pairs(asset_pairs)
+		.build()
+		.execute_with(|| {
+			//Act
+			let quotes = Router::find_sell_routes(HDX, AUSD, AMOUNT);
+
+			//Assert
+			let routes: Vec<Vec<Trade<AssetId>>> = quotes.into_iter().map(|quote| quote.route).collect();
+			assert_eq!(routes.len(), MAX_NUMBER_OF_DISCOVERED_ROUTES + 1);
+			assert!(routes.contains(&vec![trade(PoolType::XYK, HDX, AUSD)]));
+			assert!(routes.contains(&default_omnipool_route()));
+		});
+}
@@ -0,0 +1,269 @@
+// This file is part of HydraDX.
+
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::tests::mock::*;
+use crate::{Error, Event, SplitRoute, Trade};
+use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::PoolType;
+use pretty_assertions::assert_eq;
+use sp_runtime::Permill;
+
+fn split_route(percent: u32, pool: PoolType<AssetId>) -> SplitRoute<AssetId> {
+	SplitRoute {
+		weight: Permill::from_percent(percent),
+		route: vec![Trade {
+			pool,
+			asset_in: HDX,
+			asset_out: AUSD,
+		}],
+	}
+}
+
+#[test]
+fn sell_split_should_work_when_routes_are_weighted() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_sell = 10;
+		let limit = 23;
+
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_sell,
+			limit,
+			vec![split_route(60, PoolType::XYK), split_route(40, PoolType::Omnipool)]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 6, HDX, AUSD), (PoolType::Omnipool, 4, HDX, AUSD)]);
+		expect_events(vec![Event::SplitRouteExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: amount_to_sell,
+			amount_out: XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_split_should_sell_rounding_remainder_in_last_route() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			11,
+			0,
+			vec![split_route(50, PoolType::XYK), split_route(50, PoolType::LBP)]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::LBP, 6, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_use_default_route_when_route_is_empty() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act
+		assert_ok!(Router::sell_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			10,
+			0,
+			vec![
+				split_route(50, PoolType::XYK),
+				SplitRoute {
+					weight: Permill::from_percent(50),
+					route: vec![],
+				}
+			]
+		));
+
+		//Assert
+		assert_executed_sell_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::Omnipool, 5, HDX, AUSD)]);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_total_amount_out_is_below_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_SELL_CALCULATION_RESULT + OMNIPOOL_SELL_CALCULATION_RESULT + 1,
+				vec![split_route(60, PoolType::XYK), split_route(40, PoolType::Omnipool)]
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_weights_do_not_sum_up_to_one_hundred_percent() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				0,
+				vec![split_route(60, PoolType::XYK), split_route(30, PoolType::Omnipool)]
+			),
+			Error::<Test>::InvalidSplitRouteWeights
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_has_zero_weight() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				0,
+				vec![split_route(100, PoolType::XYK), split_route(0, PoolType::Omnipool)]
+			),
+			Error::<Test>::InvalidSplitRouteWeights
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_max_number_of_routes_is_exceeded() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				0,
+				vec![
+					split_route(20, PoolType::XYK),
+					split_route(20, PoolType::LBP),
+					split_route(20, PoolType::Omnipool),
+					split_route(20, PoolType::XYK),
+					split_route(20, PoolType::LBP),
+				]
+			),
+			Error::<Test>::MaxSplitRoutesExceeded
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_no_routes_are_specified() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(RuntimeOrigin::signed(ALICE), HDX, AUSD, 10, 0, vec![]),
+			Error::<Test>::RouteHasNoTrades
+		);
+	});
+}
+
+#[test]
+fn sell_split_should_fail_when_route_is_for_different_asset_pair() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let other_pair_route = SplitRoute {
+			weight: Permill::from_percent(50),
+			route: vec![Trade {
+				pool: PoolType::XYK,
+				asset_in: HDX,
+				asset_out: DOT,
+			}],
+		};
+
+		//Act and Assert
+		assert_noop!(
+			Router::sell_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				0,
+				vec![split_route(50, PoolType::XYK), other_pair_route]
+			),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn buy_split_should_work_when_routes_are_weighted() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Arrange
+		let amount_to_buy = 10;
+		let limit = 9;
+
+		//Act
+		assert_ok!(Router::buy_split(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			AUSD,
+			amount_to_buy,
+			limit,
+			vec![split_route(50, PoolType::XYK), split_route(50, PoolType::LBP)]
+		));
+
+		//Assert
+		assert_executed_buy_trades(vec![(PoolType::XYK, 5, HDX, AUSD), (PoolType::LBP, 5, HDX, AUSD)]);
+		expect_events(vec![Event::SplitRouteExecuted {
+			asset_in: HDX,
+			asset_out: AUSD,
+			amount_in: XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT,
+			amount_out: amount_to_buy,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn buy_split_should_fail_when_total_amount_in_is_above_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		//Act and Assert
+		assert_noop!(
+			Router::buy_split(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				AUSD,
+				10,
+				XYK_BUY_CALCULATION_RESULT + LBP_BUY_CALCULATION_RESULT - 1,
+				vec![split_route(50, PoolType::XYK), split_route(50, PoolType::LBP)]
+			),
+			Error::<Test>::TradingLimitReached
+		);
+	});
+}
@@ -0,0 +1,449 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use crate::*;
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, OnFinalize, OnInitialize};
+use frame_system::{Pallet as System, RawOrigin};
+use orml_traits::MultiCurrencyExtended;
+use pallet_stableswap::types::{AssetAmount, BenchmarkHelper};
+use primitives::AssetId;
+use sp_runtime::{traits::One, FixedU128, Permill};
+use sp_std::vec::Vec;
+
+const ONE: Balance = 1_000_000_000_000;
+const HDX: AssetId = 0;
+const ASSET_ID_OFFSET: u32 = 2_000;
+const POOL_ID: AssetId = 1_000;
+
+const G_FARM_TOTAL_REWARDS: Balance = 10_000_000 * ONE;
+const REWARD_CURRENCY: AssetId = HDX;
+
+fn fund<T: Config>(to: T::AccountId, currency: T::AssetId, amount: Balance) -> DispatchResult {
+	CurrencyOf::<T>::deposit(currency, &to, amount)
+}
+
+const SEED: u32 = 0;
+fn create_funded_account<T: Config>(
+	name: &'static str,
+	index: u32,
+	amount: Balance,
+	currency: T::AssetId,
+) -> T::AccountId
+where
+	T::AssetId: From<u32>,
+{
+	let caller: T::AccountId = account(name, index, SEED);
+
+	fund::<T>(caller.clone(), currency, amount).unwrap();
+
+	caller
+}
+
+fn initialize_global_farm<T: Config>(owner: T::AccountId, pool_id: AssetId) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	Pallet::<T>::create_global_farm(
+		RawOrigin::Root.into(),
+		pool_id.into(),
+		G_FARM_TOTAL_REWARDS,
+		T::BlockNumber::from(100_000_u32),
+		T::BlockNumber::from(1_u32),
+		REWARD_CURRENCY.into(),
+		owner,
+		Perquintill::from_percent(20),
+		1_000,
+		FixedU128::one(),
+	)
+}
+
+fn initialize_yield_farm<T: Config>(owner: T::AccountId, id: GlobalFarmId, pool_id: AssetId) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	Pallet::<T>::create_yield_farm(
+		RawOrigin::Signed(owner).into(),
+		id,
+		pool_id.into(),
+		FixedU128::one(),
+		None,
+	)
+}
+
+fn initialize_pool<T: Config>(pool_id: AssetId, asset_offset: u32) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+	CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let provider: T::AccountId = account("provider", asset_offset, SEED);
+	let initial_liquidity = 1_000_000 * ONE;
+
+	let mut asset_ids: Vec<T::AssetId> = Vec::new();
+	let mut initial: Vec<AssetAmount<T::AssetId>> = Vec::new();
+	for idx in 0..2 {
+		let asset_id: T::AssetId = (asset_offset + idx).into();
+		<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(asset_id, 12)?;
+		CurrencyOf::<T>::update_balance(asset_id, &provider, initial_liquidity as i128)?;
+		asset_ids.push(asset_id);
+		initial.push(AssetAmount::new(asset_id, initial_liquidity));
+	}
+
+	<T as pallet_stableswap::Config>::BenchmarkHelper::register_asset(pool_id.into(), 18)?;
+
+	let successful_origin = <T as pallet_stableswap::Config>::AuthorityOrigin::try_successful_origin().unwrap();
+	StableswapPallet::<T>::create_pool(
+		successful_origin,
+		pool_id.into(),
+		asset_ids,
+		100u16,
+		Permill::from_percent(1),
+	)?;
+
+	StableswapPallet::<T>::add_liquidity(RawOrigin::Signed(provider).into(), pool_id.into(), initial)
+}
+
+/// Provide liquidity to the pool by `lp` and return amount of received shares.
+fn stableswap_add_liquidity<T: Config>(lp: T::AccountId, pool_id: AssetId) -> Result<Balance, DispatchError>
+where
+	T::AssetId: From<u32>,
+	CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+{
+	let pool = StableswapPallet::<T>::pools(T::AssetId::from(pool_id)).ok_or(Error::<T>::PoolNotFound)?;
+
+	let amount = 1_000 * ONE;
+	let mut assets: Vec<AssetAmount<T::AssetId>> = Vec::new();
+	for asset_id in pool.assets.iter() {
+		CurrencyOf::<T>::update_balance(*asset_id, &lp, amount as i128)?;
+		assets.push(AssetAmount::new(*asset_id, amount));
+	}
+
+	StableswapPallet::<T>::add_liquidity(RawOrigin::Signed(lp.clone()).into(), pool_id.into(), assets)?;
+
+	Ok(CurrencyOf::<T>::free_balance(pool_id.into(), &lp))
+}
+
+fn lm_deposit_shares<T: Config>(
+	who: T::AccountId,
+	g_id: GlobalFarmId,
+	y_id: YieldFarmId,
+	pool_id: AssetId,
+	shares: Balance,
+) -> DispatchResult
+where
+	T::AssetId: From<u32>,
+{
+	crate::Pallet::<T>::deposit_shares(RawOrigin::Signed(who).into(), g_id, y_id, pool_id.into(), shares)
+}
+
+fn set_period<T: Config>(to: u32) {
+	//NOTE: predefined global farm has period size = 1 block.
+
+	while System::<T>::block_number() < to.into() {
+		let b = System::<T>::block_number();
+
+		System::<T>::on_finalize(b);
+		System::<T>::on_initialize(b + 1_u32.into());
+		System::<T>::set_block_number(b + 1_u32.into());
+	}
+}
+
+benchmarks! {
+	where_clause { where
+		T::AssetId: From<u32>,
+		CurrencyOf<T>: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T: crate::pallet::Config,
+	}
+
+	create_global_farm {
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		let planned_yielding_periods = T::BlockNumber::from(100_000_u32);
+		let blocks_per_period = T::BlockNumber::from(100_u32);
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let yield_per_period = Perquintill::from_percent(20);
+		let min_deposit = 1_000;
+
+	}: _(RawOrigin::Root, POOL_ID.into(), G_FARM_TOTAL_REWARDS, planned_yielding_periods, blocks_per_period, REWARD_CURRENCY.into(), owner, yield_per_period, min_deposit, FixedU128::one())
+
+	terminate_global_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		crate::Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_ID.into())?;
+		crate::Pallet::<T>::terminate_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, yield_farm_id, POOL_ID.into())?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id)
+
+	create_yield_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		set_period::<T>(100);
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		crate::Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_ID.into())?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_ID.into(), FixedU128::one(), Some(LoyaltyCurve::default()))
+
+	update_yield_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_ID.into(), FixedU128::from(2_u128))
+
+	stop_yield_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(owner), global_farm_id, POOL_ID.into())
+
+	resume_yield_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		set_period::<T>(200);
+
+		crate::Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_ID.into())?;
+
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(owner), global_farm_id, yield_farm_id, POOL_ID.into(), FixedU128::from(2))
+
+	terminate_yield_farm {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner.clone(), global_farm_id, POOL_ID)?;
+
+		let lp = account("lp_1", 1, SEED);
+		let shares = stableswap_add_liquidity::<T>(lp.clone(), POOL_ID)?;
+
+		lm_deposit_shares::<T>(lp, global_farm_id, yield_farm_id, POOL_ID, shares)?;
+
+		set_period::<T>(200);
+
+		crate::Pallet::<T>::stop_yield_farm(RawOrigin::Signed(owner.clone()).into(), global_farm_id, POOL_ID.into())?;
+
+		set_period::<T>(300);
+	}: _(RawOrigin::Signed(owner), global_farm_id, yield_farm_id, POOL_ID.into())
+
+	deposit_shares {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner, global_farm_id, POOL_ID)?;
+
+		let lp1 = account("lp_1", 1, SEED);
+		let lp1_shares = stableswap_add_liquidity::<T>(lp1.clone(), POOL_ID)?;
+
+		lm_deposit_shares::<T>(lp1, global_farm_id, yield_farm_id, POOL_ID, lp1_shares)?;
+
+		let lp2: T::AccountId = account("lp_2", 2, SEED);
+		let lp2_shares = stableswap_add_liquidity::<T>(lp2.clone(), POOL_ID)?;
+		set_period::<T>(200);
+	}: _(RawOrigin::Signed(lp2), global_farm_id, yield_farm_id, POOL_ID.into(), lp2_shares)
+
+	redeposit_shares {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner2 = create_funded_account::<T>("owner2", 1, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner3 = create_funded_account::<T>("owner3", 2, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner4 = create_funded_account::<T>("owner4", 3, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner5 = create_funded_account::<T>("owner5", 4, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+
+		let deposit_id = 1;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		//gId: 1, yId: 2
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner, 1, POOL_ID)?;
+
+		//gId: 3, yId: 4
+		initialize_global_farm::<T>(owner2.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner2, 3, POOL_ID)?;
+
+		//gId: 5, yId: 6
+		initialize_global_farm::<T>(owner3.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner3, 5, POOL_ID)?;
+
+		//gId: 7, yId: 8
+		initialize_global_farm::<T>(owner4.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner4, 7, POOL_ID)?;
+
+		//gId: 9, yId: 10
+		initialize_global_farm::<T>(owner5.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner5, 9, POOL_ID)?;
+
+		let lp1: T::AccountId = account("lp_1", 5, SEED);
+		let lp1_shares = stableswap_add_liquidity::<T>(lp1.clone(), POOL_ID)?;
+
+		let lp2: T::AccountId = account("lp_2", 6, SEED);
+		let lp2_shares = stableswap_add_liquidity::<T>(lp2.clone(), POOL_ID)?;
+
+		set_period::<T>(200);
+
+		lm_deposit_shares::<T>(lp1.clone(), 1, 2, POOL_ID, lp1_shares)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 3, 4, deposit_id)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 5, 6, deposit_id)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 7, 8, deposit_id)?;
+
+		//Deposit into the global-farm so it will be updated
+		lm_deposit_shares::<T>(lp2, 9, 10, POOL_ID, lp2_shares)?;
+
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(lp1), 9, 10, deposit_id)
+
+	claim_rewards {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner2 = create_funded_account::<T>("owner2", 1, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner3 = create_funded_account::<T>("owner3", 2, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner4 = create_funded_account::<T>("owner4", 3, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+		let owner5 = create_funded_account::<T>("owner5", 4, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+
+		let deposit_id = 1;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		//gId: 1, yId: 2
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner, 1, POOL_ID)?;
+
+		//gId: 3, yId: 4
+		initialize_global_farm::<T>(owner2.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner2, 3, POOL_ID)?;
+
+		//gId: 5, yId: 6
+		initialize_global_farm::<T>(owner3.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner3, 5, POOL_ID)?;
+
+		//gId: 7, yId: 8
+		initialize_global_farm::<T>(owner4.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner4, 7, POOL_ID)?;
+
+		//gId: 9, yId: 10
+		initialize_global_farm::<T>(owner5.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner5, 9, POOL_ID)?;
+
+		let lp1: T::AccountId = account("lp_1", 5, SEED);
+		let lp1_shares = stableswap_add_liquidity::<T>(lp1.clone(), POOL_ID)?;
+
+		//NOTE: This is necessary because paid rewards are lower than ED.
+		fund::<T>(lp1.clone(), REWARD_CURRENCY.into(), 100 * ONE)?;
+
+		set_period::<T>(200);
+
+		lm_deposit_shares::<T>(lp1.clone(), 1, 2, POOL_ID, lp1_shares)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 3, 4, deposit_id)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 5, 6, deposit_id)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 7, 8, deposit_id)?;
+		crate::Pallet::<T>::redeposit_shares(RawOrigin::Signed(lp1.clone()).into(), 9, 10, deposit_id)?;
+
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(lp1), deposit_id, 10)
+
+	withdraw_shares {
+		let owner = create_funded_account::<T>("owner", 0, G_FARM_TOTAL_REWARDS, REWARD_CURRENCY.into());
+
+		let global_farm_id = 1;
+		let yield_farm_id = 2;
+		let deposit_id = 1;
+
+		initialize_pool::<T>(POOL_ID, ASSET_ID_OFFSET)?;
+
+		initialize_global_farm::<T>(owner.clone(), POOL_ID)?;
+		initialize_yield_farm::<T>(owner, global_farm_id, POOL_ID)?;
+
+		let lp1: T::AccountId = account("lp_1", 1, SEED);
+		let lp1_shares = stableswap_add_liquidity::<T>(lp1.clone(), POOL_ID)?;
+
+		//NOTE: This is necessary because paid rewards are lower than ED.
+		fund::<T>(lp1.clone(), REWARD_CURRENCY.into(), 100 * ONE)?;
+
+		set_period::<T>(200);
+
+		lm_deposit_shares::<T>(lp1.clone(), global_farm_id, yield_farm_id, POOL_ID, lp1_shares)?;
+		set_period::<T>(400);
+	}: _(RawOrigin::Signed(lp1), deposit_id, yield_farm_id)
+
+	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
+}
@@ -0,0 +1,913 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Stableswap liquidity mining pallet
+//!
+//! ## Overview
+//!
+//! This pallet provides functionality for a liquidity mining program with a time incentive (loyalty
+//! factor) and multiple incentives scheme for stableswap pools.
+//!
+//! This pallet is build on top of the [pallet-liquidity-mining]
+//! (https://github.com/galacticcouncil/warehouse/tree/main/liquidity-mining). Incentivized asset of
+//! a global farm is always the share asset of a stableswap pool, so one global farm incentivizes
+//! one pool and deposited LP shares are valued 1:1. Exchange rate between the share asset and
+//! `reward_currency` (`price_adjustment`) is expected to be provided by `PriceAdjustment` of the
+//! liquidity mining instance, e.g. from the stableswap oracle when `reward_currency` is one of the
+//! pool assets.
+//!
+//! ### Terminology
+//!
+//! * **LP:**  liquidity provider
+//! * **LP shares:** share asset of a stableswap pool
+//! * **Deposit:** LP shares locked in the liquidity mining
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+#[cfg(test)]
+mod tests;
+
+pub mod migration;
+pub mod weights;
+
+use frame_support::{
+	ensure,
+	pallet_prelude::{DispatchError, DispatchResult},
+	sp_runtime::traits::{AccountIdConversion, Zero},
+	traits::DefensiveOption,
+	traits::{
+		tokens::nonfungibles::{Create, Inspect, Mutate},
+		Get,
+	},
+	PalletId,
+};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor};
+use hydradx_traits::{
+	liquidity_mining::{GlobalFarmId, Mutate as LiquidityMiningMutate, YieldFarmId},
+	pools::DustRemovalAccountWhitelist,
+};
+use orml_traits::MultiCurrency;
+use pallet_liquidity_mining::{FarmMultiplier, LoyaltyCurve};
+use primitives::{Balance, CollectionId, ItemId as DepositId};
+use sp_runtime::{FixedU128, Perquintill};
+use sp_std::vec;
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+type StableswapPallet<T> = pallet_stableswap::Pallet<T>;
+type CurrencyOf<T> = <T as pallet_stableswap::Config>::Currency;
+type PeriodOf<T> = <T as frame_system::Config>::BlockNumber;
+
+#[frame_support::pallet]
+#[allow(clippy::too_many_arguments)]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::genesis_config]
+	#[cfg_attr(feature = "std", derive(Default))]
+	pub struct GenesisConfig {}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig {
+		fn build(&self) {
+			let pallet_account = <Pallet<T>>::account_id();
+
+			<T as pallet::Config>::NFTHandler::create_collection(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&pallet_account,
+				&pallet_account,
+			)
+			.unwrap();
+
+			<T as pallet_stableswap::Config>::DustAccountHandler::add_account(&pallet_account).unwrap();
+		}
+	}
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_stableswap::Config {
+		/// The overarching event type.
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// The origin account that can create new liquidity mining program.
+		type CreateOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Pallet id.
+		type PalletId: Get<PalletId>;
+
+		/// NFT collection id for liquidity mining's deposit nfts.
+		#[pallet::constant]
+		type NFTCollectionId: Get<CollectionId>;
+
+		/// Non fungible handling
+		type NFTHandler: Mutate<Self::AccountId>
+			+ Create<Self::AccountId>
+			+ Inspect<Self::AccountId, ItemId = DepositId, CollectionId = CollectionId>;
+
+		/// Liquidity mining handler for managing liquidity mining functionalities
+		type LiquidityMiningHandler: LiquidityMiningMutate<
+			Self::AccountId,
+			Self::AssetId,
+			BlockNumberFor<Self>,
+			Error = DispatchError,
+			AmmPoolId = Self::AssetId,
+			Balance = Balance,
+			LoyaltyCurve = LoyaltyCurve,
+			Period = PeriodOf<Self>,
+		>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// Map of LM's deposit ids to ids of stableswap pools whose shares are locked in the deposit.
+	pub(super) type DepositPoolId<T: Config> = StorageMap<_, Blake2_128Concat, DepositId, T::AssetId, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// New global farm was created.
+		GlobalFarmCreated {
+			id: GlobalFarmId,
+			owner: T::AccountId,
+			pool_id: T::AssetId,
+			total_rewards: Balance,
+			reward_currency: T::AssetId,
+			yield_per_period: Perquintill,
+			planned_yielding_periods: PeriodOf<T>,
+			blocks_per_period: BlockNumberFor<T>,
+			max_reward_per_period: Balance,
+			min_deposit: Balance,
+			price_adjustment: FixedU128,
+		},
+
+		/// Global farm was terminated.
+		GlobalFarmTerminated {
+			global_farm_id: GlobalFarmId,
+			who: T::AccountId,
+			reward_currency: T::AssetId,
+			undistributed_rewards: Balance,
+		},
+
+		/// New yield farm was added to the farm.
+		YieldFarmCreated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+			loyalty_curve: Option<LoyaltyCurve>,
+		},
+
+		/// Yield farm multiplier was updated.
+		YieldFarmUpdated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			multiplier: FarmMultiplier,
+		},
+
+		/// Yield farm for `pool_id` was stopped.
+		YieldFarmStopped {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+		},
+
+		/// Yield farm for `pool_id` was resumed.
+		YieldFarmResumed {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			multiplier: FarmMultiplier,
+		},
+
+		/// Yield farm was terminated from the global farm.
+		YieldFarmTerminated {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+		},
+
+		/// New LP shares were deposited.
+		SharesDeposited {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			deposit_id: DepositId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares_amount: Balance,
+		},
+
+		/// Already locked LP shares were redeposited to another yield farm.
+		SharesRedeposited {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			deposit_id: DepositId,
+			pool_id: T::AssetId,
+			who: T::AccountId,
+			shares_amount: Balance,
+		},
+
+		/// Rewards were claimed.
+		RewardClaimed {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			claimed: Balance,
+			reward_currency: T::AssetId,
+			deposit_id: DepositId,
+		},
+
+		/// LP shares were withdrawn.
+		SharesWithdrawn {
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			who: T::AccountId,
+			amount: Balance,
+			deposit_id: DepositId,
+		},
+
+		/// All LP shares were unlocked and NFT representing deposit was destroyed.
+		DepositDestroyed { who: T::AccountId, deposit_id: DepositId },
+	}
+
+	#[pallet::error]
+	#[cfg_attr(test, derive(PartialEq, Eq))]
+	pub enum Error<T> {
+		/// Stableswap pool does not exist.
+		PoolNotFound,
+
+		/// Asset is not in the stableswap pool.
+		AssetNotInPool,
+
+		/// Signed account is not owner of the deposit.
+		Forbidden,
+
+		/// Rewards to claim are 0.
+		ZeroClaimedRewards,
+
+		/// Action cannot be completed because unexpected error has occurred. This should be reported
+		/// to protocol maintainers.
+		InconsistentState(InconsistentStateError),
+
+		/// Oracle price of the pool's shares is not available.
+		PriceAdjustmentNotAvailable,
+	}
+
+	//NOTE: these errors should never happen.
+	#[derive(Encode, Decode, Eq, PartialEq, TypeInfo, frame_support::PalletError, RuntimeDebug)]
+	pub enum InconsistentStateError {
+		/// Mapping of `deposit_id` to `pool_id` was not fond in the storage.
+		MissingDepositPool,
+
+		/// Deposit data not found.
+		DepositDataNotFound,
+	}
+
+	impl<T> From<InconsistentStateError> for Error<T> {
+		fn from(e: InconsistentStateError) -> Error<T> {
+			Error::<T>::InconsistentState(e)
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new liquidity mining program for stableswap pool with provided parameters.
+		///
+		/// `owner` account has to have at least `total_rewards` balance. These funds will be
+		/// transferred from `owner` to farm account.
+		///
+		/// Share asset of the pool is the incentivized asset of the farm.
+		///
+		/// The dispatch origin for this call must be `T::CreateOrigin`.
+		/// !!!WARN: `T::CreateOrigin` has power over funds of `owner`'s account and it should be
+		/// configured to trusted origin e.g Sudo or Governance.
+		///
+		/// Parameters:
+		/// - `origin`: account allowed to create new liquidity mining program(root, governance).
+		/// - `pool_id`: id of the stableswap pool whose shares are incentivized.
+		/// - `total_rewards`: total rewards planned to distribute. These rewards will be
+		/// distributed between all yield farms in the global farm.
+		/// - `planned_yielding_periods`: planned number of periods to distribute `total_rewards`.
+		/// WARN: THIS IS NOT HARD DEADLINE. Not all rewards have to be distributed in
+		/// `planned_yielding_periods`. Rewards are distributed based on the situation in the yield
+		/// farms and can be distributed in a longer, though never in a shorter, time frame.
+		/// - `blocks_per_period`:  number of blocks in a single period. Min. number of blocks per
+		/// period is 1.
+		/// - `reward_currency`: payoff currency of rewards.
+		/// - `owner`: liq. mining farm owner. This account will be able to manage created
+		/// liquidity mining program.
+		/// - `yield_per_period`: percentage return on `reward_currency` of all farms.
+		/// - `min_deposit`: minimum amount of LP shares to be deposited into the liquidity mining by each user.
+		/// - `price_adjustment`: price adjustment between pool's shares and `reward_currency`. This
+		/// value is used only if the price can't be calculated from the pool.
+		///
+		/// Emits `GlobalFarmCreated` when successful.
+		///
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_global_farm())]
+		pub fn create_global_farm(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			total_rewards: Balance,
+			planned_yielding_periods: PeriodOf<T>,
+			blocks_per_period: BlockNumberFor<T>,
+			reward_currency: T::AssetId,
+			owner: T::AccountId,
+			yield_per_period: Perquintill,
+			min_deposit: Balance,
+			price_adjustment: FixedU128,
+		) -> DispatchResult {
+			<T as pallet::Config>::CreateOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			let (id, max_reward_per_period) = T::LiquidityMiningHandler::create_global_farm(
+				total_rewards,
+				planned_yielding_periods,
+				blocks_per_period,
+				//NOTE: `incentivized_asset` is always share asset of the pool.
+				pool_id,
+				reward_currency,
+				owner.clone(),
+				yield_per_period,
+				min_deposit,
+				price_adjustment,
+			)?;
+
+			Self::deposit_event(Event::GlobalFarmCreated {
+				id,
+				owner,
+				pool_id,
+				total_rewards,
+				reward_currency,
+				yield_per_period,
+				planned_yielding_periods,
+				blocks_per_period,
+				max_reward_per_period,
+				min_deposit,
+				price_adjustment,
+			});
+
+			Ok(())
+		}
+
+		/// Terminate existing liq. mining program.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// WARN: To successfully terminate a global farm, farm have to be empty
+		/// (all yield farms in the global farm must be terminated).
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: id of global farm to be terminated.
+		///
+		/// Emits `GlobalFarmTerminated` event when successful.
+		///
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_global_farm())]
+		pub fn terminate_global_farm(origin: OriginFor<T>, global_farm_id: GlobalFarmId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let (reward_currency, undistributed_rewards, who) =
+				T::LiquidityMiningHandler::terminate_global_farm(who, global_farm_id)?;
+
+			Self::deposit_event(Event::GlobalFarmTerminated {
+				global_farm_id,
+				who,
+				reward_currency,
+				undistributed_rewards,
+			});
+
+			Ok(())
+		}
+
+		/// Create yield farm for given stableswap pool.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Pool has to exist and its share asset has to be the incentivized asset of the global farm.
+		/// At most one `active` yield farm can exist in one global farm for the same pool.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id to which a yield farm will be added.
+		/// - `pool_id`: id of the stableswap pool. Yield farm will be created for this pool and user
+		/// will be able to lock LP shares into this yield farm immediately.
+		/// - `multiplier`: yield farm's multiplier.
+		/// - `loyalty_curve`: curve to calculate loyalty multiplier to distribute rewards to users
+		/// with time incentive. `None` means no loyalty multiplier.
+		///
+		/// Emits `YieldFarmCreated` event when successful.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config>::WeightInfo::create_yield_farm())]
+		pub fn create_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+			loyalty_curve: Option<LoyaltyCurve>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			let yield_farm_id = T::LiquidityMiningHandler::create_yield_farm(
+				who,
+				global_farm_id,
+				multiplier,
+				loyalty_curve.clone(),
+				pool_id,
+				vec![pool_id],
+			)?;
+
+			Self::deposit_event(Event::YieldFarmCreated {
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				multiplier,
+				loyalty_curve,
+			});
+
+			Ok(())
+		}
+
+		/// Update yield farm's multiplier.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be updated.
+		/// - `pool_id`: id of the stableswap pool identifying yield farm in the global farm.
+		/// - `multiplier`: new yield farm's multiplier.
+		///
+		/// Emits `YieldFarmUpdated` event when successful.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_yield_farm())]
+		pub fn update_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			let yield_farm_id = T::LiquidityMiningHandler::update_yield_farm_multiplier(
+				who.clone(),
+				global_farm_id,
+				pool_id,
+				multiplier,
+			)?;
+
+			Self::deposit_event(Event::YieldFarmUpdated {
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				multiplier,
+				who,
+			});
+
+			Ok(())
+		}
+
+		/// Stop liquidity miming for specific yield farm.
+		///
+		/// This function claims rewards from `GlobalFarm` last time and stop yield farm
+		/// incentivization from a `GlobalFarm`. Users will be able to only withdraw
+		/// shares(with claiming) after calling this function.
+		/// `deposit_shares()` is not allowed on stopped yield farm.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: farm id in which yield farm will be canceled.
+		/// - `pool_id`: id of the stableswap pool identifying yield farm in the global farm.
+		///
+		/// Emits `YieldFarmStopped` event when successful.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(<T as Config>::WeightInfo::stop_yield_farm())]
+		pub fn stop_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			pool_id: T::AssetId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//NOTE: don't check if pool exists, owner must be able to stop yield farm of destroyed pool.
+			let yield_farm_id = T::LiquidityMiningHandler::stop_yield_farm(who.clone(), global_farm_id, pool_id)?;
+
+			Self::deposit_event(Event::YieldFarmStopped {
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				who,
+			});
+
+			Ok(())
+		}
+
+		/// Resume incentivization of the pool represented by yield farm.
+		///
+		/// This function resume incentivization of the pool from the `GlobalFarm` and
+		/// restore full functionality or the yield farm. Users will be able to deposit,
+		/// claim and withdraw again.
+		///
+		/// WARN: Yield farm(and users) is NOT rewarded for time it was stopped.
+		///
+		/// Only farm owner can perform this action.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm will be resumed.
+		/// - `yield_farm_id`: id of the yield farm to be resumed.
+		/// - `pool_id`: id of the stableswap pool identifying yield farm in the global farm.
+		/// - `multiplier`: yield farm multiplier.
+		///
+		/// Emits `YieldFarmResumed` event when successful.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(<T as Config>::WeightInfo::resume_yield_farm())]
+		pub fn resume_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			multiplier: FarmMultiplier,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			T::LiquidityMiningHandler::resume_yield_farm(
+				who.clone(),
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				multiplier,
+			)?;
+
+			Self::deposit_event(Event::<T>::YieldFarmResumed {
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				who,
+				multiplier,
+			});
+
+			Ok(())
+		}
+
+		/// Terminate yield farm.
+		///
+		/// This function marks a yield farm as ready to be removed from storage when it's empty. Users will
+		/// be able to only withdraw shares(without claiming rewards from yield farm). Unpaid rewards
+		/// will be transferred back to global farm and it will be used to distribute to other yield farms.
+		///
+		/// Yield farm must be stopped before it can be terminated.
+		///
+		/// Only global farm's owner can perform this action. Yield farm stays in the storage until it's
+		/// empty(all farm entries are withdrawn). Last withdrawn from yield farm trigger removing from
+		/// the storage.
+		///
+		/// Parameters:
+		/// - `origin`: global farm's owner.
+		/// - `global_farm_id`: global farm id in which yield farm should be terminated.
+		/// - `yield_farm_id`: id of yield farm to be terminated.
+		/// - `pool_id`: id of the stableswap pool identifying yield farm.
+		///
+		/// Emits `YieldFarmTerminated` event when successful.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::terminate_yield_farm())]
+		pub fn terminate_yield_farm(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			//NOTE: don't check if pool exists, owner must be able to terminate yield farm of destroyed pool.
+			T::LiquidityMiningHandler::terminate_yield_farm(who.clone(), global_farm_id, yield_farm_id, pool_id)?;
+
+			Self::deposit_event(Event::YieldFarmTerminated {
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				who,
+			});
+
+			Ok(())
+		}
+
+		/// Deposit LP shares of a stableswap pool to a liquidity mining.
+		///
+		/// This function transfers LP shares from `origin` to pallet's account and mint NFT for
+		/// `origin` account. Minted NFT represents deposit in the liquidity mining.
+		///
+		/// Parameters:
+		/// - `origin`: account depositing LP shares.
+		/// - `global_farm_id`: id of global farm to which user wants to deposit LP shares.
+		/// - `yield_farm_id`: id of yield farm to deposit to.
+		/// - `pool_id`: id of the stableswap pool whose shares are deposited.
+		/// - `shares_amount`: amount of LP shares to deposit.
+		///
+		/// Emits `SharesDeposited` event when successful.
+		///
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::deposit_shares())]
+		pub fn deposit_shares(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			pool_id: T::AssetId,
+			shares_amount: Balance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			let deposit_id = T::LiquidityMiningHandler::deposit_lp_shares(
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+				shares_amount,
+				Self::get_shares_value,
+			)?;
+
+			CurrencyOf::<T>::transfer(pool_id, &who, &Self::account_id(), shares_amount)?;
+			DepositPoolId::<T>::insert(deposit_id, pool_id);
+
+			<T as pallet::Config>::NFTHandler::mint_into(
+				&<T as pallet::Config>::NFTCollectionId::get(),
+				&deposit_id,
+				&who,
+			)?;
+
+			Self::deposit_event(Event::SharesDeposited {
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				pool_id,
+				who,
+				shares_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Redeposit already locked LP shares to another yield farm.
+		///
+		/// This function create yield farm entry for existing deposit. Amount of redeposited LP
+		/// shares is same as amount shares which are already deposited in the deposit.
+		///
+		/// This function DOESN'T create new deposit(NFT).
+		///
+		/// Parameters:
+		/// - `origin`: owner of the deposit to redeposit.
+		/// - `global_farm_id`: id of the global farm to which user wants to redeposit LP shares.
+		/// - `yield_farm_id`: id of the yield farm to redeposit to.
+		/// - `deposit_id`: identifier of the deposit to redeposit.
+		///
+		/// Emits `SharesRedeposited` event when successful.
+		///
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::redeposit_shares())]
+		pub fn redeposit_shares(
+			origin: OriginFor<T>,
+			global_farm_id: GlobalFarmId,
+			yield_farm_id: YieldFarmId,
+			deposit_id: DepositId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			//NOTE: not tested this should never fail.
+			let pool_id = DepositPoolId::<T>::get(deposit_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingDepositPool.into())?;
+
+			ensure!(
+				StableswapPallet::<T>::pools(pool_id).is_some(),
+				Error::<T>::PoolNotFound
+			);
+
+			let (shares_amount, _) = T::LiquidityMiningHandler::redeposit_lp_shares(
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				Self::get_shares_value,
+			)?;
+
+			Self::deposit_event(Event::SharesRedeposited {
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				pool_id,
+				who: owner,
+				shares_amount,
+			});
+
+			Ok(())
+		}
+
+		/// Claim rewards from liquidity mining program for deposit represented by the `deposit_id`.
+		///
+		/// This function calculate user rewards from liquidity mining and transfer rewards to `origin`
+		/// account. Claiming multiple time the same period is not allowed.
+		///
+		/// Parameters:
+		/// - `origin`: owner of deposit.
+		/// - `deposit_id`: id of the deposit to claim rewards for.
+		/// - `yield_farm_id`: id of the yield farm to claim rewards from.
+		///
+		/// Emits `RewardClaimed` event when successful.
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_rewards())]
+		pub fn claim_rewards(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_id: YieldFarmId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			let (global_farm_id, reward_currency, claimed, _) =
+				T::LiquidityMiningHandler::claim_rewards(owner.clone(), deposit_id, yield_farm_id)?;
+
+			ensure!(!claimed.is_zero(), Error::<T>::ZeroClaimedRewards);
+
+			Self::deposit_event(Event::RewardClaimed {
+				global_farm_id,
+				yield_farm_id,
+				who: owner,
+				claimed,
+				reward_currency,
+				deposit_id,
+			});
+
+			Ok(())
+		}
+
+		/// This function claim rewards and withdraw LP shares from yield farm. LP shares are
+		/// transferred to origin only if this is last withdraw in the deposit and deposit is
+		/// destroyed. This function claim rewards only if yield farm is not terminated and user
+		/// didn't already claim rewards in current period.
+		///
+		/// Unclaimable rewards represents rewards which user won't be able to claim because of
+		/// exiting early and these rewards will be transferred back to global farm for future
+		/// redistribution.
+		///
+		/// Parameters:
+		/// - `origin`: owner of deposit.
+		/// - `deposit_id`: id of the deposit to claim rewards for.
+		/// - `yield_farm_id`: id of the yield farm to claim rewards from.
+		///
+		/// Emits:
+		/// * `RewardClaimed` event if claimed rewards is > 0
+		/// * `SharesWithdrawn` event when successful
+		/// * `DepositDestroyed` event when this was last withdraw from the deposit and deposit was
+		/// destroyed.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::withdraw_shares())]
+		pub fn withdraw_shares(
+			origin: OriginFor<T>,
+			deposit_id: DepositId,
+			yield_farm_id: YieldFarmId,
+		) -> DispatchResult {
+			let owner = Self::ensure_nft_owner(origin, deposit_id)?;
+
+			//NOTE: not tested - this should never fail.
+			let pool_id = DepositPoolId::<T>::get(deposit_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::MissingDepositPool.into())?;
+
+			//NOTE: not tested - this should never fail.
+			let global_farm_id = T::LiquidityMiningHandler::get_global_farm_id(deposit_id, yield_farm_id)
+				.defensive_ok_or::<Error<T>>(InconsistentStateError::DepositDataNotFound.into())?;
+
+			let (withdrawn_amount, claim_data, is_destroyed) = T::LiquidityMiningHandler::withdraw_lp_shares(
+				owner.clone(),
+				deposit_id,
+				global_farm_id,
+				yield_farm_id,
+				pool_id,
+			)?;
+
+			if let Some((reward_currency, claimed, _)) = claim_data {
+				if !claimed.is_zero() {
+					Self::deposit_event(Event::RewardClaimed {
+						global_farm_id,
+						yield_farm_id,
+						who: owner.clone(),
+						claimed,
+						reward_currency,
+						deposit_id,
+					});
+				}
+			}
+
+			Self::deposit_event(Event::SharesWithdrawn {
+				global_farm_id,
+				yield_farm_id,
+				who: owner.clone(),
+				amount: withdrawn_amount,
+				deposit_id,
+			});
+
+			if is_destroyed {
+				CurrencyOf::<T>::transfer(pool_id, &Self::account_id(), &owner, withdrawn_amount)?;
+				DepositPoolId::<T>::remove(deposit_id);
+
+				<T as pallet::Config>::NFTHandler::burn(
+					&<T as pallet::Config>::NFTCollectionId::get(),
+					&deposit_id,
+					Some(&owner),
+				)?;
+
+				Self::deposit_event(Event::DepositDestroyed { who: owner, deposit_id });
+			}
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Account ID of the pot holding all the locked LP shares. This account is also owner of the
+	/// NFT collection used to mint liqudity mining's NFTs.
+	pub fn account_id() -> T::AccountId {
+		<T as pallet::Config>::PalletId::get().into_account_truncating()
+	}
+
+	/// Value of LP shares in the incentivized asset. Incentivized asset is the share asset of the
+	/// pool so shares are valued 1:1.
+	fn get_shares_value(
+		_incentivized_asset: T::AssetId,
+		_pool_id: T::AssetId,
+		shares_amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		Ok(shares_amount)
+	}
+
+	/// This function check if origin is signed and returns account if account is owner of the
+	/// deposit.
+	fn ensure_nft_owner(origin: OriginFor<T>, deposit_id: DepositId) -> Result<T::AccountId, DispatchError> {
+		let who = ensure_signed(origin)?;
+
+		let nft_owner =
+			<T as pallet::Config>::NFTHandler::owner(&<T as pallet::Config>::NFTCollectionId::get(), &deposit_id)
+				.ok_or(Error::<T>::Forbidden)?;
+
+		ensure!(nft_owner == who, Error::<T>::Forbidden);
+
+		Ok(who)
+	}
+}
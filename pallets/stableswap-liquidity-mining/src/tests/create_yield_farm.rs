@@ -0,0 +1,119 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+#[test]
+fn create_yield_farm_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let multiplier = One::one();
+			let loyalty_curve = Some(LoyaltyCurve::default());
+
+			assert_ok!(StableswapMining::create_yield_farm(
+				RuntimeOrigin::signed(GC),
+				global_farm_id,
+				POOL,
+				multiplier,
+				loyalty_curve.clone()
+			));
+
+			assert_last_event!(crate::Event::YieldFarmCreated {
+				global_farm_id,
+				yield_farm_id: 2,
+				pool_id: POOL,
+				multiplier,
+				loyalty_curve,
+			}
+			.into());
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::create_yield_farm(RuntimeOrigin::signed(GC), 1, POOL2, One::one(), None),
+				Error::<Test>::PoolNotFound
+			);
+		});
+}
+
+#[test]
+fn create_yield_farm_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::create_yield_farm(RuntimeOrigin::none(), 1, POOL, One::one(), None),
+				BadOrigin
+			);
+		});
+}
@@ -0,0 +1,192 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use pallet_liquidity_mining::{DepositData, YieldFarmEntry};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn deposit_shares_should_work() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+			let lp1_shares = Tokens::free_balance(POOL, &LP1);
+			let shares_amount = lp1_shares / 2;
+
+			//Act
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				global_farm_id,
+				yield_farm_id,
+				POOL,
+				shares_amount
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::SharesDeposited {
+				global_farm_id,
+				yield_farm_id,
+				deposit_id,
+				pool_id: POOL,
+				who: LP1,
+				shares_amount,
+			}
+			.into());
+
+			//Storage check
+			assert_eq!(crate::DepositPoolId::<Test>::get(deposit_id).unwrap(), POOL);
+
+			let deposit =
+				pallet_liquidity_mining::Deposit::<Test, pallet_liquidity_mining::Instance1>::get(deposit_id).unwrap();
+			let mut expected_deposit = DepositData::new(shares_amount, POOL);
+			expected_deposit
+				.add_yield_farm_entry(YieldFarmEntry::new(
+					global_farm_id,
+					yield_farm_id,
+					shares_amount,
+					FixedU128::zero(),
+					1,
+					0,
+				))
+				.unwrap();
+
+			assert_eq!(deposit, expected_deposit);
+
+			//Balance check: shares should be locked in the pallet's account.
+			assert_eq!(Tokens::free_balance(POOL, &LP1), lp1_shares - shares_amount);
+			assert_eq!(
+				Tokens::free_balance(POOL, &StableswapMining::account_id()),
+				shares_amount
+			);
+
+			//NFT check: lm deposit should be minted for user.
+			let owner: AccountId = DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id).unwrap();
+			assert_eq!(owner, LP1);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_pool_does_not_exist() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, POOL2, 1_000 * ONE),
+				Error::<Test>::PoolNotFound
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_account_has_not_enough_shares() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let lp1_shares = Tokens::free_balance(POOL, &LP1);
+
+			assert_noop!(
+				StableswapMining::deposit_shares(RuntimeOrigin::signed(LP1), 1, 2, POOL, lp1_shares + 1),
+				orml_tokens::Error::<Test>::BalanceTooLow
+			);
+		});
+}
+
+#[test]
+fn deposit_shares_should_fail_when_origin_is_not_signed() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			assert_noop!(
+				StableswapMining::deposit_shares(RuntimeOrigin::none(), 1, 2, POOL, 1_000 * ONE),
+				BadOrigin
+			);
+		});
+}
@@ -0,0 +1,149 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn redeposit_shares_should_work_when_deposit_already_exists() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(BOB, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			BOB,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.with_yield_farm(BOB, 2, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let gc_g_farm_id = 1;
+			let gc_y_farm_id = 3;
+			let bob_g_farm_id = 2;
+			let bob_y_farm_id = 4;
+			let deposit_id = 1;
+			let shares_amount = Tokens::free_balance(POOL, &LP1);
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				gc_g_farm_id,
+				gc_y_farm_id,
+				POOL,
+				shares_amount
+			));
+
+			//Act
+			assert_ok!(StableswapMining::redeposit_shares(
+				RuntimeOrigin::signed(LP1),
+				bob_g_farm_id,
+				bob_y_farm_id,
+				deposit_id
+			));
+
+			//Assert
+			assert_last_event!(crate::Event::SharesRedeposited {
+				global_farm_id: bob_g_farm_id,
+				yield_farm_id: bob_y_farm_id,
+				deposit_id,
+				pool_id: POOL,
+				who: LP1,
+				shares_amount,
+			}
+			.into());
+
+			//Shares should not be transferred again.
+			assert_eq!(
+				Tokens::free_balance(POOL, &StableswapMining::account_id()),
+				shares_amount
+			);
+		});
+}
+
+#[test]
+fn redeposit_shares_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+			(BOB, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			BOB,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.with_yield_farm(BOB, 2, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let shares_amount = Tokens::free_balance(POOL, &LP1);
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				1,
+				3,
+				POOL,
+				shares_amount
+			));
+
+			assert_noop!(
+				StableswapMining::redeposit_shares(RuntimeOrigin::signed(ALICE), 2, 4, 1),
+				Error::<Test>::Forbidden
+			);
+		});
+}
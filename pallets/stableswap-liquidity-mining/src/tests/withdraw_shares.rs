@@ -0,0 +1,129 @@
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB).
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use super::*;
+
+use pretty_assertions::assert_eq;
+
+#[test]
+fn withdraw_shares_should_return_shares_when_last_entry_in_deposit() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let global_farm_id = 1;
+			let yield_farm_id = 2;
+			let deposit_id = 1;
+			let shares_amount = Tokens::free_balance(POOL, &LP1);
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				global_farm_id,
+				yield_farm_id,
+				POOL,
+				shares_amount
+			));
+
+			set_block_number(1_000);
+
+			//Act
+			assert_ok!(StableswapMining::withdraw_shares(
+				RuntimeOrigin::signed(LP1),
+				deposit_id,
+				yield_farm_id
+			));
+
+			//Assert
+			assert!(has_event(
+				crate::Event::SharesWithdrawn {
+					global_farm_id,
+					yield_farm_id,
+					who: LP1,
+					amount: shares_amount,
+					deposit_id,
+				}
+				.into()
+			));
+
+			assert_last_event!(crate::Event::DepositDestroyed { who: LP1, deposit_id }.into());
+
+			//Storage check
+			assert_eq!(crate::DepositPoolId::<Test>::get(deposit_id), None);
+
+			//Balance check: shares should be returned to the owner.
+			assert_eq!(Tokens::free_balance(POOL, &LP1), shares_amount);
+			assert_eq!(Tokens::free_balance(POOL, &StableswapMining::account_id()), 0);
+
+			//NFT check: lm deposit should be burned.
+			assert_eq!(DummyNFT::owner(&LM_COLLECTION_ID, &deposit_id), None::<AccountId>);
+		});
+}
+
+#[test]
+fn withdraw_shares_should_fail_when_origin_is_not_deposit_owner() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(LP1, USDT, 5000 * ONE),
+			(LP1, USDC, 5000 * ONE),
+			(GC, HDX, 100_000_000 * ONE),
+		])
+		.with_pool(POOL, vec![USDT, USDC], LP1, 1000 * ONE)
+		.with_global_farm(
+			POOL,
+			80_000_000 * ONE,
+			2_628_000,
+			1,
+			HDX,
+			GC,
+			Perquintill::from_float(0.000_000_15_f64),
+			1_000,
+			FixedU128::one(),
+		)
+		.with_yield_farm(GC, 1, POOL, FixedU128::one(), None)
+		.build()
+		.execute_with(|| {
+			let shares_amount = Tokens::free_balance(POOL, &LP1);
+
+			assert_ok!(StableswapMining::deposit_shares(
+				RuntimeOrigin::signed(LP1),
+				1,
+				2,
+				POOL,
+				shares_amount
+			));
+
+			assert_noop!(
+				StableswapMining::withdraw_shares(RuntimeOrigin::signed(BOB), 1, 2),
+				Error::<Test>::Forbidden
+			);
+		});
+}
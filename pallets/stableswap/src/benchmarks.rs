@@ -19,7 +19,7 @@
 
 use super::*;
 
-use crate::types::AssetAmount;
+use crate::types::{AssetAmount, RateSource};
 use frame_benchmarking::account;
 use frame_benchmarking::benchmarks;
 use frame_support::traits::EnsureOrigin;
@@ -196,6 +196,57 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_id_to_withdraw, &lp_provider), 1_492_491_167_377_362);
 	}
 
+	remove_liquidity{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let lp_provider: T::AccountId = account("provider", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000_000u128;
+		let liquidity_added = 300_000_000_000_000u128;
+
+		let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut added_liquidity: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut min_amounts: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+			T::Currency::update_balance(asset_id, &lp_provider, liquidity_added as i128)?;
+			initial.push(AssetAmount::new(asset_id, initial_liquidity));
+			added_liquidity.push(AssetAmount::new(asset_id, liquidity_added));
+			min_amounts.push(AssetAmount::new(asset_id, 1u128));
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+
+		let amplification = 100u16;
+		let trade_fee = Permill::from_percent(1);
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin,
+			pool_id,
+			asset_ids.clone(),
+			amplification,
+			trade_fee,
+		)?;
+
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller).into(),
+			pool_id,
+			initial,
+		)?;
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(),
+			pool_id,
+			added_liquidity
+		)?;
+
+		let shares = T::Currency::free_balance(pool_id, &lp_provider);
+	}: _(RawOrigin::Signed(lp_provider.clone()), pool_id, shares, min_amounts)
+	verify {
+		assert_eq!(T::Currency::free_balance(pool_id, &lp_provider), 0u128);
+		for asset_id in asset_ids {
+			assert!(T::Currency::free_balance(asset_id, &lp_provider) > 0u128);
+		}
+	}
+
 	withdraw_asset_amount{
 		let caller: T::AccountId = account("caller", 0, 1);
 		let lp_provider: T::AccountId = account("provider", 0, 1);
@@ -467,16 +518,52 @@ benchmarks! {
 
 		System::<T>::set_block_number(500u32.into());
 
-	}: _<T::RuntimeOrigin>(successful_origin, pool_id, 5000, 501u32.into(), 1000u32.into())
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, 1000, 501u32.into(), 1000u32.into())
 	verify {
 		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
 
 		assert_eq!(pool.initial_amplification, NonZeroU16::new(500).unwrap());
-		assert_eq!(pool.final_amplification, NonZeroU16::new(5000).unwrap());
+		assert_eq!(pool.final_amplification, NonZeroU16::new(1000).unwrap());
 		assert_eq!(pool.initial_block, 501u32.into());
 		assert_eq!(pool.final_block, 1000u32.into());
 	}
 
+	stop_amplification_change{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+
+		crate::Pallet::<T>::update_amplification(RawOrigin::Root.into(),
+			pool_id,
+			1000,
+			100u32.into(),
+			1000u32.into(),
+		)?;
+
+		System::<T>::set_block_number(500u32.into());
+
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id)
+	verify {
+		let pool = crate::Pallet::<T>::pools(pool_id).unwrap();
+
+		assert_eq!(pool.initial_amplification, NonZeroU16::new(500).unwrap());
+		assert_eq!(pool.final_amplification, NonZeroU16::new(500).unwrap());
+		assert_eq!(pool.initial_block, 500u32.into());
+		assert_eq!(pool.final_block, 500u32.into());
+	}
+
 	router_execution_sell{
 		let c in 0..1;	// if c == 1, calculate_sell is executed
 		let e in 0..1;	// if e == 1, execute_sell is executed
@@ -609,5 +696,190 @@ benchmarks! {
 		}
 	}
 
+	set_asset_rate_source{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let asset_id = asset_ids[0];
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id, Some(RateSource::Fixed((11, 10))))
+	verify {
+		assert_eq!(crate::Pallet::<T>::asset_rate_source(pool_id, asset_id), Some(RateSource::Fixed((11, 10))));
+	}
+
+	update_feed_rate{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let asset_id = asset_ids[0];
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		crate::Pallet::<T>::set_asset_rate_source(successful_origin,
+			pool_id,
+			asset_id,
+			Some(RateSource::Feed),
+		)?;
+		let feed_origin = T::RateFeedOrigin::try_successful_origin().unwrap();
+	}: _<T::RuntimeOrigin>(feed_origin, pool_id, asset_id, (11, 10))
+	verify {
+		assert_eq!(crate::Pallet::<T>::feed_rate(pool_id, asset_id), Some((11, 10)));
+	}
+
+	set_dynamic_fee{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, Some(10))
+	verify {
+		assert_eq!(crate::Pallet::<T>::dynamic_fee_multiplier(pool_id), Some(10));
+	}
+
+	add_pool_asset{
+		let caller: T::AccountId = account("caller", 0, 1);
+		let recipient: T::AccountId = account("recipient", 0, 1);
+		let initial_liquidity = 1_000_000_000_000_000u128;
+
+		let mut initial: Vec<AssetAmount<T::AssetId>> = vec![];
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL - 1 {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+			T::Currency::update_balance(asset_id, &caller, initial_liquidity as i128)?;
+			initial.push(AssetAmount::new(asset_id, initial_liquidity));
+		}
+		let new_asset: T::AssetId = (MAX_ASSETS_IN_POOL - 1 + ASSET_ID_OFFSET).into();
+		T::BenchmarkHelper::register_asset(new_asset, 12)?;
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		crate::Pallet::<T>::add_liquidity(RawOrigin::Signed(caller.clone()).into(),
+			pool_id,
+			initial,
+		)?;
+		let liquidity_account = T::PoolAssetLiquidityAccount::get();
+		T::Currency::update_balance(new_asset, &liquidity_account, initial_liquidity as i128)?;
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, new_asset, initial_liquidity, recipient.clone())
+	verify {
+		assert!(crate::Pallet::<T>::pools(pool_id).unwrap().find_asset(new_asset).is_some());
+		assert_eq!(T::Currency::free_balance(new_asset, &liquidity_account), 0u128);
+		assert!(T::Currency::free_balance(pool_id, &recipient) > 0u128);
+	}
+
+	retire_pool_asset{
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let asset_id = asset_ids[0];
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id)
+	verify {
+		assert!(crate::Pallet::<T>::retiring_asset(pool_id, asset_id).is_some());
+	}
+
+	remove_pool_asset{
+		let beneficiary: T::AccountId = account("beneficiary", 0, 1);
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		let pool_id: T::AssetId = (1000u32).into();
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let asset_id = asset_ids[0];
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids,
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		crate::Pallet::<T>::retire_pool_asset(successful_origin.clone(), pool_id, asset_id)?;
+		T::Currency::update_balance(asset_id, &crate::Pallet::<T>::pool_account(pool_id), T::MinTradingLimit::get() as i128)?;
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, asset_id, beneficiary.clone())
+	verify {
+		assert!(crate::Pallet::<T>::pools(pool_id).unwrap().find_asset(asset_id).is_none());
+		assert_eq!(T::Currency::free_balance(asset_id, &beneficiary), T::MinTradingLimit::get());
+	}
+
+	destroy_pool{
+		let beneficiary: T::AccountId = account("beneficiary", 0, 1);
+		let mut asset_ids: Vec<T::AssetId> = Vec::new() ;
+		let pool_id: T::AssetId = (1000u32).into();
+		for idx in 0..MAX_ASSETS_IN_POOL {
+			let asset_id: T::AssetId = (idx + ASSET_ID_OFFSET).into();
+			T::BenchmarkHelper::register_asset(asset_id, 12)?;
+			asset_ids.push(asset_id);
+		}
+		T::BenchmarkHelper::register_asset(pool_id, 18)?;
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+		crate::Pallet::<T>::create_pool(successful_origin.clone(),
+			pool_id,
+			asset_ids.clone(),
+			100u16,
+			Permill::from_percent(1),
+		)?;
+		for asset_id in asset_ids.iter() {
+			T::Currency::update_balance(*asset_id, &crate::Pallet::<T>::pool_account(pool_id), 1_000_000_000_000i128)?;
+		}
+	}: _<T::RuntimeOrigin>(successful_origin, pool_id, beneficiary.clone())
+	verify {
+		assert!(crate::Pallet::<T>::pools(pool_id).is_none());
+		for asset_id in asset_ids {
+			assert_eq!(T::Currency::free_balance(asset_id, &beneficiary), 1_000_000_000_000u128);
+		}
+	}
+
 	impl_benchmark_test_suite!(Pallet, crate::tests::mock::ExtBuilder::default().build(), crate::tests::mock::Test);
 }
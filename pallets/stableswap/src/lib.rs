@@ -37,6 +37,36 @@
 //!
 //! When LP decides to withdraw liquidity, it receives selected asset.
 //!
+//! ### Asset rates
+//!
+//! Pool assets which are not pegged 1:1 to each other (e.g. yield-bearing or pegged assets) can be given a rate source
+//! by `AuthorityOrigin`. Reserves and amounts of such assets are multiplied by the asset's rate before they enter the
+//! invariant calculations. A rate can be fixed, taken from the `RateOracle` or provided by `RateFeedOrigin`.
+//!
+//! ### Amplification
+//!
+//! Amplification of a pool can be changed gradually over a range of blocks by `AuthorityOrigin`. The relative change
+//! per block is limited by `MaxAmplificationChangePerBlock`. A change in progress can be stopped, which freezes
+//! the amplification at its current interpolated value.
+//!
+//! ### Pool assets
+//!
+//! `AuthorityOrigin` can add a new asset to an existing pool. Initial balance of the asset must be transferred to
+//! the pool account prior to adding the asset; shares are minted to the given account proportionally to the increase
+//! of D. An asset can be retired - it can only leave the pool afterwards - and removed once drained.
+//! A pool can be destroyed when its share issuance reaches zero.
+//!
+//! ### Dynamic fees
+//!
+//! Pool can be switched to dynamic fee mode by `AuthorityOrigin`. In this mode, trade fee is equal to pool's fee
+//! when post-trade reserves of traded assets are balanced and grows up to `fee * multiplier` as they get imbalanced.
+//!
+//! ### Hooks
+//!
+//! `Hooks` are notified about every change of pool reserves or share issuance - liquidity operations, trades as well
+//! as adding and removing of pool assets. State passed to the hooks contains share price of each pool asset, which
+//! is used by the runtime to feed the oracle with share asset/pool asset prices.
+//!
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
@@ -44,10 +74,10 @@ extern crate core;
 
 use frame_support::pallet_prelude::{DispatchResult, Get};
 use frame_support::{ensure, require_transactional, transactional};
-use hydradx_traits::{registry::InspectRegistry, AccountIdFor};
+use hydradx_traits::{registry::InspectRegistry, AccountIdFor, FeeDiscountProvider};
 pub use pallet::*;
 use sp_runtime::traits::{BlockNumberProvider, Zero};
-use sp_runtime::{ArithmeticError, DispatchError, Permill, SaturatedConversion};
+use sp_runtime::{ArithmeticError, DispatchError, FixedPointNumber, FixedU128, Permill, SaturatedConversion};
 use sp_std::num::NonZeroU16;
 use sp_std::prelude::*;
 use sp_std::vec;
@@ -58,8 +88,11 @@ pub mod weights;
 
 pub use trade_execution::*;
 
-use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, StableswapHooks, Tradability};
+use crate::types::{AssetAmount, Balance, PoolInfo, PoolState, RateSource, StableswapHooks, Tradability};
+use frame_support::weights::Weight;
+use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::oracle::AggregatedPriceOracle;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use orml_traits::MultiCurrency;
 use sp_std::collections::btree_map::BTreeMap;
@@ -131,6 +164,9 @@ pub mod pallet {
 		/// The origin which can create a new pool
 		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
+		/// Account which provides initial liquidity of assets added to existing pools.
+		type PoolAssetLiquidityAccount: Get<Self::AccountId>;
+
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type DustAccountHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
 
@@ -149,6 +185,19 @@ pub mod pallet {
 		#[pallet::constant]
 		type AmplificationRange: Get<RangeInclusive<NonZeroU16>>;
 
+		/// Maximum relative change of amplification per block of an amplification ramp.
+		#[pallet::constant]
+		type MaxAmplificationChangePerBlock: Get<FixedU128>;
+
+		/// Oracle providing prices of assets with `RateSource::Oracle` rate source.
+		type RateOracle: AggregatedPriceOracle<Self::AssetId, Self::BlockNumber, EmaPrice>;
+
+		/// The origin which can update rates of assets with `RateSource::Feed` rate source.
+		type RateFeedOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Provider of trade fee discounts.
+		type FeeDiscount: FeeDiscountProvider<Self::AccountId, Self::AssetId>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 
@@ -167,6 +216,43 @@ pub mod pallet {
 	pub type AssetTradability<T: Config> =
 		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, Tradability, ValueQuery>;
 
+	/// Rate sources of pool assets. Assets without a rate source are valued 1:1.
+	#[pallet::storage]
+	#[pallet::getter(fn asset_rate_source)]
+	pub type AssetRateSources<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AssetId,
+		RateSource<T::AssetId>,
+		OptionQuery,
+	>;
+
+	/// Pool assets which are being retired. Retiring assets can only leave the pool.
+	#[pallet::storage]
+	#[pallet::getter(fn retiring_asset)]
+	pub type RetiringAssets<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AssetId, Blake2_128Concat, T::AssetId, (), OptionQuery>;
+
+	/// Fee multipliers of pools in dynamic fee mode.
+	#[pallet::storage]
+	#[pallet::getter(fn dynamic_fee_multiplier)]
+	pub type DynamicFeeMultipliers<T: Config> = StorageMap<_, Blake2_128Concat, T::AssetId, u32, OptionQuery>;
+
+	/// Rates of pool assets with `RateSource::Feed` rate source.
+	#[pallet::storage]
+	#[pallet::getter(fn feed_rate)]
+	pub type FeedRates<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AssetId,
+		Blake2_128Concat,
+		T::AssetId,
+		(Balance, Balance),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -230,6 +316,59 @@ pub mod pallet {
 			start_block: T::BlockNumber,
 			end_block: T::BlockNumber,
 		},
+
+		/// Rate source of a pool asset has been set or removed.
+		RateSourceUpdated {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			source: Option<RateSource<T::AssetId>>,
+		},
+
+		/// Feed rate of a pool asset has been updated.
+		FeedRateUpdated {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			rate: (Balance, Balance),
+		},
+
+		/// Dynamic fee mode of a pool has been enabled, updated or disabled.
+		DynamicFeeUpdated {
+			pool_id: T::AssetId,
+			fee_multiplier: Option<u32>,
+		},
+
+		/// New asset has been added to a pool.
+		PoolAssetAdded {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			shares: Balance,
+			share_recipient: T::AccountId,
+		},
+
+		/// Amplification change of a pool has been stopped at current amplification.
+		AmplificationChangeStopped {
+			pool_id: T::AssetId,
+			amplification: NonZeroU16,
+		},
+
+		/// Pool asset is being retired.
+		PoolAssetRetiring { pool_id: T::AssetId, asset_id: T::AssetId },
+
+		/// Retired asset has been removed from a pool. Remaining balance has been transferred to the beneficiary.
+		PoolAssetRemoved {
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			beneficiary: T::AccountId,
+		},
+
+		/// Pool has been destroyed. Remaining balances have been transferred to the beneficiary.
+		PoolDestroyed {
+			pool_id: T::AssetId,
+			amounts: Vec<AssetAmount<T::AssetId>>,
+			beneficiary: T::AccountId,
+		},
 	}
 
 	#[pallet::error]
@@ -315,6 +454,36 @@ pub mod pallet {
 
 		/// Failed to retrieve asset decimals.
 		UnknownDecimals,
+
+		/// Rate numerator or denominator is zero.
+		InvalidRate,
+
+		/// Rate of an asset could not be retrieved from its rate source.
+		RateUnavailable,
+
+		/// Asset's rate source is not a feed.
+		NotFeedRateSource,
+
+		/// Dynamic fee multiplier must be greater than one.
+		InvalidFeeMultiplier,
+
+		/// Asset is being retired.
+		AssetRetiring,
+
+		/// Asset is not being retired.
+		AssetNotRetiring,
+
+		/// Retiring asset has not been drained yet.
+		AssetNotDrained,
+
+		/// Pool share issuance is not zero.
+		PoolNotEmpty,
+
+		/// Amplification change exceeds maximum change per block.
+		ExcessiveAmplificationChange,
+
+		/// Amplification of a pool is not changing.
+		AmplificationNotChanging,
 	}
 
 	#[pallet::call]
@@ -441,6 +610,14 @@ pub mod pallet {
 					T::AmplificationRange::get().contains(&pool.final_amplification),
 					Error::<T>::InvalidAmplification
 				);
+				ensure!(
+					Self::is_amplification_change_allowed(
+						current_amplification,
+						final_amplification.into(),
+						end_block.saturating_sub(start_block).saturated_into(),
+					),
+					Error::<T>::ExcessiveAmplificationChange
+				);
 				Self::deposit_event(Event::AmplificationChanging {
 					pool_id,
 					current_amplification: pool.initial_amplification,
@@ -470,7 +647,8 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(3)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
 		#[transactional]
 		pub fn add_liquidity(
 			origin: OriginFor<T>,
@@ -503,7 +681,8 @@ pub mod pallet {
 		/// Emits `LiquidityAdded` event when successful.
 		#[pallet::call_index(4)]
 		#[pallet::weight(<T as Config>::WeightInfo::add_liquidity_shares()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
 		#[transactional]
 		pub fn add_liquidity_shares(
 			origin: OriginFor<T>,
@@ -542,7 +721,8 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(5)]
 		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity_one_asset()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
 		#[transactional]
 		pub fn remove_liquidity_one_asset(
 			origin: OriginFor<T>,
@@ -573,7 +753,7 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let balances = Self::pool_reserves(pool_id, &pool)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 
 			ensure!(
@@ -583,15 +763,15 @@ pub mod pallet {
 			);
 
 			let amplification = Self::get_amplification(&pool);
-			let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			let (amount, fee) = Self::calculate_withdraw_one_asset_amount(
+				pool_id,
+				&pool,
 				&balances,
 				share_amount,
 				asset_idx,
 				share_issuance,
 				amplification,
-				pool.fee,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			)?;
 
 			ensure!(amount >= min_amount_out, Error::<T>::MinimumAmountNotReached);
 
@@ -599,7 +779,7 @@ pub mod pallet {
 			T::Currency::transfer(asset_id, &pool_account, &who, amount)?;
 
 			let updated_share_issuance = T::Currency::total_issuance(pool_id);
-			let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let updated_balances = Self::pool_reserves(pool_id, &pool)?;
 
 			let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 				&updated_balances,
@@ -648,7 +828,8 @@ pub mod pallet {
 		/// Emits `LiquidityRemoved` event when successful.
 		#[pallet::call_index(6)]
 		#[pallet::weight(<T as Config>::WeightInfo::withdraw_asset_amount()
-							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
 		#[transactional]
 		pub fn withdraw_asset_amount(
 			origin: OriginFor<T>,
@@ -668,19 +849,19 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let asset_idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
 			let pool_account = Self::pool_account(pool_id);
-			let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let balances = Self::pool_reserves(pool_id, &pool)?;
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let amplification = Self::get_amplification(&pool);
 
-			let shares = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
+			let shares = Self::calculate_shares_for_asset_amount(
+				pool_id,
+				&pool,
 				&balances,
 				asset_idx,
 				amount,
 				amplification,
 				share_issuance,
-				pool.fee,
-			)
-			.ok_or(ArithmeticError::Overflow)?;
+			)?;
 
 			ensure!(shares <= max_share_amount, Error::<T>::SlippageLimit);
 
@@ -695,7 +876,7 @@ pub mod pallet {
 			T::Currency::transfer(asset_id, &pool_account, &who, amount)?;
 
 			let updated_share_issuance = T::Currency::total_issuance(pool_id);
-			let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let updated_balances = Self::pool_reserves(pool_id, &pool)?;
 			let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 				&updated_balances,
 				amplification,
@@ -744,7 +925,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(<T as Config>::WeightInfo::sell()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(T::FeeDiscount::discount_weight()))]
 		#[transactional]
 		pub fn sell(
 			origin: OriginFor<T>,
@@ -775,9 +958,11 @@ pub mod pallet {
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let pool_account = Self::pool_account(pool_id);
 			let amplification = Self::get_amplification(&pool);
-			let initial_reserves = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
-			let (amount_out, fee_amount) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)?;
+			let fee_discount = T::FeeDiscount::discount(&who, asset_in, asset_out);
+			let (amount_out, fee_amount) =
+				Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, fee_discount)?;
 			ensure!(amount_out >= min_buy_amount, Error::<T>::BuyLimitNotReached);
 
 			T::Currency::transfer(asset_in, &who, &pool_account, amount_in)?;
@@ -786,7 +971,7 @@ pub mod pallet {
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let assets = pool.assets.clone();
 
-			let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let updated_balances = Self::pool_reserves(pool_id, &pool)?;
 			let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 				&updated_balances,
 				amplification,
@@ -844,7 +1029,9 @@ pub mod pallet {
 		///
 		#[pallet::call_index(8)]
 		#[pallet::weight(<T as Config>::WeightInfo::buy()
-							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize)))]
+							.saturating_add(T::Hooks::on_trade_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight())
+							.saturating_add(T::FeeDiscount::discount_weight()))]
 		#[transactional]
 		pub fn buy(
 			origin: OriginFor<T>,
@@ -868,11 +1055,12 @@ pub mod pallet {
 			);
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
-			let pool_account = Self::pool_account(pool_id);
 			let amplification = Self::get_amplification(&pool);
-			let initial_reserves = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let initial_reserves = Self::pool_reserves(pool_id, &pool)?;
 
-			let (amount_in, fee_amount) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)?;
+			let fee_discount = T::FeeDiscount::discount(&who, asset_in, asset_out);
+			let (amount_in, fee_amount) =
+				Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, fee_discount)?;
 
 			let pool_account = Self::pool_account(pool_id);
 
@@ -889,7 +1077,7 @@ pub mod pallet {
 			let share_issuance = T::Currency::total_issuance(pool_id);
 			let assets = pool.assets.clone();
 
-			let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+			let updated_balances = Self::pool_reserves(pool_id, &pool)?;
 			let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 				&updated_balances,
 				amplification,
@@ -946,6 +1134,10 @@ pub mod pallet {
 
 			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 			let _ = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(
+				!RetiringAssets::<T>::contains_key(pool_id, asset_id),
+				Error::<T>::AssetRetiring
+			);
 
 			AssetTradability::<T>::mutate(pool_id, asset_id, |current_state| {
 				*current_state = state;
@@ -959,6 +1151,532 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Set or remove rate source of a pool asset.
+		///
+		/// Reserves and amounts of an asset with a rate source are multiplied by the asset's rate
+		/// in all pool calculations. Asset without a rate source is valued 1:1.
+		///
+		/// When switching to `RateSource::Feed`, current rate of the asset is kept until the feed reports
+		/// a new one. Switching fails if the current rate is not available, in which case `RateSource::Fixed`
+		/// can be set first to provide the initial rate.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_id`: Id of an asset in the pool
+		/// - `source`: new rate source. `None` removes current rate source.
+		///
+		/// Emits `RateSourceUpdated` event when successful.
+		#[pallet::call_index(10)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_asset_rate_source())]
+		#[transactional]
+		pub fn set_asset_rate_source(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			source: Option<RateSource<T::AssetId>>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let _ = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+
+			match source {
+				Some(RateSource::Fixed(rate)) => {
+					ensure!(Self::is_valid_rate(rate), Error::<T>::InvalidRate);
+					FeedRates::<T>::remove(pool_id, asset_id);
+				}
+				Some(RateSource::Oracle { peg_asset, .. }) => {
+					ensure!(peg_asset != asset_id, Error::<T>::IncorrectAssets);
+					FeedRates::<T>::remove(pool_id, asset_id);
+				}
+				Some(RateSource::Feed) => {
+					// Current rate is used until the feed reports a new one.
+					if !FeedRates::<T>::contains_key(pool_id, asset_id) {
+						let rate = Self::asset_rate(pool_id, asset_id)?;
+						FeedRates::<T>::insert(pool_id, asset_id, rate);
+					}
+				}
+				None => {
+					FeedRates::<T>::remove(pool_id, asset_id);
+				}
+			}
+
+			AssetRateSources::<T>::set(pool_id, asset_id, source.clone());
+
+			Self::deposit_event(Event::RateSourceUpdated {
+				pool_id,
+				asset_id,
+				source,
+			});
+
+			Ok(())
+		}
+
+		/// Update rate of a pool asset with `RateSource::Feed` rate source.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::RateFeedOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_id`: Id of an asset in the pool
+		/// - `rate`: new rate as (numerator, denominator)
+		///
+		/// Emits `FeedRateUpdated` event when successful.
+		#[pallet::call_index(11)]
+		#[pallet::weight(<T as Config>::WeightInfo::update_feed_rate())]
+		#[transactional]
+		pub fn update_feed_rate(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			rate: (Balance, Balance),
+		) -> DispatchResult {
+			T::RateFeedOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				AssetRateSources::<T>::get(pool_id, asset_id) == Some(RateSource::Feed),
+				Error::<T>::NotFeedRateSource
+			);
+			ensure!(Self::is_valid_rate(rate), Error::<T>::InvalidRate);
+
+			FeedRates::<T>::insert(pool_id, asset_id, rate);
+
+			Self::deposit_event(Event::FeedRateUpdated {
+				pool_id,
+				asset_id,
+				rate,
+			});
+
+			Ok(())
+		}
+
+		/// Remove liquidity from selected pool proportionally.
+		///
+		/// Share amount is burned and LP receives corresponding pro-rata amount of each pool asset.
+		///
+		/// No fee is applied.
+		///
+		/// Parameters:
+		/// - `origin`: liquidity provider
+		/// - `pool_id`: Pool Id
+		/// - `share_amount`: amount of shares to withdraw
+		/// - `min_amounts_out`: minimum amounts of pool assets to receive. Assets not listed have no minimum.
+		///
+		/// Emits `LiquidityRemoved` event when successful.
+		#[pallet::call_index(12)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_liquidity()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn remove_liquidity(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			share_amount: Balance,
+			min_amounts_out: Vec<AssetAmount<T::AssetId>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(share_amount > Balance::zero(), Error::<T>::InvalidAssetAmount);
+
+			let current_share_balance = T::Currency::free_balance(pool_id, &who);
+			ensure!(current_share_balance >= share_amount, Error::<T>::InsufficientShares);
+			ensure!(
+				current_share_balance == share_amount
+					|| current_share_balance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientShareBalance
+			);
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(
+				min_amounts_out.len() <= pool.assets.len(),
+				Error::<T>::MaxAssetsExceeded
+			);
+
+			let mut min_amounts = BTreeMap::<T::AssetId, Balance>::new();
+			for asset in min_amounts_out.iter() {
+				ensure!(pool.find_asset(asset.asset_id).is_some(), Error::<T>::AssetNotInPool);
+				if min_amounts.insert(asset.asset_id, asset.amount).is_some() {
+					return Err(Error::<T>::IncorrectAssets.into());
+				}
+			}
+
+			for asset_id in pool.assets.iter() {
+				ensure!(
+					Self::is_asset_allowed(pool_id, *asset_id, Tradability::REMOVE_LIQUIDITY),
+					Error::<T>::NotAllowed
+				);
+			}
+
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			ensure!(
+				share_issuance == share_amount
+					|| share_issuance.saturating_sub(share_amount) >= T::MinPoolLiquidity::get(),
+				Error::<T>::InsufficientLiquidityRemaining
+			);
+
+			let pool_account = Self::pool_account(pool_id);
+			let balances = Self::pool_reserves(pool_id, &pool)?;
+
+			let amounts =
+				hydra_dx_math::stableswap::calculate_proportional_amounts(&balances, share_amount, share_issuance)
+					.ok_or(ArithmeticError::Overflow)?;
+
+			T::Currency::withdraw(pool_id, &who, share_amount)?;
+
+			let mut removed = Vec::with_capacity(pool.assets.len());
+			for (asset_id, amount) in pool.assets.iter().zip(amounts.iter()) {
+				let min_amount = min_amounts.get(asset_id).copied().unwrap_or_default();
+				ensure!(*amount >= min_amount, Error::<T>::SlippageLimit);
+				T::Currency::transfer(*asset_id, &pool_account, &who, *amount)?;
+				removed.push(AssetAmount::new(*asset_id, *amount));
+			}
+
+			let updated_share_issuance = T::Currency::total_issuance(pool_id);
+
+			// Share prices can not be calculated once the pool has been emptied, so hooks are notified only
+			// when some liquidity remains.
+			if !updated_share_issuance.is_zero() {
+				let updated_balances = Self::pool_reserves(pool_id, &pool)?;
+				let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
+					&updated_balances,
+					Self::get_amplification(&pool),
+					updated_share_issuance,
+				)
+				.ok_or(ArithmeticError::Overflow)?;
+
+				let state = PoolState {
+					assets: pool.assets.into_inner(),
+					before: balances.into_iter().map(|v| v.into()).collect(),
+					after: updated_balances.into_iter().map(|v| v.into()).collect(),
+					delta: amounts,
+					issuance_before: share_issuance,
+					issuance_after: updated_share_issuance,
+					share_prices,
+				};
+
+				T::Hooks::on_liquidity_changed(pool_id, state)?;
+			}
+
+			Self::deposit_event(Event::LiquidityRemoved {
+				pool_id,
+				who,
+				shares: share_amount,
+				amounts: removed,
+				fee: Balance::zero(),
+			});
+
+			Ok(())
+		}
+
+		/// Enable, update or disable dynamic fee mode of a pool.
+		///
+		/// In dynamic fee mode, trade fee is equal to pool's fee when post-trade reserves of traded assets
+		/// are balanced and grows up to `fee * fee_multiplier` as they get imbalanced.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `fee_multiplier`: maximum fee multiplier. `None` disables dynamic fee mode.
+		///
+		/// Emits `DynamicFeeUpdated` event when successful.
+		#[pallet::call_index(13)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_dynamic_fee())]
+		#[transactional]
+		pub fn set_dynamic_fee(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			fee_multiplier: Option<u32>,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			ensure!(Pools::<T>::contains_key(pool_id), Error::<T>::PoolNotFound);
+			if let Some(multiplier) = fee_multiplier {
+				ensure!(multiplier > 1, Error::<T>::InvalidFeeMultiplier);
+			}
+
+			DynamicFeeMultipliers::<T>::set(pool_id, fee_multiplier);
+
+			Self::deposit_event(Event::DynamicFeeUpdated {
+				pool_id,
+				fee_multiplier,
+			});
+
+			Ok(())
+		}
+
+		/// Add new asset to an existing pool.
+		///
+		/// Initial liquidity `amount` of the asset is transferred from `T::PoolAssetLiquidityAccount`
+		/// to the pool account. Only the increase of pool account's balance is counted as initial liquidity.
+		///
+		/// Shares are minted for `share_recipient` proportionally to the increase of D.
+		/// The asset is valued 1:1 until a rate source is set.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_id`: Id of an asset to add. Must be registered in Asset registry.
+		/// - `amount`: initial liquidity of the asset
+		/// - `share_recipient`: account which receives minted shares
+		///
+		/// Emits `PoolAssetAdded` event when successful.
+		#[pallet::call_index(14)]
+		#[pallet::weight(<T as Config>::WeightInfo::add_pool_asset()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn add_pool_asset(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			amount: Balance,
+			share_recipient: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(pool.find_asset(asset_id).is_none(), Error::<T>::AssetInPool);
+			ensure!(asset_id != pool_id, Error::<T>::ShareAssetInPoolAssets);
+			ensure!(T::AssetInspection::exists(asset_id), Error::<T>::AssetNotRegistered);
+			let decimals = Self::retrieve_decimals(asset_id).ok_or(Error::<T>::UnknownDecimals)?;
+
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			ensure!(!share_issuance.is_zero(), Error::<T>::InsufficientLiquidity);
+
+			ensure!(amount >= T::MinTradingLimit::get(), Error::<T>::InvalidInitialLiquidity);
+			let pool_account = Self::pool_account(pool_id);
+			let initial_balance = T::Currency::free_balance(asset_id, &pool_account);
+
+			let reserves = Self::pool_reserves(pool_id, &pool)?;
+
+			T::Currency::transfer(asset_id, &T::PoolAssetLiquidityAccount::get(), &pool_account, amount)?;
+			let added = T::Currency::free_balance(asset_id, &pool_account)
+				.checked_sub(initial_balance)
+				.ok_or(ArithmeticError::Underflow)?;
+			ensure!(added >= T::MinTradingLimit::get(), Error::<T>::InvalidInitialLiquidity);
+
+			let amplification = Self::get_amplification(&pool);
+			let shares = hydra_dx_math::stableswap::calculate_shares_for_new_asset::<D_ITERATIONS>(
+				&reserves,
+				AssetReserve::new(added, decimals),
+				amplification,
+				share_issuance,
+			)
+			.ok_or(ArithmeticError::Overflow)?;
+			ensure!(!shares.is_zero(), Error::<T>::InvalidInitialLiquidity);
+
+			let idx = pool.assets.binary_search(&asset_id).unwrap_or_else(|idx| idx);
+			pool.assets
+				.try_insert(idx, asset_id)
+				.map_err(|_| Error::<T>::MaxAssetsExceeded)?;
+
+			T::Currency::deposit(pool_id, &share_recipient, shares)?;
+
+			let mut before: Vec<Balance> = reserves.into_iter().map(|v| v.amount).collect();
+			before.insert(idx, initial_balance);
+			let mut delta = vec![Balance::zero(); before.len()];
+			delta[idx] = added;
+			Self::notify_liquidity_changed(pool_id, &pool, before, delta, share_issuance)?;
+
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolAssetAdded {
+				pool_id,
+				asset_id,
+				amount: added,
+				shares,
+				share_recipient,
+			});
+
+			Ok(())
+		}
+
+		/// Retire a pool asset.
+		///
+		/// Retiring asset can only leave the pool - it can be bought or withdrawn, but not sold into the pool
+		/// or added as liquidity. Tradable state of a retiring asset cannot be changed.
+		/// Use `remove_pool_asset` to remove the asset once it has been drained.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_id`: Id of a pool asset to retire
+		///
+		/// Emits `PoolAssetRetiring` event when successful.
+		#[pallet::call_index(15)]
+		#[pallet::weight(<T as Config>::WeightInfo::retire_pool_asset())]
+		#[transactional]
+		pub fn retire_pool_asset(origin: OriginFor<T>, pool_id: T::AssetId, asset_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let _ = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(pool.assets.len() > 2, Error::<T>::IncorrectAssets);
+			ensure!(
+				!RetiringAssets::<T>::contains_key(pool_id, asset_id),
+				Error::<T>::AssetRetiring
+			);
+
+			RetiringAssets::<T>::insert(pool_id, asset_id, ());
+			AssetTradability::<T>::insert(pool_id, asset_id, Tradability::BUY | Tradability::REMOVE_LIQUIDITY);
+
+			Self::deposit_event(Event::PoolAssetRetiring { pool_id, asset_id });
+
+			Ok(())
+		}
+
+		/// Remove retired asset from a pool.
+		///
+		/// The asset must have been retired by `retire_pool_asset` and pool's reserve of the asset
+		/// must not exceed the minimum trading limit. Remaining reserve is transferred to `beneficiary`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `asset_id`: Id of a retiring pool asset
+		/// - `beneficiary`: account which receives remaining reserve of the asset
+		///
+		/// Emits `PoolAssetRemoved` event when successful.
+		#[pallet::call_index(16)]
+		#[pallet::weight(<T as Config>::WeightInfo::remove_pool_asset()
+							.saturating_add(T::Hooks::on_liquidity_changed_weight(MAX_ASSETS_IN_POOL as usize))
+							.saturating_add(Pallet::<T>::rates_weight()))]
+		#[transactional]
+		pub fn remove_pool_asset(
+			origin: OriginFor<T>,
+			pool_id: T::AssetId,
+			asset_id: T::AssetId,
+			beneficiary: T::AccountId,
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let mut pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			let idx = pool.find_asset(asset_id).ok_or(Error::<T>::AssetNotInPool)?;
+			ensure!(
+				RetiringAssets::<T>::contains_key(pool_id, asset_id),
+				Error::<T>::AssetNotRetiring
+			);
+			ensure!(pool.assets.len() > 2, Error::<T>::IncorrectAssets);
+
+			let pool_account = Self::pool_account(pool_id);
+			let amount = T::Currency::free_balance(asset_id, &pool_account);
+			ensure!(amount <= T::MinTradingLimit::get(), Error::<T>::AssetNotDrained);
+
+			if !amount.is_zero() {
+				T::Currency::transfer(asset_id, &pool_account, &beneficiary, amount)?;
+			}
+
+			pool.assets.remove(idx);
+			Self::remove_asset_state(pool_id, asset_id);
+
+			// Reserves of remaining assets are not changed, but share prices are.
+			let share_issuance = T::Currency::total_issuance(pool_id);
+			if !share_issuance.is_zero() {
+				let before = pool
+					.assets
+					.iter()
+					.map(|asset| T::Currency::free_balance(*asset, &pool_account))
+					.collect::<Vec<Balance>>();
+				let delta = vec![Balance::zero(); before.len()];
+				Self::notify_liquidity_changed(pool_id, &pool, before, delta, share_issuance)?;
+			}
+
+			Pools::<T>::insert(pool_id, pool);
+
+			Self::deposit_event(Event::PoolAssetRemoved {
+				pool_id,
+				asset_id,
+				amount,
+				beneficiary,
+			});
+
+			Ok(())
+		}
+
+		/// Destroy a pool.
+		///
+		/// Pool's share issuance must be zero. Remaining balances of pool assets are transferred to `beneficiary`.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: Id of a pool
+		/// - `beneficiary`: account which receives remaining balances of pool assets
+		///
+		/// Emits `PoolDestroyed` event when successful.
+		#[pallet::call_index(17)]
+		#[pallet::weight(<T as Config>::WeightInfo::destroy_pool())]
+		#[transactional]
+		pub fn destroy_pool(origin: OriginFor<T>, pool_id: T::AssetId, beneficiary: T::AccountId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
+			ensure!(T::Currency::total_issuance(pool_id).is_zero(), Error::<T>::PoolNotEmpty);
+
+			let pool_account = Self::pool_account(pool_id);
+			let mut amounts = Vec::with_capacity(pool.assets.len());
+			for asset_id in pool.assets.iter() {
+				let amount = T::Currency::free_balance(*asset_id, &pool_account);
+				if !amount.is_zero() {
+					T::Currency::transfer(*asset_id, &pool_account, &beneficiary, amount)?;
+				}
+				amounts.push(AssetAmount::new(*asset_id, amount));
+				Self::remove_asset_state(pool_id, *asset_id);
+			}
+
+			Pools::<T>::remove(pool_id);
+			DynamicFeeMultipliers::<T>::remove(pool_id);
+			T::DustAccountHandler::remove_account(&pool_account)?;
+
+			Self::deposit_event(Event::PoolDestroyed {
+				pool_id,
+				amounts,
+				beneficiary,
+			});
+
+			Ok(())
+		}
+
+		/// Stop amplification change of a pool in progress.
+		///
+		/// Amplification is frozen at its current interpolated value.
+		///
+		/// Parameters:
+		/// - `origin`: Must be T::AuthorityOrigin
+		/// - `pool_id`: pool to update
+		///
+		/// Emits `AmplificationChangeStopped` event if successful.
+		#[pallet::call_index(18)]
+		#[pallet::weight(<T as Config>::WeightInfo::stop_amplification_change())]
+		#[transactional]
+		pub fn stop_amplification_change(origin: OriginFor<T>, pool_id: T::AssetId) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let current_block = T::BlockNumberProvider::current_block_number();
+
+			Pools::<T>::try_mutate(pool_id, |maybe_pool| -> DispatchResult {
+				let mut pool = maybe_pool.as_mut().ok_or(Error::<T>::PoolNotFound)?;
+
+				ensure!(
+					pool.initial_amplification != pool.final_amplification && current_block < pool.final_block,
+					Error::<T>::AmplificationNotChanging
+				);
+
+				let current_amplification = NonZeroU16::new(Self::get_amplification(pool).saturated_into())
+					.ok_or(Error::<T>::InvalidAmplification)?;
+
+				pool.initial_amplification = current_amplification;
+				pool.final_amplification = current_amplification;
+				pool.initial_block = current_block;
+				pool.final_block = current_block;
+
+				Self::deposit_event(Event::AmplificationChangeStopped {
+					pool_id,
+					amplification: current_amplification,
+				});
+				Ok(())
+			})
+		}
 	}
 
 	#[pallet::hooks]
@@ -966,48 +1684,67 @@ pub mod pallet {
 }
 
 impl<T: Config> Pallet<T> {
+	/// Calculate amount of `asset_out` received for `amount_in` of `asset_in` and the fee amount.
+	///
+	/// Pool fee is reduced by `fee_discount`.
 	fn calculate_out_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_in: Balance,
+		fee_discount: Permill,
 	) -> Result<(Balance, Balance), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
-		let pool_account = Self::pool_account(pool_id);
-		let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+		let balances = Self::pool_reserves(pool_id, &pool)?;
 
 		ensure!(!balances[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 		ensure!(!balances[index_out].is_zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
-		hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&balances,
-			index_in,
-			index_out,
-			amount_in,
-			amplification,
-			pool.fee,
-		)
+		let fee = hydra_dx_math::fee::calculate_discounted_fee(pool.fee, fee_discount);
+		if let Some(fee_multiplier) = DynamicFeeMultipliers::<T>::get(pool_id) {
+			hydra_dx_math::stableswap::calculate_out_given_in_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&balances,
+				index_in,
+				index_out,
+				amount_in,
+				amplification,
+				fee,
+				fee_multiplier,
+			)
+		} else {
+			hydra_dx_math::stableswap::calculate_out_given_in_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&balances,
+				index_in,
+				index_out,
+				amount_in,
+				amplification,
+				fee,
+			)
+		}
 		.ok_or_else(|| ArithmeticError::Overflow.into())
 	}
 
+	/// Calculate amount of `asset_in` required to receive `amount_out` of `asset_out` and the fee amount.
+	///
+	/// Pool fee is reduced by `fee_discount`.
 	fn calculate_in_amount(
 		pool_id: T::AssetId,
 		asset_in: T::AssetId,
 		asset_out: T::AssetId,
 		amount_out: Balance,
+		fee_discount: Permill,
 	) -> Result<(Balance, Balance), DispatchError> {
 		let pool = Pools::<T>::get(pool_id).ok_or(Error::<T>::PoolNotFound)?;
 
 		let index_in = pool.find_asset(asset_in).ok_or(Error::<T>::AssetNotInPool)?;
 		let index_out = pool.find_asset(asset_out).ok_or(Error::<T>::AssetNotInPool)?;
 
-		let pool_account = Self::pool_account(pool_id);
-		let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+		let balances = Self::pool_reserves(pool_id, &pool)?;
 
 		ensure!(
 			balances[index_out].amount > amount_out,
@@ -1016,19 +1753,169 @@ impl<T: Config> Pallet<T> {
 		ensure!(!balances[index_in].is_zero(), Error::<T>::InsufficientLiquidity);
 
 		let amplification = Self::get_amplification(&pool);
-		hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
-			&balances,
-			index_in,
-			index_out,
-			amount_out,
+		let fee = hydra_dx_math::fee::calculate_discounted_fee(pool.fee, fee_discount);
+		if let Some(fee_multiplier) = DynamicFeeMultipliers::<T>::get(pool_id) {
+			hydra_dx_math::stableswap::calculate_in_given_out_with_dynamic_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&balances,
+				index_in,
+				index_out,
+				amount_out,
+				amplification,
+				fee,
+				fee_multiplier,
+			)
+		} else {
+			hydra_dx_math::stableswap::calculate_in_given_out_with_fee::<D_ITERATIONS, Y_ITERATIONS>(
+				&balances,
+				index_in,
+				index_out,
+				amount_out,
+				amplification,
+				fee,
+			)
+		}
+		.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Calculate surcharge of the dynamic fee over the pool fee for a liquidity operation
+	/// which results in `updated_reserves`.
+	///
+	/// Surcharge is zero if dynamic fee is not enabled for the pool.
+	fn liquidity_fee_surcharge(
+		pool_id: T::AssetId,
+		pool_fee: Permill,
+		updated_reserves: &[AssetReserve],
+	) -> Result<Permill, DispatchError> {
+		let Some(fee_multiplier) = DynamicFeeMultipliers::<T>::get(pool_id) else {
+			return Ok(Permill::zero());
+		};
+		let fee =
+			hydra_dx_math::stableswap::calculate_liquidity_dynamic_fee(updated_reserves, pool_fee, fee_multiplier)
+				.ok_or(ArithmeticError::Overflow)?;
+		Ok(Permill::from_parts(
+			fee.deconstruct().saturating_sub(pool_fee.deconstruct()),
+		))
+	}
+
+	/// Calculate amount of shares received for providing liquidity which changes pool reserves
+	/// from `initial_reserves` to `updated_reserves`.
+	fn calculate_shares_for_reserves(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		initial_reserves: &[AssetReserve],
+		updated_reserves: &[AssetReserve],
+		amplification: Balance,
+		share_issuance: Balance,
+	) -> Result<Balance, DispatchError> {
+		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
+			initial_reserves,
+			updated_reserves,
 			amplification,
+			share_issuance,
 			pool.fee,
 		)
-		.ok_or_else(|| ArithmeticError::Overflow.into())
+		.ok_or(ArithmeticError::Overflow)?;
+
+		if share_issuance.is_zero() {
+			return Ok(share_amount);
+		}
+
+		let surcharge = Self::liquidity_fee_surcharge(pool_id, pool.fee, updated_reserves)?;
+		Ok(share_amount.saturating_sub(surcharge.mul_ceil(share_amount)))
 	}
 
+	/// Calculate amount of shares to burn to withdraw `amount` of asset at `asset_idx`.
+	fn calculate_shares_for_asset_amount(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		reserves: &[AssetReserve],
+		asset_idx: usize,
+		amount: Balance,
+		amplification: Balance,
+		share_issuance: Balance,
+	) -> Result<Balance, DispatchError> {
+		let shares = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
+			reserves,
+			asset_idx,
+			amount,
+			amplification,
+			share_issuance,
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let updated_reserves = Self::updated_reserves(reserves, asset_idx, |reserve| reserve.checked_sub(amount))?;
+		let surcharge = Self::liquidity_fee_surcharge(pool_id, pool.fee, &updated_reserves)?;
+		shares
+			.checked_add(surcharge.mul_ceil(shares))
+			.ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+
+	/// Calculate amount of asset at `asset_idx` received for burning `shares` and the fee amount.
+	fn calculate_withdraw_one_asset_amount(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		reserves: &[AssetReserve],
+		shares: Balance,
+		asset_idx: usize,
+		share_issuance: Balance,
+		amplification: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (amount, fee) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			reserves,
+			shares,
+			asset_idx,
+			share_issuance,
+			amplification,
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let updated_reserves = Self::updated_reserves(reserves, asset_idx, |reserve| reserve.checked_sub(amount))?;
+		let surcharge = Self::liquidity_fee_surcharge(pool_id, pool.fee, &updated_reserves)?.mul_ceil(amount);
+		Ok((amount.saturating_sub(surcharge), fee.saturating_add(surcharge)))
+	}
+
+	/// Calculate amount of asset at `asset_idx` required to receive `shares` and the fee amount.
+	fn calculate_add_one_asset_amount(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		reserves: &[AssetReserve],
+		shares: Balance,
+		asset_idx: usize,
+		share_issuance: Balance,
+		amplification: Balance,
+	) -> Result<(Balance, Balance), DispatchError> {
+		let (amount_in, fee) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+			reserves,
+			shares,
+			asset_idx,
+			share_issuance,
+			amplification,
+			pool.fee,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let updated_reserves = Self::updated_reserves(reserves, asset_idx, |reserve| reserve.checked_add(amount_in))?;
+		let surcharge = Self::liquidity_fee_surcharge(pool_id, pool.fee, &updated_reserves)?.mul_ceil(amount_in);
+		let amount_in = amount_in.checked_add(surcharge).ok_or(ArithmeticError::Overflow)?;
+		Ok((amount_in, fee.saturating_add(surcharge)))
+	}
+
+	fn updated_reserves(
+		reserves: &[AssetReserve],
+		asset_idx: usize,
+		update: impl FnOnce(Balance) -> Option<Balance>,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		let mut updated_reserves = reserves.to_vec();
+		let reserve = updated_reserves.get_mut(asset_idx).ok_or(Error::<T>::AssetNotInPool)?;
+		reserve.amount = update(reserve.amount).ok_or(ArithmeticError::Overflow)?;
+		Ok(updated_reserves)
+	}
+
+	/// Create a new pool with given assets. No event is emitted.
 	#[require_transactional]
-	fn do_create_pool(
+	pub fn do_create_pool(
 		share_asset: T::AssetId,
 		assets: &[T::AssetId],
 		amplification: NonZeroU16,
@@ -1105,24 +1992,16 @@ impl<T: Config> Pallet<T> {
 		let mut added_amounts = Vec::with_capacity(pool.assets.len());
 		for pool_asset in pool.assets.iter() {
 			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
+			let rate = Self::asset_rate(pool_id, *pool_asset)?;
 			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+			initial_reserves.push(AssetReserve::new(reserve, decimals).with_rate(rate));
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
 				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				updated_reserves.push(AssetReserve::new(inc_reserve, decimals).with_rate(rate));
 				added_amounts.push(liq_added);
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(AssetReserve::new(reserve, decimals).with_rate(rate));
 				added_amounts.push(0);
 			}
 		}
@@ -1130,14 +2009,14 @@ impl<T: Config> Pallet<T> {
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
-		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
+		let share_amount = Self::calculate_shares_for_reserves(
+			pool_id,
+			&pool,
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+		)?;
 
 		ensure!(!share_amount.is_zero(), Error::<T>::InvalidAssetAmount);
 		let current_share_balance = T::Currency::free_balance(pool_id, who);
@@ -1189,22 +2068,22 @@ impl<T: Config> Pallet<T> {
 		let share_issuance = T::Currency::total_issuance(pool_id);
 		let amplification = Self::get_amplification(&pool);
 		let pool_account = Self::pool_account(pool_id);
-		let balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+		let balances = Self::pool_reserves(pool_id, &pool)?;
 
 		// Ensure that initial liquidity has been already provided
 		for reserve in balances.iter() {
 			ensure!(!reserve.amount.is_zero(), Error::<T>::InvalidInitialLiquidity);
 		}
 
-		let (amount_in, _) = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+		let (amount_in, _) = Self::calculate_add_one_asset_amount(
+			pool_id,
+			&pool,
 			&balances,
 			shares,
 			asset_idx,
 			share_issuance,
 			amplification,
-			pool.fee,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+		)?;
 
 		ensure!(amount_in <= max_asset_amount, Error::<T>::SlippageLimit);
 
@@ -1219,7 +2098,7 @@ impl<T: Config> Pallet<T> {
 		T::Currency::deposit(pool_id, who, shares)?;
 		T::Currency::transfer(asset_id, who, &pool_account, amount_in)?;
 
-		let updated_balances = pool.balances::<T>(&pool_account).ok_or(Error::<T>::UnknownDecimals)?;
+		let updated_balances = Self::pool_reserves(pool_id, &pool)?;
 		let updated_issuance = share_issuance.saturating_add(shares);
 		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
 			&updated_balances,
@@ -1253,11 +2132,67 @@ impl<T: Config> Pallet<T> {
 		AssetTradability::<T>::get(pool_id, asset_id).contains(operation)
 	}
 
+	/// Notify hooks about liquidity change of a pool which was not done by adding or removing liquidity,
+	/// e.g. when assets were moved to the pool directly or the set of pool assets has changed.
+	///
+	/// `before` and `delta` must be in order of pool assets. Updated state is read from the pool account.
+	pub fn notify_liquidity_changed(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+		before: Vec<Balance>,
+		delta: Vec<Balance>,
+		issuance_before: Balance,
+	) -> DispatchResult {
+		let updated_reserves = Self::pool_reserves(pool_id, pool)?;
+		let issuance_after = T::Currency::total_issuance(pool_id);
+		let share_prices = hydra_dx_math::stableswap::calculate_share_prices::<D_ITERATIONS>(
+			&updated_reserves,
+			Self::get_amplification(pool),
+			issuance_after,
+		)
+		.ok_or(ArithmeticError::Overflow)?;
+
+		let state = PoolState {
+			assets: pool.assets.to_vec(),
+			before,
+			after: updated_reserves.into_iter().map(|v| v.into()).collect(),
+			delta,
+			issuance_before,
+			issuance_after,
+			share_prices,
+		};
+
+		T::Hooks::on_liquidity_changed(pool_id, state)
+	}
+
+	/// Remove tradability, rate and retirement state of a pool asset.
+	fn remove_asset_state(pool_id: T::AssetId, asset_id: T::AssetId) {
+		AssetTradability::<T>::remove(pool_id, asset_id);
+		AssetRateSources::<T>::remove(pool_id, asset_id);
+		FeedRates::<T>::remove(pool_id, asset_id);
+		RetiringAssets::<T>::remove(pool_id, asset_id);
+	}
+
 	#[inline]
-	fn pool_account(pool_id: T::AssetId) -> T::AccountId {
+	pub fn pool_account(pool_id: T::AssetId) -> T::AccountId {
 		T::ShareAccountId::from_assets(&pool_id, Some(POOL_IDENTIFIER))
 	}
 
+	/// Current effective amplification of a pool.
+	pub fn current_amplification(pool_id: T::AssetId) -> Option<u128> {
+		Pools::<T>::get(pool_id).map(|pool| Self::get_amplification(&pool))
+	}
+
+	/// Amplification change is allowed if its relative change per block - relative to the lower of the current
+	/// and final amplification - does not exceed `MaxAmplificationChangePerBlock`.
+	fn is_amplification_change_allowed(current_amplification: u128, final_amplification: u128, blocks: u128) -> bool {
+		let change = current_amplification.abs_diff(final_amplification);
+		let base = current_amplification.min(final_amplification);
+		T::MaxAmplificationChangePerBlock::get()
+			.checked_mul_int(base.saturating_mul(blocks))
+			.map_or(false, |max_change| change <= max_change)
+	}
+
 	#[inline]
 	pub(crate) fn get_amplification(pool: &PoolInfo<T::AssetId, T::BlockNumber>) -> u128 {
 		hydra_dx_math::stableswap::calculate_amplification(
@@ -1273,6 +2208,56 @@ impl<T: Config> Pallet<T> {
 	pub(crate) fn retrieve_decimals(asset_id: T::AssetId) -> Option<u8> {
 		T::AssetInspection::decimals(asset_id)
 	}
+
+	/// Retrieve reserves of all pool assets together with their decimals and rates.
+	pub fn pool_reserves(
+		pool_id: T::AssetId,
+		pool: &PoolInfo<T::AssetId, T::BlockNumber>,
+	) -> Result<Vec<AssetReserve>, DispatchError> {
+		let pool_account = Self::pool_account(pool_id);
+		pool.assets
+			.iter()
+			.map(|asset| {
+				let reserve = T::Currency::free_balance(*asset, &pool_account);
+				let decimals = Self::retrieve_decimals(*asset).ok_or(Error::<T>::UnknownDecimals)?;
+				let rate = Self::asset_rate(pool_id, *asset)?;
+				Ok(AssetReserve::new(reserve, decimals).with_rate(rate))
+			})
+			.collect()
+	}
+
+	/// Retrieve current rate of a pool asset from its rate source.
+	pub fn asset_rate(pool_id: T::AssetId, asset_id: T::AssetId) -> Result<(Balance, Balance), DispatchError> {
+		let rate = match AssetRateSources::<T>::get(pool_id, asset_id) {
+			None => return Ok((1, 1)),
+			Some(RateSource::Fixed(rate)) => rate,
+			Some(RateSource::Oracle {
+				source,
+				period,
+				peg_asset,
+			}) => {
+				// Oracle price is the amount of asset for one unit of the peg asset
+				let (price, _) = T::RateOracle::get_price(asset_id, peg_asset, period, source)
+					.map_err(|_| Error::<T>::RateUnavailable)?;
+				(price.d, price.n)
+			}
+			Some(RateSource::Feed) => FeedRates::<T>::get(pool_id, asset_id).ok_or(Error::<T>::RateUnavailable)?,
+		};
+		ensure!(Self::is_valid_rate(rate), Error::<T>::RateUnavailable);
+		Ok(rate)
+	}
+
+	/// Worst case weight of retrieving rates of all pool assets.
+	pub fn rates_weight() -> Weight {
+		T::DbWeight::get()
+			.reads(2 * MAX_ASSETS_IN_POOL as u64)
+			.saturating_add(T::RateOracle::get_price_weight().saturating_mul(MAX_ASSETS_IN_POOL as u64))
+	}
+
+	#[inline]
+	fn is_valid_rate(rate: (Balance, Balance)) -> bool {
+		!rate.0.is_zero() && !rate.1.is_zero()
+	}
 }
 
 impl<T: Config> Pallet<T> {
@@ -1304,36 +2289,28 @@ impl<T: Config> Pallet<T> {
 		let mut updated_reserves = Vec::with_capacity(pool.assets.len());
 		for pool_asset in pool.assets.iter() {
 			let decimals = Self::retrieve_decimals(*pool_asset).ok_or(Error::<T>::UnknownDecimals)?;
+			let rate = Self::asset_rate(pool_id, *pool_asset)?;
 			let reserve = T::Currency::free_balance(*pool_asset, &pool_account);
-			initial_reserves.push(AssetReserve {
-				amount: reserve,
-				decimals,
-			});
+			initial_reserves.push(AssetReserve::new(reserve, decimals).with_rate(rate));
 			if let Some(liq_added) = added_assets.remove(pool_asset) {
 				let inc_reserve = reserve.checked_add(liq_added).ok_or(ArithmeticError::Overflow)?;
-				updated_reserves.push(AssetReserve {
-					amount: inc_reserve,
-					decimals,
-				});
+				updated_reserves.push(AssetReserve::new(inc_reserve, decimals).with_rate(rate));
 			} else {
 				ensure!(!reserve.is_zero(), Error::<T>::InvalidInitialLiquidity);
-				updated_reserves.push(AssetReserve {
-					amount: reserve,
-					decimals,
-				});
+				updated_reserves.push(AssetReserve::new(reserve, decimals).with_rate(rate));
 			}
 		}
 
 		let amplification = Self::get_amplification(&pool);
 		let share_issuance = T::Currency::total_issuance(pool_id);
-		let share_amount = hydra_dx_math::stableswap::calculate_shares::<D_ITERATIONS>(
+		let share_amount = Self::calculate_shares_for_reserves(
+			pool_id,
+			&pool,
 			&initial_reserves,
 			&updated_reserves,
 			amplification,
 			share_issuance,
-			pool.fee,
-		)
-		.ok_or(ArithmeticError::Overflow)?;
+		)?;
 
 		Ok(share_amount)
 	}
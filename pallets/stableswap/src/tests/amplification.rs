@@ -1,9 +1,9 @@
 use crate::tests::mock::*;
 use crate::types::PoolInfo;
-use crate::{Error, Pools};
+use crate::{Error, Event, Pools};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::traits::BlockNumberProvider;
-use sp_runtime::Permill;
+use sp_runtime::{DispatchError::BadOrigin, FixedU128, Permill};
 use std::num::NonZeroU16;
 
 #[test]
@@ -315,3 +315,217 @@ fn amplification_should_change_when_block_changes() {
 			}
 		});
 }
+
+#[test]
+fn update_amplification_should_fail_when_change_per_block_exceeds_maximum() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_max_amplification_change(FixedU128::from_rational(1, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+
+			assert_noop!(
+				Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 1000, 10, 100),
+				Error::<Test>::ExcessiveAmplificationChange,
+			);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				910,
+			));
+		});
+}
+
+#[test]
+fn update_amplification_should_limit_decrease_relative_to_final_amplification() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_max_amplification_change(FixedU128::from_rational(1, 100))
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				1000,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+
+			assert_noop!(
+				Stableswap::update_amplification(RuntimeOrigin::root(), pool_id, 100, 10, 909),
+				Error::<Test>::ExcessiveAmplificationChange,
+			);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				100,
+				10,
+				910,
+			));
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_freeze_current_amplification() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+
+			System::set_block_number(500);
+			assert_ok!(Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id));
+
+			assert_eq!(
+				<Pools<Test>>::get(pool_id).unwrap(),
+				PoolInfo {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(545).unwrap(),
+					final_amplification: NonZeroU16::new(545).unwrap(),
+					initial_block: 500,
+					final_block: 500,
+					fee: Permill::from_percent(10),
+				}
+			);
+			System::assert_last_event(
+				Event::AmplificationChangeStopped {
+					pool_id,
+					amplification: NonZeroU16::new(545).unwrap(),
+				}
+				.into(),
+			);
+
+			System::set_block_number(1000);
+			assert_eq!(Stableswap::current_amplification(pool_id), Some(545));
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_fail_when_amplification_is_not_changing() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging,
+			);
+
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+			System::set_block_number(1000);
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::root(), pool_id),
+				Error::<Test>::AmplificationNotChanging,
+			);
+		});
+}
+
+#[test]
+fn stop_amplification_change_should_fail_when_called_by_non_authority_origin() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let pool_id: AssetId = 100;
+
+	ExtBuilder::default()
+		.with_registered_asset("pool".as_bytes().to_vec(), pool_id, 12)
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.build()
+		.execute_with(|| {
+			assert_ok!(Stableswap::create_pool(
+				RuntimeOrigin::root(),
+				pool_id,
+				vec![asset_a, asset_b],
+				100,
+				Permill::from_percent(10),
+			));
+
+			System::set_block_number(1);
+			assert_ok!(Stableswap::update_amplification(
+				RuntimeOrigin::root(),
+				pool_id,
+				1000,
+				10,
+				1000,
+			));
+
+			assert_noop!(
+				Stableswap::stop_amplification_change(RuntimeOrigin::signed(ALICE), pool_id),
+				BadOrigin,
+			);
+		});
+}
+
+#[test]
+fn current_amplification_should_return_none_when_pool_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_eq!(Stableswap::current_amplification(100), None);
+	});
+}
@@ -0,0 +1,135 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo};
+use crate::{assert_balance, Error, Event};
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const ASSET_A: AssetId = 2;
+const ASSET_B: AssetId = 3;
+
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_B, 100 * ONE),
+		])
+		.with_registered_asset("a".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("b".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn destroy_pool_should_work_when_all_shares_are_redeemed() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![]
+		));
+		// leftover transferred to the pool account after all liquidity was removed
+		assert_ok!(Tokens::transfer(ASSET_A, &BOB, &pool_account, ONE));
+
+		assert_ok!(Stableswap::destroy_pool(RuntimeOrigin::root(), pool_id, BOB));
+
+		assert!(Stableswap::pools(pool_id).is_none());
+		assert_balance!(BOB, ASSET_A, 100 * ONE);
+		assert_balance!(pool_account, ASSET_A, 0);
+		assert_balance!(pool_account, ASSET_B, 0);
+
+		System::assert_last_event(
+			Event::PoolDestroyed {
+				pool_id,
+				amounts: vec![AssetAmount::new(ASSET_A, ONE), AssetAmount::new(ASSET_B, 0)],
+				beneficiary: BOB,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn destroyed_pool_should_be_created_again_with_same_share_asset() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![]
+		));
+		assert_ok!(Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(10)));
+		assert_ok!(Stableswap::destroy_pool(RuntimeOrigin::root(), pool_id, BOB));
+
+		assert_eq!(Stableswap::dynamic_fee_multiplier(pool_id), None);
+		assert_ok!(Stableswap::create_pool(
+			RuntimeOrigin::root(),
+			pool_id,
+			vec![ASSET_A, ASSET_B],
+			100,
+			Permill::from_percent(0),
+		));
+	});
+}
+
+#[test]
+fn destroy_pool_should_fail_when_share_issuance_is_not_zero() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::destroy_pool(RuntimeOrigin::root(), pool_id, BOB),
+			Error::<Test>::PoolNotEmpty
+		);
+	});
+}
+
+#[test]
+fn destroy_pool_should_fail_when_pool_does_not_exist() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::destroy_pool(RuntimeOrigin::root(), 1000, BOB),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn destroy_pool_should_fail_when_called_by_non_authority_origin() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::destroy_pool(RuntimeOrigin::signed(ALICE), pool_id, BOB),
+			BadOrigin
+		);
+	});
+}
@@ -0,0 +1,360 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo};
+use crate::{assert_balance, Error, Event};
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const ASSET_A: AssetId = 1;
+const ASSET_B: AssetId = 2;
+const ASSET_C: AssetId = 3;
+const ASSET_D: AssetId = 4;
+
+fn pool(asset_a: AssetId, asset_b: AssetId) -> PoolInfo<AssetId, u64> {
+	PoolInfo::<AssetId, u64> {
+		assets: vec![asset_a, asset_b].try_into().unwrap(),
+		initial_amplification: NonZeroU16::new(100).unwrap(),
+		final_amplification: NonZeroU16::new(100).unwrap(),
+		initial_block: 0,
+		final_block: 0,
+		fee: Permill::from_percent(1),
+	}
+}
+
+// Two pools with the same parameters and reserves of 100 of each asset.
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_C, 100 * ONE),
+			(ALICE, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_B, 100 * ONE),
+			(ALICE, ASSET_C, 100 * ONE),
+			(ALICE, ASSET_D, 100 * ONE),
+		])
+		.with_registered_asset("a".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("b".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("c".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("d".as_bytes().to_vec(), ASSET_D, 12)
+		.with_pool(
+			ALICE,
+			pool(ASSET_A, ASSET_B),
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+				],
+			},
+		)
+		.with_pool(
+			ALICE,
+			pool(ASSET_C, ASSET_D),
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_C, 100 * ONE),
+					AssetAmount::new(ASSET_D, 100 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn set_dynamic_fee_should_work() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(10)));
+
+		assert_eq!(Stableswap::dynamic_fee_multiplier(pool_id), Some(10));
+		System::assert_last_event(
+			Event::DynamicFeeUpdated {
+				pool_id,
+				fee_multiplier: Some(10),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_should_disable_dynamic_fee_when_none() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(10)));
+		assert_ok!(Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, None));
+
+		assert_eq!(Stableswap::dynamic_fee_multiplier(pool_id), None);
+		System::assert_last_event(
+			Event::DynamicFeeUpdated {
+				pool_id,
+				fee_multiplier: None,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_should_fail_when_multiplier_is_not_greater_than_one() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(0)),
+			Error::<Test>::InvalidFeeMultiplier
+		);
+		assert_noop!(
+			Stableswap::set_dynamic_fee(RuntimeOrigin::root(), pool_id, Some(1)),
+			Error::<Test>::InvalidFeeMultiplier
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_should_fail_when_pool_does_not_exist() {
+	ext().execute_with(|| {
+		assert_noop!(
+			Stableswap::set_dynamic_fee(RuntimeOrigin::root(), 1000, Some(10)),
+			Error::<Test>::PoolNotFound
+		);
+	});
+}
+
+#[test]
+fn set_dynamic_fee_should_fail_when_called_by_non_authority_origin() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_dynamic_fee(RuntimeOrigin::signed(ALICE), pool_id, Some(10)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_should_charge_higher_fee_when_dynamic_fee_is_enabled() {
+	ext().execute_with(|| {
+		let dynamic_pool = get_pool_id_at(0);
+		let flat_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			dynamic_pool,
+			Some(10)
+		));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			dynamic_pool,
+			ASSET_A,
+			ASSET_B,
+			50 * ONE,
+			0,
+		));
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			flat_pool,
+			ASSET_C,
+			ASSET_D,
+			50 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, ASSET_A, 50 * ONE);
+		assert_balance!(BOB, ASSET_C, 50 * ONE);
+
+		let received_dynamic = Tokens::free_balance(ASSET_B, &BOB);
+		let received_flat = Tokens::free_balance(ASSET_D, &BOB);
+		assert!(received_dynamic < received_flat);
+	});
+}
+
+#[test]
+fn buy_should_charge_higher_fee_when_dynamic_fee_is_enabled() {
+	ext().execute_with(|| {
+		let dynamic_pool = get_pool_id_at(0);
+		let flat_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			dynamic_pool,
+			Some(10)
+		));
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			dynamic_pool,
+			ASSET_B,
+			ASSET_A,
+			30 * ONE,
+			100 * ONE,
+		));
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			flat_pool,
+			ASSET_D,
+			ASSET_C,
+			30 * ONE,
+			100 * ONE,
+		));
+
+		assert_balance!(BOB, ASSET_B, 30 * ONE);
+		assert_balance!(BOB, ASSET_D, 30 * ONE);
+
+		let paid_dynamic = 100 * ONE - Tokens::free_balance(ASSET_A, &BOB);
+		let paid_flat = 100 * ONE - Tokens::free_balance(ASSET_C, &BOB);
+		assert!(paid_dynamic > paid_flat);
+	});
+}
+
+#[test]
+fn add_liquidity_should_mint_less_shares_when_dynamic_fee_is_enabled() {
+	ext().execute_with(|| {
+		let dynamic_pool = get_pool_id_at(0);
+		let flat_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			dynamic_pool,
+			Some(10)
+		));
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			dynamic_pool,
+			vec![AssetAmount::new(ASSET_A, 50 * ONE)],
+		));
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			flat_pool,
+			vec![AssetAmount::new(ASSET_C, 50 * ONE)],
+		));
+
+		let shares_dynamic = Tokens::free_balance(dynamic_pool, &BOB);
+		let shares_flat = Tokens::free_balance(flat_pool, &BOB);
+		assert!(shares_dynamic < shares_flat);
+	});
+}
+
+#[test]
+fn remove_liquidity_one_asset_should_charge_higher_fee_when_dynamic_fee_is_enabled() {
+	ext().execute_with(|| {
+		let dynamic_pool = get_pool_id_at(0);
+		let flat_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			dynamic_pool,
+			Some(10)
+		));
+
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			dynamic_pool,
+			ASSET_B,
+			50 * ONE,
+			0,
+		));
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(ALICE),
+			flat_pool,
+			ASSET_D,
+			50 * ONE,
+			0,
+		));
+
+		let received_dynamic = Tokens::free_balance(ASSET_B, &ALICE);
+		let received_flat = Tokens::free_balance(ASSET_D, &ALICE);
+		assert!(received_dynamic < received_flat);
+	});
+}
+
+#[test]
+fn withdraw_asset_amount_should_burn_more_shares_when_dynamic_fee_is_enabled() {
+	ext().execute_with(|| {
+		let dynamic_pool = get_pool_id_at(0);
+		let flat_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			dynamic_pool,
+			Some(10)
+		));
+
+		let shares_dynamic = Tokens::free_balance(dynamic_pool, &ALICE);
+		let shares_flat = Tokens::free_balance(flat_pool, &ALICE);
+
+		assert_ok!(Stableswap::withdraw_asset_amount(
+			RuntimeOrigin::signed(ALICE),
+			dynamic_pool,
+			ASSET_B,
+			50 * ONE,
+			shares_dynamic,
+		));
+		assert_ok!(Stableswap::withdraw_asset_amount(
+			RuntimeOrigin::signed(ALICE),
+			flat_pool,
+			ASSET_D,
+			50 * ONE,
+			shares_flat,
+		));
+
+		let burned_dynamic = shares_dynamic - Tokens::free_balance(dynamic_pool, &ALICE);
+		let burned_flat = shares_flat - Tokens::free_balance(flat_pool, &ALICE);
+		assert!(burned_dynamic > burned_flat);
+	});
+}
+
+#[test]
+fn add_liquidity_and_remove_liquidity_one_asset_should_not_yield_more_than_sell() {
+	ext().execute_with(|| {
+		let liquidity_pool = get_pool_id_at(0);
+		let trade_pool = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_dynamic_fee(
+			RuntimeOrigin::root(),
+			liquidity_pool,
+			Some(10)
+		));
+		assert_ok!(Stableswap::set_dynamic_fee(RuntimeOrigin::root(), trade_pool, Some(10)));
+
+		assert_ok!(Stableswap::add_liquidity(
+			RuntimeOrigin::signed(BOB),
+			liquidity_pool,
+			vec![AssetAmount::new(ASSET_A, 50 * ONE)],
+		));
+		let shares = Tokens::free_balance(liquidity_pool, &BOB);
+		assert_ok!(Stableswap::remove_liquidity_one_asset(
+			RuntimeOrigin::signed(BOB),
+			liquidity_pool,
+			ASSET_B,
+			shares,
+			0,
+		));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			trade_pool,
+			ASSET_C,
+			ASSET_D,
+			50 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, ASSET_A, 50 * ONE);
+		assert_balance!(BOB, ASSET_C, 50 * ONE);
+		assert_balance!(BOB, liquidity_pool, 0);
+
+		let received_via_liquidity = Tokens::free_balance(ASSET_B, &BOB);
+		let received_via_sell = Tokens::free_balance(ASSET_D, &BOB);
+		assert!(received_via_liquidity <= received_via_sell);
+	});
+}
@@ -31,7 +31,7 @@ use crate::Config;
 
 use frame_support::assert_ok;
 use frame_support::dispatch::Weight;
-use frame_support::traits::{Contains, Everything, GenesisBuild};
+use frame_support::traits::{Contains, Everything, GenesisBuild, Get};
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
@@ -43,7 +43,7 @@ use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
-	DispatchError,
+	DispatchError, FixedU128, Permill,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -58,6 +58,7 @@ pub const DAI: AssetId = 1;
 
 pub const ALICE: AccountId = 1;
 pub const BOB: AccountId = 2;
+pub const TREASURY: AccountId = 3;
 
 pub const ONE: Balance = 1_000_000_000_000;
 
@@ -75,6 +76,9 @@ thread_local! {
 	pub static DUSTER_WHITELIST: RefCell<Vec<AccountId>> = RefCell::new(Vec::new());
 	pub static LAST_LIQUDITY_CHANGE_HOOK: RefCell<Option<(AssetId, PoolState<AssetId>)>> = RefCell::new(None);
 	pub static LAST_TRADE_HOOK: RefCell<Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)>> = RefCell::new(None);
+	pub static ORACLE_PRICES: RefCell<HashMap<(AssetId, AssetId), EmaPrice>> = RefCell::new(HashMap::default());
+	pub static MAX_AMPLIFICATION_CHANGE: RefCell<FixedU128> = RefCell::new(FixedU128::from(100));
+	pub static FEE_DISCOUNT: RefCell<Permill> = RefCell::new(Permill::zero());
 }
 
 construct_runtime!(
@@ -141,9 +145,18 @@ parameter_types! {
 	pub const DAIAssetId: AssetId = DAI;
 	pub const MinimumLiquidity: Balance = 1000;
 	pub const MinimumTradingLimit: Balance = 1000;
+	pub const TreasuryAccount: AccountId = TREASURY;
 	pub AmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
 }
 
+pub struct MaxAmplificationChange;
+
+impl Get<FixedU128> for MaxAmplificationChange {
+	fn get() -> FixedU128 {
+		MAX_AMPLIFICATION_CHANGE.with(|v| *v.borrow())
+	}
+}
+
 pub struct Whitelist;
 
 impl Contains<AccountId> for Whitelist {
@@ -179,17 +192,34 @@ impl Config for Test {
 	type ShareAccountId = AccountIdConstructor;
 	type AssetInspection = DummyRegistry;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PoolAssetLiquidityAccount = TreasuryAccount;
 	type MinPoolLiquidity = MinimumLiquidity;
 	type AmplificationRange = AmplificationRange;
+	type MaxAmplificationChangePerBlock = MaxAmplificationChange;
 	type MinTradingLimit = MinimumTradingLimit;
 	type WeightInfo = ();
 	type BlockNumberProvider = System;
 	type DustAccountHandler = Whitelist;
 	type Hooks = DummyHookAdapter;
+	type RateOracle = PriceOracleMock;
+	type RateFeedOrigin = EnsureRoot<AccountId>;
+	type FeeDiscount = FeeDiscountMock;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = DummyRegistry;
 }
 
+pub struct FeeDiscountMock;
+
+impl FeeDiscountProvider<AccountId, AssetId> for FeeDiscountMock {
+	fn discount(_who: &AccountId, _asset_in: AssetId, _asset_out: AssetId) -> Permill {
+		FEE_DISCOUNT.with(|v| *v.borrow())
+	}
+
+	fn discount_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub struct InitialLiquidity {
 	pub(crate) account: AccountId,
 	pub(crate) assets: Vec<AssetAmount<AssetId>>,
@@ -215,6 +245,15 @@ impl Default for ExtBuilder {
 		POOL_IDS.with(|v| {
 			v.borrow_mut().clear();
 		});
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		MAX_AMPLIFICATION_CHANGE.with(|v| {
+			*v.borrow_mut() = FixedU128::from(100);
+		});
+		FEE_DISCOUNT.with(|v| {
+			*v.borrow_mut() = Permill::zero();
+		});
 		Self {
 			endowed_accounts: vec![],
 			registered_assets: vec![],
@@ -234,6 +273,20 @@ impl ExtBuilder {
 		self
 	}
 
+	pub fn with_max_amplification_change(self, max_change: FixedU128) -> Self {
+		MAX_AMPLIFICATION_CHANGE.with(|v| {
+			*v.borrow_mut() = max_change;
+		});
+		self
+	}
+
+	pub fn with_fee_discount(self, discount: Permill) -> Self {
+		FEE_DISCOUNT.with(|v| {
+			*v.borrow_mut() = discount;
+		});
+		self
+	}
+
 	pub fn with_pool(
 		mut self,
 		who: AccountId,
@@ -310,8 +363,10 @@ impl ExtBuilder {
 #[cfg(feature = "runtime-benchmarks")]
 use crate::types::BenchmarkHelper;
 use crate::types::{AssetAmount, PoolInfo, PoolState, StableswapHooks};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
-use hydradx_traits::{AccountIdFor, InspectRegistry};
+use hydradx_traits::{AccountIdFor, FeeDiscountProvider, InspectRegistry};
 use sp_runtime::traits::Zero;
 
 pub struct DummyRegistry;
@@ -415,3 +470,31 @@ pub(crate) fn last_liquidity_changed_hook_state() -> Option<(AssetId, PoolState<
 pub(crate) fn last_trade_hook_state() -> Option<(AssetId, AssetId, AssetId, PoolState<AssetId>)> {
 	LAST_TRADE_HOOK.with(|v| v.borrow().clone())
 }
+
+pub struct PriceOracleMock;
+
+impl AggregatedPriceOracle<AssetId, u64, EmaPrice> for PriceOracleMock {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, u64), Self::Error> {
+		let price = ORACLE_PRICES
+			.with(|v| v.borrow().get(&(asset_a, asset_b)).copied())
+			.ok_or(())?;
+		Ok((price, 0))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+pub(crate) fn set_oracle_price(asset_a: AssetId, asset_b: AssetId, price: EmaPrice) {
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert((asset_a, asset_b), price);
+	});
+}
@@ -0,0 +1,457 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo, RateSource, Tradability};
+use crate::{assert_balance, Error, Event};
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const ASSET_A: AssetId = 2;
+const ASSET_B: AssetId = 3;
+const ASSET_C: AssetId = 4;
+const NEW_ASSET: AssetId = 5;
+
+// Pool at index 0 contains assets A, B and C with reserve 100 each. New asset is registered, but not in the pool.
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, ASSET_A, 100 * ONE),
+			(BOB, ASSET_C, 100 * ONE),
+			(BOB, NEW_ASSET, 100 * ONE),
+			(TREASURY, NEW_ASSET, 100 * ONE),
+			(ALICE, ASSET_A, 100 * ONE),
+			(ALICE, ASSET_B, 100 * ONE),
+			(ALICE, ASSET_C, 100 * ONE),
+		])
+		.with_registered_asset("a".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("b".as_bytes().to_vec(), ASSET_B, 12)
+		.with_registered_asset("c".as_bytes().to_vec(), ASSET_C, 12)
+		.with_registered_asset("new".as_bytes().to_vec(), NEW_ASSET, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B, ASSET_C].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+					AssetAmount::new(ASSET_C, 100 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn add_pool_asset_should_transfer_initial_liquidity_from_liquidity_account() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			100 * ONE,
+			BOB
+		));
+
+		let pool = Stableswap::pools(pool_id).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![ASSET_A, ASSET_B, ASSET_C, NEW_ASSET]);
+		assert_balance!(pool_account, NEW_ASSET, 100 * ONE);
+		assert_balance!(TREASURY, NEW_ASSET, 0);
+		assert_balance!(BOB, NEW_ASSET, 100 * ONE);
+
+		// balanced pool stays balanced - new shares are a third of initial issuance
+		let shares = Tokens::free_balance(pool_id, &BOB);
+		assert!(shares <= issuance / 3);
+		assert!(shares >= issuance / 3 - 1_000);
+
+		System::assert_last_event(
+			Event::PoolAssetAdded {
+				pool_id,
+				asset_id: NEW_ASSET,
+				amount: 100 * ONE,
+				shares,
+				share_recipient: BOB,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn added_asset_should_be_tradable() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			50 * ONE,
+			BOB
+		));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			NEW_ASSET,
+			ASSET_A,
+			10 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, NEW_ASSET, 90 * ONE);
+		assert!(Tokens::free_balance(ASSET_A, &BOB) > 100 * ONE);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_not_count_existing_balance_of_pool_account_as_initial_liquidity() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Tokens::transfer(NEW_ASSET, &BOB, &pool_account(pool_id), 50 * ONE));
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			50 * ONE,
+			ALICE
+		));
+
+		// shares are minted only for the transferred amount - a sixth of initial issuance
+		let shares = Tokens::free_balance(pool_id, &ALICE) - issuance;
+		assert!(shares <= issuance / 6);
+		assert!(shares > issuance / 7);
+		assert_balance!(pool_account(pool_id), NEW_ASSET, 100 * ONE);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_asset_is_already_in_pool() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_A, 100 * ONE, BOB),
+			Error::<Test>::AssetInPool
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_asset_is_not_registered() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, 1000, 100 * ONE, BOB),
+			Error::<Test>::AssetNotRegistered
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_amount_is_below_min_trading_limit() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, NEW_ASSET, 0, BOB),
+			Error::<Test>::InvalidInitialLiquidity
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_liquidity_account_has_insufficient_balance() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::root(), pool_id, NEW_ASSET, 101 * ONE, BOB),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
+
+#[test]
+fn add_pool_asset_should_fail_when_called_by_non_authority_origin() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::add_pool_asset(RuntimeOrigin::signed(BOB), pool_id, NEW_ASSET, 100 * ONE, BOB),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn retire_pool_asset_should_allow_asset_only_to_leave_pool() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C));
+
+		assert_eq!(Stableswap::retiring_asset(pool_id, ASSET_C), Some(()));
+		assert_eq!(
+			Stableswap::asset_tradability(pool_id, ASSET_C),
+			Tradability::BUY | Tradability::REMOVE_LIQUIDITY
+		);
+		System::assert_last_event(
+			Event::PoolAssetRetiring {
+				pool_id,
+				asset_id: ASSET_C,
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			Stableswap::sell(RuntimeOrigin::signed(BOB), pool_id, ASSET_C, ASSET_A, 10 * ONE, 0),
+			Error::<Test>::NotAllowed
+		);
+		assert_noop!(
+			Stableswap::add_liquidity(
+				RuntimeOrigin::signed(BOB),
+				pool_id,
+				vec![AssetAmount::new(ASSET_C, 10 * ONE)]
+			),
+			Error::<Test>::NotAllowed
+		);
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			ASSET_A,
+			ASSET_C,
+			10 * ONE,
+			0,
+		));
+	});
+}
+
+#[test]
+fn retire_pool_asset_should_fail_when_asset_is_already_retiring() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C));
+
+		assert_noop!(
+			Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C),
+			Error::<Test>::AssetRetiring
+		);
+	});
+}
+
+#[test]
+fn retire_pool_asset_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, NEW_ASSET),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn set_asset_tradable_state_should_fail_when_asset_is_retiring() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C));
+
+		assert_noop!(
+			Stableswap::set_asset_tradable_state(RuntimeOrigin::root(), pool_id, ASSET_C, Tradability::default()),
+			Error::<Test>::AssetRetiring
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_work_when_asset_is_drained() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			1_000,
+			BOB
+		));
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			Some(RateSource::Fixed((1, 1)))
+		));
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, NEW_ASSET));
+
+		assert_ok!(Stableswap::remove_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			ALICE
+		));
+
+		let pool = Stableswap::pools(pool_id).unwrap();
+		assert_eq!(pool.assets.to_vec(), vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_balance!(ALICE, NEW_ASSET, 1_000);
+		assert_balance!(pool_account(pool_id), NEW_ASSET, 0);
+		assert_eq!(Stableswap::retiring_asset(pool_id, NEW_ASSET), None);
+		assert_eq!(Stableswap::asset_rate_source(pool_id, NEW_ASSET), None);
+		assert_eq!(
+			Stableswap::asset_tradability(pool_id, NEW_ASSET),
+			Tradability::default()
+		);
+
+		System::assert_last_event(
+			Event::PoolAssetRemoved {
+				pool_id,
+				asset_id: NEW_ASSET,
+				amount: 1_000,
+				beneficiary: ALICE,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_asset_is_not_retiring() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C, ALICE),
+			Error::<Test>::AssetNotRetiring
+		);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_fail_when_asset_is_not_drained() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C));
+
+		assert_noop!(
+			Stableswap::remove_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_C, ALICE),
+			Error::<Test>::AssetNotDrained
+		);
+	});
+}
+
+#[test]
+fn retire_pool_asset_should_fail_when_pool_has_two_assets_only() {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![(ALICE, ASSET_A, 100 * ONE), (ALICE, ASSET_B, 100 * ONE)])
+		.with_registered_asset("a".as_bytes().to_vec(), ASSET_A, 12)
+		.with_registered_asset("b".as_bytes().to_vec(), ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![ASSET_A, ASSET_B].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(0),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(ASSET_A, 100 * ONE),
+					AssetAmount::new(ASSET_B, 100 * ONE),
+				],
+			},
+		)
+		.build()
+		.execute_with(|| {
+			let pool_id = get_pool_id_at(0);
+
+			assert_noop!(
+				Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, ASSET_B),
+				Error::<Test>::IncorrectAssets
+			);
+		});
+}
+
+#[test]
+fn add_pool_asset_should_provide_correct_values_in_the_hook() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			100 * ONE,
+			BOB
+		));
+
+		let (p, state) = last_liquidity_changed_hook_state().unwrap();
+		assert_eq!(p, pool_id);
+		assert_eq!(state.assets, vec![ASSET_A, ASSET_B, ASSET_C, NEW_ASSET]);
+		assert_eq!(state.before, vec![100 * ONE, 100 * ONE, 100 * ONE, 0]);
+		assert_eq!(state.after, vec![100 * ONE, 100 * ONE, 100 * ONE, 100 * ONE]);
+		assert_eq!(state.delta, vec![0, 0, 0, 100 * ONE]);
+		assert_eq!(state.issuance_before, issuance);
+		assert_eq!(state.issuance_after, Tokens::total_issuance(pool_id));
+		assert_eq!(state.share_prices.len(), 4);
+	});
+}
+
+#[test]
+fn remove_pool_asset_should_provide_correct_values_in_the_hook() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::add_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			1_000,
+			BOB
+		));
+		assert_ok!(Stableswap::retire_pool_asset(RuntimeOrigin::root(), pool_id, NEW_ASSET));
+		let issuance = Tokens::total_issuance(pool_id);
+
+		assert_ok!(Stableswap::remove_pool_asset(
+			RuntimeOrigin::root(),
+			pool_id,
+			NEW_ASSET,
+			ALICE
+		));
+
+		let (p, state) = last_liquidity_changed_hook_state().unwrap();
+		assert_eq!(p, pool_id);
+		assert_eq!(state.assets, vec![ASSET_A, ASSET_B, ASSET_C]);
+		assert_eq!(state.before, vec![100 * ONE, 100 * ONE, 100 * ONE]);
+		assert_eq!(state.after, vec![100 * ONE, 100 * ONE, 100 * ONE]);
+		assert_eq!(state.delta, vec![0, 0, 0]);
+		assert_eq!(state.issuance_before, issuance);
+		assert_eq!(state.issuance_after, issuance);
+		assert_eq!(state.share_prices.len(), 3);
+	});
+}
@@ -0,0 +1,485 @@
+use crate::tests::mock::*;
+use crate::types::{AssetAmount, PoolInfo, RateSource};
+use crate::{assert_balance, Error, Event};
+use std::num::NonZeroU16;
+
+use frame_support::{assert_noop, assert_ok};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::OraclePeriod;
+use hydradx_traits::router::{PoolType, TradeExecution};
+use sp_runtime::{DispatchError::BadOrigin, Permill};
+
+const RATED_ASSET: AssetId = 1;
+const STABLE_ASSET: AssetId = 2;
+const EQUIVALENT_ASSET_A: AssetId = 3;
+const EQUIVALENT_ASSET_B: AssetId = 4;
+
+fn pool(asset_a: AssetId, asset_b: AssetId) -> PoolInfo<AssetId, u64> {
+	PoolInfo::<AssetId, u64> {
+		assets: vec![asset_a, asset_b].try_into().unwrap(),
+		initial_amplification: NonZeroU16::new(100).unwrap(),
+		final_amplification: NonZeroU16::new(100).unwrap(),
+		initial_block: 0,
+		final_block: 0,
+		fee: Permill::from_percent(0),
+	}
+}
+
+// Pool at index 0 contains rated asset with reserve 100 and stable asset with reserve 200.
+// Pool at index 1 contains two assets with reserve 200 each - equivalent to pool 0 when rate of rated asset is 2.
+fn ext() -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(BOB, RATED_ASSET, 100 * ONE),
+			(BOB, STABLE_ASSET, 100 * ONE),
+			(BOB, EQUIVALENT_ASSET_A, 100 * ONE),
+			(BOB, EQUIVALENT_ASSET_B, 100 * ONE),
+			(ALICE, RATED_ASSET, 100 * ONE),
+			(ALICE, STABLE_ASSET, 200 * ONE),
+			(ALICE, EQUIVALENT_ASSET_A, 200 * ONE),
+			(ALICE, EQUIVALENT_ASSET_B, 200 * ONE),
+		])
+		.with_registered_asset("rated".as_bytes().to_vec(), RATED_ASSET, 12)
+		.with_registered_asset("stable".as_bytes().to_vec(), STABLE_ASSET, 12)
+		.with_registered_asset("eqa".as_bytes().to_vec(), EQUIVALENT_ASSET_A, 12)
+		.with_registered_asset("eqb".as_bytes().to_vec(), EQUIVALENT_ASSET_B, 12)
+		.with_pool(
+			ALICE,
+			pool(RATED_ASSET, STABLE_ASSET),
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(RATED_ASSET, 100 * ONE),
+					AssetAmount::new(STABLE_ASSET, 200 * ONE),
+				],
+			},
+		)
+		.with_pool(
+			ALICE,
+			pool(EQUIVALENT_ASSET_A, EQUIVALENT_ASSET_B),
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(EQUIVALENT_ASSET_A, 200 * ONE),
+					AssetAmount::new(EQUIVALENT_ASSET_B, 200 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn set_asset_rate_source_should_work_when_rate_is_valid() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		assert_eq!(
+			Stableswap::asset_rate_source(pool_id, RATED_ASSET),
+			Some(RateSource::Fixed((2, 1)))
+		);
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((2, 1)));
+		System::assert_last_event(
+			Event::RateSourceUpdated {
+				pool_id,
+				asset_id: RATED_ASSET,
+				source: Some(RateSource::Fixed((2, 1))),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_remove_rate_source_when_none_is_provided() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Feed),
+		));
+		assert_ok!(Stableswap::update_feed_rate(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			(2, 1)
+		));
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			None
+		));
+
+		assert_eq!(Stableswap::asset_rate_source(pool_id, RATED_ASSET), None);
+		assert_eq!(Stableswap::feed_rate(pool_id, RATED_ASSET), None);
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((1, 1)));
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_fail_when_rate_is_zero() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_asset_rate_source(
+				RuntimeOrigin::root(),
+				pool_id,
+				RATED_ASSET,
+				Some(RateSource::Fixed((0, 1)))
+			),
+			Error::<Test>::InvalidRate
+		);
+		assert_noop!(
+			Stableswap::set_asset_rate_source(
+				RuntimeOrigin::root(),
+				pool_id,
+				RATED_ASSET,
+				Some(RateSource::Fixed((1, 0)))
+			),
+			Error::<Test>::InvalidRate
+		);
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_fail_when_asset_is_not_in_pool() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_asset_rate_source(
+				RuntimeOrigin::root(),
+				pool_id,
+				EQUIVALENT_ASSET_A,
+				Some(RateSource::Fixed((2, 1)))
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_fail_when_origin_is_not_authority() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::set_asset_rate_source(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				RATED_ASSET,
+				Some(RateSource::Fixed((2, 1)))
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn update_feed_rate_should_work_when_rate_source_is_feed() {
+	ext().execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Feed),
+		));
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((1, 1)));
+
+		assert_ok!(Stableswap::update_feed_rate(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			(21, 10)
+		));
+
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((21, 10)));
+		System::assert_last_event(
+			Event::FeedRateUpdated {
+				pool_id,
+				asset_id: RATED_ASSET,
+				rate: (21, 10),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn update_feed_rate_should_fail_when_rate_source_is_not_feed() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::update_feed_rate(RuntimeOrigin::root(), pool_id, RATED_ASSET, (2, 1)),
+			Error::<Test>::NotFeedRateSource
+		);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		assert_noop!(
+			Stableswap::update_feed_rate(RuntimeOrigin::root(), pool_id, RATED_ASSET, (2, 1)),
+			Error::<Test>::NotFeedRateSource
+		);
+	});
+}
+
+#[test]
+fn update_feed_rate_should_fail_when_origin_is_not_feed_origin() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Feed),
+		));
+
+		assert_noop!(
+			Stableswap::update_feed_rate(RuntimeOrigin::signed(ALICE), pool_id, RATED_ASSET, (2, 1)),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn sell_should_apply_fixed_rate_of_asset_in() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let equivalent_pool_id = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			RATED_ASSET,
+			STABLE_ASSET,
+			10 * ONE,
+			0,
+		));
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			equivalent_pool_id,
+			EQUIVALENT_ASSET_A,
+			EQUIVALENT_ASSET_B,
+			20 * ONE,
+			0,
+		));
+
+		let received = Tokens::free_balance(STABLE_ASSET, &BOB) - 100 * ONE;
+		let equivalent_received = Tokens::free_balance(EQUIVALENT_ASSET_B, &BOB) - 100 * ONE;
+
+		assert_eq!(received, equivalent_received);
+		assert!(received > 19 * ONE);
+		assert_balance!(BOB, RATED_ASSET, 90 * ONE);
+	});
+}
+
+#[test]
+fn sell_should_apply_oracle_rate_of_asset_in() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let equivalent_pool_id = get_pool_id_at(1);
+
+		// one unit of the peg asset is worth half of the rated asset
+		set_oracle_price(RATED_ASSET, STABLE_ASSET, EmaPrice::new(1, 2));
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Oracle {
+				source: *b"testsrc_",
+				period: OraclePeriod::Short,
+				peg_asset: STABLE_ASSET,
+			}),
+		));
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((2, 1)));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			RATED_ASSET,
+			STABLE_ASSET,
+			10 * ONE,
+			0,
+		));
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			equivalent_pool_id,
+			EQUIVALENT_ASSET_A,
+			EQUIVALENT_ASSET_B,
+			20 * ONE,
+			0,
+		));
+
+		let received = Tokens::free_balance(STABLE_ASSET, &BOB) - 100 * ONE;
+		let equivalent_received = Tokens::free_balance(EQUIVALENT_ASSET_B, &BOB) - 100 * ONE;
+
+		assert_eq!(received, equivalent_received);
+	});
+}
+
+#[test]
+fn buy_should_apply_fixed_rate_of_asset_out() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let equivalent_pool_id = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			RATED_ASSET,
+			STABLE_ASSET,
+			10 * ONE,
+			100 * ONE,
+		));
+		assert_ok!(Stableswap::buy(
+			RuntimeOrigin::signed(BOB),
+			equivalent_pool_id,
+			EQUIVALENT_ASSET_A,
+			EQUIVALENT_ASSET_B,
+			20 * ONE,
+			100 * ONE,
+		));
+
+		let paid = 100 * ONE - Tokens::free_balance(STABLE_ASSET, &BOB);
+		let equivalent_paid = 100 * ONE - Tokens::free_balance(EQUIVALENT_ASSET_B, &BOB);
+
+		assert!(paid.abs_diff(equivalent_paid) <= 2);
+		assert!(paid > 20 * ONE);
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_keep_current_rate_when_switching_to_feed() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let equivalent_pool_id = get_pool_id_at(1);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Feed),
+		));
+
+		assert_eq!(Stableswap::feed_rate(pool_id, RATED_ASSET), Some((2, 1)));
+		assert_eq!(Stableswap::asset_rate(pool_id, RATED_ASSET), Ok((2, 1)));
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			RATED_ASSET,
+			STABLE_ASSET,
+			10 * ONE,
+			0,
+		));
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			equivalent_pool_id,
+			EQUIVALENT_ASSET_A,
+			EQUIVALENT_ASSET_B,
+			20 * ONE,
+			0,
+		));
+
+		let received = Tokens::free_balance(STABLE_ASSET, &BOB) - 100 * ONE;
+		let equivalent_received = Tokens::free_balance(EQUIVALENT_ASSET_B, &BOB) - 100 * ONE;
+
+		assert_eq!(received, equivalent_received);
+	});
+}
+
+#[test]
+fn set_asset_rate_source_should_fail_when_switching_to_feed_and_current_rate_is_not_available() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Oracle {
+				source: *b"testsrc_",
+				period: OraclePeriod::Short,
+				peg_asset: STABLE_ASSET,
+			}),
+		));
+
+		assert_noop!(
+			Stableswap::set_asset_rate_source(RuntimeOrigin::root(), pool_id, RATED_ASSET, Some(RateSource::Feed)),
+			Error::<Test>::RateUnavailable
+		);
+	});
+}
+
+#[test]
+fn calculate_sell_should_apply_rate_when_called_by_router() {
+	ext().execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_ok!(Stableswap::set_asset_rate_source(
+			RuntimeOrigin::root(),
+			pool_id,
+			RATED_ASSET,
+			Some(RateSource::Fixed((2, 1))),
+		));
+
+		let expected =
+			Stableswap::calculate_sell(PoolType::Stableswap(pool_id), RATED_ASSET, STABLE_ASSET, 10 * ONE).unwrap();
+
+		assert_ok!(Stableswap::sell(
+			RuntimeOrigin::signed(BOB),
+			pool_id,
+			RATED_ASSET,
+			STABLE_ASSET,
+			10 * ONE,
+			0,
+		));
+
+		assert_balance!(BOB, STABLE_ASSET, 100 * ONE + expected);
+		assert!(expected > 19 * ONE);
+	});
+}
@@ -1,5 +1,5 @@
 use crate::tests::mock::*;
-use crate::types::{AssetAmount, PoolInfo};
+use crate::types::{AssetAmount, PoolInfo, Tradability};
 use crate::{assert_balance, Error};
 use frame_support::{assert_noop, assert_ok};
 use sp_runtime::Permill;
@@ -992,3 +992,242 @@ fn removing_liquidity_with_exact_amount_should_apply_fee() {
 			assert_eq!(balance, 1988517979234162416);
 		});
 }
+
+fn three_asset_pool_ext(asset_a: AssetId, asset_b: AssetId, asset_c: AssetId) -> sp_io::TestExternalities {
+	ExtBuilder::default()
+		.with_endowed_accounts(vec![
+			(ALICE, asset_a, 100 * ONE),
+			(ALICE, asset_b, 200 * ONE),
+			(ALICE, asset_c, 300 * ONE),
+		])
+		.with_registered_asset("one".as_bytes().to_vec(), asset_a, 12)
+		.with_registered_asset("two".as_bytes().to_vec(), asset_b, 12)
+		.with_registered_asset("three".as_bytes().to_vec(), asset_c, 12)
+		.with_pool(
+			ALICE,
+			PoolInfo::<AssetId, u64> {
+				assets: vec![asset_a, asset_b, asset_c].try_into().unwrap(),
+				initial_amplification: NonZeroU16::new(100).unwrap(),
+				final_amplification: NonZeroU16::new(100).unwrap(),
+				initial_block: 0,
+				final_block: 0,
+				fee: Permill::from_percent(1),
+			},
+			InitialLiquidity {
+				account: ALICE,
+				assets: vec![
+					AssetAmount::new(asset_a, 100 * ONE),
+					AssetAmount::new(asset_b, 200 * ONE),
+					AssetAmount::new(asset_c, 300 * ONE),
+				],
+			},
+		)
+		.build()
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_work_when_withdrawing_part_of_shares() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		System::set_block_number(1);
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+
+		let issuance = Tokens::total_issuance(pool_id);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 4;
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![AssetAmount::new(asset_a, 24 * ONE)],
+		));
+
+		let expected_a = 100 * ONE * shares / issuance;
+		let expected_b = 200 * ONE * shares / issuance;
+		let expected_c = 300 * ONE * shares / issuance;
+
+		assert_balance!(ALICE, asset_a, expected_a);
+		assert_balance!(ALICE, asset_b, expected_b);
+		assert_balance!(ALICE, asset_c, expected_c);
+		assert_balance!(ALICE, pool_id, issuance - shares);
+		assert_balance!(pool_account, asset_a, 100 * ONE - expected_a);
+		assert_balance!(pool_account, asset_b, 200 * ONE - expected_b);
+		assert_balance!(pool_account, asset_c, 300 * ONE - expected_c);
+
+		System::assert_last_event(
+			crate::Event::LiquidityRemoved {
+				pool_id,
+				who: ALICE,
+				shares,
+				amounts: vec![
+					AssetAmount::new(asset_a, expected_a),
+					AssetAmount::new(asset_b, expected_b),
+					AssetAmount::new(asset_c, expected_c),
+				],
+				fee: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_empty_pool_when_withdrawing_all_shares() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let pool_account = pool_account(pool_id);
+
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_ok!(Stableswap::remove_liquidity(
+			RuntimeOrigin::signed(ALICE),
+			pool_id,
+			shares,
+			vec![
+				AssetAmount::new(asset_a, 100 * ONE),
+				AssetAmount::new(asset_b, 200 * ONE),
+				AssetAmount::new(asset_c, 300 * ONE),
+			],
+		));
+
+		assert_balance!(ALICE, asset_a, 100 * ONE);
+		assert_balance!(ALICE, asset_b, 200 * ONE);
+		assert_balance!(ALICE, asset_c, 300 * ONE);
+		assert_balance!(ALICE, pool_id, 0u128);
+		assert_balance!(pool_account, asset_a, 0u128);
+		assert_balance!(pool_account, asset_b, 0u128);
+		assert_balance!(pool_account, asset_c, 0u128);
+		assert_eq!(Tokens::total_issuance(pool_id), 0u128);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_is_not_reached() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 2;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(asset_b, 101 * ONE)],
+			),
+			Error::<Test>::SlippageLimit
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_asset_is_not_in_pool() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 2;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(HDX, ONE)],
+			),
+			Error::<Test>::AssetNotInPool
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_min_amount_asset_is_duplicated() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 2;
+
+		assert_noop!(
+			Stableswap::remove_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				pool_id,
+				shares,
+				vec![AssetAmount::new(asset_a, ONE), AssetAmount::new(asset_a, ONE)],
+			),
+			Error::<Test>::IncorrectAssets
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_removing_liquidity_of_asset_is_not_allowed() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE) / 2;
+
+		assert_ok!(Stableswap::set_asset_tradable_state(
+			RuntimeOrigin::root(),
+			pool_id,
+			asset_c,
+			Tradability::SELL | Tradability::BUY,
+		));
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares, vec![]),
+			Error::<Test>::NotAllowed
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_shares_is_zero() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, 0, vec![]),
+			Error::<Test>::InvalidAssetAmount
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_proportionally_should_fail_when_shares_are_insufficient() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+	let asset_c: AssetId = 3;
+
+	three_asset_pool_ext(asset_a, asset_b, asset_c).execute_with(|| {
+		let pool_id = get_pool_id_at(0);
+		let shares = Tokens::free_balance(pool_id, &ALICE);
+
+		assert_noop!(
+			Stableswap::remove_liquidity(RuntimeOrigin::signed(ALICE), pool_id, shares + 1, vec![]),
+			Error::<Test>::InsufficientShares
+		);
+	});
+}
@@ -4,7 +4,7 @@ use crate::{assert_balance, to_precision, Error};
 use std::num::NonZeroU16;
 
 use frame_support::{assert_noop, assert_ok};
-use sp_runtime::Permill;
+use sp_runtime::{traits::Zero, Permill};
 
 #[test]
 fn sell_should_work_when_correct_input_provided() {
@@ -251,6 +251,118 @@ fn buy_should_work_when_fee_is_set() {
 		});
 }
 
+#[test]
+fn sell_should_apply_fee_discount() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	let sell = |fee: Permill, discount: Permill| -> Balance {
+		let mut received = 0;
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+			.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+			.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+			.with_fee_discount(discount)
+			.with_pool(
+				ALICE,
+				PoolInfo::<AssetId, u64> {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(100).unwrap(),
+					initial_block: 0,
+					final_block: 0,
+					fee,
+				},
+				InitialLiquidity {
+					account: ALICE,
+					assets: vec![
+						AssetAmount::new(asset_a, 100 * ONE),
+						AssetAmount::new(asset_b, 100 * ONE),
+					],
+				},
+			)
+			.build()
+			.execute_with(|| {
+				let pool_id = get_pool_id_at(0);
+
+				assert_ok!(Stableswap::sell(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					asset_a,
+					asset_b,
+					30 * ONE,
+					25 * ONE,
+				));
+
+				received = Tokens::free_balance(asset_b, &BOB);
+			});
+		received
+	};
+
+	let discounted = sell(Permill::from_percent(10), Permill::from_percent(50));
+	let expected = sell(Permill::from_percent(5), Permill::zero());
+	let without_discount = sell(Permill::from_percent(10), Permill::zero());
+
+	assert_eq!(discounted, expected);
+	assert!(discounted > without_discount);
+}
+
+#[test]
+fn buy_should_apply_fee_discount() {
+	let asset_a: AssetId = 1;
+	let asset_b: AssetId = 2;
+
+	let buy = |fee: Permill, discount: Permill| -> Balance {
+		let mut sold = 0;
+		ExtBuilder::default()
+			.with_endowed_accounts(vec![(BOB, 1, 200 * ONE), (ALICE, 1, 200 * ONE), (ALICE, 2, 200 * ONE)])
+			.with_registered_asset("one".as_bytes().to_vec(), 1, 12)
+			.with_registered_asset("two".as_bytes().to_vec(), 2, 12)
+			.with_fee_discount(discount)
+			.with_pool(
+				ALICE,
+				PoolInfo::<AssetId, u64> {
+					assets: vec![asset_a, asset_b].try_into().unwrap(),
+					initial_amplification: NonZeroU16::new(100).unwrap(),
+					final_amplification: NonZeroU16::new(100).unwrap(),
+					initial_block: 0,
+					final_block: 0,
+					fee,
+				},
+				InitialLiquidity {
+					account: ALICE,
+					assets: vec![
+						AssetAmount::new(asset_a, 100 * ONE),
+						AssetAmount::new(asset_b, 100 * ONE),
+					],
+				},
+			)
+			.build()
+			.execute_with(|| {
+				let pool_id = get_pool_id_at(0);
+
+				assert_ok!(Stableswap::buy(
+					RuntimeOrigin::signed(BOB),
+					pool_id,
+					asset_b,
+					asset_a,
+					30 * ONE,
+					35 * ONE,
+				));
+
+				sold = 200 * ONE - Tokens::free_balance(asset_a, &BOB);
+			});
+		sold
+	};
+
+	let discounted = buy(Permill::from_percent(10), Permill::from_percent(50));
+	let expected = buy(Permill::from_percent(5), Permill::zero());
+	let without_discount = buy(Permill::from_percent(10), Permill::zero());
+
+	assert_eq!(discounted, expected);
+	assert!(discounted < without_discount);
+}
+
 #[test]
 fn sell_should_fail_when_insufficient_amount_is_provided() {
 	let asset_a: AssetId = 1000;
@@ -1,9 +1,9 @@
 use crate::types::AssetAmount;
-use crate::{Balance, Config, Error, Pallet, Pools, D_ITERATIONS, Y_ITERATIONS};
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use crate::{Balance, Config, Error, Pallet, Pools};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
 use orml_traits::MultiCurrency;
-use sp_runtime::{ArithmeticError, DispatchError};
-use sp_std::vec;
+use sp_runtime::{traits::Zero, DispatchError, Permill};
+use sp_std::{vec, vec::Vec};
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -22,18 +22,20 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 					let asset_idx = pool
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.balances::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 
 					let amplification = Self::get_amplification(&pool);
-					let (amount, _) = hydra_dx_math::stableswap::calculate_withdraw_one_asset::<
-						D_ITERATIONS,
-						Y_ITERATIONS,
-					>(&balances, amount_in, asset_idx, share_issuance, amplification, pool.fee)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					let (amount, _) = Self::calculate_withdraw_one_asset_amount(
+						pool_id,
+						&pool,
+						&balances,
+						amount_in,
+						asset_idx,
+						share_issuance,
+						amplification,
+					)
+					.map_err(ExecutorError::Error)?;
 
 					Ok(amount)
 				} else if asset_out == pool_id {
@@ -48,8 +50,11 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 
 					Ok(share_amount)
 				} else {
-					let (amount_out, _) = Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in)
-						.map_err(ExecutorError::Error)?;
+					// Fee discount is not applied as the calculation is not bound to an account.
+					// Executed sell can only yield more, so the calculated amount is a safe estimate.
+					let (amount_out, _) =
+						Self::calculate_out_amount(pool_id, asset_in, asset_out, amount_in, Permill::zero())
+							.map_err(ExecutorError::Error)?;
 
 					Ok(amount_out)
 				}
@@ -73,22 +78,20 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 					let asset_idx = pool
 						.find_asset(asset_in)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.balances::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
-					let liqudity = hydra_dx_math::stableswap::calculate_add_one_asset::<D_ITERATIONS, Y_ITERATIONS>(
+					let liqudity = Self::calculate_add_one_asset_amount(
+						pool_id,
+						&pool,
 						&balances,
 						amount_out,
 						asset_idx,
 						share_issuance,
 						amplification,
-						pool.fee,
 					)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					.map_err(ExecutorError::Error)?;
 
 					Ok(liqudity.0)
 				} else if asset_in == pool_id {
@@ -97,30 +100,31 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 					let asset_idx = pool
 						.find_asset(asset_out)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::AssetNotInPool.into()))?;
-					let pool_account = Self::pool_account(pool_id);
-					let balances = pool
-						.balances::<T>(&pool_account)
-						.ok_or_else(|| ExecutorError::Error(Error::<T>::UnknownDecimals.into()))?;
+					let balances = Self::pool_reserves(pool_id, &pool).map_err(ExecutorError::Error)?;
 					let share_issuance = T::Currency::total_issuance(pool_id);
 					let amplification = Self::get_amplification(&pool);
 
 					let pool = Pools::<T>::get(pool_id)
 						.ok_or_else(|| ExecutorError::Error(Error::<T>::PoolNotFound.into()))?;
 
-					let shares_amount = hydra_dx_math::stableswap::calculate_shares_for_amount::<D_ITERATIONS>(
+					let shares_amount = Self::calculate_shares_for_asset_amount(
+						pool_id,
+						&pool,
 						&balances,
 						asset_idx,
 						amount_out,
 						amplification,
 						share_issuance,
-						pool.fee,
 					)
-					.ok_or_else(|| ExecutorError::Error(ArithmeticError::Overflow.into()))?;
+					.map_err(ExecutorError::Error)?;
 
 					Ok(shares_amount)
 				} else {
-					let (amount_in, _) = Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out)
-						.map_err(ExecutorError::Error)?;
+					// Fee discount is not applied as the calculation is not bound to an account.
+					// Executed buy can only cost less, so the calculated amount is a safe estimate.
+					let (amount_in, _) =
+						Self::calculate_in_amount(pool_id, asset_in, asset_out, amount_out, Permill::zero())
+							.map_err(ExecutorError::Error)?;
 
 					Ok(amount_in)
 				}
@@ -198,3 +202,26 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, T::AssetId, Balan
 		}
 	}
 }
+
+impl<T: Config> PoolAssetPairs<T::AssetId> for Pallet<T> {
+	fn asset_pairs() -> Vec<Trade<T::AssetId>> {
+		let mut pairs = Vec::new();
+		for (pool_id, pool) in Pools::<T>::iter() {
+			for (idx, asset_a) in pool.assets.iter().enumerate() {
+				pairs.push(Trade {
+					pool: PoolType::Stableswap(pool_id),
+					asset_in: pool_id,
+					asset_out: *asset_a,
+				});
+				for asset_b in pool.assets.iter().skip(idx + 1) {
+					pairs.push(Trade {
+						pool: PoolType::Stableswap(pool_id),
+						asset_in: *asset_a,
+						asset_out: *asset_b,
+					});
+				}
+			}
+		}
+		pairs
+	}
+}
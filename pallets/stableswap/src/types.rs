@@ -12,6 +12,7 @@ use frame_support::traits::ConstU32;
 use frame_support::weights::Weight;
 use frame_support::BoundedVec;
 use hydra_dx_math::stableswap::types::AssetReserve;
+use hydradx_traits::oracle::{OraclePeriod, Source};
 use orml_traits::MultiCurrency;
 use scale_info::TypeInfo;
 use sp_core::RuntimeDebug;
@@ -54,6 +55,7 @@ where
 		self.assets.len() >= 2 && has_unique_elements(&mut self.assets.iter())
 	}
 
+	/// Reserves of pool assets held by `account`, without asset rates. See `Pallet::pool_reserves` for rated reserves.
 	pub fn balances<T: Config>(&self, account: &T::AccountId) -> Option<Vec<AssetReserve>>
 	where
 		T::AssetId: From<AssetId>,
@@ -63,15 +65,28 @@ where
 			.map(|asset| {
 				let reserve = T::Currency::free_balance((*asset).into(), account);
 				let decimals = Pallet::<T>::retrieve_decimals((*asset).into())?;
-				Some(AssetReserve {
-					amount: reserve,
-					decimals,
-				})
+				Some(AssetReserve::new(reserve, decimals))
 			})
 			.collect()
 	}
 }
 
+/// Source of the rate at which a pool asset is valued against other assets of the pool.
+/// Assets without a rate source are valued 1:1.
+#[derive(Encode, Decode, Eq, PartialEq, Clone, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum RateSource<AssetId> {
+	/// Constant rate given as (numerator, denominator).
+	Fixed((Balance, Balance)),
+	/// Oracle price of the asset denominated in `peg_asset`.
+	Oracle {
+		source: Source,
+		period: OraclePeriod,
+		peg_asset: AssetId,
+	},
+	/// Rate provided by `RateFeedOrigin` via `update_feed_rate`.
+	Feed,
+}
+
 #[derive(Debug, Clone, Encode, Decode, PartialEq, Eq, TypeInfo, Default)]
 pub struct AssetAmount<AssetId> {
 	pub asset_id: AssetId,
@@ -58,6 +58,15 @@ pub trait WeightInfo {
 	fn set_asset_tradable_state() -> Weight;
 	fn update_pool_fee() -> Weight;
 	fn update_amplification() -> Weight;
+	fn stop_amplification_change() -> Weight;
+	fn set_asset_rate_source() -> Weight;
+	fn update_feed_rate() -> Weight;
+	fn remove_liquidity() -> Weight;
+	fn set_dynamic_fee() -> Weight;
+	fn add_pool_asset() -> Weight;
+	fn retire_pool_asset() -> Weight;
+	fn remove_pool_asset() -> Weight;
+	fn destroy_pool() -> Weight;
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
 }
@@ -98,10 +107,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn add_liquidity() -> Weight {
 		// Minimum execution time: 1_140_516 nanoseconds.
 		Weight::from_ref_time(1_143_845_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(33 as u64))
+			.saturating_add(T::DbWeight::get().reads(34 as u64))
 			.saturating_add(T::DbWeight::get().writes(14 as u64))
 	}
 	// Storage: Stableswap Pools (r:1 w:0)
@@ -122,10 +133,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn add_liquidity_shares() -> Weight {
 		// Minimum execution time: 783_827 nanoseconds.
 		Weight::from_ref_time(787_422_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().reads(21 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:1 w:0)
@@ -148,10 +161,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn remove_liquidity_one_asset() -> Weight {
 		// Minimum execution time: 817_053 nanoseconds.
 		Weight::from_ref_time(821_506_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(21 as u64))
+			.saturating_add(T::DbWeight::get().reads(22 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:1 w:0)
@@ -174,10 +189,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn withdraw_asset_amount() -> Weight {
 		// Minimum execution time: 1_124_456 nanoseconds.
 		Weight::from_ref_time(1_133_226_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(T::DbWeight::get().reads(23 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:2 w:0)
@@ -200,10 +217,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn sell() -> Weight {
 		// Minimum execution time: 768_776 nanoseconds.
 		Weight::from_ref_time(772_108_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(22 as u64))
+			.saturating_add(T::DbWeight::get().reads(23 as u64))
 			.saturating_add(T::DbWeight::get().writes(7 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:2 w:0)
@@ -226,10 +245,12 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn buy() -> Weight {
 		// Minimum execution time: 747_434 nanoseconds.
 		Weight::from_ref_time(751_463_000 as u64)
-			.saturating_add(T::DbWeight::get().reads(23 as u64))
+			.saturating_add(T::DbWeight::get().reads(24 as u64))
 			.saturating_add(T::DbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap Pools (r:1 w:0)
@@ -258,6 +279,146 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as u64))
 			.saturating_add(T::DbWeight::get().writes(1 as u64))
 	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn stop_amplification_change() -> Weight {
+		// Minimum execution time: 22_671 nanoseconds.
+		Weight::from_ref_time(23_215_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:1)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn set_asset_rate_source() -> Weight {
+		// Minimum execution time: 27_312 nanoseconds.
+		Weight::from_ref_time(27_874_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Stableswap AssetRateSources (r:1 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn update_feed_rate() -> Weight {
+		// Minimum execution time: 23_051 nanoseconds.
+		Weight::from_ref_time(23_611_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Tokens Accounts (r:11 w:11)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:5 w:0)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadataMap (r:5 w:0)
+	// Proof: AssetRegistry AssetMetadataMap (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:5 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: EmaOracle Accumulator (r:1 w:1)
+	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	fn remove_liquidity() -> Weight {
+		// Minimum execution time: 493_708 nanoseconds.
+		Weight::from_ref_time(497_216_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(30 as u64))
+			.saturating_add(T::DbWeight::get().writes(14 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:0 w:1)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_dynamic_fee() -> Weight {
+		// Minimum execution time: 21_907 nanoseconds.
+		Weight::from_ref_time(22_384_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadataMap (r:6 w:0)
+	// Proof: AssetRegistry AssetMetadataMap (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:6 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:4 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn add_pool_asset() -> Weight {
+		// Minimum execution time: 362_412 nanoseconds.
+		Weight::from_ref_time(364_801_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(20 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:1 w:1)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:1)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn retire_pool_asset() -> Weight {
+		// Minimum execution time: 27_153 nanoseconds.
+		Weight::from_ref_time(27_690_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(2 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:1 w:1)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:1)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:1)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn remove_pool_asset() -> Weight {
+		// Minimum execution time: 74_836 nanoseconds.
+		Weight::from_ref_time(75_942_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:10 w:10)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Duster AccountBlacklist (r:0 w:1)
+	// Proof: Duster AccountBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:5)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:5)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:5)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:0 w:5)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:0 w:1)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn destroy_pool() -> Weight {
+		// Minimum execution time: 268_517 nanoseconds.
+		Weight::from_ref_time(271_304_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(13 as u64))
+			.saturating_add(T::DbWeight::get().writes(34 as u64))
+	}
 	// Storage: Stableswap Pools (r:1 w:0)
 	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:7 w:4)
@@ -278,6 +439,8 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `c` is `[0, 1]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32) -> Weight {
@@ -286,7 +449,7 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(Weight::from_ref_time(304_810_632 as u64).saturating_mul(c as u64))
 			// Standard Error: 262_827
 			.saturating_add(Weight::from_ref_time(742_434_706 as u64).saturating_mul(e as u64))
-			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
 			.saturating_add(T::DbWeight::get().reads((11 as u64).saturating_mul(e as u64)))
 			.saturating_add(T::DbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
 	}
@@ -310,6 +473,8 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32) -> Weight {
@@ -318,7 +483,7 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(Weight::from_ref_time(13_465_646 as u64).saturating_mul(c as u64))
 			// Standard Error: 7_920_813
 			.saturating_add(Weight::from_ref_time(456_375_218 as u64).saturating_mul(e as u64))
-			.saturating_add(T::DbWeight::get().reads(11 as u64))
+			.saturating_add(T::DbWeight::get().reads(12 as u64))
 			.saturating_add(T::DbWeight::get().reads((12 as u64).saturating_mul(e as u64)))
 			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(e as u64)))
 	}
@@ -358,10 +523,12 @@ impl WeightInfo for () {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn add_liquidity() -> Weight {
 		// Minimum execution time: 1_140_516 nanoseconds.
 		Weight::from_ref_time(1_143_845_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(33 as u64))
+			.saturating_add(RocksDbWeight::get().reads(34 as u64))
 			.saturating_add(RocksDbWeight::get().writes(14 as u64))
 	}
 	// Storage: Stableswap Pools (r:1 w:0)
@@ -382,10 +549,12 @@ impl WeightInfo for () {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn add_liquidity_shares() -> Weight {
 		// Minimum execution time: 783_827 nanoseconds.
 		Weight::from_ref_time(787_422_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().reads(21 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:1 w:0)
@@ -408,10 +577,12 @@ impl WeightInfo for () {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn remove_liquidity_one_asset() -> Weight {
 		// Minimum execution time: 817_053 nanoseconds.
 		Weight::from_ref_time(821_506_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(21 as u64))
+			.saturating_add(RocksDbWeight::get().reads(22 as u64))
 			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:1 w:0)
@@ -434,10 +605,12 @@ impl WeightInfo for () {
 	// Proof: MultiTransactionPayment AcceptedCurrencies (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn withdraw_asset_amount() -> Weight {
 		// Minimum execution time: 1_124_456 nanoseconds.
 		Weight::from_ref_time(1_133_226_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(22 as u64))
+			.saturating_add(RocksDbWeight::get().reads(23 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:2 w:0)
@@ -460,10 +633,12 @@ impl WeightInfo for () {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn sell() -> Weight {
 		// Minimum execution time: 768_776 nanoseconds.
 		Weight::from_ref_time(772_108_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(22 as u64))
+			.saturating_add(RocksDbWeight::get().reads(23 as u64))
 			.saturating_add(RocksDbWeight::get().writes(7 as u64))
 	}
 	// Storage: Stableswap AssetTradability (r:2 w:0)
@@ -486,10 +661,12 @@ impl WeightInfo for () {
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	fn buy() -> Weight {
 		// Minimum execution time: 747_434 nanoseconds.
 		Weight::from_ref_time(751_463_000 as u64)
-			.saturating_add(RocksDbWeight::get().reads(23 as u64))
+			.saturating_add(RocksDbWeight::get().reads(24 as u64))
 			.saturating_add(RocksDbWeight::get().writes(6 as u64))
 	}
 	// Storage: Stableswap Pools (r:1 w:0)
@@ -518,6 +695,146 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as u64))
 			.saturating_add(RocksDbWeight::get().writes(1 as u64))
 	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	fn stop_amplification_change() -> Weight {
+		// Minimum execution time: 22_671 nanoseconds.
+		Weight::from_ref_time(23_215_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:1)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	fn set_asset_rate_source() -> Weight {
+		// Minimum execution time: 27_312 nanoseconds.
+		Weight::from_ref_time(27_874_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Stableswap AssetRateSources (r:1 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(72), added: 2547, mode: MaxEncodedLen)
+	fn update_feed_rate() -> Weight {
+		// Minimum execution time: 23_051 nanoseconds.
+		Weight::from_ref_time(23_611_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Tokens Accounts (r:11 w:11)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:5 w:0)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadataMap (r:5 w:0)
+	// Proof: AssetRegistry AssetMetadataMap (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:5 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(54), added: 2529, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: EmaOracle Accumulator (r:1 w:1)
+	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	fn remove_liquidity() -> Weight {
+		// Minimum execution time: 493_708 nanoseconds.
+		Weight::from_ref_time(497_216_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(30 as u64))
+			.saturating_add(RocksDbWeight::get().writes(14 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:0 w:1)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn set_dynamic_fee() -> Weight {
+		// Minimum execution time: 21_907 nanoseconds.
+		Weight::from_ref_time(22_384_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: AssetRegistry Assets (r:1 w:0)
+	// Proof: AssetRegistry Assets (max_values: None, max_size: Some(87), added: 2562, mode: MaxEncodedLen)
+	// Storage: AssetRegistry AssetMetadataMap (r:6 w:0)
+	// Proof: AssetRegistry AssetMetadataMap (max_values: None, max_size: Some(46), added: 2521, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:6 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:4 w:0)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn add_pool_asset() -> Weight {
+		// Minimum execution time: 362_412 nanoseconds.
+		Weight::from_ref_time(364_801_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(20 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:0)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:1 w:1)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:1)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	fn retire_pool_asset() -> Weight {
+		// Minimum execution time: 27_153 nanoseconds.
+		Weight::from_ref_time(27_690_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(2 as u64))
+			.saturating_add(RocksDbWeight::get().writes(2 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:1 w:1)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:2 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:1)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:1)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:1)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn remove_pool_asset() -> Weight {
+		// Minimum execution time: 74_836 nanoseconds.
+		Weight::from_ref_time(75_942_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(5 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: Stableswap Pools (r:1 w:1)
+	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:0)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:10 w:10)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Duster AccountBlacklist (r:0 w:1)
+	// Proof: Duster AccountBlacklist (max_values: None, max_size: Some(48), added: 2523, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetTradability (r:0 w:5)
+	// Proof: Stableswap AssetTradability (max_values: None, max_size: Some(41), added: 2516, mode: MaxEncodedLen)
+	// Storage: Stableswap AssetRateSources (r:0 w:5)
+	// Proof: Stableswap AssetRateSources (max_values: None, max_size: Some(67), added: 2542, mode: MaxEncodedLen)
+	// Storage: Stableswap FeedRates (r:0 w:5)
+	// Proof: Stableswap FeedRates (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Stableswap RetiringAssets (r:0 w:5)
+	// Proof: Stableswap RetiringAssets (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:0 w:1)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
+	fn destroy_pool() -> Weight {
+		// Minimum execution time: 268_517 nanoseconds.
+		Weight::from_ref_time(271_304_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(13 as u64))
+			.saturating_add(RocksDbWeight::get().writes(34 as u64))
+	}
 	// Storage: Stableswap Pools (r:1 w:0)
 	// Proof: Stableswap Pools (max_values: None, max_size: Some(57), added: 2532, mode: MaxEncodedLen)
 	// Storage: Tokens Accounts (r:7 w:4)
@@ -538,6 +855,8 @@ impl WeightInfo for () {
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
 	// Storage: MultiTransactionPayment AccountCurrencyMap (r:0 w:1)
 	// Proof: MultiTransactionPayment AccountCurrencyMap (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `c` is `[0, 1]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_sell(c: u32, e: u32) -> Weight {
@@ -546,7 +865,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(304_810_632 as u64).saturating_mul(c as u64))
 			// Standard Error: 262_827
 			.saturating_add(Weight::from_ref_time(742_434_706 as u64).saturating_mul(e as u64))
-			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
 			.saturating_add(RocksDbWeight::get().reads((11 as u64).saturating_mul(e as u64)))
 			.saturating_add(RocksDbWeight::get().writes((7 as u64).saturating_mul(e as u64)))
 	}
@@ -570,6 +889,8 @@ impl WeightInfo for () {
 	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
 	// Storage: EmaOracle Accumulator (r:1 w:1)
 	// Proof: EmaOracle Accumulator (max_values: Some(1), max_size: Some(5921), added: 6416, mode: MaxEncodedLen)
+	// Storage: Stableswap DynamicFeeMultipliers (r:1 w:0)
+	// Proof: Stableswap DynamicFeeMultipliers (max_values: None, max_size: Some(24), added: 2499, mode: MaxEncodedLen)
 	/// The range of component `c` is `[1, 2]`.
 	/// The range of component `e` is `[0, 1]`.
 	fn router_execution_buy(c: u32, e: u32) -> Weight {
@@ -578,7 +899,7 @@ impl WeightInfo for () {
 			.saturating_add(Weight::from_ref_time(13_465_646 as u64).saturating_mul(c as u64))
 			// Standard Error: 7_920_813
 			.saturating_add(Weight::from_ref_time(456_375_218 as u64).saturating_mul(e as u64))
-			.saturating_add(RocksDbWeight::get().reads(11 as u64))
+			.saturating_add(RocksDbWeight::get().reads(12 as u64))
 			.saturating_add(RocksDbWeight::get().reads((12 as u64).saturating_mul(e as u64)))
 			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(e as u64)))
 	}
@@ -0,0 +1,114 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use orml_traits::MultiCurrencyExtended;
+
+const ONE: Balance = 1_000_000_000_000;
+const HDX: u32 = 0;
+const DAI: u32 = 2;
+
+const SEED: u32 = 0;
+
+fn create_funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId
+where
+	T::Currencies: MultiCurrencyExtended<T::AccountId, Amount = i128>,
+	T::AssetId: From<u32>,
+{
+	let caller: T::AccountId = account(name, index, SEED);
+
+	T::Currencies::update_balance(HDX.into(), &caller, (1_000 * ONE) as i128).unwrap();
+
+	caller
+}
+
+fn place_take_profit_order<T: Config>(owner: T::AccountId) -> Result<OrderId, DispatchError>
+where
+	T::AssetId: From<u32>,
+{
+	let order_id = Pallet::<T>::next_order_id();
+
+	Pallet::<T>::place_order(
+		RawOrigin::Signed(owner).into(),
+		HDX.into(),
+		DAI.into(),
+		10 * ONE,
+		0,
+		FixedU128::from(100),
+		TriggerKind::TakeProfit,
+		OraclePeriod::Short,
+		BoundedVec::default(),
+	)?;
+
+	Ok(order_id)
+}
+
+benchmarks! {
+	where_clause { where
+		T::Currencies: MultiCurrencyExtended<T::AccountId, Amount=i128>,
+		T::AssetId: From<u32>,
+	}
+
+	place_order {
+		let owner = create_funded_account::<T>("owner", 1);
+	}:  _(RawOrigin::Signed(owner.clone()), HDX.into(), DAI.into(), 10 * ONE, 0, FixedU128::from(100), TriggerKind::TakeProfit, OraclePeriod::Short, BoundedVec::default())
+	verify {
+		assert!(<Orders<T>>::get(0).is_some());
+		assert_eq!(T::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX.into(), &owner), 10 * ONE);
+	}
+
+	cancel_order {
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_take_profit_order::<T>(owner.clone())?;
+	}:  _(RawOrigin::Signed(owner.clone()), order_id)
+	verify {
+		assert!(<Orders<T>>::get(order_id).is_none());
+		assert_eq!(T::Currencies::reserved_balance_named(&NAMED_RESERVE_ID, HDX.into(), &owner), 0);
+	}
+
+	check_order {
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_take_profit_order::<T>(owner)?;
+		let mut is_triggered = true;
+	}: {
+		let order = <Orders<T>>::get(order_id).unwrap();
+		is_triggered = order.is_triggered(FixedU128::one());
+	}
+	verify {
+		assert!(!is_triggered);
+	}
+
+	execute_order {
+		let owner = create_funded_account::<T>("owner", 1);
+		let order_id = place_take_profit_order::<T>(owner)?;
+		let order = <Orders<T>>::get(order_id).unwrap();
+	}: { Pallet::<T>::execute_order(order_id, &order)?; }
+	verify {
+		assert!(<Orders<T>>::get(order_id).is_none());
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Pallet;
+	use crate::tests::mock::*;
+	use frame_benchmarking::impl_benchmark_test_suite;
+
+	impl_benchmark_test_suite!(Pallet, super::ExtBuilder::default().build(), super::Test);
+}
@@ -0,0 +1,575 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//
+// # Triggered orders pallet
+// ## General description
+// This pallet provides stop-loss and take-profit orders which are triggered by oracle prices.
+// It allows anyone to `place_order` by specifying a pair of assets (in and out), the amount to sell, the minimum
+// amount to receive, a trigger price and the oracle period used to determine the price.
+//
+// The trigger price is the price of asset in denominated in asset out. A stop-loss order is triggered when the oracle
+// price falls to or below the trigger price, a take-profit order when the oracle price rises to or above it.
+// A triggered order is executed as a sell via the router.
+//
+// ## Notes
+// Prices are taken from the aggregated EMA oracle instead of the spot price, so an order cannot be triggered by
+// manipulating a pool within one block. The oracle price of the pair is derived from the prices of both assets
+// against the hub asset.
+//
+// Amount in is reserved when an order is placed and released when the order is executed or cancelled.
+//
+// Orders are checked in `on_initialize`. At most `MaxOrdersCheckedPerBlock` orders are checked in a block and
+// the weight of checking and executing them is limited by `MaxCheckOrdersWeight`. The following block continues
+// from the last checked order. If the execution of a triggered order fails, the order stays open and it is retried
+// once checked again. An order whose execution failed `MaxExecutionFailures` times is terminated and its amount in
+// is released.
+//
+// ## Dispatachable functions
+// * `place_order` -  create a new triggered order.
+// * `cancel_order` - cancel an open triggered order.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::MaxEncodedLen;
+use frame_support::{pallet_prelude::*, transactional};
+use frame_system::{ensure_signed, pallet_prelude::OriginFor, RawOrigin};
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
+use hydradx_traits::router::{AmmTradeWeights, AmountInAndOut, RouterT, Trade};
+use orml_traits::{MultiCurrency, NamedMultiReservableCurrency};
+use sp_runtime::{
+	traits::{ConstU32, One, Zero},
+	ArithmeticError, DispatchError, FixedPointNumber, FixedU128,
+};
+use sp_std::vec::Vec;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod benchmarks;
+
+pub mod weights;
+
+use weights::WeightInfo;
+
+// Re-export pallet items so that they can be accessed from the crate namespace.
+pub use pallet::*;
+
+pub type Balance = u128;
+pub type OrderId = u32;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const NAMED_RESERVE_ID: NamedReserveIdentifier = *b"trigordr";
+
+pub const MAX_NUMBER_OF_TRADES: u32 = 5;
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, Copy, TypeInfo, MaxEncodedLen)]
+pub enum TriggerKind {
+	/// Triggered when the oracle price is at or below the trigger price.
+	StopLoss,
+	/// Triggered when the oracle price is at or above the trigger price.
+	TakeProfit,
+}
+
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo, MaxEncodedLen)]
+pub struct TriggeredOrder<AccountId, AssetId> {
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	pub amount_in: Balance,
+	pub min_amount_out: Balance,
+	/// Price of asset in denominated in asset out.
+	pub trigger_price: FixedU128,
+	pub kind: TriggerKind,
+	/// Oracle period used to determine the price.
+	pub period: OraclePeriod,
+	/// Route of the sell. Default route of the router is used if empty.
+	pub route: BoundedVec<Trade<AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+}
+
+impl<AccountId, AssetId> TriggeredOrder<AccountId, AssetId> {
+	pub fn is_triggered(&self, oracle_price: FixedU128) -> bool {
+		match self.kind {
+			TriggerKind::StopLoss => oracle_price <= self.trigger_price,
+			TriggerKind::TakeProfit => oracle_price >= self.trigger_price,
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(crate) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+		/// Identifier for the class of asset.
+		type AssetId: Member + Parameter + Copy + MaybeSerializeDeserialize + MaxEncodedLen;
+
+		/// Named reservable multi currency
+		type Currencies: NamedMultiReservableCurrency<
+			Self::AccountId,
+			ReserveIdentifier = NamedReserveIdentifier,
+			CurrencyId = Self::AssetId,
+			Balance = Balance,
+		>;
+
+		/// Oracle providing the prices which trigger the orders.
+		type Oracle: AggregatedPriceOracle<Self::AssetId, Self::BlockNumber, EmaPrice>;
+
+		/// Source of the oracle prices.
+		#[pallet::constant]
+		type OracleSource: Get<Source>;
+
+		/// Asset which prices of both assets of an order are determined against.
+		#[pallet::constant]
+		type HubAssetId: Get<Self::AssetId>;
+
+		/// Router implementation
+		type RouteExecutor: RouterT<
+			Self::RuntimeOrigin,
+			Self::AssetId,
+			Balance,
+			Trade<Self::AssetId>,
+			AmountInAndOut<Balance>,
+		>;
+
+		/// Weight information for the trades executed by the router.
+		type AmmTradeWeights: AmmTradeWeights<Trade<Self::AssetId>>;
+
+		/// Minimum amount of an order
+		#[pallet::constant]
+		type MinimumTradingLimit: Get<Balance>;
+
+		/// Maximum number of orders checked in `on_initialize` of one block.
+		#[pallet::constant]
+		type MaxOrdersCheckedPerBlock: Get<u32>;
+
+		/// Maximum weight of checking and executing orders in `on_initialize` of one block.
+		/// At least one order is checked in every block regardless of this limit.
+		#[pallet::constant]
+		type MaxCheckOrdersWeight: Get<Weight>;
+
+		/// Number of failed executions after which an order is terminated.
+		#[pallet::constant]
+		type MaxExecutionFailures: Get<u32>;
+
+		/// Weight information for the extrinsics.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An order has been placed
+		Placed {
+			order_id: OrderId,
+			owner: T::AccountId,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_amount_out: Balance,
+			trigger_price: FixedU128,
+			kind: TriggerKind,
+			period: OraclePeriod,
+		},
+		/// An order has been triggered and executed
+		Executed {
+			order_id: OrderId,
+			owner: T::AccountId,
+			kind: TriggerKind,
+			trigger_price: FixedU128,
+			oracle_price: FixedU128,
+			execution_price: FixedU128,
+			amount_in: Balance,
+			amount_out: Balance,
+		},
+		/// An order has been triggered but its execution failed. The order stays open.
+		ExecutionFailed {
+			order_id: OrderId,
+			trigger_price: FixedU128,
+			oracle_price: FixedU128,
+			error: DispatchError,
+		},
+		/// An order has been cancelled
+		Cancelled { order_id: OrderId },
+		/// An order has been terminated after its execution failed `MaxExecutionFailures` times
+		Terminated { order_id: OrderId, error: DispatchError },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// Order cannot be found
+		OrderNotFound,
+		/// Size of order ID exceeds the bound
+		OrderIdOutOfBound,
+		/// Asset in and asset out of an order must be different
+		SameAssetOrderNotAllowed,
+		/// Amount in must be at least the minimum trading limit
+		OrderAmountTooSmall,
+		/// Trigger price must not be zero
+		InvalidTriggerPrice,
+		/// Route must start with asset in and end with asset out
+		InvalidRoute,
+		/// Oracle price of the asset pair is not available for the period
+		OraclePriceNotAvailable,
+		/// Order would be triggered immediately
+		TriggerPriceAlreadyReached,
+		/// The caller does not have permission to complete the action
+		Forbidden,
+		/// Reserved amount not sufficient.
+		InsufficientReservedAmount,
+	}
+
+	/// ID sequencer for Orders
+	#[pallet::storage]
+	#[pallet::getter(fn next_order_id)]
+	pub type NextOrderId<T: Config> = StorageValue<_, OrderId, ValueQuery>;
+
+	#[pallet::storage]
+	#[pallet::getter(fn orders)]
+	pub type Orders<T: Config> =
+		StorageMap<_, Blake2_128Concat, OrderId, TriggeredOrder<T::AccountId, T::AssetId>, OptionQuery>;
+
+	/// Last order checked in `on_initialize`. Next check continues with the following order.
+	#[pallet::storage]
+	#[pallet::getter(fn last_checked_order)]
+	pub type LastCheckedOrder<T: Config> = StorageValue<_, OrderId, OptionQuery>;
+
+	/// Number of failed executions of an order.
+	#[pallet::storage]
+	#[pallet::getter(fn execution_failures)]
+	pub type ExecutionFailures<T: Config> = StorageMap<_, Blake2_128Concat, OrderId, u32, ValueQuery>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			Self::check_orders()
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a new triggered order
+		///
+		/// Parameters:
+		/// - `asset_in`: Asset which is being sold
+		/// - `asset_out`: Asset which is being bought
+		/// - `amount_in`: Amount to sell
+		/// - `min_amount_out`: Minimum amount to receive when the order is executed
+		/// - `trigger_price`: Price of asset in denominated in asset out which triggers the order
+		/// - `kind`: Whether the order is stop-loss or take-profit order
+		/// - `period`: Oracle period used to determine the price
+		/// - `route`: Route of the sell. Default route is used if empty.
+		///
+		/// Validations:
+		/// - asset_in and asset_out must be different
+		/// - amount_in must be at least the minimum trading limit
+		/// - trigger_price must not be zero
+		/// - oracle price of the pair must be available and the order must not be triggered immediately
+		///
+		/// Events:
+		/// - `Placed` event when successful.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_order()
+			.saturating_add(T::Oracle::get_price_weight().saturating_mul(2)))]
+		#[allow(clippy::too_many_arguments)]
+		pub fn place_order(
+			origin: OriginFor<T>,
+			asset_in: T::AssetId,
+			asset_out: T::AssetId,
+			amount_in: Balance,
+			min_amount_out: Balance,
+			trigger_price: FixedU128,
+			kind: TriggerKind,
+			period: OraclePeriod,
+			route: BoundedVec<Trade<T::AssetId>, ConstU32<MAX_NUMBER_OF_TRADES>>,
+		) -> DispatchResult {
+			let owner = ensure_signed(origin)?;
+
+			ensure!(asset_in != asset_out, Error::<T>::SameAssetOrderNotAllowed);
+			ensure!(
+				amount_in >= T::MinimumTradingLimit::get(),
+				Error::<T>::OrderAmountTooSmall
+			);
+			ensure!(!trigger_price.is_zero(), Error::<T>::InvalidTriggerPrice);
+
+			if let (Some(first), Some(last)) = (route.first(), route.last()) {
+				ensure!(
+					first.asset_in == asset_in && last.asset_out == asset_out,
+					Error::<T>::InvalidRoute
+				);
+			}
+
+			let order = TriggeredOrder {
+				owner: owner.clone(),
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				trigger_price,
+				kind,
+				period,
+				route,
+			};
+
+			let oracle_price =
+				Self::oracle_price(asset_in, asset_out, period).ok_or(Error::<T>::OraclePriceNotAvailable)?;
+			ensure!(
+				!order.is_triggered(oracle_price),
+				Error::<T>::TriggerPriceAlreadyReached
+			);
+
+			let order_id = <NextOrderId<T>>::try_mutate(|next_id| -> Result<OrderId, DispatchError> {
+				let order_id = *next_id;
+				*next_id = next_id.checked_add(One::one()).ok_or(Error::<T>::OrderIdOutOfBound)?;
+				Ok(order_id)
+			})?;
+
+			T::Currencies::reserve_named(&NAMED_RESERVE_ID, asset_in, &owner, amount_in)?;
+
+			<Orders<T>>::insert(order_id, order);
+
+			Self::deposit_event(Event::Placed {
+				order_id,
+				owner,
+				asset_in,
+				asset_out,
+				amount_in,
+				min_amount_out,
+				trigger_price,
+				kind,
+				period,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel an open triggered order
+		///
+		/// Parameters:
+		/// - `order_id`: ID of the order
+		///
+		/// Validations:
+		/// - caller is order owner
+		///
+		/// Emits `Cancelled` event when successful.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_order())]
+		pub fn cancel_order(origin: OriginFor<T>, order_id: OrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let order = <Orders<T>>::get(order_id).ok_or(Error::<T>::OrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::Forbidden);
+
+			Self::unreserve_amount_in(&order)?;
+			<Orders<T>>::remove(order_id);
+			<ExecutionFailures<T>>::remove(order_id);
+
+			Self::deposit_event(Event::Cancelled { order_id });
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Oracle price of asset in denominated in asset out, derived from prices of both assets against the hub asset.
+	pub fn oracle_price(asset_in: T::AssetId, asset_out: T::AssetId, period: OraclePeriod) -> Option<FixedU128> {
+		let hub_asset_id = T::HubAssetId::get();
+
+		let asset_in_price = Self::oracle_price_of_pair(asset_in, hub_asset_id, period)?;
+		let hub_asset_price = Self::oracle_price_of_pair(hub_asset_id, asset_out, period)?;
+
+		asset_in_price.checked_mul(&hub_asset_price)
+	}
+
+	/// Price of `asset_a` denominated in `asset_b` as provided by the oracle.
+	fn oracle_price_of_pair(asset_a: T::AssetId, asset_b: T::AssetId, period: OraclePeriod) -> Option<FixedU128> {
+		if asset_a == asset_b {
+			return Some(FixedU128::one());
+		}
+
+		// Oracle price is the amount of asset a for one unit of asset b
+		let (price, _) = T::Oracle::get_price(asset_a, asset_b, period, T::OracleSource::get()).ok()?;
+
+		FixedU128::checked_from_rational(price.d, price.n)
+	}
+
+	/// Check open orders and execute those which have been triggered.
+	fn check_orders() -> Weight {
+		let mut weight = T::DbWeight::get().reads_writes(1, 1);
+
+		let max_weight = T::MaxCheckOrdersWeight::get();
+		let max_orders = T::MaxOrdersCheckedPerBlock::get() as usize;
+
+		let previous_checked_order = <LastCheckedOrder<T>>::get();
+		let orders_iter = match previous_checked_order {
+			Some(order_id) => <Orders<T>>::iter_from(<Orders<T>>::hashed_key_for(order_id)),
+			None => <Orders<T>>::iter(),
+		};
+		let orders: Vec<_> = orders_iter.take(max_orders).collect();
+		let orders_count = orders.len();
+
+		let mut checked_orders = 0usize;
+		let mut last_checked_order = previous_checked_order;
+
+		for (order_id, order) in orders {
+			// Each order is accounted as if it was triggered and its execution failed.
+			let max_order_weight = Self::check_order_weight()
+				.saturating_add(Self::execute_order_weight(&order.route))
+				.saturating_add(Self::execution_failure_weight());
+			if !checked_orders.is_zero() && weight.saturating_add(max_order_weight).any_gt(max_weight) {
+				break;
+			}
+
+			checked_orders += 1;
+			last_checked_order = Some(order_id);
+
+			weight.saturating_accrue(Self::check_order_weight());
+
+			let Some(oracle_price) = Self::oracle_price(order.asset_in, order.asset_out, order.period) else {
+				continue;
+			};
+
+			if !order.is_triggered(oracle_price) {
+				continue;
+			}
+
+			weight.saturating_accrue(Self::execute_order_weight(&order.route));
+
+			match Self::execute_order(order_id, &order) {
+				Ok((amount_in, amount_out)) => {
+					let execution_price = FixedU128::checked_from_rational(amount_out, amount_in).unwrap_or_default();
+
+					Self::deposit_event(Event::Executed {
+						order_id,
+						owner: order.owner,
+						kind: order.kind,
+						trigger_price: order.trigger_price,
+						oracle_price,
+						execution_price,
+						amount_in,
+						amount_out,
+					});
+				}
+				Err(error) => {
+					weight.saturating_accrue(Self::execution_failure_weight());
+					Self::handle_execution_failure(order_id, &order, oracle_price, error);
+				}
+			}
+		}
+
+		// Orders which were read but not checked are checked in next block.
+		weight.saturating_accrue(T::DbWeight::get().reads(orders_count.saturating_sub(checked_orders) as u64));
+
+		// Start from the beginning in next block once all orders have been checked.
+		if checked_orders == orders_count && orders_count < max_orders {
+			last_checked_order = None;
+		}
+		<LastCheckedOrder<T>>::set(last_checked_order);
+
+		weight
+	}
+
+	/// Keep the order open to be retried or terminate it once its execution failed `MaxExecutionFailures` times.
+	fn handle_execution_failure(
+		order_id: OrderId,
+		order: &TriggeredOrder<T::AccountId, T::AssetId>,
+		oracle_price: FixedU128,
+		error: DispatchError,
+	) {
+		let failures = <ExecutionFailures<T>>::get(order_id).saturating_add(1);
+
+		if failures < T::MaxExecutionFailures::get() {
+			<ExecutionFailures<T>>::insert(order_id, failures);
+
+			Self::deposit_event(Event::ExecutionFailed {
+				order_id,
+				trigger_price: order.trigger_price,
+				oracle_price,
+				error,
+			});
+			return;
+		}
+
+		// Release whatever is reserved, the order is removed anyway
+		T::Currencies::unreserve_named(&NAMED_RESERVE_ID, order.asset_in, &order.owner, order.amount_in);
+		<Orders<T>>::remove(order_id);
+		<ExecutionFailures<T>>::remove(order_id);
+
+		Self::deposit_event(Event::Terminated { order_id, error });
+	}
+
+	/// Sell amount in via the router on behalf of the owner.
+	///
+	/// Returns amounts which were actually sold and bought.
+	#[transactional]
+	fn execute_order(
+		order_id: OrderId,
+		order: &TriggeredOrder<T::AccountId, T::AssetId>,
+	) -> Result<(Balance, Balance), DispatchError> {
+		Self::unreserve_amount_in(order)?;
+
+		let asset_in_balance = T::Currencies::free_balance(order.asset_in, &order.owner);
+		let asset_out_balance = T::Currencies::free_balance(order.asset_out, &order.owner);
+
+		T::RouteExecutor::sell(
+			RawOrigin::Signed(order.owner.clone()).into(),
+			order.asset_in,
+			order.asset_out,
+			order.amount_in,
+			order.min_amount_out,
+			order.route.to_vec(),
+		)?;
+
+		let amount_in = asset_in_balance
+			.checked_sub(T::Currencies::free_balance(order.asset_in, &order.owner))
+			.ok_or(ArithmeticError::Underflow)?;
+		let amount_out = T::Currencies::free_balance(order.asset_out, &order.owner)
+			.checked_sub(asset_out_balance)
+			.ok_or(ArithmeticError::Underflow)?;
+
+		<Orders<T>>::remove(order_id);
+		<ExecutionFailures<T>>::remove(order_id);
+
+		Ok((amount_in, amount_out))
+	}
+
+	fn check_order_weight() -> Weight {
+		<T as Config>::WeightInfo::check_order().saturating_add(T::Oracle::get_price_weight().saturating_mul(2))
+	}
+
+	fn execute_order_weight(route: &[Trade<T::AssetId>]) -> Weight {
+		<T as Config>::WeightInfo::execute_order().saturating_add(T::AmmTradeWeights::sell_weight(route))
+	}
+
+	fn execution_failure_weight() -> Weight {
+		T::DbWeight::get().reads_writes(2, 4)
+	}
+
+	fn unreserve_amount_in(order: &TriggeredOrder<T::AccountId, T::AssetId>) -> DispatchResult {
+		let remaining_to_unreserve =
+			T::Currencies::unreserve_named(&NAMED_RESERVE_ID, order.asset_in, &order.owner, order.amount_in);
+		ensure!(remaining_to_unreserve.is_zero(), Error::<T>::InsufficientReservedAmount);
+
+		Ok(())
+	}
+}
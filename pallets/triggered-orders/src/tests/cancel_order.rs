@@ -0,0 +1,82 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as triggered_orders;
+use crate::tests::mock::*;
+use crate::{Error, Event, TriggerKind};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::oracle::OraclePeriod;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+fn place_stop_loss_order(owner: AccountId) {
+	assert_ok!(TriggeredOrders::place_order(
+		RuntimeOrigin::signed(owner),
+		HDX,
+		DAI,
+		10 * ONE,
+		0,
+		FixedU128::from_float(1.5),
+		TriggerKind::StopLoss,
+		OraclePeriod::Short,
+		BoundedVec::default(),
+	));
+}
+
+#[test]
+fn cancel_order_should_unreserve_amount_in_and_remove_order() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_stop_loss_order(ALICE);
+
+		// Act
+		assert_ok!(TriggeredOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0));
+
+		// Assert
+		assert_eq!(TriggeredOrders::orders(0), None);
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 1000 * ONE);
+
+		expect_events(vec![Event::Cancelled { order_id: 0 }.into()]);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_called_by_non_owner() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_stop_loss_order(ALICE);
+
+		// Act & Assert
+		assert_noop!(
+			TriggeredOrders::cancel_order(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::Forbidden
+		);
+	});
+}
+
+#[test]
+fn cancel_order_should_fail_when_order_does_not_exist() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::cancel_order(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::OrderNotFound
+		);
+	});
+}
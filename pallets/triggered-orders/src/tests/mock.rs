@@ -0,0 +1,326 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as triggered_orders;
+use crate::Config;
+use frame_support::{
+	parameter_types,
+	traits::{ConstU32, Everything, GenesisBuild, Get, Nothing},
+	weights::Weight,
+};
+use frame_system::ensure_signed;
+use hydra_dx_math::ema::EmaPrice;
+use hydradx_traits::oracle::{AggregatedPriceOracle, OraclePeriod, Source};
+use hydradx_traits::router::{AmountInAndOut, AssetPair, RouterT, Trade};
+use orml_traits::{parameter_type_with_key, MultiCurrency};
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError, DispatchResult, DispatchResultWithPostInfo, FixedPointNumber, FixedU128,
+};
+use std::{cell::RefCell, collections::HashMap};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+pub type AccountId = u64;
+pub type Amount = i128;
+pub type AssetId = u32;
+pub type Balance = u128;
+pub type BlockNumber = u64;
+pub type NamedReserveIdentifier = [u8; 8];
+
+pub const HDX: AssetId = 0;
+pub const LRNA: AssetId = 1;
+pub const DAI: AssetId = 2;
+pub const NO_ORACLE: AssetId = 2_000;
+
+pub const ONE: Balance = 1_000_000_000_000;
+
+pub const ALICE: AccountId = 1;
+pub const BOB: AccountId = 2;
+
+frame_support::construct_runtime!(
+	pub enum Test where
+	 Block = Block,
+	 NodeBlock = Block,
+	 UncheckedExtrinsic = UncheckedExtrinsic,
+	 {
+		 System: frame_system,
+		 Tokens: orml_tokens,
+		 TriggeredOrders: triggered_orders,
+	 }
+);
+
+thread_local! {
+	/// Oracle prices of assets denominated in hub asset
+	pub static ORACLE_PRICES: RefCell<HashMap<AssetId, FixedU128>> = RefCell::new(HashMap::default());
+	/// Price of asset in denominated in asset out used by the router
+	pub static EXECUTION_PRICE: RefCell<FixedU128> = RefCell::new(FixedU128::one());
+	pub static MAX_CHECK_ORDERS_WEIGHT: RefCell<Weight> = RefCell::new(Weight::MAX);
+}
+
+parameter_types! {
+	pub const HubAssetId: AssetId = LRNA;
+	pub const OracleSource: Source = *b"omnipool";
+	pub const MinTradeAmount: Balance = 1_000u128;
+	pub const MaxOrdersCheckedPerBlock: u32 = 3;
+	pub const MaxExecutionFailures: u32 = 3;
+}
+
+pub struct MaxCheckOrdersWeight;
+
+impl Get<Weight> for MaxCheckOrdersWeight {
+	fn get() -> Weight {
+		MAX_CHECK_ORDERS_WEIGHT.with(|v| *v.borrow())
+	}
+}
+
+impl Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currencies = Tokens;
+	type Oracle = PriceOracleMock;
+	type OracleSource = OracleSource;
+	type HubAssetId = HubAssetId;
+	type RouteExecutor = RouterMock;
+	type AmmTradeWeights = ();
+	type MinimumTradingLimit = MinTradeAmount;
+	type MaxOrdersCheckedPerBlock = MaxOrdersCheckedPerBlock;
+	type MaxCheckOrdersWeight = MaxCheckOrdersWeight;
+	type MaxExecutionFailures = MaxExecutionFailures;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 63;
+	pub const MaxReserves: u32 = 50;
+}
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	type Index = u64;
+	type BlockNumber = BlockNumber;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type RuntimeEvent = RuntimeEvent;
+	type BlockHashCount = BlockHashCount;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_type_with_key! {
+	pub ExistentialDeposits: |_currency_id: AssetId| -> Balance {
+		0
+	};
+}
+
+impl orml_tokens::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type Balance = Balance;
+	type Amount = Amount;
+	type CurrencyId = AssetId;
+	type WeightInfo = ();
+	type ExistentialDeposits = ExistentialDeposits;
+	type MaxLocks = ();
+	type DustRemovalWhitelist = Nothing;
+	type ReserveIdentifier = NamedReserveIdentifier;
+	type MaxReserves = MaxReserves;
+	type CurrencyHooks = ();
+}
+
+pub struct PriceOracleMock;
+
+impl AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice> for PriceOracleMock {
+	type Error = ();
+
+	fn get_price(
+		asset_a: AssetId,
+		asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumber), Self::Error> {
+		// Oracle price is the amount of asset a for one unit of asset b
+		let price = match (asset_a, asset_b) {
+			(LRNA, asset) => oracle_price_in_hub_asset(asset)?,
+			(asset, LRNA) => oracle_price_in_hub_asset(asset)?.reciprocal().ok_or(())?,
+			_ => return Err(()),
+		};
+
+		Ok((EmaPrice::new(price.into_inner(), FixedU128::DIV), 10))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+fn oracle_price_in_hub_asset(asset_id: AssetId) -> Result<FixedU128, ()> {
+	ORACLE_PRICES.with(|v| v.borrow().get(&asset_id).copied().ok_or(()))
+}
+
+pub fn set_oracle_price(asset_id: AssetId, price_in_hub_asset: FixedU128) {
+	ORACLE_PRICES.with(|v| {
+		v.borrow_mut().insert(asset_id, price_in_hub_asset);
+	});
+}
+
+pub fn set_execution_price(price: FixedU128) {
+	EXECUTION_PRICE.with(|v| {
+		*v.borrow_mut() = price;
+	});
+}
+
+pub struct RouterMock;
+
+impl RouterT<RuntimeOrigin, AssetId, Balance, Trade<AssetId>, AmountInAndOut<Balance>> for RouterMock {
+	fn sell(
+		origin: RuntimeOrigin,
+		asset_in: AssetId,
+		asset_out: AssetId,
+		amount_in: Balance,
+		min_amount_out: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		let who = ensure_signed(origin)?;
+
+		let amount_out = EXECUTION_PRICE.with(|v| v.borrow().saturating_mul_int(amount_in));
+		if amount_out < min_amount_out {
+			return Err(DispatchError::Other("TradingLimitReached"));
+		}
+
+		Tokens::withdraw(asset_in, &who, amount_in)?;
+		Tokens::deposit(asset_out, &who, amount_out)
+	}
+
+	fn buy(
+		_origin: RuntimeOrigin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResult {
+		unimplemented!()
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade<AssetId>],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut<Balance>>, DispatchError> {
+		unimplemented!()
+	}
+
+	fn set_route(
+		_origin: RuntimeOrigin,
+		_asset_pair: AssetPair<AssetId>,
+		_route: Vec<Trade<AssetId>>,
+	) -> DispatchResultWithPostInfo {
+		unimplemented!()
+	}
+}
+
+pub struct ExtBuilder {
+	endowed_accounts: Vec<(AccountId, AssetId, Balance)>,
+	oracle_prices: Vec<(AssetId, FixedU128)>,
+}
+
+impl Default for ExtBuilder {
+	fn default() -> Self {
+		// If eg. tests running on one thread only, this thread local is shared.
+		// let's make sure that it is empty for each  test case
+		// or set to original default value
+		ORACLE_PRICES.with(|v| {
+			v.borrow_mut().clear();
+		});
+		EXECUTION_PRICE.with(|v| {
+			*v.borrow_mut() = FixedU128::one();
+		});
+		MAX_CHECK_ORDERS_WEIGHT.with(|v| {
+			*v.borrow_mut() = Weight::MAX;
+		});
+
+		Self {
+			endowed_accounts: vec![
+				(ALICE, HDX, 1000 * ONE),
+				(ALICE, DAI, 1000 * ONE),
+				(BOB, HDX, 1000 * ONE),
+				(BOB, DAI, 1000 * ONE),
+			],
+			// HDX is worth 2 DAI
+			oracle_prices: vec![(HDX, FixedU128::one()), (DAI, FixedU128::from_rational(1, 2))],
+		}
+	}
+}
+
+impl ExtBuilder {
+	pub fn with_oracle_price(mut self, asset_id: AssetId, price_in_hub_asset: FixedU128) -> Self {
+		self.oracle_prices.push((asset_id, price_in_hub_asset));
+		self
+	}
+
+	pub fn with_max_check_orders_weight(self, weight: Weight) -> Self {
+		MAX_CHECK_ORDERS_WEIGHT.with(|v| {
+			*v.borrow_mut() = weight;
+		});
+		self
+	}
+
+	pub fn build(self) -> sp_io::TestExternalities {
+		let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+		orml_tokens::GenesisConfig::<Test> {
+			balances: self.endowed_accounts,
+		}
+		.assimilate_storage(&mut t)
+		.unwrap();
+
+		for (asset_id, price) in self.oracle_prices {
+			set_oracle_price(asset_id, price);
+		}
+
+		let mut r: sp_io::TestExternalities = t.into();
+		r.execute_with(|| System::set_block_number(1));
+		r
+	}
+}
+
+pub fn expect_events(e: Vec<RuntimeEvent>) {
+	test_utils::expect_events::<RuntimeEvent, Test>(e);
+}
@@ -0,0 +1,248 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as triggered_orders;
+use crate::tests::mock::*;
+use crate::{Event, TriggerKind};
+use frame_support::{assert_ok, traits::Hooks, BoundedVec};
+use hydradx_traits::oracle::OraclePeriod;
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::{DispatchError, FixedU128};
+
+fn place_order(trigger_price: FixedU128, kind: TriggerKind, min_amount_out: Balance) {
+	assert_ok!(TriggeredOrders::place_order(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		DAI,
+		10 * ONE,
+		min_amount_out,
+		trigger_price,
+		kind,
+		OraclePeriod::TenMinutes,
+		BoundedVec::default(),
+	));
+}
+
+#[test]
+fn stop_loss_order_should_be_executed_when_oracle_price_falls_to_trigger_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(FixedU128::from_float(1.5), TriggerKind::StopLoss, 9 * ONE);
+
+		// HDX is worth 1 DAI now
+		set_oracle_price(HDX, FixedU128::from_rational(1, 2));
+		set_execution_price(FixedU128::from_rational(98, 100));
+
+		// Act
+		TriggeredOrders::on_initialize(2);
+
+		// Assert
+		assert_eq!(TriggeredOrders::orders(0), None);
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 990 * ONE);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1000 * ONE + 98 * ONE / 10);
+
+		expect_events(vec![Event::Executed {
+			order_id: 0,
+			owner: ALICE,
+			kind: TriggerKind::StopLoss,
+			trigger_price: FixedU128::from_float(1.5),
+			oracle_price: FixedU128::from(1),
+			execution_price: FixedU128::from_rational(98, 100),
+			amount_in: 10 * ONE,
+			amount_out: 98 * ONE / 10,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn take_profit_order_should_be_executed_when_oracle_price_rises_to_trigger_price() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(FixedU128::from(3), TriggerKind::TakeProfit, 0);
+
+		// HDX is worth 4 DAI now
+		set_oracle_price(HDX, FixedU128::from(2));
+		set_execution_price(FixedU128::from(4));
+
+		// Act
+		TriggeredOrders::on_initialize(2);
+
+		// Assert
+		assert_eq!(TriggeredOrders::orders(0), None);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1040 * ONE);
+
+		expect_events(vec![Event::Executed {
+			order_id: 0,
+			owner: ALICE,
+			kind: TriggerKind::TakeProfit,
+			trigger_price: FixedU128::from(3),
+			oracle_price: FixedU128::from(4),
+			execution_price: FixedU128::from(4),
+			amount_in: 10 * ONE,
+			amount_out: 40 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn orders_should_not_be_executed_when_trigger_price_is_not_reached() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(FixedU128::from_float(1.5), TriggerKind::StopLoss, 0);
+		place_order(FixedU128::from(3), TriggerKind::TakeProfit, 0);
+
+		// Act
+		TriggeredOrders::on_initialize(2);
+
+		// Assert
+		assert!(TriggeredOrders::orders(0).is_some());
+		assert!(TriggeredOrders::orders(1).is_some());
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			20 * ONE
+		);
+	});
+}
+
+#[test]
+fn order_should_stay_open_when_execution_fails() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(FixedU128::from_float(1.5), TriggerKind::StopLoss, 15 * ONE);
+
+		set_oracle_price(HDX, FixedU128::from_rational(1, 2));
+
+		// Act
+		TriggeredOrders::on_initialize(2);
+
+		// Assert
+		assert!(TriggeredOrders::orders(0).is_some());
+		assert_eq!(TriggeredOrders::execution_failures(0), 1);
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			10 * ONE
+		);
+		assert_eq!(Tokens::free_balance(DAI, &ALICE), 1000 * ONE);
+
+		expect_events(vec![Event::ExecutionFailed {
+			order_id: 0,
+			trigger_price: FixedU128::from_float(1.5),
+			oracle_price: FixedU128::from(1),
+			error: DispatchError::Other("TradingLimitReached"),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_initialize_should_check_at_most_max_orders_per_block() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		for _ in 0..4 {
+			place_order(FixedU128::from(3), TriggerKind::TakeProfit, 0);
+		}
+		set_oracle_price(HDX, FixedU128::from(2));
+
+		// Act
+		TriggeredOrders::on_initialize(2);
+
+		// Assert
+		assert_eq!(
+			triggered_orders::Orders::<Test>::iter().count(),
+			4 - MaxOrdersCheckedPerBlock::get() as usize
+		);
+		assert!(TriggeredOrders::last_checked_order().is_some());
+
+		// Act
+		TriggeredOrders::on_initialize(3);
+
+		// Assert
+		assert_eq!(triggered_orders::Orders::<Test>::iter().count(), 0);
+		assert_eq!(TriggeredOrders::last_checked_order(), None);
+	});
+}
+
+#[test]
+fn order_should_be_terminated_when_execution_fails_max_execution_failures_times() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		place_order(FixedU128::from_float(1.5), TriggerKind::StopLoss, 15 * ONE);
+
+		set_oracle_price(HDX, FixedU128::from_rational(1, 2));
+
+		for block in 2..MaxExecutionFailures::get() as u64 + 1 {
+			TriggeredOrders::on_initialize(block);
+		}
+		assert!(TriggeredOrders::orders(0).is_some());
+		assert_eq!(TriggeredOrders::execution_failures(0), MaxExecutionFailures::get() - 1);
+
+		// Act
+		TriggeredOrders::on_initialize(MaxExecutionFailures::get() as u64 + 1);
+
+		// Assert
+		assert_eq!(TriggeredOrders::orders(0), None);
+		assert_eq!(TriggeredOrders::execution_failures(0), 0);
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			0
+		);
+		assert_eq!(Tokens::free_balance(HDX, &ALICE), 1000 * ONE);
+
+		expect_events(vec![Event::Terminated {
+			order_id: 0,
+			error: DispatchError::Other("TradingLimitReached"),
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn on_initialize_should_not_check_more_orders_than_max_check_orders_weight_allows() {
+	let max_order_weight = TriggeredOrders::check_order_weight()
+		.saturating_add(TriggeredOrders::execute_order_weight(&[]))
+		.saturating_add(TriggeredOrders::execution_failure_weight());
+
+	ExtBuilder::default()
+		.with_max_check_orders_weight(max_order_weight.saturating_mul(2))
+		.build()
+		.execute_with(|| {
+			// Arrange
+			for _ in 0..3 {
+				place_order(FixedU128::from(3), TriggerKind::TakeProfit, 0);
+			}
+			set_oracle_price(HDX, FixedU128::from(2));
+
+			// Act
+			TriggeredOrders::on_initialize(2);
+
+			// Assert
+			assert_eq!(triggered_orders::Orders::<Test>::iter().count(), 1);
+			assert!(TriggeredOrders::last_checked_order().is_some());
+
+			// Act
+			TriggeredOrders::on_initialize(3);
+
+			// Assert
+			assert_eq!(triggered_orders::Orders::<Test>::iter().count(), 0);
+			assert_eq!(TriggeredOrders::last_checked_order(), None);
+		});
+}
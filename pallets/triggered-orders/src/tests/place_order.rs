@@ -0,0 +1,273 @@
+// This file is part of HydraDX.
+// Copyright (C) 2020-2023  Intergalactic, Limited (GIB). SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate as triggered_orders;
+use crate::tests::mock::*;
+use crate::{Error, Event, TriggerKind, TriggeredOrder};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use hydradx_traits::oracle::OraclePeriod;
+use hydradx_traits::router::{PoolType, Trade};
+use orml_traits::NamedMultiReservableCurrency;
+use pretty_assertions::assert_eq;
+use sp_runtime::FixedU128;
+
+#[test]
+fn place_order_should_reserve_amount_in() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Act
+		assert_ok!(TriggeredOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			14 * ONE,
+			FixedU128::from_float(1.5),
+			TriggerKind::StopLoss,
+			OraclePeriod::TenMinutes,
+			BoundedVec::default(),
+		));
+
+		// Assert
+		assert_eq!(
+			TriggeredOrders::orders(0),
+			Some(TriggeredOrder {
+				owner: ALICE,
+				asset_in: HDX,
+				asset_out: DAI,
+				amount_in: 10 * ONE,
+				min_amount_out: 14 * ONE,
+				trigger_price: FixedU128::from_float(1.5),
+				kind: TriggerKind::StopLoss,
+				period: OraclePeriod::TenMinutes,
+				route: BoundedVec::default(),
+			})
+		);
+		assert_eq!(TriggeredOrders::next_order_id(), 1);
+
+		assert_eq!(
+			Tokens::reserved_balance_named(&triggered_orders::NAMED_RESERVE_ID, HDX, &ALICE),
+			10 * ONE
+		);
+
+		expect_events(vec![Event::Placed {
+			order_id: 0,
+			owner: ALICE,
+			asset_in: HDX,
+			asset_out: DAI,
+			amount_in: 10 * ONE,
+			min_amount_out: 14 * ONE,
+			trigger_price: FixedU128::from_float(1.5),
+			kind: TriggerKind::StopLoss,
+			period: OraclePeriod::TenMinutes,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_order_should_work_when_route_is_provided() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Arrange
+		let route = vec![
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: HDX,
+				asset_out: LRNA,
+			},
+			Trade {
+				pool: PoolType::Omnipool,
+				asset_in: LRNA,
+				asset_out: DAI,
+			},
+		];
+
+		// Act
+		assert_ok!(TriggeredOrders::place_order(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			DAI,
+			10 * ONE,
+			0,
+			FixedU128::from(3),
+			TriggerKind::TakeProfit,
+			OraclePeriod::Short,
+			route.clone().try_into().unwrap(),
+		));
+
+		// Assert
+		assert_eq!(TriggeredOrders::orders(0).unwrap().route.to_vec(), route);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_assets_are_same() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				HDX,
+				10 * ONE,
+				0,
+				FixedU128::from(3),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::SameAssetOrderNotAllowed
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_amount_is_below_minimum_trading_limit() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				MinTradeAmount::get() - 1,
+				0,
+				FixedU128::from(3),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::OrderAmountTooSmall
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_trigger_price_is_zero() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				0,
+				FixedU128::from(0),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::InvalidTriggerPrice
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_route_does_not_match_assets() {
+	ExtBuilder::default().build().execute_with(|| {
+		let route = vec![Trade {
+			pool: PoolType::Omnipool,
+			asset_in: HDX,
+			asset_out: LRNA,
+		}];
+
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				0,
+				FixedU128::from(3),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				route.try_into().unwrap(),
+			),
+			Error::<Test>::InvalidRoute
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_oracle_price_is_not_available() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				NO_ORACLE,
+				10 * ONE,
+				0,
+				FixedU128::from(3),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::OraclePriceNotAvailable
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_order_would_be_triggered_immediately() {
+	ExtBuilder::default().build().execute_with(|| {
+		// Oracle price of HDX is 2 DAI
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				0,
+				FixedU128::from_float(2.5),
+				TriggerKind::StopLoss,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::TriggerPriceAlreadyReached
+		);
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				10 * ONE,
+				0,
+				FixedU128::from(2),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			Error::<Test>::TriggerPriceAlreadyReached
+		);
+	});
+}
+
+#[test]
+fn place_order_should_fail_when_balance_is_insufficient() {
+	ExtBuilder::default().build().execute_with(|| {
+		assert_noop!(
+			TriggeredOrders::place_order(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				DAI,
+				1001 * ONE,
+				0,
+				FixedU128::from(3),
+				TriggerKind::TakeProfit,
+				OraclePeriod::Short,
+				BoundedVec::default(),
+			),
+			orml_tokens::Error::<Test>::BalanceTooLow
+		);
+	});
+}
@@ -18,7 +18,7 @@
 pub use crate as pallet_xcm_rate_limiter;
 
 use frame_support::traits::{Contains, GenesisBuild};
-pub use frame_support::traits::{Everything, OnFinalize};
+pub use frame_support::traits::{Everything, Nothing, OnFinalize};
 pub use frame_support::{assert_noop, assert_ok, parameter_types};
 use frame_system::EnsureRoot;
 use orml_traits::parameter_type_with_key;
@@ -247,6 +247,7 @@ impl pallet_omnipool::Config for Test {
 	type MinimumTradingLimit = MinTradeAmount;
 	type MinimumPoolLiquidity = MinAddedLiquidity;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
@@ -255,6 +256,11 @@ impl pallet_omnipool::Config for Test {
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
 	type Fee = FeeProvider;
+	type RouteExecutor = ();
+	type AmmTradeWeights = ();
+	type FeeDiscount = ();
+	type LockedPositionOwners = Nothing;
+	type MaxDelistings = ConstU32<10>;
 }
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
 use frame_support::weights::Weight;
@@ -20,6 +20,7 @@
 use super::*;
 
 use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{EnsureOrigin, Hooks};
 use frame_system::RawOrigin;
 use sp_std::prelude::*;
 
@@ -27,6 +28,7 @@ use crate::Pallet as XYK;
 
 use crate::types::{AssetId, Balance};
 use hydradx_traits::router::{PoolType, TradeExecution};
+use hydradx_traits::Registry;
 
 const SEED: u32 = 1;
 
@@ -37,6 +39,12 @@ fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
 	caller
 }
 
+fn next_block<T: Config>() -> T::BlockNumber {
+	let block = frame_system::Pallet::<T>::block_number().saturating_add(1u32.into());
+	frame_system::Pallet::<T>::set_block_number(block);
+	block
+}
+
 benchmarks! {
 	create_pool {
 		let caller = funded_account::<T>("caller", 0);
@@ -51,6 +59,21 @@ benchmarks! {
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999990000000000);
 	}
 
+	set_pool_fee {
+		let maker = funded_account::<T>("maker", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let fee = T::MaxExchangeFee::get();
+		let successful_origin = T::AuthorityOrigin::try_successful_origin().unwrap();
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000, asset_b, 1_000_000_000)?;
+
+	}: _<T::RuntimeOrigin>(successful_origin, asset_a, asset_b, fee)
+	verify {
+		assert_eq!(XYK::<T>::get_fee(&XYK::<T>::pair_account_from_assets(asset_a, asset_b)), fee);
+	}
+
 	add_liquidity {
 		let maker = funded_account::<T>("maker", 0);
 		let caller = funded_account::<T>("caller", 0);
@@ -95,13 +118,12 @@ benchmarks! {
 		let asset_a: AssetId = 1;
 		let asset_b: AssetId = 2;
 		let amount : Balance = 1_000_000_000;
-		let discount = false;
 
 		let min_bought: Balance = 10 * 1_000;
 
 		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
 
-	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_bought, discount)
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, min_bought)
 	verify{
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 999999000000000);
 	}
@@ -113,13 +135,12 @@ benchmarks! {
 		let asset_a: AssetId = 1;
 		let asset_b: AssetId = 2;
 		let amount : Balance = 1_000_000_000;
-		let discount = false;
 
 		let max_sold: Balance = 6_000_000_000;
 
 		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
 
-	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, max_sold, discount)
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, max_sold)
 	verify{
 		assert_eq!(T::Currency::free_balance(asset_a, &caller), 1000001000000000);
 	}
@@ -134,7 +155,6 @@ benchmarks! {
 		let asset_a: AssetId = 1;
 		let asset_b: AssetId = 2;
 		let amount : Balance = 1_000_000_000;
-		let discount = false;
 
 		let min_bought: Balance = 10 * 1_000;
 
@@ -164,7 +184,6 @@ benchmarks! {
 		let asset_a: AssetId = 1;
 		let asset_b: AssetId = 2;
 		let amount : Balance = 1_000_000_000;
-		let discount = false;
 
 		let max_sold: Balance = 6_000_000_000;
 
@@ -183,6 +202,94 @@ benchmarks! {
 			assert_eq!(T::Currency::free_balance(asset_b, &caller), 1000001000000000);
 		}
 	}
+
+	on_initialize {
+		let p in 0 .. T::MaxLongTermOrderPools::get();
+
+		let maker = funded_account::<T>("maker", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let amount : Balance = 1_000_000_000;
+
+		// Orders of both directions expire in the next block, so both execution and expiry are included.
+		for i in 0..p {
+			let asset_b = T::AssetRegistry::create_asset(&[b"LTO".to_vec(), i.to_le_bytes().to_vec()].concat(), 1)?;
+			T::Currency::update_balance(asset_b, &maker, 1_000_000_000_000_000)?;
+			T::Currency::update_balance(asset_b, &caller, 1_000_000_000_000_000)?;
+
+			XYK::<T>::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+			XYK::<T>::place_long_term_order(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, 1u32.into())?;
+			XYK::<T>::place_long_term_order(RawOrigin::Signed(caller.clone()).into(), asset_b, asset_a, amount, 1u32.into())?;
+		}
+
+		assert_eq!(XYK::<T>::active_long_term_order_pools().len() as u32, p);
+
+		let block = next_block::<T>();
+	}: {
+		XYK::<T>::on_initialize(block);
+	}
+	verify {
+		assert!(XYK::<T>::active_long_term_order_pools().is_empty());
+	}
+
+	place_long_term_order {
+		let maker = funded_account::<T>("maker", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 10_000_000_000;
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+
+	}: _(RawOrigin::Signed(caller.clone()), asset_a, asset_b, amount, 10u32.into())
+	verify {
+		assert!(XYK::<T>::long_term_order(0).is_some());
+		assert_eq!(T::Currency::free_balance(asset_a, &caller), 1_000_000_000_000_000 - amount);
+	}
+
+	cancel_long_term_order {
+		let maker = funded_account::<T>("maker", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 10_000_000_000;
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::place_long_term_order(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, 10u32.into())?;
+
+		XYK::<T>::on_initialize(next_block::<T>());
+
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(XYK::<T>::long_term_order(0).is_none());
+		assert!(XYK::<T>::active_long_term_order_pools().is_empty());
+	}
+
+	claim_long_term_order_proceeds {
+		let maker = funded_account::<T>("maker", 0);
+		let caller = funded_account::<T>("caller", 0);
+
+		let asset_a: AssetId = 1;
+		let asset_b: AssetId = 2;
+		let amount : Balance = 10_000_000_000;
+
+		XYK::<T>::create_pool(RawOrigin::Signed(maker.clone()).into(), asset_a, 1_000_000_000_000, asset_b, 3_000_000_000_000)?;
+		XYK::<T>::place_long_term_order(RawOrigin::Signed(caller.clone()).into(), asset_a, asset_b, amount, 1u32.into())?;
+
+		// worst case - reserve drops below the sell rate so part of the order is left unsold
+		XYK::<T>::remove_liquidity(RawOrigin::Signed(maker).into(), asset_a, asset_b, 990_000_000_000)?;
+
+		XYK::<T>::on_initialize(next_block::<T>());
+
+	}: _(RawOrigin::Signed(caller.clone()), 0)
+	verify {
+		assert!(XYK::<T>::long_term_order(0).is_none());
+		assert!(T::Currency::free_balance(asset_a, &caller) > 1_000_000_000_000_000 - amount);
+		assert!(T::Currency::free_balance(asset_b, &caller) > 1_000_000_000_000_000);
+	}
 }
 
 #[cfg(test)]
@@ -196,12 +303,17 @@ mod tests {
 		ExtBuilder::default().build().execute_with(|| {
 			System::set_block_number(1);
 			assert_ok!(Pallet::<Test>::test_benchmark_create_pool());
+			assert_ok!(Pallet::<Test>::test_benchmark_set_pool_fee());
 			assert_ok!(Pallet::<Test>::test_benchmark_add_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_remove_liquidity());
 			assert_ok!(Pallet::<Test>::test_benchmark_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_buy());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_sell());
 			assert_ok!(Pallet::<Test>::test_benchmark_router_execution_buy());
+			assert_ok!(Pallet::<Test>::test_benchmark_on_initialize());
+			assert_ok!(Pallet::<Test>::test_benchmark_place_long_term_order());
+			assert_ok!(Pallet::<Test>::test_benchmark_cancel_long_term_order());
+			assert_ok!(Pallet::<Test>::test_benchmark_claim_long_term_order_proceeds());
 		});
 	}
 }
@@ -23,21 +23,46 @@
 //!
 //! This pallet implements AMM Api trait therefore it is possible to plug this pool implementation
 //! into the exchange pallet.
+//!
+//! ### Long-term orders
+//!
+//! Large sells can be placed as long-term orders which are split into equal parts. One part of every active
+//! order is executed at the beginning of each block of the order duration.
+//!
+//! Execution is discrete - parts of long-term orders of both directions in a pool are matched with each other
+//! at the spot price of the pool at the beginning of the block and only the remainder is sold to the pool
+//! as a single trade. At most `1 / MaxInRatio` of the pool reserve is sold to the pool in a block, the rest
+//! is left unsold and returned to the order owners. If the execution fails, whole part of every order is left
+//! unsold. Proceeds and unsold amounts are accumulated in the pallet account and can be claimed by the order
+//! owner at any time.
+//!
+//! ### Share tokens
+//!
+//! Share token of a pool is registered with symbol and decimals derived from the pool assets.
+//! Value of the share token in both pool assets is provided to the oracle on every liquidity change,
+//! so share tokens can be priced like any other asset.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![allow(clippy::unused_unit)]
 #![allow(clippy::upper_case_acronyms)]
 
-use frame_support::sp_runtime::{traits::Zero, DispatchError};
-use frame_support::{dispatch::DispatchResult, ensure, traits::Get, transactional};
+use frame_support::sp_runtime::{
+	traits::{AccountIdConversion, One, SaturatedConversion, Saturating, Zero},
+	DispatchError, FixedPointNumber, FixedU128,
+};
+use frame_support::{
+	dispatch::DispatchResult, ensure, storage::with_storage_layer, traits::Get, transactional, PalletId,
+};
 use frame_system::ensure_signed;
 use hydradx_traits::{
-	AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, OnCreatePoolHandler, OnLiquidityChangedHandler,
-	OnTradeHandler, AMM,
+	AMMPosition, AMMTransfer, AssetPairAccountIdFor, CanCreatePool, FeeDiscountProvider, InspectRegistry,
+	MutateRegistry, OnCreatePoolHandler, OnLiquidityChangedHandler, OnTradeHandler, AMM,
 };
 use sp_std::{vec, vec::Vec};
 
-use crate::types::{Amount, AssetId, AssetPair, Balance};
+use crate::types::{
+	Amount, AssetId, AssetPair, Balance, LongTermOrder, LongTermOrderExpiry, LongTermOrderId, LongTermOrderPool,
+};
 use hydra_dx_math::ratio::Ratio;
 use orml_traits::{MultiCurrency, MultiCurrencyExtended};
 
@@ -69,14 +94,48 @@ pub mod pallet {
 	pub struct Pallet<T>(_);
 
 	#[pallet::hooks]
-	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {}
+	impl<T: Config> Hooks<T::BlockNumber> for Pallet<T> {
+		fn on_initialize(current_block: T::BlockNumber) -> Weight {
+			let pools = <ActiveLongTermOrderPools<T>>::get();
+
+			let mut weight = <T as Config>::WeightInfo::on_initialize(pools.len() as u32);
+
+			for pool_account in pools {
+				weight.saturating_accrue(T::AMMHandler::on_trade_weight());
+
+				if let Err(e) = with_storage_layer(|| Self::execute_long_term_orders(&pool_account)) {
+					log::error!(
+						target: "xyk::on_initialize", "XYK: Failed to execute long-term orders of pool {:?}. Reason {:?}",
+						pool_account,
+						e
+					);
+
+					// Nothing was sold in this block, make the whole sell rate claimable as unsold amount
+					Self::return_long_term_orders_sell_rates(&pool_account);
+					weight.saturating_accrue(T::DbWeight::get().reads_writes(1, 1));
+				}
+
+				if let Err(e) = with_storage_layer(|| Self::expire_long_term_orders(&pool_account, current_block)) {
+					log::error!(
+						target: "xyk::on_initialize", "XYK: Failed to expire long-term orders of pool {:?}. Reason {:?}",
+						pool_account,
+						e
+					);
+				}
+			}
+
+			weight
+		}
+	}
 
 	#[pallet::config]
 	pub trait Config: frame_system::Config {
 		type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
 
 		/// Registry support
-		type AssetRegistry: ShareTokenRegistry<AssetId, Vec<u8>, Balance, DispatchError>;
+		type AssetRegistry: ShareTokenRegistry<AssetId, Vec<u8>, Balance, DispatchError>
+			+ InspectRegistry<AssetId>
+			+ MutateRegistry<AssetId, Error = DispatchError>;
 
 		/// Share token support
 		type AssetPairAccountId: AssetPairAccountIdFor<AssetId, Self::AccountId>;
@@ -84,10 +143,6 @@ pub mod pallet {
 		/// Multi currency for transfer of currencies
 		type Currency: MultiCurrencyExtended<Self::AccountId, CurrencyId = AssetId, Balance = Balance, Amount = Amount>;
 
-		/// Native Asset Id
-		#[pallet::constant]
-		type NativeAssetId: Get<AssetId>;
-
 		/// Weight information for the extrinsics.
 		type WeightInfo: WeightInfo;
 
@@ -123,11 +178,26 @@ pub mod pallet {
 			+ OnTradeHandler<AssetId, Balance, Ratio>
 			+ OnLiquidityChangedHandler<AssetId, Balance, Ratio>;
 
-		/// Discounted fee
-		type DiscountedFee: Get<(u32, u32)>;
+		/// Provider of trade fee discounts.
+		type FeeDiscount: FeeDiscountProvider<Self::AccountId, AssetId>;
+
+		/// Maximum trading fee rate which can be set for a pool.
+		#[pallet::constant]
+		type MaxExchangeFee: Get<(u32, u32)>;
+
+		/// Origin allowed to change trading fee of an existing pool.
+		type AuthorityOrigin: EnsureOrigin<Self::RuntimeOrigin>;
 
 		/// Account whitelist manager to exclude pool accounts from dusting mechanism.
 		type NonDustableWhitelistHandler: DustRemovalAccountWhitelist<Self::AccountId, Error = DispatchError>;
+
+		/// Maximum duration of a long-term order in blocks.
+		#[pallet::constant]
+		type MaxLongTermOrderDuration: Get<Self::BlockNumber>;
+
+		/// Maximum number of pools with active long-term orders.
+		#[pallet::constant]
+		type MaxLongTermOrderPools: Get<u32>;
 	}
 
 	#[pallet::error]
@@ -170,9 +240,6 @@ pub mod pallet {
 		/// Not enough asset liquidity in the pool.
 		InsufficientPoolAssetBalance,
 
-		/// Not enough core asset liquidity in the pool.
-		InsufficientNativeCurrencyBalance,
-
 		/// Liquidity pool for given assets does not exist.
 		TokenPoolNotFound,
 
@@ -191,9 +258,6 @@ pub mod pallet {
 		/// Overflow
 		FeeAmountInvalid,
 
-		/// Overflow
-		CannotApplyDiscount,
-
 		/// Max fraction of pool to buy in single transaction has been exceeded.
 		MaxOutRatioExceeded,
 		/// Max fraction of pool to sell in single transaction has been exceeded.
@@ -204,6 +268,24 @@ pub mod pallet {
 
 		/// Pool cannot be created due to outside factors.
 		CannotCreatePool,
+
+		/// Fee rate is invalid or exceeds `MaxExchangeFee`.
+		InvalidFee,
+
+		/// Long-term order duration is zero or exceeds `MaxLongTermOrderDuration`.
+		InvalidLongTermOrderDuration,
+
+		/// Long-term order does not exist.
+		LongTermOrderNotFound,
+
+		/// Account is not owner of the long-term order.
+		NotLongTermOrderOwner,
+
+		/// Maximum number of pools with active long-term orders has been reached.
+		MaxLongTermOrderPoolsReached,
+
+		/// Pool cannot be destroyed while it has active long-term orders.
+		PoolHasActiveLongTermOrders,
 	}
 
 	#[pallet::event]
@@ -268,6 +350,56 @@ pub mod pallet {
 			fee_amount: Balance,
 			pool: T::AccountId,
 		},
+
+		/// Trading fee of a pool was set.
+		PoolFeeUpdated { pool: T::AccountId, fee: (u32, u32) },
+
+		/// Long-term order was placed.
+		LongTermOrderPlaced {
+			order_id: LongTermOrderId,
+			who: T::AccountId,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			sell_rate: Balance,
+			expiry_block: T::BlockNumber,
+		},
+
+		/// Long-term order was cancelled and unsold amount was returned to the owner.
+		LongTermOrderCancelled {
+			order_id: LongTermOrderId,
+			who: T::AccountId,
+			asset_in: AssetId,
+			unsold_amount: Balance,
+		},
+
+		/// Proceeds of long-term order were paid out to the owner.
+		LongTermOrderProceedsClaimed {
+			order_id: LongTermOrderId,
+			who: T::AccountId,
+			asset_out: AssetId,
+			amount: Balance,
+		},
+
+		/// Unsold amount of long-term order was returned to the owner.
+		LongTermOrderUnsoldAmountReturned {
+			order_id: LongTermOrderId,
+			who: T::AccountId,
+			asset_in: AssetId,
+			amount: Balance,
+		},
+
+		/// Long-term orders of a pool were executed in current block.
+		/// Amounts in do not include amounts left unsold. Amounts out are net of trading fee.
+		LongTermOrdersExecuted {
+			pool: T::AccountId,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			amount_a_in: Balance,
+			amount_b_in: Balance,
+			amount_a_out: Balance,
+			amount_b_out: Balance,
+		},
 	}
 
 	/// Asset id storage for shared pool tokens
@@ -286,6 +418,47 @@ pub mod pallet {
 	pub(crate) type PoolAssets<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, (AssetId, AssetId), OptionQuery>;
 
+	/// Trading fee rate of a pool.
+	/// Pools without an entry are charged `GetExchangeFee`.
+	#[pallet::storage]
+	pub(crate) type PoolFee<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, (u32, u32), OptionQuery>;
+
+	/// Aggregated state of active long-term orders of a pool.
+	#[pallet::storage]
+	#[pallet::getter(fn long_term_order_pool)]
+	pub(crate) type LongTermOrderPools<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, LongTermOrderPool, OptionQuery>;
+
+	/// Pools with active long-term orders which are executed in every block.
+	#[pallet::storage]
+	#[pallet::getter(fn active_long_term_order_pools)]
+	pub(crate) type ActiveLongTermOrderPools<T: Config> =
+		StorageValue<_, BoundedVec<T::AccountId, T::MaxLongTermOrderPools>, ValueQuery>;
+
+	/// Long-term orders of a pool grouped by the block of expiry.
+	#[pallet::storage]
+	#[pallet::getter(fn long_term_order_expiry)]
+	pub(crate) type LongTermOrderExpiries<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::BlockNumber,
+		LongTermOrderExpiry,
+		OptionQuery,
+	>;
+
+	/// Long-term orders which are active or have unclaimed proceeds.
+	#[pallet::storage]
+	#[pallet::getter(fn long_term_order)]
+	pub(crate) type LongTermOrders<T: Config> =
+		StorageMap<_, Blake2_128Concat, LongTermOrderId, LongTermOrder<T::AccountId, T::BlockNumber>, OptionQuery>;
+
+	/// Id of the next long-term order.
+	#[pallet::storage]
+	#[pallet::getter(fn next_long_term_order_id)]
+	pub(crate) type NextLongTermOrderId<T: Config> = StorageValue<_, LongTermOrderId, ValueQuery>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Create new pool for given asset pair.
@@ -296,9 +469,14 @@ pub mod pallet {
 		/// Pool is created with initial liquidity provided by `origin`.
 		/// Shares are issued with specified initial price and represents proportion of asset in the pool.
 		///
+		/// Share token is registered with symbol and decimals derived from pool assets, if both pool assets have metadata.
+		///
 		/// Emits `PoolCreated` event when successful.
 		#[pallet::call_index(0)]
-		#[pallet::weight(<T as Config>::WeightInfo::create_pool())]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_pool()
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight().saturating_mul(2))
+		)]
 		pub fn create_pool(
 			origin: OriginFor<T>,
 			asset_a: AssetId,
@@ -308,71 +486,7 @@ pub mod pallet {
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			ensure!(
-				T::CanCreatePool::can_create(asset_a, asset_b),
-				Error::<T>::CannotCreatePool
-			);
-
-			ensure!(
-				amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
-				Error::<T>::InsufficientLiquidity
-			);
-
-			ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
-
-			let asset_pair = AssetPair {
-				asset_in: asset_a,
-				asset_out: asset_b,
-			};
-
-			ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
-
-			let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
-
-			ensure!(
-				T::Currency::free_balance(asset_a, &who) >= amount_a,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			ensure!(
-				T::Currency::free_balance(asset_b, &who) >= amount_b,
-				Error::<T>::InsufficientAssetBalance
-			);
-
-			let pair_account = Self::get_pair_id(asset_pair);
-
-			let token_name = asset_pair.name();
-
-			let share_token = T::AssetRegistry::get_or_create_shared_asset(
-				token_name,
-				vec![asset_a, asset_b],
-				T::MinPoolLiquidity::get(),
-			)?;
-
-			let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
-
-			T::NonDustableWhitelistHandler::add_account(&pair_account)?;
-
-			<ShareToken<T>>::insert(&pair_account, share_token);
-			<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
-
-			Self::deposit_event(Event::PoolCreated {
-				who: who.clone(),
-				asset_a,
-				asset_b,
-				initial_shares_amount: shares_added,
-				share_token,
-				pool: pair_account.clone(),
-			});
-
-			T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
-			T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
-
-			T::Currency::deposit(share_token, &who, shares_added)?;
-
-			<TotalLiquidity<T>>::insert(&pair_account, shares_added);
-
-			Ok(())
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b, None)
 		}
 
 		/// Add liquidity to previously created asset pair pool.
@@ -383,7 +497,7 @@ pub mod pallet {
 		#[pallet::call_index(1)]
 		#[pallet::weight(
 			<T as Config>::WeightInfo::add_liquidity()
-				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight().saturating_mul(3))
 		)]
 		#[transactional]
 		pub fn add_liquidity(
@@ -473,6 +587,14 @@ pub mod pallet {
 			)
 			.map_err(|(_w, e)| e)?;
 
+			Self::update_share_token_oracle(
+				share_token,
+				(asset_a, amount_a, liquidity_a),
+				(asset_b, amount_b, liquidity_b),
+				shares_added,
+				liquidity_amount,
+			);
+
 			Self::deposit_event(Event::LiquidityAdded {
 				who,
 				asset_a,
@@ -493,7 +615,7 @@ pub mod pallet {
 		#[pallet::call_index(2)]
 		#[pallet::weight(
 			<T as Config>::WeightInfo::remove_liquidity()
-				.saturating_add(T::AMMHandler::on_liquidity_changed_weight())
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight().saturating_mul(3))
 		)]
 		#[transactional]
 		pub fn remove_liquidity(
@@ -558,6 +680,11 @@ pub mod pallet {
 				.checked_sub(liquidity_amount)
 				.ok_or(Error::<T>::InvalidLiquidityAmount)?;
 
+			ensure!(
+				liquidity_left != 0 || !<LongTermOrderPools<T>>::contains_key(&pair_account),
+				Error::<T>::PoolHasActiveLongTermOrders
+			);
+
 			T::Currency::transfer(asset_a, &pair_account, &who, remove_amount_a)?;
 			T::Currency::transfer(asset_b, &pair_account, &who, remove_amount_b)?;
 
@@ -579,6 +706,14 @@ pub mod pallet {
 			)
 			.map_err(|(_w, e)| e)?;
 
+			Self::update_share_token_oracle(
+				share_token,
+				(asset_a, remove_amount_a, liquidity_a),
+				(asset_b, remove_amount_b, liquidity_b),
+				liquidity_amount,
+				liquidity_left,
+			);
+
 			Self::deposit_event(Event::LiquidityRemoved {
 				who: who.clone(),
 				asset_a,
@@ -590,6 +725,7 @@ pub mod pallet {
 				<ShareToken<T>>::remove(&pair_account);
 				<PoolAssets<T>>::remove(&pair_account);
 				<TotalLiquidity<T>>::remove(&pair_account);
+				<PoolFee<T>>::remove(&pair_account);
 
 				// Ignore the failure, this cant stop liquidity removal
 				let r = T::NonDustableWhitelistHandler::remove_account(&pair_account);
@@ -620,20 +756,23 @@ pub mod pallet {
 		///
 		/// `max_limit` - minimum amount of `asset_out` / amount of asset_out to be obtained from the pool in exchange for `asset_in`.
 		///
+		/// Trade fee is reduced by the discount of the origin provided by `FeeDiscount`.
+		///
 		/// Emits `SellExecuted` when successful.
 		#[pallet::call_index(3)]
-		#[pallet::weight(<T as Config>::WeightInfo::sell() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::sell()
+			+ <T as Config>::AMMHandler::on_trade_weight()
+			+ <T as Config>::FeeDiscount::discount_weight())]
 		pub fn sell(
 			origin: OriginFor<T>,
 			asset_in: AssetId,
 			asset_out: AssetId,
 			amount: Balance,
 			max_limit: Balance,
-			discount: bool,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit, discount)?;
+			<Self as AMM<_, _, _, _>>::sell(&who, AssetPair { asset_in, asset_out }, amount, max_limit)?;
 
 			Ok(())
 		}
@@ -644,23 +783,333 @@ pub mod pallet {
 		///
 		/// `max_limit` - maximum amount of `asset_in` to be sold in exchange for `asset_out`.
 		///
+		/// Trade fee is reduced by the discount of the origin provided by `FeeDiscount`.
+		///
 		/// Emits `BuyExecuted` when successful.
 		#[pallet::call_index(4)]
-		#[pallet::weight(<T as Config>::WeightInfo::buy() + <T as Config>::AMMHandler::on_trade_weight())]
+		#[pallet::weight(<T as Config>::WeightInfo::buy()
+			+ <T as Config>::AMMHandler::on_trade_weight()
+			+ <T as Config>::FeeDiscount::discount_weight())]
 		pub fn buy(
 			origin: OriginFor<T>,
 			asset_out: AssetId,
 			asset_in: AssetId,
 			amount: Balance,
 			max_limit: Balance,
-			discount: bool,
 		) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 
-			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit, discount)?;
+			<Self as AMM<_, _, _, _>>::buy(&who, AssetPair { asset_in, asset_out }, amount, max_limit)?;
+
+			Ok(())
+		}
+
+		/// Create new pool for given asset pair with custom trading fee.
+		///
+		/// Same as `create_pool`, but trades in the pool are charged `fee` instead of `GetExchangeFee`.
+		///
+		/// `fee` must not exceed `MaxExchangeFee`.
+		///
+		/// Emits `PoolCreated` and `PoolFeeUpdated` events when successful.
+		#[pallet::call_index(5)]
+		#[pallet::weight(
+			<T as Config>::WeightInfo::create_pool()
+				.saturating_add(T::DbWeight::get().writes(1))
+				.saturating_add(T::AMMHandler::on_liquidity_changed_weight().saturating_mul(2))
+		)]
+		pub fn create_pool_with_fee(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			amount_a: Balance,
+			asset_b: AssetId,
+			amount_b: Balance,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Self::do_create_pool(who, asset_a, amount_a, asset_b, amount_b, Some(fee))
+		}
+
+		/// Set trading fee of an existing pool.
+		///
+		/// Can be called only by `AuthorityOrigin`.
+		///
+		/// `fee` must not exceed `MaxExchangeFee`.
+		///
+		/// Emits `PoolFeeUpdated` event when successful.
+		#[pallet::call_index(6)]
+		#[pallet::weight(<T as Config>::WeightInfo::set_pool_fee())]
+		pub fn set_pool_fee(
+			origin: OriginFor<T>,
+			asset_a: AssetId,
+			asset_b: AssetId,
+			fee: (u32, u32),
+		) -> DispatchResult {
+			T::AuthorityOrigin::ensure_origin(origin)?;
+
+			let asset_pair = AssetPair {
+				asset_in: asset_a,
+				asset_out: asset_b,
+			};
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			Self::ensure_valid_fee(fee)?;
+
+			let pair_account = Self::get_pair_id(asset_pair);
+
+			<PoolFee<T>>::insert(&pair_account, fee);
+
+			Self::deposit_event(Event::PoolFeeUpdated {
+				pool: pair_account,
+				fee,
+			});
 
 			Ok(())
 		}
+
+		/// Place long-term order selling `amount` of `asset_in` for `asset_out`.
+		///
+		/// Order is split into equal parts which are sold in each of the following `duration` blocks.
+		/// Remainder of `amount` which cannot be split equally stays in caller's account.
+		///
+		/// Combined sell rate of orders selling the same asset in the pool must not exceed `1 / MaxInRatio`
+		/// of the pool reserve. The same limit is applied to the amount sold to the pool in each block,
+		/// the amount above the limit is left unsold and can be claimed back.
+		///
+		/// Emits `LongTermOrderPlaced` event when successful.
+		#[pallet::call_index(7)]
+		#[pallet::weight(<T as Config>::WeightInfo::place_long_term_order())]
+		#[transactional]
+		pub fn place_long_term_order(
+			origin: OriginFor<T>,
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount: Balance,
+			duration: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let asset_pair = AssetPair { asset_in, asset_out };
+
+			ensure!(Self::exists(asset_pair), Error::<T>::TokenPoolNotFound);
+
+			ensure!(
+				!duration.is_zero() && duration <= T::MaxLongTermOrderDuration::get(),
+				Error::<T>::InvalidLongTermOrderDuration
+			);
+
+			ensure!(
+				amount >= T::MinTradingLimit::get(),
+				Error::<T>::InsufficientTradingAmount
+			);
+
+			let blocks: Balance = duration.saturated_into();
+			let sell_rate = amount.checked_div(blocks).ok_or(Error::<T>::Overflow)?;
+			let amount = sell_rate.checked_mul(blocks).ok_or(Error::<T>::Overflow)?;
+
+			ensure!(!sell_rate.is_zero(), Error::<T>::InsufficientTradingAmount);
+
+			ensure!(
+				T::Currency::free_balance(asset_in, &who) >= amount,
+				Error::<T>::InsufficientAssetBalance
+			);
+
+			let pair_account = Self::get_pair_id(asset_pair);
+			let sells_a = asset_pair.ordered_pair().0 == asset_in;
+
+			let mut pool = match <LongTermOrderPools<T>>::get(&pair_account) {
+				Some(pool) => pool,
+				None => {
+					<ActiveLongTermOrderPools<T>>::try_mutate(|pools| pools.try_push(pair_account.clone()))
+						.map_err(|_| Error::<T>::MaxLongTermOrderPoolsReached)?;
+					LongTermOrderPool::default()
+				}
+			};
+
+			let (pool_sell_rate, proceeds_factor, unsold_factor) = if sells_a {
+				(&mut pool.sell_rate_a, pool.proceeds_factor_a, pool.unsold_factor_a)
+			} else {
+				(&mut pool.sell_rate_b, pool.proceeds_factor_b, pool.unsold_factor_b)
+			};
+			*pool_sell_rate = pool_sell_rate.checked_add(sell_rate).ok_or(Error::<T>::Overflow)?;
+
+			ensure!(
+				*pool_sell_rate
+					<= T::Currency::free_balance(asset_in, &pair_account)
+						.checked_div(T::MaxInRatio::get())
+						.ok_or(Error::<T>::Overflow)?,
+				Error::<T>::MaxInRatioExceeded
+			);
+
+			let expiry_block = frame_system::Pallet::<T>::block_number().saturating_add(duration);
+
+			<LongTermOrderExpiries<T>>::try_mutate(&pair_account, expiry_block, |maybe_expiry| -> DispatchResult {
+				let expiry = maybe_expiry.get_or_insert_with(LongTermOrderExpiry::default);
+				if sells_a {
+					expiry.sell_rate_a = expiry.sell_rate_a.checked_add(sell_rate).ok_or(Error::<T>::Overflow)?;
+				} else {
+					expiry.sell_rate_b = expiry.sell_rate_b.checked_add(sell_rate).ok_or(Error::<T>::Overflow)?;
+				}
+				expiry.orders = expiry.orders.checked_add(1).ok_or(Error::<T>::Overflow)?;
+				Ok(())
+			})?;
+
+			<LongTermOrderPools<T>>::insert(&pair_account, pool);
+
+			let order_id = <NextLongTermOrderId<T>>::try_mutate(|next_id| -> Result<LongTermOrderId, DispatchError> {
+				let current_id = *next_id;
+				*next_id = next_id.checked_add(1).ok_or(Error::<T>::Overflow)?;
+				Ok(current_id)
+			})?;
+
+			<LongTermOrders<T>>::insert(
+				order_id,
+				LongTermOrder {
+					owner: who.clone(),
+					asset_in,
+					asset_out,
+					sell_rate,
+					expiry_block,
+					proceeds_factor,
+					unsold_factor,
+				},
+			);
+
+			T::Currency::transfer(asset_in, &who, &Self::long_term_orders_account(), amount)?;
+
+			Self::deposit_event(Event::LongTermOrderPlaced {
+				order_id,
+				who,
+				asset_in,
+				asset_out,
+				amount,
+				sell_rate,
+				expiry_block,
+			});
+
+			Ok(())
+		}
+
+		/// Cancel active long-term order.
+		///
+		/// Unsold amount of `asset_in` is returned and accumulated proceeds are paid out to the owner.
+		/// If the order has already expired, only proceeds and amount left unsold during execution are paid out.
+		///
+		/// Emits `LongTermOrderCancelled` and `LongTermOrderProceedsClaimed` events when successful.
+		#[pallet::call_index(8)]
+		#[pallet::weight(<T as Config>::WeightInfo::cancel_long_term_order())]
+		#[transactional]
+		pub fn cancel_long_term_order(origin: OriginFor<T>, order_id: LongTermOrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let order = <LongTermOrders<T>>::get(order_id).ok_or(Error::<T>::LongTermOrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::NotLongTermOrderOwner);
+
+			let current_block = frame_system::Pallet::<T>::block_number();
+
+			if current_block >= order.expiry_block {
+				return Self::do_claim_long_term_order_proceeds(order_id, order, current_block);
+			}
+
+			let pair_account = Self::pair_account_from_assets(order.asset_in, order.asset_out);
+			let sells_a = AssetPair::new(order.asset_in, order.asset_out).ordered_pair().0 == order.asset_in;
+
+			let mut pool = <LongTermOrderPools<T>>::get(&pair_account).ok_or(Error::<T>::LongTermOrderNotFound)?;
+
+			let (pool_sell_rate, proceeds_factor, unsold_factor) = if sells_a {
+				(&mut pool.sell_rate_a, pool.proceeds_factor_a, pool.unsold_factor_a)
+			} else {
+				(&mut pool.sell_rate_b, pool.proceeds_factor_b, pool.unsold_factor_b)
+			};
+			*pool_sell_rate = pool_sell_rate
+				.checked_sub(order.sell_rate)
+				.ok_or(Error::<T>::Overflow)?;
+
+			let proceeds =
+				Self::calculate_long_term_order_amount(order.sell_rate, order.proceeds_factor, proceeds_factor)?;
+
+			let remaining_blocks: Balance = order.expiry_block.saturating_sub(current_block).saturated_into();
+			let unsold_amount = order
+				.sell_rate
+				.checked_mul(remaining_blocks)
+				.ok_or(Error::<T>::Overflow)?
+				.checked_add(Self::calculate_long_term_order_amount(
+					order.sell_rate,
+					order.unsold_factor,
+					unsold_factor,
+				)?)
+				.ok_or(Error::<T>::Overflow)?;
+
+			<LongTermOrderExpiries<T>>::try_mutate_exists(
+				&pair_account,
+				order.expiry_block,
+				|maybe_expiry| -> DispatchResult {
+					let expiry = maybe_expiry.as_mut().ok_or(Error::<T>::LongTermOrderNotFound)?;
+					if sells_a {
+						expiry.sell_rate_a = expiry
+							.sell_rate_a
+							.checked_sub(order.sell_rate)
+							.ok_or(Error::<T>::Overflow)?;
+					} else {
+						expiry.sell_rate_b = expiry
+							.sell_rate_b
+							.checked_sub(order.sell_rate)
+							.ok_or(Error::<T>::Overflow)?;
+					}
+					expiry.orders = expiry.orders.saturating_sub(1);
+					if expiry.orders.is_zero() {
+						*maybe_expiry = None;
+					}
+					Ok(())
+				},
+			)?;
+
+			Self::update_long_term_order_pool(&pair_account, pool);
+
+			<LongTermOrders<T>>::remove(order_id);
+
+			let account = Self::long_term_orders_account();
+			T::Currency::transfer(order.asset_in, &account, &who, unsold_amount)?;
+			T::Currency::transfer(order.asset_out, &account, &who, proceeds)?;
+
+			Self::deposit_event(Event::LongTermOrderCancelled {
+				order_id,
+				who: who.clone(),
+				asset_in: order.asset_in,
+				unsold_amount,
+			});
+
+			Self::deposit_event(Event::LongTermOrderProceedsClaimed {
+				order_id,
+				who,
+				asset_out: order.asset_out,
+				amount: proceeds,
+			});
+
+			Ok(())
+		}
+
+		/// Claim proceeds accumulated by long-term order.
+		///
+		/// Amount which was left unsold because of the per-block limit is returned to the owner as well.
+		/// Order is removed once it has expired and its proceeds are claimed.
+		///
+		/// Emits `LongTermOrderProceedsClaimed` event when successful and `LongTermOrderUnsoldAmountReturned`
+		/// event if there is unsold amount to return.
+		#[pallet::call_index(9)]
+		#[pallet::weight(<T as Config>::WeightInfo::claim_long_term_order_proceeds())]
+		#[transactional]
+		pub fn claim_long_term_order_proceeds(origin: OriginFor<T>, order_id: LongTermOrderId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let order = <LongTermOrders<T>>::get(order_id).ok_or(Error::<T>::LongTermOrderNotFound)?;
+
+			ensure!(order.owner == who, Error::<T>::NotLongTermOrderOwner);
+
+			Self::do_claim_long_term_order_proceeds(order_id, order, frame_system::Pallet::<T>::block_number())
+		}
 	}
 }
 
@@ -677,24 +1126,501 @@ impl<T: Config> Pallet<T> {
 		}
 		Some(balances)
 	}
-	/// Calculate discounted trade fee
-	fn calculate_discounted_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		Ok(
-			hydra_dx_math::fee::calculate_pool_trade_fee(amount, T::DiscountedFee::get())
-				.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?,
-		)
+	/// Calculate trade fee of given pool reduced by the fee discount of `who`.
+	fn calculate_discounted_fee(
+		who: &T::AccountId,
+		assets: AssetPair,
+		pool_account: &T::AccountId,
+		amount: Balance,
+	) -> Result<Balance, DispatchError> {
+		let fee = Self::calculate_fee(pool_account, amount)?;
+		let discount = T::FeeDiscount::discount(who, assets.asset_in, assets.asset_out);
+
+		Ok(hydra_dx_math::fee::calculate_discounted_fee_amount(fee, discount))
 	}
 
-	/// Calculate trade fee
-	fn calculate_fee(amount: Balance) -> Result<Balance, DispatchError> {
-		let fee = T::GetExchangeFee::get();
+	/// Calculate trade fee of given pool
+	fn calculate_fee(pool_account: &T::AccountId, amount: Balance) -> Result<Balance, DispatchError> {
+		let fee = Self::get_fee(pool_account);
 		Ok(hydra_dx_math::fee::calculate_pool_trade_fee(amount, (fee.0, fee.1))
 			.ok_or::<Error<T>>(Error::<T>::FeeAmountInvalid)?)
 	}
 
+	/// Ensure fee rate is well-formed and does not exceed `MaxExchangeFee`.
+	fn ensure_valid_fee(fee: (u32, u32)) -> DispatchResult {
+		let max_fee = T::MaxExchangeFee::get();
+
+		ensure!(!fee.1.is_zero(), Error::<T>::InvalidFee);
+		ensure!(fee.0 <= fee.1, Error::<T>::InvalidFee);
+		ensure!(
+			(fee.0 as u64).saturating_mul(max_fee.1 as u64) <= (max_fee.0 as u64).saturating_mul(fee.1 as u64),
+			Error::<T>::InvalidFee
+		);
+
+		Ok(())
+	}
+
+	fn do_create_pool(
+		who: T::AccountId,
+		asset_a: AssetId,
+		amount_a: Balance,
+		asset_b: AssetId,
+		amount_b: Balance,
+		fee: Option<(u32, u32)>,
+	) -> DispatchResult {
+		ensure!(
+			T::CanCreatePool::can_create(asset_a, asset_b),
+			Error::<T>::CannotCreatePool
+		);
+
+		ensure!(
+			amount_a >= T::MinPoolLiquidity::get() && amount_b >= T::MinPoolLiquidity::get(),
+			Error::<T>::InsufficientLiquidity
+		);
+
+		ensure!(asset_a != asset_b, Error::<T>::CannotCreatePoolWithSameAssets);
+
+		if let Some(fee) = fee {
+			Self::ensure_valid_fee(fee)?;
+		}
+
+		let asset_pair = AssetPair {
+			asset_in: asset_a,
+			asset_out: asset_b,
+		};
+
+		ensure!(!Self::exists(asset_pair), Error::<T>::TokenPoolAlreadyExists);
+
+		let shares_added = if asset_a < asset_b { amount_a } else { amount_b };
+
+		ensure!(
+			T::Currency::free_balance(asset_a, &who) >= amount_a,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		ensure!(
+			T::Currency::free_balance(asset_b, &who) >= amount_b,
+			Error::<T>::InsufficientAssetBalance
+		);
+
+		let pair_account = Self::get_pair_id(asset_pair);
+
+		let token_name = asset_pair.name();
+
+		let share_token = T::AssetRegistry::get_or_create_shared_asset(
+			token_name,
+			vec![asset_a, asset_b],
+			T::MinPoolLiquidity::get(),
+		)?;
+
+		// Ignore the failure (e.g. symbol exceeding registry limit), missing metadata cannot stop pool creation
+		let _ = with_storage_layer(|| Self::register_share_token_metadata(share_token, asset_pair));
+
+		let _ = T::AMMHandler::on_create_pool(asset_pair.asset_in, asset_pair.asset_out);
+
+		T::NonDustableWhitelistHandler::add_account(&pair_account)?;
+
+		<ShareToken<T>>::insert(&pair_account, share_token);
+		<PoolAssets<T>>::insert(&pair_account, (asset_a, asset_b));
+		if let Some(fee) = fee {
+			<PoolFee<T>>::insert(&pair_account, fee);
+		}
+
+		Self::deposit_event(Event::PoolCreated {
+			who: who.clone(),
+			asset_a,
+			asset_b,
+			initial_shares_amount: shares_added,
+			share_token,
+			pool: pair_account.clone(),
+		});
+
+		T::Currency::transfer(asset_a, &who, &pair_account, amount_a)?;
+		T::Currency::transfer(asset_b, &who, &pair_account, amount_b)?;
+
+		T::Currency::deposit(share_token, &who, shares_added)?;
+
+		<TotalLiquidity<T>>::insert(&pair_account, shares_added);
+
+		let liquidity_a = T::Currency::total_balance(asset_a, &pair_account);
+		let liquidity_b = T::Currency::total_balance(asset_b, &pair_account);
+		Self::update_share_token_oracle(
+			share_token,
+			(asset_a, amount_a, liquidity_a),
+			(asset_b, amount_b, liquidity_b),
+			shares_added,
+			shares_added,
+		);
+
+		if let Some(fee) = fee {
+			Self::deposit_event(Event::PoolFeeUpdated {
+				pool: pair_account,
+				fee,
+			});
+		}
+
+		Ok(())
+	}
+
+	/// Register symbol and decimals of the share token of given asset pair.
+	///
+	/// Initial shares are issued 1:1 to the amount of the asset with lower id, so the share token
+	/// uses its decimals. Symbol is composed of symbols of both assets, e.g. `HDX-DOT`.
+	///
+	/// Nothing is registered if any of the assets has no metadata.
+	/// Fails if the composed symbol exceeds the string limit of the registry.
+	fn register_share_token_metadata(share_token: AssetId, asset_pair: AssetPair) -> DispatchResult {
+		let (asset_a, asset_b) = asset_pair.ordered_pair();
+
+		let (Some(symbol_a), Some(symbol_b), Some(decimals)) = (
+			T::AssetRegistry::asset_symbol(asset_a),
+			T::AssetRegistry::asset_symbol(asset_b),
+			T::AssetRegistry::decimals(asset_a),
+		) else {
+			return Ok(());
+		};
+
+		let symbol = [symbol_a, b"-".to_vec(), symbol_b].concat();
+
+		T::AssetRegistry::set_metadata(share_token, &symbol, decimals)
+	}
+
+	/// Provide value of the share token in both pool assets to the oracle.
+	///
+	/// One share is backed by `reserve / issuance` of each pool asset and both parts have equal value
+	/// at the spot price, so price of the share token in a pool asset is `2 * reserve / issuance`.
+	///
+	/// `pool_asset_a` and `pool_asset_b` are `(asset id, liquidity change, pool reserve)`.
+	///
+	/// Failures (e.g. too many unique oracle entries in a block) are ignored, share token price
+	/// cannot stop liquidity operations.
+	fn update_share_token_oracle(
+		share_token: AssetId,
+		pool_asset_a: (AssetId, Balance, Balance),
+		pool_asset_b: (AssetId, Balance, Balance),
+		shares_amount: Balance,
+		shares_issuance: Balance,
+	) {
+		// Pool has been destroyed, share token has no value anymore.
+		if shares_issuance.is_zero() {
+			return;
+		}
+
+		for (asset, amount, reserve) in [pool_asset_a, pool_asset_b] {
+			let Some(share_value) = reserve.checked_mul(2) else {
+				continue;
+			};
+			let _ = T::AMMHandler::on_liquidity_changed(
+				T::OracleSource::get(),
+				asset,
+				share_token,
+				amount,
+				shares_amount,
+				reserve,
+				shares_issuance,
+				Ratio::new(share_value, shares_issuance),
+			);
+		}
+	}
+
 	pub fn pair_account_from_assets(asset_a: AssetId, asset_b: AssetId) -> T::AccountId {
 		T::AssetPairAccountId::from_assets(asset_a, asset_b, "xyk")
 	}
+
+	/// Account holding unsold amounts and unclaimed proceeds of long-term orders.
+	pub fn long_term_orders_account() -> T::AccountId {
+		PalletId(*b"xyk/ltos").into_account_truncating()
+	}
+
+	/// Execute one block of long-term orders of given pool.
+	///
+	/// Opposing orders are matched with each other and the remainder is traded with the pool.
+	/// Amount sold to the pool is limited to `1 / MaxInRatio` of the pool reserve, the rest is left unsold.
+	/// Trading fee is charged from the proceeds of both sides and stays in the pool.
+	fn execute_long_term_orders(pool_account: &T::AccountId) -> DispatchResult {
+		let Some(mut pool) = <LongTermOrderPools<T>>::get(pool_account) else {
+			return Ok(());
+		};
+
+		let (asset_a, asset_b) = <PoolAssets<T>>::get(pool_account)
+			.map(|(asset_a, asset_b)| AssetPair::new(asset_a, asset_b).ordered_pair())
+			.ok_or(Error::<T>::TokenPoolNotFound)?;
+
+		let reserve_a = T::Currency::free_balance(asset_a, pool_account);
+		let reserve_b = T::Currency::free_balance(asset_b, pool_account);
+
+		let execution = hydra_dx_math::xyk::long_term_orders::calculate_long_term_orders_execution(
+			reserve_a,
+			reserve_b,
+			pool.sell_rate_a,
+			pool.sell_rate_b,
+			reserve_a / T::MaxInRatio::get(),
+			reserve_b / T::MaxInRatio::get(),
+		)
+		.map_err(|_| Error::<T>::Overflow)?;
+
+		let fee_a = Self::calculate_fee(pool_account, execution.amount_a_out)?;
+		let fee_b = Self::calculate_fee(pool_account, execution.amount_b_out)?;
+
+		let amount_a_out = execution
+			.amount_a_out
+			.checked_sub(fee_a)
+			.ok_or(Error::<T>::FeeAmountInvalid)?;
+		let amount_b_out = execution
+			.amount_b_out
+			.checked_sub(fee_b)
+			.ok_or(Error::<T>::FeeAmountInvalid)?;
+
+		Self::settle_long_term_orders(
+			asset_a,
+			pool_account,
+			execution.pool_amount_a_in.saturating_add(fee_a),
+			execution.pool_amount_a_out,
+		)?;
+		Self::settle_long_term_orders(
+			asset_b,
+			pool_account,
+			execution.pool_amount_b_in.saturating_add(fee_b),
+			execution.pool_amount_b_out,
+		)?;
+
+		if !pool.sell_rate_a.is_zero() {
+			let factor =
+				FixedU128::checked_from_rational(amount_b_out, pool.sell_rate_a).ok_or(Error::<T>::Overflow)?;
+			pool.proceeds_factor_a = Self::accumulate_factor(pool.proceeds_factor_a, factor);
+			let factor = FixedU128::checked_from_rational(execution.unsold_amount_a, pool.sell_rate_a)
+				.ok_or(Error::<T>::Overflow)?;
+			pool.unsold_factor_a = Self::accumulate_factor(pool.unsold_factor_a, factor);
+		}
+		if !pool.sell_rate_b.is_zero() {
+			let factor =
+				FixedU128::checked_from_rational(amount_a_out, pool.sell_rate_b).ok_or(Error::<T>::Overflow)?;
+			pool.proceeds_factor_b = Self::accumulate_factor(pool.proceeds_factor_b, factor);
+			let factor = FixedU128::checked_from_rational(execution.unsold_amount_b, pool.sell_rate_b)
+				.ok_or(Error::<T>::Overflow)?;
+			pool.unsold_factor_b = Self::accumulate_factor(pool.unsold_factor_b, factor);
+		}
+
+		let (asset_in, asset_out, amount_in, amount_out) = if !execution.pool_amount_a_in.is_zero() {
+			(
+				asset_a,
+				asset_b,
+				execution.pool_amount_a_in,
+				execution.pool_amount_b_out,
+			)
+		} else {
+			(
+				asset_b,
+				asset_a,
+				execution.pool_amount_b_in,
+				execution.pool_amount_a_out,
+			)
+		};
+
+		if !amount_in.is_zero() {
+			let liquidity_in = T::Currency::total_balance(asset_in, pool_account);
+			let liquidity_out = T::Currency::total_balance(asset_out, pool_account);
+			// Ignore the failure, oracle update cannot stop execution of long-term orders
+			let _ = T::AMMHandler::on_trade(
+				T::OracleSource::get(),
+				asset_in,
+				asset_out,
+				amount_in,
+				amount_out,
+				liquidity_in,
+				liquidity_out,
+				Ratio::new(liquidity_in, liquidity_out),
+			);
+		}
+
+		Self::deposit_event(Event::LongTermOrdersExecuted {
+			pool: pool_account.clone(),
+			asset_a,
+			asset_b,
+			amount_a_in: pool.sell_rate_a.saturating_sub(execution.unsold_amount_a),
+			amount_b_in: pool.sell_rate_b.saturating_sub(execution.unsold_amount_b),
+			amount_a_out,
+			amount_b_out,
+		});
+
+		<LongTermOrderPools<T>>::insert(pool_account, pool);
+
+		Ok(())
+	}
+
+	/// Credit the whole sell rate of both sides of the pool as unsold in current block.
+	///
+	/// Used when execution of long-term orders fails, so the amounts which were not sold can be claimed back.
+	fn return_long_term_orders_sell_rates(pool_account: &T::AccountId) {
+		<LongTermOrderPools<T>>::mutate(pool_account, |maybe_pool| {
+			if let Some(pool) = maybe_pool {
+				pool.unsold_factor_a = Self::accumulate_factor(pool.unsold_factor_a, FixedU128::one());
+				pool.unsold_factor_b = Self::accumulate_factor(pool.unsold_factor_b, FixedU128::one());
+			}
+		});
+	}
+
+	/// Add `increment` to accumulated proceeds or unsold factor.
+	///
+	/// Factors are never reset while the pool has active orders, so they wrap around on overflow.
+	/// Only the difference of two factors is ever used, which stays correct across the wrap.
+	fn accumulate_factor(factor: FixedU128, increment: FixedU128) -> FixedU128 {
+		FixedU128::from_inner(factor.into_inner().wrapping_add(increment.into_inner()))
+	}
+
+	/// Transfer net amount of `asset` between long-term orders account and the pool.
+	fn settle_long_term_orders(
+		asset: AssetId,
+		pool_account: &T::AccountId,
+		amount_to_pool: Balance,
+		amount_from_pool: Balance,
+	) -> DispatchResult {
+		let account = Self::long_term_orders_account();
+
+		if amount_to_pool > amount_from_pool {
+			T::Currency::transfer(asset, &account, pool_account, amount_to_pool - amount_from_pool)
+		} else if amount_from_pool > amount_to_pool {
+			T::Currency::transfer(asset, pool_account, &account, amount_from_pool - amount_to_pool)
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Remove sell rates of orders expiring in `block` from the pool and record proceeds and unsold factors
+	/// at the time of expiry so the proceeds and unsold amounts can be claimed later.
+	fn expire_long_term_orders(pool_account: &T::AccountId, block: T::BlockNumber) -> DispatchResult {
+		let Some(mut pool) = <LongTermOrderPools<T>>::get(pool_account) else {
+			return Ok(());
+		};
+
+		<LongTermOrderExpiries<T>>::try_mutate(pool_account, block, |maybe_expiry| -> DispatchResult {
+			if let Some(expiry) = maybe_expiry {
+				pool.sell_rate_a = pool
+					.sell_rate_a
+					.checked_sub(expiry.sell_rate_a)
+					.ok_or(Error::<T>::Overflow)?;
+				pool.sell_rate_b = pool
+					.sell_rate_b
+					.checked_sub(expiry.sell_rate_b)
+					.ok_or(Error::<T>::Overflow)?;
+				expiry.proceeds_factor_a = pool.proceeds_factor_a;
+				expiry.proceeds_factor_b = pool.proceeds_factor_b;
+				expiry.unsold_factor_a = pool.unsold_factor_a;
+				expiry.unsold_factor_b = pool.unsold_factor_b;
+			}
+			Ok(())
+		})?;
+
+		Self::update_long_term_order_pool(pool_account, pool);
+
+		Ok(())
+	}
+
+	/// Store state of long-term orders of a pool or remove it when there are no active orders left.
+	fn update_long_term_order_pool(pool_account: &T::AccountId, pool: LongTermOrderPool) {
+		if pool.sell_rate_a.is_zero() && pool.sell_rate_b.is_zero() {
+			<LongTermOrderPools<T>>::remove(pool_account);
+			<ActiveLongTermOrderPools<T>>::mutate(|pools| pools.retain(|account| account != pool_account));
+		} else {
+			<LongTermOrderPools<T>>::insert(pool_account, pool);
+		}
+	}
+
+	/// Calculate amount accumulated by the order since it was last paid out, given the factor
+	/// at the time of last payout and the current factor.
+	fn calculate_long_term_order_amount(
+		sell_rate: Balance,
+		last_factor: FixedU128,
+		factor: FixedU128,
+	) -> Result<Balance, DispatchError> {
+		Ok(
+			FixedU128::from_inner(factor.into_inner().wrapping_sub(last_factor.into_inner()))
+				.checked_mul_int(sell_rate)
+				.ok_or(Error::<T>::Overflow)?,
+		)
+	}
+
+	fn do_claim_long_term_order_proceeds(
+		order_id: LongTermOrderId,
+		mut order: LongTermOrder<T::AccountId, T::BlockNumber>,
+		current_block: T::BlockNumber,
+	) -> DispatchResult {
+		let pair_account = Self::pair_account_from_assets(order.asset_in, order.asset_out);
+		let sells_a = AssetPair::new(order.asset_in, order.asset_out).ordered_pair().0 == order.asset_in;
+
+		let (proceeds, unsold_amount) = if current_block >= order.expiry_block {
+			let amounts = <LongTermOrderExpiries<T>>::try_mutate_exists(
+				&pair_account,
+				order.expiry_block,
+				|maybe_expiry| -> Result<(Balance, Balance), DispatchError> {
+					let expiry = maybe_expiry.as_mut().ok_or(Error::<T>::LongTermOrderNotFound)?;
+					let (proceeds_factor, unsold_factor) = if sells_a {
+						(expiry.proceeds_factor_a, expiry.unsold_factor_a)
+					} else {
+						(expiry.proceeds_factor_b, expiry.unsold_factor_b)
+					};
+					let proceeds = Self::calculate_long_term_order_amount(
+						order.sell_rate,
+						order.proceeds_factor,
+						proceeds_factor,
+					)?;
+					let unsold_amount =
+						Self::calculate_long_term_order_amount(order.sell_rate, order.unsold_factor, unsold_factor)?;
+
+					expiry.orders = expiry.orders.saturating_sub(1);
+					if expiry.orders.is_zero() {
+						*maybe_expiry = None;
+					}
+					Ok((proceeds, unsold_amount))
+				},
+			)?;
+
+			<LongTermOrders<T>>::remove(order_id);
+
+			amounts
+		} else {
+			let pool = <LongTermOrderPools<T>>::get(&pair_account).ok_or(Error::<T>::LongTermOrderNotFound)?;
+			let (proceeds_factor, unsold_factor) = if sells_a {
+				(pool.proceeds_factor_a, pool.unsold_factor_a)
+			} else {
+				(pool.proceeds_factor_b, pool.unsold_factor_b)
+			};
+			let proceeds =
+				Self::calculate_long_term_order_amount(order.sell_rate, order.proceeds_factor, proceeds_factor)?;
+			let unsold_amount =
+				Self::calculate_long_term_order_amount(order.sell_rate, order.unsold_factor, unsold_factor)?;
+
+			order.proceeds_factor = proceeds_factor;
+			order.unsold_factor = unsold_factor;
+			<LongTermOrders<T>>::insert(order_id, &order);
+
+			(proceeds, unsold_amount)
+		};
+
+		let account = Self::long_term_orders_account();
+
+		T::Currency::transfer(order.asset_out, &account, &order.owner, proceeds)?;
+
+		Self::deposit_event(Event::LongTermOrderProceedsClaimed {
+			order_id,
+			who: order.owner.clone(),
+			asset_out: order.asset_out,
+			amount: proceeds,
+		});
+
+		if !unsold_amount.is_zero() {
+			T::Currency::transfer(order.asset_in, &account, &order.owner, unsold_amount)?;
+
+			Self::deposit_event(Event::LongTermOrderUnsoldAmountReturned {
+				order_id,
+				who: order.owner,
+				asset_in: order.asset_in,
+				amount: unsold_amount,
+			});
+		}
+
+		Ok(())
+	}
 }
 
 // Implementation of AMM API which makes possible to plug the AMM pool into the exchange pallet.
@@ -739,7 +1665,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		assets: AssetPair,
 		amount: Balance,
 		min_bought: Balance,
-		discount: bool,
 	) -> Result<AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>, sp_runtime::DispatchError> {
 		ensure!(
 			amount >= T::MinTradingLimit::get(),
@@ -753,17 +1678,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::InsufficientAssetBalance
 		);
 
-		// If discount, pool for Sell asset and native asset must exist
-		if discount {
-			ensure!(
-				Self::exists(AssetPair {
-					asset_in: assets.asset_in,
-					asset_out: T::NativeAssetId::get()
-				}),
-				Error::<T>::CannotApplyDiscount
-			);
-		}
-
 		let pair_account = Self::get_pair_id(assets);
 
 		let asset_in_reserve = T::Currency::free_balance(assets.asset_in, &pair_account);
@@ -788,11 +1702,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::MaxOutRatioExceeded
 		);
 
-		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(amount_out)?
-		} else {
-			Self::calculate_fee(amount_out)?
-		};
+		let transfer_fee = Self::calculate_discounted_fee(who, assets, &pair_account, amount_out)?;
 
 		let amount_out_without_fee = amount_out
 			.checked_sub(transfer_fee)
@@ -805,38 +1715,11 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::AssetAmountNotReachedLimit
 		);
 
-		let discount_fee = if discount {
-			let native_asset = T::NativeAssetId::get();
-
-			let native_pair_account = Self::get_pair_id(AssetPair {
-				asset_in: assets.asset_in,
-				asset_out: native_asset,
-			});
-
-			let native_reserve = T::Currency::free_balance(native_asset, &native_pair_account);
-			let asset_reserve = T::Currency::free_balance(assets.asset_in, &native_pair_account);
-
-			let native_fee_spot_price =
-				hydra_dx_math::xyk::calculate_spot_price(asset_reserve, native_reserve, transfer_fee)
-					.map_err(|_| Error::<T>::CannotApplyDiscount)?;
-
-			ensure!(
-				T::Currency::free_balance(native_asset, who) >= native_fee_spot_price,
-				Error::<T>::InsufficientNativeCurrencyBalance
-			);
-
-			native_fee_spot_price
-		} else {
-			Balance::zero()
-		};
-
 		let transfer = AMMTransfer {
 			origin: who.clone(),
 			assets,
 			amount,
 			amount_b: amount_out_without_fee,
-			discount,
-			discount_amount: discount_fee,
 			fee: (assets.asset_out, transfer_fee),
 		};
 
@@ -850,11 +1733,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 	fn execute_sell(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
 		let pair_account = Self::get_pair_id(transfer.assets);
 
-		if transfer.discount && transfer.discount_amount > 0u128 {
-			let native_asset = T::NativeAssetId::get();
-			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
-		}
-
 		T::Currency::transfer(
 			transfer.assets.asset_in,
 			&transfer.origin,
@@ -905,7 +1783,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		assets: AssetPair,
 		amount: Balance,
 		max_limit: Balance,
-		discount: bool,
 	) -> Result<AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>, DispatchError> {
 		ensure!(
 			amount >= T::MinTradingLimit::get(),
@@ -929,17 +1806,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::MaxOutRatioExceeded
 		);
 
-		// If discount, pool for Sell asset and native asset must exist
-		if discount {
-			ensure!(
-				Self::exists(AssetPair {
-					asset_in: assets.asset_out,
-					asset_out: T::NativeAssetId::get()
-				}),
-				Error::<T>::CannotApplyDiscount
-			);
-		}
-
 		let buy_price = hydra_dx_math::xyk::calculate_in_given_out(asset_out_reserve, asset_in_reserve, amount)
 			.map_err(|_| Error::<T>::BuyAssetAmountInvalid)?;
 
@@ -951,11 +1817,7 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::MaxInRatioExceeded
 		);
 
-		let transfer_fee = if discount {
-			Self::calculate_discounted_fee(buy_price)?
-		} else {
-			Self::calculate_fee(buy_price)?
-		};
+		let transfer_fee = Self::calculate_discounted_fee(who, assets, &pair_account, buy_price)?;
 
 		let buy_price_with_fee = buy_price
 			.checked_add(transfer_fee)
@@ -968,37 +1830,11 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			Error::<T>::InsufficientAssetBalance
 		);
 
-		let discount_fee = if discount {
-			let native_asset = T::NativeAssetId::get();
-
-			let native_pair_account = Self::get_pair_id(AssetPair {
-				asset_in: assets.asset_out,
-				asset_out: native_asset,
-			});
-
-			let native_reserve = T::Currency::free_balance(native_asset, &native_pair_account);
-			let asset_reserve = T::Currency::free_balance(assets.asset_out, &native_pair_account);
-
-			let native_fee_spot_price =
-				hydra_dx_math::xyk::calculate_spot_price(asset_reserve, native_reserve, transfer_fee)
-					.map_err(|_| Error::<T>::CannotApplyDiscount)?;
-
-			ensure!(
-				T::Currency::free_balance(native_asset, who) >= native_fee_spot_price,
-				Error::<T>::InsufficientNativeCurrencyBalance
-			);
-			native_fee_spot_price
-		} else {
-			Balance::zero()
-		};
-
 		let transfer = AMMTransfer {
 			origin: who.clone(),
 			assets,
 			amount,
 			amount_b: buy_price,
-			discount,
-			discount_amount: discount_fee,
 			fee: (assets.asset_in, transfer_fee),
 		};
 
@@ -1012,11 +1848,6 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 	fn execute_buy(transfer: &AMMTransfer<T::AccountId, AssetId, AssetPair, Balance>) -> DispatchResult {
 		let pair_account = Self::get_pair_id(transfer.assets);
 
-		if transfer.discount && transfer.discount_amount > 0 {
-			let native_asset = T::NativeAssetId::get();
-			T::Currency::withdraw(native_asset, &transfer.origin, transfer.discount_amount)?;
-		}
-
 		T::Currency::transfer(
 			transfer.assets.asset_out,
 			&pair_account,
@@ -1030,14 +1861,15 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 			transfer.amount_b + transfer.fee.1,
 		)?;
 
+		// Report volume as amounts actually transferred, including the fee paid in `asset_in`.
 		let liquidity_in = T::Currency::total_balance(transfer.assets.asset_in, &pair_account);
 		let liquidity_out = T::Currency::total_balance(transfer.assets.asset_out, &pair_account);
 		T::AMMHandler::on_trade(
 			T::OracleSource::get(),
 			transfer.assets.asset_in,
 			transfer.assets.asset_out,
+			transfer.amount_b.saturating_add(transfer.fee.1),
 			transfer.amount,
-			transfer.amount_b,
 			liquidity_in,
 			liquidity_out,
 			Ratio::new(liquidity_in, liquidity_out),
@@ -1074,8 +1906,8 @@ impl<T: Config> AMM<T::AccountId, AssetId, AssetPair, Balance> for Pallet<T> {
 		T::MaxOutRatio::get()
 	}
 
-	fn get_fee(_pool_account_id: &T::AccountId) -> (u32, u32) {
-		T::GetExchangeFee::get()
+	fn get_fee(pool_account_id: &T::AccountId) -> (u32, u32) {
+		<PoolFee<T>>::get(pool_account_id).unwrap_or_else(T::GetExchangeFee::get)
 	}
 }
 
@@ -1,13 +1,13 @@
 pub use super::mock::*;
 use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok, BoundedVec};
-use hydradx_traits::Registry;
 use hydradx_traits::AMM as AmmPool;
+use hydradx_traits::{InspectRegistry, Registry};
 use orml_traits::MultiCurrency;
 use pallet_asset_registry::AssetType;
 use sp_std::convert::TryInto;
 
-use crate::types::AssetPair;
+use crate::types::{AssetId, AssetPair};
 
 #[test]
 fn create_pool_should_work() {
@@ -423,3 +423,98 @@ fn share_asset_id_should_be_offset() {
 		assert_eq!(AssetRegistry::retrieve_asset(&asset_pair.name()).unwrap(), share_token);
 	});
 }
+
+fn register_asset_with_metadata(asset_id: AssetId, name: &[u8], decimals: u8) {
+	assert_ok!(AssetRegistry::register(
+		RuntimeOrigin::signed(ALICE),
+		name.to_vec(),
+		AssetType::Token,
+		1,
+		Some(asset_id),
+		None,
+		None,
+		None,
+	));
+	assert_ok!(AssetRegistry::set_metadata(
+		RuntimeOrigin::signed(ALICE),
+		asset_id,
+		name.to_vec(),
+		decimals
+	));
+}
+
+#[test]
+fn create_pool_should_register_share_token_metadata() {
+	new_test_ext().execute_with(|| {
+		register_asset_with_metadata(HDX, b"HDX", 12);
+		register_asset_with_metadata(DOT, b"DOT", 10);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			100_000_000_000_000,
+			HDX,
+			10 * 100_000_000_000_000,
+		));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+
+		assert_eq!(
+			<AssetRegistry as InspectRegistry<AssetId>>::asset_symbol(share_token),
+			Some(b"HDX-DOT".to_vec())
+		);
+		// Initial shares are issued in amount of the asset with lower id
+		assert_eq!(
+			<AssetRegistry as InspectRegistry<AssetId>>::decimals(share_token),
+			Some(12)
+		);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 10 * 100_000_000_000_000);
+	});
+}
+
+#[test]
+fn create_pool_should_not_register_share_token_metadata_when_asset_has_no_metadata() {
+	new_test_ext().execute_with(|| {
+		register_asset_with_metadata(HDX, b"HDX", 12);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100_000_000_000_000,
+			DOT,
+			10 * 100_000_000_000_000,
+		));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+
+		assert_eq!(
+			<AssetRegistry as InspectRegistry<AssetId>>::asset_symbol(share_token),
+			None
+		);
+		assert_eq!(<AssetRegistry as InspectRegistry<AssetId>>::decimals(share_token), None);
+	});
+}
+
+#[test]
+fn create_pool_should_not_register_share_token_metadata_when_symbol_is_too_long() {
+	new_test_ext().execute_with(|| {
+		register_asset_with_metadata(HDX, &[b'H'; 50], 12);
+		register_asset_with_metadata(DOT, &[b'D'; 50], 10);
+
+		assert_ok!(XYK::create_pool(
+			RuntimeOrigin::signed(ALICE),
+			HDX,
+			100_000_000_000_000,
+			DOT,
+			10 * 100_000_000_000_000,
+		));
+
+		let share_token = XYK::share_token(HDX_DOT_POOL_ID);
+
+		assert_eq!(
+			<AssetRegistry as InspectRegistry<AssetId>>::asset_symbol(share_token),
+			None
+		);
+		assert_eq!(Currency::free_balance(share_token, &ALICE), 100_000_000_000_000);
+	});
+}
@@ -1,46 +1,67 @@
 pub use super::mock::*;
 use crate::{Error, Event};
 use frame_support::{assert_noop, assert_ok};
+use hydradx_traits::router::{PoolType, TradeExecution};
 use hydradx_traits::AMM as AmmPool;
 use orml_traits::MultiCurrency;
+use sp_runtime::Permill;
 
 use crate::types::AssetPair;
 
 #[test]
 fn fee_calculation() {
 	ExtBuilder::default().build().execute_with(|| {
-		assert_eq!(XYK::calculate_fee(100_000), Ok(200));
-		assert_eq!(XYK::calculate_fee(10_000), Ok(20));
-
-		assert_eq!(XYK::calculate_discounted_fee(9_999), Ok(0));
-		assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(7));
-		assert_eq!(XYK::calculate_discounted_fee(100_000), Ok(70));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(200));
+		assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(20));
+
+		// no discount configured
+		assert_eq!(
+			XYK::calculate_discounted_fee(&ALICE, AssetPair::new(HDX, DOT), &HDX_DOT_POOL_ID, 100_000),
+			Ok(200)
+		);
 	});
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1000))
-		.with_discounted_fee((10, 1000))
+		.with_fee_discount(ALICE, Permill::from_percent(20))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100_000), Ok(1_000));
-			assert_eq!(XYK::calculate_fee(10_000), Ok(100));
-
-			assert_eq!(XYK::calculate_discounted_fee(999), Ok(0));
-			assert_eq!(XYK::calculate_discounted_fee(1_000), Ok(10));
-			assert_eq!(XYK::calculate_discounted_fee(10_000), Ok(100));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100_000), Ok(1_000));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 10_000), Ok(100));
+
+			let assets = AssetPair::new(HDX, DOT);
+			assert_eq!(
+				XYK::calculate_discounted_fee(&ALICE, assets, &HDX_DOT_POOL_ID, 999),
+				Ok(0)
+			);
+			assert_eq!(
+				XYK::calculate_discounted_fee(&ALICE, assets, &HDX_DOT_POOL_ID, 10_000),
+				Ok(80)
+			);
+			assert_eq!(
+				XYK::calculate_discounted_fee(&ALICE, assets, &HDX_DOT_POOL_ID, 100_000),
+				Ok(800)
+			);
+			assert_eq!(
+				XYK::calculate_discounted_fee(&BOB, assets, &HDX_DOT_POOL_ID, 100_000),
+				Ok(1_000)
+			);
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 0))
 		.build()
 		.execute_with(|| {
-			assert_eq!(XYK::calculate_fee(100000), Ok(0));
+			assert_eq!(XYK::calculate_fee(&HDX_DOT_POOL_ID, 100000), Ok(0));
 		});
 
 	ExtBuilder::default()
 		.with_exchange_fee((10, 1))
 		.build()
 		.execute_with(|| {
-			assert_noop!(XYK::calculate_fee(u128::MAX), Error::<Test>::FeeAmountInvalid);
+			assert_noop!(
+				XYK::calculate_fee(&HDX_DOT_POOL_ID, u128::MAX),
+				Error::<Test>::FeeAmountInvalid
+			);
 		});
 }
 
@@ -65,434 +86,404 @@ fn get_fee_should_work() {
 }
 
 #[test]
-fn discount_sell_fees_should_work() {
-	let accounts = vec![
-		(ALICE, HDX, 1_000_000_000_000_000u128),
-		(ALICE, ACA, 1_000_000_000_000_000u128),
-		(ALICE, DOT, 1_000_000_000_000_000u128),
-	];
-
-	let asset_a = ACA;
-	let asset_b = DOT;
-
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default().with_accounts(accounts.clone()).build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
-		assert_ok!(XYK::create_pool(
+fn create_pool_with_fee_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool_with_fee(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
-		));
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
+			(1, 100),
 		));
 
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
-		let native_pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: HDX,
-		});
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (1, 100));
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 998_000_000_000_000);
-
-		assert_ok!(XYK::sell(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
-			1_500,
-			true,
-		));
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 399_999_980_013_994);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 798_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_019_986_006);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 997_999_999_972_014);
-
-		expect_events(vec![Event::SellExecuted {
-			who: ALICE,
-			asset_in: asset_a,
-			asset_out: asset_b,
-			amount: 10_000_000,
-			sale_price: 19_986_006,
-			fee_asset: asset_b,
-			fee_amount: 13_993,
-			pool: pair_account,
+		expect_events(vec![Event::PoolFeeUpdated {
+			pool: HDX_DOT_POOL_ID,
+			fee: (1, 100),
 		}
 		.into()]);
 	});
+}
 
-	// 0.1% discount fee
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default()
-		.with_accounts(accounts.clone())
-		.with_discounted_fee((10, 10_000))
-		.build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
+#[test]
+fn create_pool_with_fee_should_fail_when_fee_is_invalid() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::create_pool_with_fee(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000_000,
+				DOT,
+				2_000_000_000_000,
+				(2, 100),
+			),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			XYK::create_pool_with_fee(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000_000,
+				DOT,
+				2_000_000_000_000,
+				(1, 0),
+			),
+			Error::<Test>::InvalidFee
+		);
+		assert_noop!(
+			XYK::create_pool_with_fee(
+				RuntimeOrigin::signed(ALICE),
+				HDX,
+				1_000_000_000_000,
+				DOT,
+				2_000_000_000_000,
+				(0, 0),
+			),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
+
+#[test]
+fn set_pool_fee_should_work() {
+	new_test_ext().execute_with(|| {
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
 		));
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
-		));
-
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
-		let native_pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: HDX,
-		});
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
+		assert_ok!(XYK::set_pool_fee(RuntimeOrigin::root(), HDX, DOT, (5, 1_000)));
 
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 998_000_000_000_000);
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (5, 1_000));
 
-		assert_ok!(XYK::sell(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
-			1_500,
-			true,
-		));
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 399_999_980_019_991);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 798_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_019_980_009);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 997_999_999_960_020);
-
-		expect_events(vec![Event::SellExecuted {
-			who: ALICE,
-			asset_in: asset_a,
-			asset_out: asset_b,
-			amount: 10_000_000,
-			sale_price: 19_980_009,
-			fee_asset: asset_b,
-			fee_amount: 19_990,
-			pool: pair_account,
+		expect_events(vec![Event::PoolFeeUpdated {
+			pool: HDX_DOT_POOL_ID,
+			fee: (5, 1_000),
 		}
 		.into()]);
 	});
+}
 
-	// zero discount fee
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default()
-		.with_accounts(accounts)
-		.with_discounted_fee((0, 0))
-		.build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
-		let asset_a = ACA;
-		let asset_b = DOT;
-
+#[test]
+fn set_pool_fee_should_fail_when_called_by_non_authority() {
+	new_test_ext().execute_with(|| {
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
 		));
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
-		));
-
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
-
-		assert_ok!(XYK::sell(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
-			1_500,
-			true,
-		));
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 399_999_980_000_001);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 798_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_019_999_999);
 
-		expect_events(vec![Event::SellExecuted {
-			who: ALICE,
-			asset_in: asset_a,
-			asset_out: asset_b,
-			amount: 10_000_000,
-			sale_price: 19_999_999,
-			fee_asset: asset_b,
-			fee_amount: 0,
-			pool: pair_account,
-		}
-		.into()]);
+		assert_noop!(
+			XYK::set_pool_fee(RuntimeOrigin::signed(ALICE), HDX, DOT, (5, 1_000)),
+			sp_runtime::DispatchError::BadOrigin
+		);
 	});
 }
 
 #[test]
-fn discount_buy_fees_should_work() {
-	let accounts = vec![
-		(ALICE, HDX, 1_000_000_000_000_000u128),
-		(ALICE, ACA, 1_000_000_000_000_000u128),
-		(ALICE, DOT, 1_000_000_000_000_000u128),
-	];
-
-	let asset_a = ACA;
-	let asset_b = DOT;
-
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default().with_accounts(accounts.clone()).build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
+fn set_pool_fee_should_fail_when_denominator_is_zero() {
+	new_test_ext().execute_with(|| {
 		assert_ok!(XYK::create_pool(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
 		));
 
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
-		));
-
-		let native_pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: HDX,
-		});
-
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
+		assert_noop!(
+			XYK::set_pool_fee(RuntimeOrigin::root(), HDX, DOT, (0, 0)),
+			Error::<Test>::InvalidFee
+		);
+	});
+}
 
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 998_000_000_000_000);
+#[test]
+fn set_pool_fee_should_fail_when_pool_does_not_exist() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::set_pool_fee(RuntimeOrigin::root(), HDX, DOT, (5, 1_000)),
+			Error::<Test>::TokenPoolNotFound
+		);
+	});
+}
 
-		assert_ok!(XYK::buy(
+#[test]
+fn sell_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool_with_fee(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
+			HDX,
 			1_000_000_000_000,
-			true,
+			DOT,
+			2_000_000_000_000,
+			(1, 100),
 		));
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 199_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_020_014_002);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
+		assert_eq!(
+			XYK::calculate_sell(PoolType::XYK, HDX, DOT, 1_000_000_000),
+			Ok(1_978_021_979)
+		);
 
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 599_999_979_985_998); // compare to values in previous test to see difference!
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 997_999_999_972_000);
+		assert_ok!(XYK::sell(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			1_000_000_000,
+			1_978_021_979,
+		));
 
-		expect_events(vec![Event::BuyExecuted {
-			who: ALICE,
-			asset_out: asset_a,
-			asset_in: asset_b,
-			amount: 10_000_000,
-			buy_price: 20_000_002,
-			fee_asset: asset_b,
-			fee_amount: 14_000,
-			pool: pair_account,
+		expect_events(vec![Event::SellExecuted {
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DOT,
+			amount: 1_000_000_000,
+			sale_price: 1_978_021_979,
+			fee_asset: DOT,
+			fee_amount: 19_980_019,
+			pool: HDX_DOT_POOL_ID,
 		}
 		.into()]);
 	});
+}
 
-	// 0.1% discount fee
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default()
-		.with_accounts(accounts.clone())
-		.with_discounted_fee((10, 10_000))
-		.build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
-		assert_ok!(XYK::create_pool(
+#[test]
+fn buy_should_charge_pool_fee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool_with_fee(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
+			(1, 100),
 		));
 
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
-		));
-
-		let native_pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: HDX,
-		});
-
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
-
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 998_000_000_000_000);
+		assert_eq!(
+			XYK::calculate_buy(PoolType::XYK, HDX, DOT, 1_000_000_000),
+			Ok(505_252_627)
+		);
 
 		assert_ok!(XYK::buy(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
-			1_000_000_000_000,
-			true,
+			RuntimeOrigin::signed(BOB),
+			DOT,
+			HDX,
+			1_000_000_000,
+			505_252_627,
 		));
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 199_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_020_020_002);
-		assert_eq!(Currency::free_balance(asset_a, &native_pair_account), 1_000_000_000_000);
-		assert_eq!(Currency::free_balance(HDX, &native_pair_account), 2_000_000_000_000);
-
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 599_999_979_979_998); // compare to values in previous test to see difference!
-		assert_eq!(Currency::free_balance(HDX, &ALICE), 997_999_999_960_000);
-
 		expect_events(vec![Event::BuyExecuted {
-			who: ALICE,
-			asset_out: asset_a,
-			asset_in: asset_b,
-			amount: 10_000_000,
-			buy_price: 20_000_002,
-			fee_asset: asset_b,
-			fee_amount: 20_000,
-			pool: pair_account,
+			who: BOB,
+			asset_out: DOT,
+			asset_in: HDX,
+			amount: 1_000_000_000,
+			buy_price: 500_250_126,
+			fee_asset: HDX,
+			fee_amount: 5_002_501,
+			pool: HDX_DOT_POOL_ID,
 		}
 		.into()]);
 	});
+}
 
-	// zero discount fee
-	let mut ext: sp_io::TestExternalities = ExtBuilder::default()
-		.with_accounts(accounts)
-		.with_discounted_fee((0, 0))
-		.build();
-	ext.execute_with(|| System::set_block_number(1));
-	ext.execute_with(|| {
-		let asset_a = ACA;
-		let asset_b = DOT;
-
-		assert_ok!(XYK::create_pool(
+#[test]
+fn pool_fee_should_be_removed_when_pool_is_destroyed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(XYK::create_pool_with_fee(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			1_000_000_000_000,
 			HDX,
+			1_000_000_000_000,
+			DOT,
 			2_000_000_000_000,
+			(1, 100),
 		));
 
-		assert_ok!(XYK::create_pool(
+		assert_ok!(XYK::remove_liquidity(
 			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			200_000_000_000_000,
-			asset_b,
-			400_000_000_000_000,
+			HDX,
+			DOT,
+			1_000_000_000_000
 		));
 
-		let pair_account = XYK::get_pair_id(AssetPair {
-			asset_in: asset_a,
-			asset_out: asset_b,
-		});
+		assert!(!XYK::exists(AssetPair::new(HDX, DOT)));
+		assert_eq!(XYK::get_fee(&HDX_DOT_POOL_ID), (2, 1_000));
+	});
+}
+
+#[test]
+fn sell_fee_should_be_discounted_when_fee_discount_is_provided() {
+	let asset_a = ACA;
+	let asset_b = DOT;
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_000_000_000);
+	// (discount, fee amount, sale price)
+	let cases = vec![
+		(Permill::zero(), 39_998, 19_960_001),
+		(Permill::from_percent(50), 19_999, 19_980_000),
+		(Permill::from_percent(100), 0, 19_999_999),
+	];
 
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_000_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 600_000_000_000_000);
+	for (discount, fee_amount, sale_price) in cases {
+		ExtBuilder::default()
+			.with_fee_discount(ALICE, discount)
+			.build()
+			.execute_with(|| {
+				System::set_block_number(1);
+
+				assert_ok!(XYK::create_pool(
+					RuntimeOrigin::signed(ALICE),
+					asset_a,
+					200_000_000_000_000,
+					asset_b,
+					400_000_000_000_000,
+				));
+
+				let pair_account = XYK::get_pair_id(AssetPair {
+					asset_in: asset_a,
+					asset_out: asset_b,
+				});
+
+				assert_ok!(XYK::sell(
+					RuntimeOrigin::signed(ALICE),
+					asset_a,
+					asset_b,
+					10_000_000,
+					1_500,
+				));
+
+				assert_eq!(Currency::free_balance(asset_a, &pair_account), 200_000_010_000_000);
+				assert_eq!(
+					Currency::free_balance(asset_b, &pair_account),
+					400_000_000_000_000 - sale_price
+				);
+				assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_999_990_000_000);
+				assert_eq!(
+					Currency::free_balance(asset_b, &ALICE),
+					600_000_000_000_000 + sale_price
+				);
+
+				expect_events(vec![Event::SellExecuted {
+					who: ALICE,
+					asset_in: asset_a,
+					asset_out: asset_b,
+					amount: 10_000_000,
+					sale_price,
+					fee_asset: asset_b,
+					fee_amount,
+					pool: pair_account,
+				}
+				.into()]);
+			});
+	}
+}
 
-		assert_ok!(XYK::buy(
-			RuntimeOrigin::signed(ALICE),
-			asset_a,
-			asset_b,
-			10_000_000,
-			1_000_000_000,
-			true,
-		));
+#[test]
+fn buy_fee_should_be_discounted_when_fee_discount_is_provided() {
+	let asset_a = ACA;
+	let asset_b = DOT;
 
-		assert_eq!(Currency::free_balance(asset_a, &pair_account), 199_999_990_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &pair_account), 400_000_020_000_002);
+	// (discount, fee amount)
+	let cases = vec![
+		(Permill::zero(), 40_000),
+		(Permill::from_percent(50), 20_000),
+		(Permill::from_percent(100), 0),
+	];
 
-		assert_eq!(Currency::free_balance(asset_a, &ALICE), 799_000_010_000_000);
-		assert_eq!(Currency::free_balance(asset_b, &ALICE), 599_999_979_999_998);
+	for (discount, fee_amount) in cases {
+		ExtBuilder::default()
+			.with_fee_discount(ALICE, discount)
+			.build()
+			.execute_with(|| {
+				System::set_block_number(1);
+
+				assert_ok!(XYK::create_pool(
+					RuntimeOrigin::signed(ALICE),
+					asset_a,
+					200_000_000_000_000,
+					asset_b,
+					400_000_000_000_000,
+				));
+
+				let pair_account = XYK::get_pair_id(AssetPair {
+					asset_in: asset_a,
+					asset_out: asset_b,
+				});
+
+				assert_ok!(XYK::buy(
+					RuntimeOrigin::signed(ALICE),
+					asset_a,
+					asset_b,
+					10_000_000,
+					1_000_000_000_000,
+				));
+
+				let buy_price = 20_000_002;
+
+				assert_eq!(Currency::free_balance(asset_a, &pair_account), 199_999_990_000_000);
+				assert_eq!(
+					Currency::free_balance(asset_b, &pair_account),
+					400_000_000_000_000 + buy_price + fee_amount
+				);
+				assert_eq!(Currency::free_balance(asset_a, &ALICE), 800_000_010_000_000);
+				assert_eq!(
+					Currency::free_balance(asset_b, &ALICE),
+					600_000_000_000_000 - buy_price - fee_amount
+				);
+
+				expect_events(vec![Event::BuyExecuted {
+					who: ALICE,
+					asset_out: asset_a,
+					asset_in: asset_b,
+					amount: 10_000_000,
+					buy_price,
+					fee_asset: asset_b,
+					fee_amount,
+					pool: pair_account,
+				}
+				.into()]);
+			});
+	}
+}
 
-		expect_events(vec![Event::BuyExecuted {
-			who: ALICE,
-			asset_out: asset_a,
-			asset_in: asset_b,
-			amount: 10_000_000,
-			buy_price: 20_000_002,
-			fee_asset: asset_b,
-			fee_amount: 0,
-			pool: pair_account,
-		}
-		.into()]);
-	});
+#[test]
+fn fee_discount_should_only_apply_to_discounted_account() {
+	ExtBuilder::default()
+		.with_fee_discount(BOB, Permill::from_percent(50))
+		.build()
+		.execute_with(|| {
+			System::set_block_number(1);
+
+			assert_ok!(XYK::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				ACA,
+				200_000_000_000_000,
+				DOT,
+				400_000_000_000_000,
+			));
+
+			let pair_account = XYK::get_pair_id(AssetPair {
+				asset_in: ACA,
+				asset_out: DOT,
+			});
+
+			assert_ok!(XYK::sell(RuntimeOrigin::signed(ALICE), ACA, DOT, 10_000_000, 1_500,));
+
+			expect_events(vec![Event::SellExecuted {
+				who: ALICE,
+				asset_in: ACA,
+				asset_out: DOT,
+				amount: 10_000_000,
+				sale_price: 19_960_001,
+				fee_asset: DOT,
+				fee_amount: 39_998,
+				pool: pair_account,
+			}
+			.into()]);
+		});
 }
@@ -5,7 +5,7 @@ use proptest::prelude::*;
 
 use frame_support::assert_ok;
 use primitive_types::U256;
-use sp_runtime::{FixedPointNumber, FixedU128};
+use sp_runtime::{FixedPointNumber, FixedU128, Permill};
 
 const TOLERANCE: Balance = 1_000;
 
@@ -320,7 +320,6 @@ proptest! {
 						asset_b,
 						amount,
 						0u128, // limit not interesting here,
-						false,
 				));
 
 				let new_pool_balance_a = Currency::free_balance(asset_a, &pool_account);
@@ -389,7 +388,6 @@ proptest! {
 						asset_a,
 						amount,
 						u128::MAX, // limit not interesting here,
-						false,
 				));
 
 				let new_pool_balance_a = Currency::free_balance(asset_a, &pool_account);
@@ -417,8 +415,7 @@ proptest! {
 		let asset_b = DOT;
 
 		ExtBuilder::default()
-			.with_exchange_fee((0, 0))
-			.with_discounted_fee((0,0))
+			.with_fee_discount(CHARLIE, Permill::from_percent(100))
 			.with_accounts(vec![
 				(ALICE, asset_a,initial_liquidity * 1000),
 				(ALICE, HDX,initial_liquidity),
@@ -438,14 +435,6 @@ proptest! {
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
 				));
 
-				assert_ok!(XYK::create_pool(
-					RuntimeOrigin::signed(ALICE),
-					asset_b,
-					10 * ONE,
-					HDX,
-					10 * ONE,
-				));
-
 				let pool_account = XYK::get_pair_id(AssetPair {
 					asset_in: asset_a,
 					asset_out: asset_b,
@@ -467,7 +456,6 @@ proptest! {
 						asset_a,
 						amount,
 						u128::MAX, // limit not interesting here,
-						true,
 				));
 
 				let _new_pool_balance_a = Currency::free_balance(asset_a, &pool_account);
@@ -494,8 +482,7 @@ proptest! {
 		let asset_b = DOT;
 
 		ExtBuilder::default()
-			.with_exchange_fee((0, 0))
-			.with_discounted_fee((0,0))
+			.with_fee_discount(CHARLIE, Permill::from_percent(100))
 			.with_accounts(vec![
 				(ALICE, asset_a,initial_liquidity * 1000),
 				(ALICE, HDX,initial_liquidity),
@@ -515,14 +502,6 @@ proptest! {
 					FixedU128::from_float(price).saturating_mul_int(initial_liquidity),
 				));
 
-				assert_ok!(XYK::create_pool(
-					RuntimeOrigin::signed(ALICE),
-					asset_a,
-					10 * ONE,
-					HDX,
-					10 * ONE,
-				));
-
 				let pool_account = XYK::get_pair_id(AssetPair {
 					asset_in: asset_a,
 					asset_out: asset_b,
@@ -544,7 +523,6 @@ proptest! {
 						asset_b,
 						amount,
 						0u128, // limit not interesting here,
-						true,
 				));
 
 				let _new_pool_balance_a = Currency::free_balance(asset_a, &pool_account);
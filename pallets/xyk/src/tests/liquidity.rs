@@ -634,7 +634,6 @@ fn share_ratio_calculations_are_correct() {
 				asset_b,
 				10 * ONE,
 				0u128,
-				false,
 			));
 
 			assert_ok!(XYK::remove_liquidity(
@@ -0,0 +1,586 @@
+pub use super::mock::*;
+use crate::types::{Balance, LongTermOrder, LongTermOrderExpiry, LongTermOrderPool};
+use crate::{Error, Event, LongTermOrderPools, LongTermOrders, PoolAssets};
+use frame_support::traits::{Get, Hooks};
+use frame_support::{assert_noop, assert_ok};
+use orml_traits::MultiCurrency;
+use sp_runtime::FixedU128;
+
+const INITIAL_BALANCE: Balance = 1_000_000_000_000_000;
+
+fn create_hdx_dot_pool() {
+	assert_ok!(XYK::create_pool(
+		RuntimeOrigin::signed(ALICE),
+		HDX,
+		100 * ONE,
+		DOT,
+		200 * ONE,
+	));
+}
+
+fn run_to_block(to: u64) {
+	for block in System::block_number() + 1..=to {
+		System::set_block_number(block);
+		XYK::on_initialize(block);
+	}
+}
+
+#[test]
+fn place_long_term_order_should_work() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		// remainder which cannot be split equally stays in the account
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE + 5,
+			10,
+		));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE - 10 * ONE);
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 10 * ONE);
+
+		assert_eq!(
+			XYK::long_term_order(0),
+			Some(LongTermOrder {
+				owner: BOB,
+				asset_in: HDX,
+				asset_out: DOT,
+				sell_rate: ONE,
+				expiry_block: 11,
+				proceeds_factor: FixedU128::from_inner(0),
+				unsold_factor: FixedU128::from_inner(0),
+			})
+		);
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID),
+			Some(LongTermOrderPool {
+				sell_rate_a: ONE,
+				..Default::default()
+			})
+		);
+		assert_eq!(
+			XYK::long_term_order_expiry(HDX_DOT_POOL_ID, 11),
+			Some(LongTermOrderExpiry {
+				sell_rate_a: ONE,
+				orders: 1,
+				..Default::default()
+			})
+		);
+		assert_eq!(XYK::active_long_term_order_pools().to_vec(), vec![HDX_DOT_POOL_ID]);
+		assert_eq!(XYK::next_long_term_order_id(), 1);
+
+		expect_events(vec![Event::LongTermOrderPlaced {
+			order_id: 0,
+			who: BOB,
+			asset_in: HDX,
+			asset_out: DOT,
+			amount: 10 * ONE,
+			sell_rate: ONE,
+			expiry_block: 11,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn place_long_term_order_should_fail_when_called_with_invalid_params() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 10),
+			Error::<Test>::TokenPoolNotFound
+		);
+
+		create_hdx_dot_pool();
+
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 0),
+			Error::<Test>::InvalidLongTermOrderDuration
+		);
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(BOB), HDX, DOT, 10 * ONE, 1_001),
+			Error::<Test>::InvalidLongTermOrderDuration
+		);
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(BOB), HDX, DOT, 999, 10),
+			Error::<Test>::InsufficientTradingAmount
+		);
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(CHARLIE), DOT, HDX, 10 * ONE, 10),
+			Error::<Test>::InsufficientAssetBalance
+		);
+		// sell rate exceeds 1/3 of the pool reserve
+		assert_noop!(
+			XYK::place_long_term_order(RuntimeOrigin::signed(BOB), HDX, DOT, 340 * ONE, 10),
+			Error::<Test>::MaxInRatioExceeded
+		);
+	});
+}
+
+#[test]
+fn long_term_order_should_be_executed_in_each_block() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		run_to_block(3);
+
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 102 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &HDX_DOT_POOL_ID), 196_086_235_760_046);
+
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 8 * ONE);
+		assert_eq!(
+			Currency::free_balance(DOT, &XYK::long_term_orders_account()),
+			3_913_764_239_954
+		);
+
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID),
+			Some(LongTermOrderPool {
+				sell_rate_a: ONE,
+				proceeds_factor_a: FixedU128::from_inner(3_913_764_239_954_000_000),
+				..Default::default()
+			})
+		);
+
+		expect_events(vec![Event::LongTermOrdersExecuted {
+			pool: HDX_DOT_POOL_ID,
+			asset_a: HDX,
+			asset_b: DOT,
+			amount_a_in: ONE,
+			amount_b_in: 0,
+			amount_a_out: 0,
+			amount_b_out: 1_937_526_616_191,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn opposing_long_term_orders_should_be_matched() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(ALICE),
+			DOT,
+			HDX,
+			30 * ONE,
+			10,
+		));
+
+		run_to_block(2);
+
+		// 1 HDX is matched with 2 DOT, remaining 1 DOT is sold to the pool
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 99_505_482_587_064);
+		assert_eq!(Currency::free_balance(DOT, &HDX_DOT_POOL_ID), 201_004_000_000_000);
+
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID),
+			Some(LongTermOrderPool {
+				sell_rate_a: ONE,
+				sell_rate_b: 3 * ONE,
+				proceeds_factor_a: FixedU128::from_inner(1_996_000_000_000_000_000),
+				proceeds_factor_b: FixedU128::from_inner(498_172_470_978_666_666),
+				..Default::default()
+			})
+		);
+
+		expect_events(vec![Event::LongTermOrdersExecuted {
+			pool: HDX_DOT_POOL_ID,
+			asset_a: HDX,
+			asset_b: DOT,
+			amount_a_in: ONE,
+			amount_b_in: 3 * ONE,
+			amount_a_out: 1_494_517_412_936,
+			amount_b_out: 1_996_000_000_000,
+		}
+		.into()]);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(ALICE), 1));
+
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE + 1_996_000_000_000);
+		assert_eq!(
+			Currency::free_balance(HDX, &ALICE),
+			INITIAL_BALANCE - 100 * ONE + 1_494_517_412_935
+		);
+	});
+}
+
+#[test]
+fn claim_long_term_order_proceeds_should_work_when_order_is_active() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		run_to_block(3);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE + 3_913_764_239_954);
+		assert_eq!(Currency::free_balance(DOT, &XYK::long_term_orders_account()), 0);
+		assert_eq!(
+			XYK::long_term_order(0).map(|order| order.proceeds_factor),
+			Some(FixedU128::from_inner(3_913_764_239_954_000_000))
+		);
+
+		expect_events(vec![Event::LongTermOrderProceedsClaimed {
+			order_id: 0,
+			who: BOB,
+			asset_out: DOT,
+			amount: 3_913_764_239_954,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn claim_long_term_order_proceeds_should_remove_order_when_expired() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		run_to_block(11);
+
+		assert_eq!(XYK::long_term_order_pool(HDX_DOT_POOL_ID), None);
+		assert!(XYK::active_long_term_order_pools().is_empty());
+
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 110 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &HDX_DOT_POOL_ID), 181_853_008_881_918);
+
+		// no more execution after expiry
+		run_to_block(12);
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 110 * ONE);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE - 10 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE + 18_146_991_118_082);
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 0);
+		assert_eq!(Currency::free_balance(DOT, &XYK::long_term_orders_account()), 0);
+
+		assert_eq!(XYK::long_term_order(0), None);
+		assert_eq!(XYK::long_term_order_expiry(HDX_DOT_POOL_ID, 11), None);
+
+		assert_noop!(
+			XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0),
+			Error::<Test>::LongTermOrderNotFound
+		);
+	});
+}
+
+#[test]
+fn cancel_long_term_order_should_return_unsold_amount_and_proceeds() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		run_to_block(3);
+
+		assert_ok!(XYK::cancel_long_term_order(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), INITIAL_BALANCE - 2 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE + 3_913_764_239_954);
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 0);
+		assert_eq!(Currency::free_balance(DOT, &XYK::long_term_orders_account()), 0);
+
+		assert_eq!(XYK::long_term_order(0), None);
+		assert_eq!(XYK::long_term_order_pool(HDX_DOT_POOL_ID), None);
+		assert_eq!(XYK::long_term_order_expiry(HDX_DOT_POOL_ID, 11), None);
+		assert!(XYK::active_long_term_order_pools().is_empty());
+
+		expect_events(vec![
+			Event::LongTermOrderCancelled {
+				order_id: 0,
+				who: BOB,
+				asset_in: HDX,
+				unsold_amount: 8 * ONE,
+			}
+			.into(),
+			Event::LongTermOrderProceedsClaimed {
+				order_id: 0,
+				who: BOB,
+				asset_out: DOT,
+				amount: 3_913_764_239_954,
+			}
+			.into(),
+		]);
+
+		// pool is not traded anymore
+		run_to_block(4);
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 102 * ONE);
+	});
+}
+
+#[test]
+fn cancel_long_term_order_should_keep_other_orders_active() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(CHARLIE),
+			HDX,
+			DOT,
+			20 * ONE,
+			10,
+		));
+
+		assert_ok!(XYK::cancel_long_term_order(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID),
+			Some(LongTermOrderPool {
+				sell_rate_a: 2 * ONE,
+				..Default::default()
+			})
+		);
+		assert_eq!(
+			XYK::long_term_order_expiry(HDX_DOT_POOL_ID, 11),
+			Some(LongTermOrderExpiry {
+				sell_rate_a: 2 * ONE,
+				orders: 1,
+				..Default::default()
+			})
+		);
+		assert_eq!(XYK::active_long_term_order_pools().to_vec(), vec![HDX_DOT_POOL_ID]);
+	});
+}
+
+#[test]
+fn cancel_and_claim_should_fail_when_called_by_non_owner() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		assert_noop!(
+			XYK::cancel_long_term_order(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NotLongTermOrderOwner
+		);
+		assert_noop!(
+			XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(ALICE), 0),
+			Error::<Test>::NotLongTermOrderOwner
+		);
+		assert_noop!(
+			XYK::cancel_long_term_order(RuntimeOrigin::signed(BOB), 1),
+			Error::<Test>::LongTermOrderNotFound
+		);
+	});
+}
+
+#[test]
+fn remove_liquidity_should_not_destroy_pool_with_active_long_term_orders() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		assert_noop!(
+			XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, 100 * ONE),
+			Error::<Test>::PoolHasActiveLongTermOrders
+		);
+
+		run_to_block(11);
+
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, 100 * ONE));
+	});
+}
+
+#[test]
+fn long_term_orders_should_not_sell_more_than_max_in_ratio_of_reserve_in_block() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		let sell_rate = 30 * ONE;
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * sell_rate,
+			10,
+		));
+
+		// reserve drops after the order is placed, so the sell rate exceeds max in ratio of the reserve
+		assert_ok!(XYK::remove_liquidity(RuntimeOrigin::signed(ALICE), HDX, DOT, 50 * ONE));
+
+		let reserve = Currency::free_balance(HDX, &HDX_DOT_POOL_ID);
+		let max_amount_in = reserve / MaxInRatio::get();
+		assert!(max_amount_in < sell_rate);
+
+		run_to_block(2);
+
+		let unsold_amount = sell_rate - max_amount_in;
+		let unsold_factor = FixedU128::checked_from_rational(unsold_amount, sell_rate).unwrap();
+
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID).map(|pool| pool.unsold_factor_a),
+			Some(unsold_factor)
+		);
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), reserve + max_amount_in);
+		assert_eq!(
+			Currency::free_balance(HDX, &XYK::long_term_orders_account()),
+			9 * sell_rate + unsold_amount
+		);
+
+		let balance_before = Currency::free_balance(HDX, &BOB);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+
+		let returned_amount = unsold_factor.checked_mul_int(sell_rate).unwrap();
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), balance_before + returned_amount);
+		assert_eq!(
+			XYK::long_term_order(0).map(|order| order.unsold_factor),
+			Some(unsold_factor)
+		);
+
+		expect_events(vec![Event::LongTermOrderUnsoldAmountReturned {
+			order_id: 0,
+			who: BOB,
+			asset_in: HDX,
+			amount: returned_amount,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn sell_rate_should_be_returned_when_long_term_orders_execution_fails() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		// execution fails when pool assets are not known
+		PoolAssets::<Test>::remove(HDX_DOT_POOL_ID);
+
+		run_to_block(3);
+
+		assert_eq!(Currency::free_balance(HDX, &HDX_DOT_POOL_ID), 100 * ONE);
+		assert_eq!(Currency::free_balance(DOT, &HDX_DOT_POOL_ID), 200 * ONE);
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 10 * ONE);
+		assert_eq!(
+			XYK::long_term_order_pool(HDX_DOT_POOL_ID),
+			Some(LongTermOrderPool {
+				sell_rate_a: ONE,
+				unsold_factor_a: FixedU128::from_inner(2_000_000_000_000_000_000),
+				unsold_factor_b: FixedU128::from_inner(2_000_000_000_000_000_000),
+				..Default::default()
+			})
+		);
+
+		let balance_before = Currency::free_balance(HDX, &BOB);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(Currency::free_balance(HDX, &BOB), balance_before + 2 * ONE);
+		assert_eq!(Currency::free_balance(HDX, &XYK::long_term_orders_account()), 8 * ONE);
+
+		expect_events(vec![Event::LongTermOrderUnsoldAmountReturned {
+			order_id: 0,
+			who: BOB,
+			asset_in: HDX,
+			amount: 2 * ONE,
+		}
+		.into()]);
+	});
+}
+
+#[test]
+fn claim_long_term_order_proceeds_should_work_when_proceeds_factor_overflows() {
+	new_test_ext().execute_with(|| {
+		create_hdx_dot_pool();
+
+		assert_ok!(XYK::place_long_term_order(
+			RuntimeOrigin::signed(BOB),
+			HDX,
+			DOT,
+			10 * ONE,
+			10,
+		));
+
+		// factor wraps around in the next block
+		let factor = FixedU128::from_inner(u128::MAX);
+		LongTermOrderPools::<Test>::mutate(HDX_DOT_POOL_ID, |pool| {
+			pool.as_mut().unwrap().proceeds_factor_a = factor;
+		});
+		LongTermOrders::<Test>::mutate(0, |order| {
+			order.as_mut().unwrap().proceeds_factor = factor;
+		});
+
+		run_to_block(3);
+
+		assert_ok!(XYK::claim_long_term_order_proceeds(RuntimeOrigin::signed(BOB), 0));
+
+		assert_eq!(Currency::free_balance(DOT, &BOB), INITIAL_BALANCE + 3_913_764_239_954);
+		assert_eq!(Currency::free_balance(DOT, &XYK::long_term_orders_account()), 0);
+		assert_eq!(
+			XYK::long_term_order(0).map(|order| order.proceeds_factor),
+			Some(FixedU128::from_inner(3_913_764_239_953_999_999))
+		);
+	});
+}
@@ -18,20 +18,21 @@
 use crate as xyk;
 use crate::Config;
 use crate::*;
-use frame_support::parameter_types;
+use frame_support::{parameter_types, weights::Weight};
 use frame_system as system;
 use orml_traits::parameter_type_with_key;
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup, One},
+	Permill,
 };
 
 use crate::types::{AssetId, Balance};
 use frame_support::traits::{Everything, GenesisBuild, Get, Nothing};
-use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, Source};
+use hydradx_traits::{AssetPairAccountIdFor, CanCreatePool, FeeDiscountProvider, Source};
 
-use frame_system::EnsureSigned;
+use frame_system::{EnsureRoot, EnsureSigned};
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
 use std::cell::RefCell;
 
@@ -69,7 +70,7 @@ frame_support::construct_runtime!(
 
 thread_local! {
 		static EXCHANGE_FEE: RefCell<(u32, u32)> = RefCell::new((2, 1_000));
-		static DISCOUNTED_FEE: RefCell<(u32, u32)> = RefCell::new((7, 10_000));
+		static FEE_DISCOUNTS: RefCell<Vec<(AccountId, Permill)>> = RefCell::new(vec![]);
 		static MAX_OUT_RATIO: RefCell<u128> = RefCell::new(3);
 }
 
@@ -80,10 +81,20 @@ impl Get<(u32, u32)> for ExchangeFee {
 	}
 }
 
-struct DiscountedFee;
-impl Get<(u32, u32)> for DiscountedFee {
-	fn get() -> (u32, u32) {
-		DISCOUNTED_FEE.with(|v| *v.borrow())
+pub struct FeeDiscountMock;
+impl FeeDiscountProvider<AccountId, AssetId> for FeeDiscountMock {
+	fn discount(who: &AccountId, _asset_in: AssetId, _asset_out: AssetId) -> Permill {
+		FEE_DISCOUNTS.with(|v| {
+			v.borrow()
+				.iter()
+				.find(|(account, _)| account == who)
+				.map(|(_, discount)| *discount)
+				.unwrap_or_default()
+		})
+	}
+
+	fn discount_weight() -> Weight {
+		Weight::zero()
 	}
 }
 
@@ -180,8 +191,10 @@ parameter_types! {
 	pub const MaxInRatio: u128 = 3;
 	pub MaxOutRatio: u128 = MaximumOutRatio::get();
 	pub ExchangeFeeRate: (u32, u32) = ExchangeFee::get();
-	pub DiscountedFeeRate: (u32, u32) = DiscountedFee::get();
+	pub const MaxExchangeFee: (u32, u32) = (1, 100);
 	pub const OracleSourceIdentifier: Source = *b"hydraxyk";
+	pub const MaxLongTermOrderDuration: u64 = 1_000;
+	pub const MaxLongTermOrderPools: u32 = 10;
 }
 
 pub struct Disallow10_10Pool();
@@ -197,7 +210,6 @@ impl Config for Test {
 	type AssetRegistry = AssetRegistry;
 	type AssetPairAccountId = AssetPairAccountIdTest;
 	type Currency = Currency;
-	type NativeAssetId = NativeAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = ExchangeFeeRate;
 	type MinTradingLimit = MinTradingLimit;
@@ -206,9 +218,13 @@ impl Config for Test {
 	type MaxOutRatio = MaxOutRatio;
 	type CanCreatePool = Disallow10_10Pool;
 	type AMMHandler = ();
-	type DiscountedFee = DiscountedFeeRate;
+	type FeeDiscount = FeeDiscountMock;
+	type MaxExchangeFee = MaxExchangeFee;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type NonDustableWhitelistHandler = Whitelist;
 	type OracleSource = OracleSourceIdentifier;
+	type MaxLongTermOrderDuration = MaxLongTermOrderDuration;
+	type MaxLongTermOrderPools = MaxLongTermOrderPools;
 }
 
 pub struct ExtBuilder {
@@ -245,8 +261,8 @@ impl ExtBuilder {
 		self
 	}
 
-	pub fn with_discounted_fee(self, f: (u32, u32)) -> Self {
-		DISCOUNTED_FEE.with(|v| *v.borrow_mut() = f);
+	pub fn with_fee_discount(self, who: AccountId, discount: Permill) -> Self {
+		FEE_DISCOUNTS.with(|v| v.borrow_mut().push((who, discount)));
 		self
 	}
 
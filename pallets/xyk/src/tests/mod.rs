@@ -3,6 +3,7 @@ mod creation;
 mod fees;
 mod invariants;
 mod liquidity;
+mod long_term_orders;
 pub(crate) mod mock;
 mod spot_price;
 mod trades;
@@ -40,7 +40,6 @@ fn sell_test() {
 			asset_b,
 			456_444_678,
 			1000000000000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999799543555322);
@@ -164,7 +163,6 @@ fn work_flow_happy_path_should_work() {
 			asset_b,
 			216_666_666_666,
 			100_000_000_000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_650_000_000_000);
@@ -186,7 +184,6 @@ fn work_flow_happy_path_should_work() {
 			asset_b,
 			288_888_888_888,
 			100_000_000_000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_361_111_111_112);
@@ -337,7 +334,6 @@ fn sell_with_correct_fees_should_work() {
 			asset_b,
 			100_000,
 			1_000_000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &pair_account), 10100000);
@@ -393,49 +389,12 @@ fn sell_without_sufficient_balance_should_not_work() {
 		));
 
 		assert_noop!(
-			XYK::sell(RuntimeOrigin::signed(user), ACA, DOT, 1_000, 100, false),
+			XYK::sell(RuntimeOrigin::signed(user), ACA, DOT, 1_000, 100),
 			Error::<Test>::InsufficientAssetBalance
 		);
 	});
 }
 
-#[test]
-fn sell_without_sufficient_discount_balance_should_not_work() {
-	new_test_ext().execute_with(|| {
-		let user = ALICE;
-		let asset_a = ACA;
-		let asset_b = DOT;
-
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(user),
-			asset_a,
-			1_000_000_000_000,
-			asset_b,
-			1_000_000_000_000,
-		));
-
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(user),
-			asset_a,
-			1_000_000_000_000,
-			HDX,
-			1_000_000_000_000,
-		));
-
-		assert_ok!(Currency::transfer(
-			RuntimeOrigin::signed(user),
-			BOB,
-			HDX,
-			998_999_999_999_999
-		));
-
-		assert_noop!(
-			XYK::sell(RuntimeOrigin::signed(user), ACA, DOT, 1_000_000_000, 100, true),
-			Error::<Test>::InsufficientNativeCurrencyBalance
-		);
-	});
-}
-
 #[test]
 fn buy_without_sufficient_balance_should_not_work() {
 	new_test_ext().execute_with(|| {
@@ -459,56 +418,12 @@ fn buy_without_sufficient_balance_should_not_work() {
 		));
 
 		assert_noop!(
-			XYK::buy(RuntimeOrigin::signed(user), DOT, ACA, 1_000, 10_000, false),
+			XYK::buy(RuntimeOrigin::signed(user), DOT, ACA, 1_000, 10_000),
 			Error::<Test>::InsufficientAssetBalance
 		);
 	});
 }
 
-#[test]
-fn buy_without_sufficient_discount_balance_should_not_work() {
-	new_test_ext().execute_with(|| {
-		let user = ALICE;
-		let asset_a = ACA;
-		let asset_b = DOT;
-
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(user),
-			asset_a,
-			1_000_000_000_000,
-			asset_b,
-			1_000_000_000_000,
-		));
-
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(user),
-			asset_b,
-			1_000_000_000_000,
-			HDX,
-			1_000_000_000_000,
-		));
-
-		assert_ok!(Currency::transfer(
-			RuntimeOrigin::signed(user),
-			BOB,
-			HDX,
-			998_999_999_999_999
-		));
-
-		assert_noop!(
-			XYK::buy(
-				RuntimeOrigin::signed(user),
-				DOT,
-				ACA,
-				1_000_000_000,
-				10_000_000_000,
-				true
-			),
-			Error::<Test>::InsufficientNativeCurrencyBalance
-		);
-	});
-}
-
 #[test]
 fn single_buy_should_work() {
 	new_test_ext().execute_with(|| {
@@ -543,7 +458,6 @@ fn single_buy_should_work() {
 			asset_b,
 			6_666_666,
 			1_000_000_000_000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999_999_806_666_666);
@@ -622,65 +536,22 @@ fn remove_zero_liquidity_from_non_existing_pool_should_not_work() {
 fn sell_with_non_existing_pool_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::sell(RuntimeOrigin::signed(ALICE), HDX, DOT, 456_444_678, 1_000_000, false),
+			XYK::sell(RuntimeOrigin::signed(ALICE), HDX, DOT, 456_444_678, 1_000_000),
 			Error::<Test>::TokenPoolNotFound
 		);
 	});
 }
 
-#[test]
-fn discount_sell_with_no_native_pool_should_not_work() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			ACA,
-			1000,
-			DOT,
-			3_200_000
-		));
-
-		assert_noop!(
-			XYK::sell(RuntimeOrigin::signed(ALICE), ACA, DOT, 456_444_678, 1_000_000, true),
-			Error::<Test>::CannotApplyDiscount
-		);
-	});
-}
-
 #[test]
 fn buy_with_non_existing_pool_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::buy(
-				RuntimeOrigin::signed(ALICE),
-				HDX,
-				DOT,
-				456_444_678,
-				1_000_000_000,
-				false
-			),
+			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 456_444_678, 1_000_000_000),
 			Error::<Test>::TokenPoolNotFound
 		);
 	});
 }
 
-#[test]
-fn discount_buy_with_no_native_pool_should_not_work() {
-	new_test_ext().execute_with(|| {
-		assert_ok!(XYK::create_pool(
-			RuntimeOrigin::signed(ALICE),
-			ACA,
-			10_000,
-			DOT,
-			32_000_000
-		));
-
-		assert_noop!(
-			XYK::buy(RuntimeOrigin::signed(ALICE), ACA, DOT, 1000, 1_000_000_000, true),
-			Error::<Test>::CannotApplyDiscount
-		);
-	});
-}
-
 #[test]
 fn money_in_sell_money_out_should_leave_the_same_balance() {
 	new_test_ext().execute_with(|| {
@@ -718,7 +589,6 @@ fn money_in_sell_money_out_should_leave_the_same_balance() {
 			asset_b,
 			456_444_678,
 			1000000000000,
-			false,
 		));
 
 		assert_eq!(Currency::free_balance(asset_a, &user_1), 999799543555322);
@@ -849,7 +719,6 @@ fn sell_test_not_reaching_limit() {
 				asset_b,
 				456_444_678,
 				1_000_000_000_000_000,
-				false,
 			),
 			Error::<Test>::AssetAmountNotReachedLimit
 		);
@@ -897,7 +766,6 @@ fn buy_test_exceeding_max_limit() {
 				asset_b,
 				456_444_678,
 				1_000_000_000,
-				false,
 			),
 			Error::<Test>::AssetAmountExceededLimit
 		);
@@ -945,7 +813,6 @@ fn single_buy_more_than_ratio_out_should_not_work() {
 				asset_b,
 				66_666_667,
 				1_000_000_000_000,
-				false,
 			),
 			Error::<Test>::MaxOutRatioExceeded
 		);
@@ -974,7 +841,6 @@ fn single_buy_more_than_ratio_in_should_not_work() {
 				asset_b,
 				33_333_333_333,
 				1_000_000_000_000,
-				false,
 			),
 			Error::<Test>::MaxInRatioExceeded
 		);
@@ -1016,7 +882,6 @@ fn single_sell_more_than_ratio_in_should_not_work() {
 				asset_b,
 				66_666_666_667,
 				10_000_000,
-				false,
 			),
 			Error::<Test>::MaxInRatioExceeded
 		);
@@ -1045,7 +910,6 @@ fn single_sell_more_than_ratio_out_should_not_work() {
 				asset_b,
 				33_333_333_333,
 				10_000_000,
-				false,
 			),
 			Error::<Test>::MaxOutRatioExceeded
 		);
@@ -1056,7 +920,7 @@ fn single_sell_more_than_ratio_out_should_not_work() {
 fn sell_with_low_amount_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::sell(RuntimeOrigin::signed(ALICE), HDX, DOT, 1, 1_000_000, false),
+			XYK::sell(RuntimeOrigin::signed(ALICE), HDX, DOT, 1, 1_000_000),
 			Error::<Test>::InsufficientTradingAmount
 		);
 	});
@@ -1066,7 +930,7 @@ fn sell_with_low_amount_should_not_work() {
 fn buy_with_low_amount_should_not_work() {
 	new_test_ext().execute_with(|| {
 		assert_noop!(
-			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 1, 1_000_000, false),
+			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 1, 1_000_000),
 			Error::<Test>::InsufficientTradingAmount
 		);
 	});
@@ -1078,7 +942,7 @@ fn buy_with_excesive_amount_should_not_work() {
 		assert_ok!(XYK::create_pool(RuntimeOrigin::signed(ALICE), HDX, 10_000, DOT, 10_000,));
 
 		assert_noop!(
-			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 20_000, 1_000_000, false),
+			XYK::buy(RuntimeOrigin::signed(ALICE), HDX, DOT, 20_000, 1_000_000),
 			Error::<Test>::InsufficientPoolAssetBalance
 		);
 	});
@@ -1,11 +1,12 @@
 use crate::types::{AssetId, AssetPair, Balance};
-use crate::{Config, Error, Pallet};
+use crate::{Config, Error, Pallet, PoolAssets};
 use frame_support::ensure;
 use frame_support::traits::Get;
-use hydradx_traits::router::{ExecutorError, PoolType, TradeExecution};
+use hydradx_traits::router::{ExecutorError, PoolAssetPairs, PoolType, Trade, TradeExecution};
 use hydradx_traits::AMM;
 use orml_traits::MultiCurrency;
 use sp_runtime::DispatchError;
+use sp_std::vec::Vec;
 
 impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance> for Pallet<T> {
 	type Error = DispatchError;
@@ -39,7 +40,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 			ExecutorError::Error(Error::<T>::InsufficientPoolAssetBalance.into())
 		);
 
-		let transfer_fee = Self::calculate_fee(amount_out).map_err(ExecutorError::Error)?;
+		let transfer_fee = Self::calculate_fee(&pair_account, amount_out).map_err(ExecutorError::Error)?;
 
 		let amount_out_without_fee = amount_out
 			.checked_sub(transfer_fee)
@@ -83,7 +84,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		let amount_in = hydra_dx_math::xyk::calculate_in_given_out(asset_out_reserve, asset_in_reserve, amount_out)
 			.map_err(|_| ExecutorError::Error(Error::<T>::BuyAssetAmountInvalid.into()))?;
 
-		let transfer_fee = Self::calculate_fee(amount_in).map_err(ExecutorError::Error)?;
+		let transfer_fee = Self::calculate_fee(&pair_account, amount_in).map_err(ExecutorError::Error)?;
 
 		let amount_in_with_fee = amount_in
 			.checked_add(transfer_fee)
@@ -104,7 +105,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 			return Err(ExecutorError::NotSupported);
 		}
 
-		Self::sell(who, asset_in, asset_out, amount_in, min_limit, false).map_err(ExecutorError::Error)
+		Self::sell(who, asset_in, asset_out, amount_in, min_limit).map_err(ExecutorError::Error)
 	}
 
 	fn execute_buy(
@@ -119,7 +120,7 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 			return Err(ExecutorError::NotSupported);
 		}
 
-		Self::buy(who, asset_out, asset_in, amount_out, max_limit, false).map_err(ExecutorError::Error)
+		Self::buy(who, asset_out, asset_in, amount_out, max_limit).map_err(ExecutorError::Error)
 	}
 
 	fn get_liquidity_depth(
@@ -141,3 +142,15 @@ impl<T: Config> TradeExecution<T::RuntimeOrigin, T::AccountId, AssetId, Balance>
 		Ok(liquidty)
 	}
 }
+
+impl<T: Config> PoolAssetPairs<AssetId> for Pallet<T> {
+	fn asset_pairs() -> Vec<Trade<AssetId>> {
+		PoolAssets::<T>::iter_values()
+			.map(|(asset_a, asset_b)| Trade {
+				pool: PoolType::XYK,
+				asset_in: asset_a,
+				asset_out: asset_b,
+			})
+			.collect()
+	}
+}
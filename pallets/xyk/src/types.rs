@@ -19,10 +19,11 @@ pub type AssetId = u32;
 pub type Amount = i128;
 pub type Balance = u128;
 pub type Price = FixedU128;
+pub type LongTermOrderId = u32;
 
-use codec::{Decode, Encode};
+use codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
-use sp_runtime::FixedU128;
+use sp_runtime::{FixedU128, RuntimeDebug};
 use sp_std::vec::Vec;
 
 #[cfg(feature = "std")]
@@ -67,3 +68,57 @@ impl AssetPair {
 		buf
 	}
 }
+
+/// Aggregated state of active long-term orders of a pool.
+///
+/// Side A is the asset with lower id of the pool asset pair.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LongTermOrderPool {
+	/// Amount of asset A sold by all active orders in each block.
+	pub sell_rate_a: Balance,
+	/// Amount of asset B sold by all active orders in each block.
+	pub sell_rate_b: Balance,
+	/// Accumulated amount of asset B received per unit of sell rate of asset A.
+	pub proceeds_factor_a: FixedU128,
+	/// Accumulated amount of asset A received per unit of sell rate of asset B.
+	pub proceeds_factor_b: FixedU128,
+	/// Accumulated amount of asset A left unsold per unit of sell rate of asset A.
+	pub unsold_factor_a: FixedU128,
+	/// Accumulated amount of asset B left unsold per unit of sell rate of asset B.
+	pub unsold_factor_b: FixedU128,
+}
+
+/// Long-term orders of a pool expiring in the same block.
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LongTermOrderExpiry {
+	/// Sell rate of expiring orders selling asset A.
+	pub sell_rate_a: Balance,
+	/// Sell rate of expiring orders selling asset B.
+	pub sell_rate_b: Balance,
+	/// Number of orders which are not cancelled or claimed yet.
+	pub orders: u32,
+	/// Proceeds factor of asset A at the time of expiry.
+	pub proceeds_factor_a: FixedU128,
+	/// Proceeds factor of asset B at the time of expiry.
+	pub proceeds_factor_b: FixedU128,
+	/// Unsold factor of asset A at the time of expiry.
+	pub unsold_factor_a: FixedU128,
+	/// Unsold factor of asset B at the time of expiry.
+	pub unsold_factor_b: FixedU128,
+}
+
+/// Long-term order selling `asset_in` for `asset_out` continuously until `expiry_block`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, MaxEncodedLen, TypeInfo)]
+pub struct LongTermOrder<AccountId, BlockNumber> {
+	pub owner: AccountId,
+	pub asset_in: AssetId,
+	pub asset_out: AssetId,
+	/// Amount of `asset_in` sold in each block.
+	pub sell_rate: Balance,
+	/// Last block in which the order is executed.
+	pub expiry_block: BlockNumber,
+	/// Proceeds factor of the pool when proceeds were last paid out.
+	pub proceeds_factor: FixedU128,
+	/// Unsold factor of the pool when unsold amount was last paid out.
+	pub unsold_factor: FixedU128,
+}
@@ -55,6 +55,11 @@ pub trait WeightInfo {
 	fn buy() -> Weight;
 	fn router_execution_sell(c: u32, e: u32) -> Weight;
 	fn router_execution_buy(c: u32, e: u32) -> Weight;
+	fn set_pool_fee() -> Weight;
+	fn on_initialize(p: u32) -> Weight;
+	fn place_long_term_order() -> Weight;
+	fn cancel_long_term_order() -> Weight;
+	fn claim_long_term_order_proceeds() -> Weight;
 }
 
 /// Weights for amm using the hydraDX node and recommended hardware.
@@ -211,6 +216,91 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads((7 as u64).saturating_mul(e as u64)))
 			.saturating_add(T::DbWeight::get().writes((5 as u64).saturating_mul(e as u64)))
 	}
+	// Storage: XYK ShareToken (r:1 w:0)
+	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:0 w:1)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_pool_fee() -> Weight {
+		// Minimum execution time: 18_204 nanoseconds.
+		Weight::from_ref_time(18_658_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:0)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:50 w:50)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK PoolAssets (r:50 w:0)
+	// Proof: XYK PoolAssets (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:200 w:200)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:50 w:0)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:50 w:50)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 50]`.
+	fn on_initialize(p: u32) -> Weight {
+		// Minimum execution time: 4_328 nanoseconds.
+		Weight::from_ref_time(5_012_000 as u64) // Standard Error: 28_541
+			.saturating_add(Weight::from_ref_time(98_417_325 as u64).saturating_mul(p as u64))
+			.saturating_add(T::DbWeight::get().reads(1 as u64))
+			.saturating_add(T::DbWeight::get().reads((8 as u64).saturating_mul(p as u64)))
+			.saturating_add(T::DbWeight::get().writes((6 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: XYK ShareToken (r:1 w:0)
+	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:1 w:1)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:1)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK NextLongTermOrderId (r:1 w:1)
+	// Proof: XYK NextLongTermOrderId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrders (r:0 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn place_long_term_order() -> Weight {
+		// Minimum execution time: 78_614 nanoseconds.
+		Weight::from_ref_time(79_905_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(8 as u64))
+	}
+	// Storage: XYK LongTermOrders (r:1 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:1 w:1)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:1)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_long_term_order() -> Weight {
+		// Minimum execution time: 81_273 nanoseconds.
+		Weight::from_ref_time(82_390_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(9 as u64))
+	}
+	// Storage: XYK LongTermOrders (r:1 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_long_term_order_proceeds() -> Weight {
+		// Minimum execution time: 67_318 nanoseconds.
+		Weight::from_ref_time(68_452_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(7 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
 }
 
 // For backwards compatibility and tests
@@ -365,4 +455,89 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads((7 as u64).saturating_mul(e as u64)))
 			.saturating_add(RocksDbWeight::get().writes((5 as u64).saturating_mul(e as u64)))
 	}
+	// Storage: XYK ShareToken (r:1 w:0)
+	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:0 w:1)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	fn set_pool_fee() -> Weight {
+		// Minimum execution time: 18_204 nanoseconds.
+		Weight::from_ref_time(18_658_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().writes(1 as u64))
+	}
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:0)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:50 w:50)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK PoolAssets (r:50 w:0)
+	// Proof: XYK PoolAssets (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:200 w:200)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: XYK PoolFee (r:50 w:0)
+	// Proof: XYK PoolFee (max_values: None, max_size: Some(56), added: 2531, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:50 w:50)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	/// The range of component `p` is `[0, 50]`.
+	fn on_initialize(p: u32) -> Weight {
+		// Minimum execution time: 4_328 nanoseconds.
+		Weight::from_ref_time(5_012_000 as u64) // Standard Error: 28_541
+			.saturating_add(Weight::from_ref_time(98_417_325 as u64).saturating_mul(p as u64))
+			.saturating_add(RocksDbWeight::get().reads(1 as u64))
+			.saturating_add(RocksDbWeight::get().reads((8 as u64).saturating_mul(p as u64)))
+			.saturating_add(RocksDbWeight::get().writes((6 as u64).saturating_mul(p as u64)))
+	}
+	// Storage: XYK ShareToken (r:1 w:0)
+	// Proof: XYK ShareToken (max_values: None, max_size: Some(52), added: 2527, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:2)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:1 w:1)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:1)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK NextLongTermOrderId (r:1 w:1)
+	// Proof: XYK NextLongTermOrderId (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrders (r:0 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn place_long_term_order() -> Weight {
+		// Minimum execution time: 78_614 nanoseconds.
+		Weight::from_ref_time(79_905_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(8 as u64))
+	}
+	// Storage: XYK LongTermOrders (r:1 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderPools (r:1 w:1)
+	// Proof: XYK LongTermOrderPools (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: XYK ActiveLongTermOrderPools (r:1 w:1)
+	// Proof: XYK ActiveLongTermOrderPools (max_values: Some(1), max_size: Some(1602), added: 2097, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:1)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn cancel_long_term_order() -> Weight {
+		// Minimum execution time: 81_273 nanoseconds.
+		Weight::from_ref_time(82_390_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(9 as u64))
+			.saturating_add(RocksDbWeight::get().writes(9 as u64))
+	}
+	// Storage: XYK LongTermOrders (r:1 w:1)
+	// Proof: XYK LongTermOrders (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: XYK LongTermOrderExpiries (r:1 w:1)
+	// Proof: XYK LongTermOrderExpiries (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:4 w:4)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	fn claim_long_term_order_proceeds() -> Weight {
+		// Minimum execution time: 67_318 nanoseconds.
+		Weight::from_ref_time(68_452_000 as u64)
+			.saturating_add(RocksDbWeight::get().reads(7 as u64))
+			.saturating_add(RocksDbWeight::get().writes(6 as u64))
+	}
 }
@@ -22,7 +22,7 @@ use cumulus_primitives_core::relay_chain::Hash;
 use frame_support::{
 	sp_runtime::{
 		traits::{AtLeast32BitUnsigned, Convert, Get, MaybeSerializeDeserialize, Saturating, Zero},
-		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128,
+		ArithmeticError, DispatchError, DispatchResult, FixedPointNumber, FixedPointOperand, FixedU128, Permill,
 		SaturatedConversion,
 	},
 	traits::{Contains, LockIdentifier, OriginTrait},
@@ -37,8 +37,8 @@ use hydra_dx_math::{
 };
 use hydradx_traits::router::{AssetPair, PoolType, RouteProvider, Trade};
 use hydradx_traits::{
-	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, LockedBalance, NativePriceOracle,
-	OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
+	liquidity_mining::PriceAdjustment, AggregatedOracle, AggregatedPriceOracle, FeeDiscountProvider, LockedBalance,
+	NativePriceOracle, OnLiquidityChangedHandler, OnTradeHandler, OraclePeriod, PriceOracle,
 };
 use orml_traits::GetByKey;
 use orml_xcm_support::{OnDepositFail, UnknownAsset as UnknownAssetT};
@@ -49,8 +49,9 @@ use pallet_stableswap::types::{PoolState, StableswapHooks};
 use pallet_transaction_multi_payment::DepositFee;
 use polkadot_xcm::latest::prelude::*;
 use primitive_types::{U128, U512};
-use primitives::constants::chain::{STABLESWAP_SOURCE, XYK_SOURCE};
+use primitives::constants::chain::{CONCENTRATED_LIQUIDITY_SOURCE, STABLESWAP_SOURCE, XYK_SOURCE};
 use primitives::{constants::chain::OMNIPOOL_SOURCE, AccountId, AssetId, Balance, BlockNumber, CollectionId};
+use sp_runtime::helpers_128bit::multiply_by_rational_with_rounding;
 use sp_runtime::traits::BlockNumberProvider;
 use sp_std::vec::Vec;
 use sp_std::{collections::btree_map::BTreeMap, fmt::Debug, marker::PhantomData};
@@ -598,6 +599,16 @@ where
 						Err(_) => return None,
 					}
 				}
+				PoolType::ConcentratedLiquidity => {
+					let price_result =
+						AggregatedPriceGetter::get_price(asset_a, asset_b, period, CONCENTRATED_LIQUIDITY_SOURCE);
+
+					match price_result {
+						Ok(price) => price.0,
+						Err(OracleError::SameAsset) => EmaPrice::from(1),
+						Err(_) => return None,
+					}
+				}
 				_ => return None,
 			};
 
@@ -647,6 +658,33 @@ where
 	}
 }
 
+/// Price adjustment for stableswap liquidity mining. Incentivized asset of the global farm is the
+/// share asset of the pool and shares are priced in `reward_currency` by the stableswap oracle.
+pub struct StableswapPriceAdjustmentAdapter<Runtime, LMInstance>(PhantomData<(Runtime, LMInstance)>);
+
+impl<Runtime, LMInstance> PriceAdjustment<GlobalFarmData<Runtime, LMInstance>>
+	for StableswapPriceAdjustmentAdapter<Runtime, LMInstance>
+where
+	Runtime: warehouse_liquidity_mining::Config<LMInstance>
+		+ pallet_ema_oracle::Config
+		+ pallet_stableswap_liquidity_mining::Config,
+{
+	type Error = DispatchError;
+	type PriceAdjustment = FixedU128;
+
+	fn get(global_farm: &GlobalFarmData<Runtime, LMInstance>) -> Result<Self::PriceAdjustment, Self::Error> {
+		let (price, _) = pallet_ema_oracle::Pallet::<Runtime>::get_price(
+			global_farm.reward_currency.into(),
+			global_farm.incentivized_asset.into(), //share asset
+			OraclePeriod::TenMinutes,
+			STABLESWAP_SOURCE,
+		)
+		.map_err(|_| pallet_stableswap_liquidity_mining::Error::<Runtime>::PriceAdjustmentNotAvailable)?;
+
+		FixedU128::checked_from_rational(price.n, price.d).ok_or_else(|| ArithmeticError::Overflow.into())
+	}
+}
+
 /// Asset transaction errors.
 enum Error {
 	/// Failed to match fungible.
@@ -869,6 +907,41 @@ where
 	}
 }
 
+/// Trade fee discount of accounts with staking position of at least `MinStake`.
+///
+/// Staked amount includes rewards locked in the position.
+pub struct StakingFeeDiscount<Runtime, MinStake, Discount>(PhantomData<(Runtime, MinStake, Discount)>);
+
+impl<Runtime, MinStake, Discount> FeeDiscountProvider<AccountId, AssetId>
+	for StakingFeeDiscount<Runtime, MinStake, Discount>
+where
+	Runtime: pallet_staking::Config,
+	<Runtime as frame_system::Config>::AccountId: From<AccountId>,
+	MinStake: Get<Balance>,
+	Discount: Get<Permill>,
+{
+	fn discount(who: &AccountId, _asset_in: AssetId, _asset_out: AssetId) -> Permill {
+		let staked = pallet_staking::Pallet::<Runtime>::get_user_position_id(&who.clone().into())
+			.ok()
+			.flatten()
+			.and_then(pallet_staking::Pallet::<Runtime>::get_position)
+			.and_then(|position| position.get_total_locked().ok())
+			.unwrap_or_default();
+
+		if staked >= MinStake::get() {
+			Discount::get()
+		} else {
+			Permill::zero()
+		}
+	}
+
+	fn discount_weight() -> Weight {
+		// position NFT owned by the account (prefix iteration reads the first key and checks there is no next one)
+		// and the position, min stake is a constant
+		Runtime::DbWeight::get().reads(3)
+	}
+}
+
 pub struct MultiCurrencyLockedBalance<T, NativeAssetId: Get<AssetId>>(PhantomData<(T, NativeAssetId)>);
 
 impl<T: orml_tokens::Config + pallet_balances::Config + frame_system::Config, NativeAssetId: Get<AssetId>>
@@ -971,12 +1044,22 @@ where
 		);
 
 		for idx in 0..pool_size {
+			// Share price is amount of asset per one share, so the share side of the trade
+			// is the traded amount of asset valued in shares.
+			let (price_n, price_d) = state.share_prices[idx];
+			let share_amount = if price_n.is_zero() {
+				Balance::zero()
+			} else {
+				multiply_by_rational_with_rounding(state.delta[idx], price_d, price_n, sp_runtime::Rounding::Down)
+					.ok_or(ArithmeticError::Overflow)?
+			};
+
 			OnActivityHandler::<Runtime>::on_trade(
 				STABLESWAP_SOURCE,
 				state.assets[idx],
 				pool_id,
 				state.delta[idx],
-				0, // Correct
+				share_amount,
 				state.after[idx],
 				state.issuance_after,
 				Price::new(state.share_prices[idx].0, state.share_prices[idx].1),
@@ -21,7 +21,7 @@ use primitives::Amount;
 
 use frame_support::dispatch::Weight;
 use frame_support::traits::tokens::nonfungibles::{Create, Inspect, Mutate};
-use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild};
+use frame_support::traits::{ConstU128, Contains, Everything, GenesisBuild, Nothing};
 use frame_support::{
 	assert_ok, construct_runtime, parameter_types,
 	traits::{ConstU32, ConstU64},
@@ -31,7 +31,9 @@ use hydra_dx_math::ema::EmaPrice;
 use hydra_dx_math::support::rational::Rounding;
 use hydra_dx_math::to_u128_wrapper;
 use hydradx_traits::pools::DustRemovalAccountWhitelist;
-use hydradx_traits::{AssetKind, AssetPairAccountIdFor, CanCreatePool, Registry, ShareTokenRegistry};
+use hydradx_traits::{
+	AssetKind, AssetPairAccountIdFor, CanCreatePool, InspectRegistry, MutateRegistry, Registry, ShareTokenRegistry,
+};
 use orml_traits::{parameter_type_with_key, GetByKey};
 use pallet_currencies::fungibles::FungibleCurrencies;
 use pallet_currencies::BasicCurrencyAdapter;
@@ -196,6 +198,7 @@ impl pallet_omnipool::Config for Test {
 	type MinimumTradingLimit = MinTradeAmount;
 	type MinimumPoolLiquidity = MinAddedLiquidity;
 	type TechnicalOrigin = EnsureRoot<Self::AccountId>;
+	type ProtocolLiquidityOrigin = EnsureRoot<Self::AccountId>;
 	type MaxInRatio = MaxInRatio;
 	type MaxOutRatio = MaxOutRatio;
 	type CollectionId = u32;
@@ -206,6 +209,11 @@ impl pallet_omnipool::Config for Test {
 	);
 	type MinWithdrawalFee = MinWithdrawFee;
 	type ExternalPriceOracle = WithdrawFeePriceOracle;
+	type RouteExecutor = ();
+	type AmmTradeWeights = ();
+	type FeeDiscount = ();
+	type LockedPositionOwners = Nothing;
+	type MaxDelistings = ConstU32<10>;
 }
 
 pub struct FeeProvider;
@@ -230,7 +238,9 @@ parameter_types! {
 
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
-	pub const DiscountedFee: (u32, u32) = (7, 10_000);
+	pub const XYKMaxExchangeFee: (u32, u32) = (1, 100);
+	pub const XYKMaxLongTermOrderDuration: u64 = 1_000;
+	pub const XYKMaxLongTermOrderPools: u32 = 10;
 }
 
 impl pallet_xyk::Config for Test {
@@ -238,7 +248,6 @@ impl pallet_xyk::Config for Test {
 	type AssetRegistry = DummyRegistry<Test>;
 	type AssetPairAccountId = AssetPairAccountIdTest;
 	type Currency = Currencies;
-	type NativeAssetId = HDXAssetId;
 	type WeightInfo = ();
 	type GetExchangeFee = XYKExchangeFee;
 	type MinTradingLimit = MinTradingLimit;
@@ -248,8 +257,12 @@ impl pallet_xyk::Config for Test {
 	type OracleSource = ();
 	type CanCreatePool = DummyCanCreatePool;
 	type AMMHandler = ();
-	type DiscountedFee = DiscountedFee;
+	type FeeDiscount = ();
+	type MaxExchangeFee = XYKMaxExchangeFee;
+	type AuthorityOrigin = EnsureRoot<Self::AccountId>;
 	type NonDustableWhitelistHandler = DummyDuster;
+	type MaxLongTermOrderDuration = XYKMaxLongTermOrderDuration;
+	type MaxLongTermOrderPools = XYKMaxLongTermOrderPools;
 }
 
 pub struct Whitelist;
@@ -322,6 +335,7 @@ impl pallet_route_executor::Config for Test {
 	type NativeAssetId = NativeCurrencyId;
 	type Currency = FungibleCurrencies<Test>;
 	type AMM = Pools;
+	type AssetPairs = ();
 	type WeightInfo = ();
 }
 
@@ -613,6 +627,36 @@ where
 	}
 }
 
+impl<T: pallet_omnipool::Config> InspectRegistry<T::AssetId> for DummyRegistry<T>
+where
+	u32: From<<T as pallet_omnipool::Config>::AssetId>,
+{
+	fn exists(asset_id: T::AssetId) -> bool {
+		let asset = REGISTERED_ASSETS.with(|v| v.borrow().get(&(asset_id.into())).copied());
+		matches!(asset, Some(_))
+	}
+
+	fn decimals(_asset_id: T::AssetId) -> Option<u8> {
+		None
+	}
+
+	fn asset_name(_asset_id: T::AssetId) -> Option<Vec<u8>> {
+		None
+	}
+
+	fn asset_symbol(_asset_id: T::AssetId) -> Option<Vec<u8>> {
+		None
+	}
+}
+
+impl<T: pallet_omnipool::Config> MutateRegistry<T::AssetId> for DummyRegistry<T> {
+	type Error = DispatchError;
+
+	fn set_metadata(_asset_id: T::AssetId, _symbol: &[u8], _decimals: u8) -> Result<(), Self::Error> {
+		Ok(())
+	}
+}
+
 pub struct MockOracle;
 
 impl ExternalPriceProvider<AssetId, EmaPrice> for MockOracle {
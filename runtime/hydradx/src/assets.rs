@@ -20,12 +20,14 @@ use crate::system::NativeAssetId;
 
 use hydradx_adapters::{
 	AssetFeeOraclePriceProvider, EmaOraclePriceAdapter, FreezableNFT, MultiCurrencyLockedBalance, OmnipoolHookAdapter,
-	OracleAssetVolumeProvider, PriceAdjustmentAdapter, StableswapHooksAdapter, VestingInfo,
+	OracleAssetVolumeProvider, PriceAdjustmentAdapter, StableswapHooksAdapter, StableswapPriceAdjustmentAdapter,
+	StakingFeeDiscount, VestingInfo,
 };
 
 use hydradx_adapters::{RelayChainBlockHashProvider, RelayChainBlockNumberProvider};
 use hydradx_traits::{
-	router::PoolType, AccountIdFor, AssetKind, AssetPairAccountIdFor, OnTradeHandler, OraclePeriod, Source,
+	router::PoolType, AccountIdFor, AssetKind, AssetPairAccountIdFor, FeeDiscountProvider, OnTradeHandler,
+	OraclePeriod, Source,
 };
 use pallet_currencies::BasicCurrencyAdapter;
 use pallet_omnipool::{
@@ -35,7 +37,7 @@ use pallet_omnipool::{
 use pallet_otc::NamedReserveIdentifier;
 use pallet_stableswap::weights::WeightInfo as StableswapWeights;
 use pallet_transaction_multi_payment::{AddTxAssetOnAccount, RemoveTxAssetOnKilled};
-use primitives::constants::chain::XYK_SOURCE;
+use primitives::constants::chain::{CONCENTRATED_LIQUIDITY_SOURCE, XYK_SOURCE};
 use primitives::constants::time::DAYS;
 use primitives::constants::{
 	chain::OMNIPOOL_SOURCE,
@@ -227,6 +229,7 @@ parameter_types! {
 	pub const EmaOracleSpotPriceShort: OraclePeriod = OraclePeriod::Short;
 	pub const OmnipoolMaxAllowedPriceDifference: Permill = Permill::from_percent(1);
 	pub MinimumWithdrawalFee: Permill = Permill::from_rational(1u32,10000);
+	pub const MaxOmnipoolDelistings: u32 = 10;
 }
 
 impl pallet_omnipool::Config for Runtime {
@@ -235,6 +238,7 @@ impl pallet_omnipool::Config for Runtime {
 	type Currency = Currencies;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type TechnicalOrigin = SuperMajorityTechCommittee;
+	type ProtocolLiquidityOrigin = MoreThanHalfCouncil;
 	type AssetRegistry = AssetRegistry;
 	type HdxAssetId = NativeAssetId;
 	type HubAssetId = LRNA;
@@ -267,6 +271,19 @@ impl pallet_omnipool::Config for Runtime {
 	);
 	type ExternalPriceOracle = EmaOraclePriceAdapter<EmaOracleSpotPriceShort, Runtime>;
 	type Fee = pallet_dynamic_fees::UpdateAndRetrieveFees<Runtime>;
+	type RouteExecutor = Router;
+	type AmmTradeWeights = RouterWeightInfo;
+	type FeeDiscount = TradeFeeDiscount;
+	type LockedPositionOwners = OmnipoolLockedPositionOwners;
+	type MaxDelistings = MaxOmnipoolDelistings;
+}
+
+pub struct OmnipoolLockedPositionOwners;
+
+impl Contains<AccountId> for OmnipoolLockedPositionOwners {
+	fn contains(a: &AccountId) -> bool {
+		<PalletId as AccountIdConversion<AccountId>>::into_account_truncating(&OmniLMPalletId::get()) == *a
+	}
 }
 
 pub struct CircuitBreakerWhitelist;
@@ -417,7 +434,7 @@ where
 
 use hydradx_traits::pools::SpotPriceProvider;
 #[cfg(feature = "runtime-benchmarks")]
-use hydradx_traits::PriceOracle;
+use hydradx_traits::{AggregatedPriceOracle, PriceOracle};
 
 #[cfg(feature = "runtime-benchmarks")]
 use hydra_dx_math::ema::EmaPrice;
@@ -588,11 +605,21 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 							AssetId,
 							Balance,
 						>>::on_liquidity_changed_weight(),
-					),
+					)
+					.saturating_add(TradeFeeDiscount::discount_weight()),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(c, e),
-				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
+				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e)
+					.saturating_add(TradeFeeDiscount::discount_weight()),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(TradeFeeDiscount::discount_weight()),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -626,11 +653,21 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 							AssetId,
 							Balance,
 						>>::on_liquidity_changed_weight(),
-					),
+					)
+					.saturating_add(TradeFeeDiscount::discount_weight()),
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_buy(c, e),
-				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
+				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e)
+					.saturating_add(TradeFeeDiscount::discount_weight()),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
-					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight())
+					.saturating_add(TradeFeeDiscount::discount_weight()),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -653,6 +690,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -675,6 +719,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -697,6 +748,13 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_buy(c, e),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_buy(c, e)
 					.saturating_add(<Runtime as pallet_xyk::Config>::AMMHandler::on_trade_weight()),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_buy(c, e)
+						.saturating_add(
+							<Runtime as pallet_concentrated_liquidity::Config>::AMMHandler::on_trade_weight(),
+						)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -727,6 +785,10 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -738,6 +800,10 @@ impl AmmTradeWeights<Trade<AssetId>> for RouterWeightInfo {
 				PoolType::LBP => weights::lbp::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::Stableswap(_) => weights::stableswap::HydraWeight::<Runtime>::router_execution_sell(1, 0),
 				PoolType::XYK => weights::xyk::HydraWeight::<Runtime>::router_execution_sell(1, 0),
+				PoolType::ConcentratedLiquidity => {
+					weights::concentrated_liquidity::HydraWeight::<Runtime>::router_execution_sell(1, 0)
+						.saturating_add(ConcentratedLiquidity::max_tick_crossings_weight())
+				}
 			};
 			weight.saturating_accrue(amm_weight);
 		}
@@ -752,7 +818,8 @@ impl pallet_route_executor::Config for Runtime {
 	type Balance = Balance;
 	type Currency = FungibleCurrencies<Runtime>;
 	type WeightInfo = RouterWeightInfo;
-	type AMM = (Omnipool, Stableswap, XYK, LBP);
+	type AMM = (Omnipool, Stableswap, XYK, LBP, ConcentratedLiquidity);
+	type AssetPairs = (Omnipool, Stableswap, XYK, LBP, ConcentratedLiquidity);
 	type NativeAssetId = NativeAssetId;
 }
 
@@ -770,6 +837,68 @@ impl pallet_otc::Config for Runtime {
 	type WeightInfo = weights::otc::HydraWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const MaxOrdersExpiringPerBlock: u32 = 20;
+	pub const MaxOrdersCheckedPerBlock: u32 = 20;
+}
+
+impl pallet_limit_orders::Config for Runtime {
+	type Currencies = Currencies;
+	type RuntimeEvent = RuntimeEvent;
+	type MaxOrdersExpiringPerBlock = MaxOrdersExpiringPerBlock;
+	type MaxOrdersCheckedPerBlock = MaxOrdersCheckedPerBlock;
+	type WeightInfo = weights::limit_orders::HydraWeight<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct DummyAggregatedPriceOracle;
+#[cfg(feature = "runtime-benchmarks")]
+impl AggregatedPriceOracle<AssetId, BlockNumber, EmaPrice> for DummyAggregatedPriceOracle {
+	type Error = ();
+
+	fn get_price(
+		_asset_a: AssetId,
+		_asset_b: AssetId,
+		_period: OraclePeriod,
+		_source: Source,
+	) -> Result<(EmaPrice, BlockNumber), Self::Error> {
+		Ok((EmaPrice::one(), 1))
+	}
+
+	fn get_price_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
+parameter_types! {
+	pub const TriggeredOrdersOracleSource: Source = OMNIPOOL_SOURCE;
+	pub const TriggeredOrdersCheckedPerBlock: u32 = 20;
+	pub TriggeredOrdersMaxCheckWeight: Weight = Perbill::from_percent(10) * BlockWeights::get().max_block;
+	pub const TriggeredOrdersMaxExecutionFailures: u32 = 5;
+}
+
+impl pallet_triggered_orders::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currencies = Currencies;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Oracle = EmaOracle;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Oracle = DummyAggregatedPriceOracle;
+	type OracleSource = TriggeredOrdersOracleSource;
+	type HubAssetId = LRNA;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type RouteExecutor = Router;
+	#[cfg(feature = "runtime-benchmarks")]
+	type RouteExecutor = pallet_route_executor::DummyRouter<Runtime>;
+	type AmmTradeWeights = RouterWeightInfo;
+	type MinimumTradingLimit = MinTradingLimit;
+	type MaxOrdersCheckedPerBlock = TriggeredOrdersCheckedPerBlock;
+	type MaxCheckOrdersWeight = TriggeredOrdersMaxCheckWeight;
+	type MaxExecutionFailures = TriggeredOrdersMaxExecutionFailures;
+	type WeightInfo = weights::triggered_orders::HydraWeight<Runtime>;
+}
+
 // Dynamic fees
 parameter_types! {
 	pub AssetFeeParams: FeeParams<Permill> = FeeParams{
@@ -802,6 +931,7 @@ impl pallet_dynamic_fees::Config for Runtime {
 // Stableswap
 parameter_types! {
 	pub StableswapAmplificationRange: RangeInclusive<NonZeroU16> = RangeInclusive::new(NonZeroU16::new(2).unwrap(), NonZeroU16::new(10_000).unwrap());
+	pub StableswapMaxAmplificationChangePerBlock: FixedU128 = FixedU128::from_rational(1, 100);
 }
 
 pub struct StableswapAccountIdConstructor<T: frame_system::Config>(PhantomData<T>);
@@ -870,16 +1000,63 @@ impl pallet_stableswap::Config for Runtime {
 	type ShareAccountId = StableswapAccountIdConstructor<Runtime>;
 	type AssetInspection = AssetRegistry;
 	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PoolAssetLiquidityAccount = TreasuryAccount;
 	type DustAccountHandler = Duster;
 	type Hooks = StableswapHooksAdapter<Runtime>;
 	type MinPoolLiquidity = MinPoolLiquidity;
 	type MinTradingLimit = MinTradingLimit;
 	type AmplificationRange = StableswapAmplificationRange;
+	type MaxAmplificationChangePerBlock = StableswapMaxAmplificationChangePerBlock;
+	type RateOracle = EmaOracle;
+	type RateFeedOrigin = SuperMajorityTechCommittee;
 	type WeightInfo = weights::stableswap::HydraWeight<Runtime>;
+	type FeeDiscount = TradeFeeDiscount;
 	#[cfg(feature = "runtime-benchmarks")]
 	type BenchmarkHelper = RegisterAsset<Runtime>;
 }
 
+parameter_types! {
+	pub const StableswapWarehouseLMPalletId: PalletId = PalletId(*b"StblWhLM");
+}
+
+type StableswapLiquidityMiningInstance = warehouse_liquidity_mining::Instance2;
+impl warehouse_liquidity_mining::Config<StableswapLiquidityMiningInstance> for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type MultiCurrency = Currencies;
+	type PalletId = StableswapWarehouseLMPalletId;
+	type MinTotalFarmRewards = MinTotalFarmRewards;
+	type MinPlannedYieldingPeriods = MinPlannedYieldingPeriods;
+	type BlockNumberProvider = RelayChainBlockNumberProvider<Runtime>;
+	type AmmPoolId = AssetId;
+	type MaxFarmEntriesPerDeposit = MaxEntriesPerDeposit;
+	type MaxYieldFarmsPerGlobalFarm = MaxYieldFarmsPerGlobalFarm;
+	type AssetRegistry = AssetRegistry;
+	type NonDustableWhitelistHandler = Duster;
+	type PriceAdjustment = StableswapPriceAdjustmentAdapter<Runtime, StableswapLiquidityMiningInstance>;
+}
+
+parameter_types! {
+	pub const StableswapLMPalletId: PalletId = PalletId(*b"Stbl//LM");
+	pub const StableswapLMCollectionId: CollectionId = 2585_u128;
+}
+
+impl pallet_stableswap_liquidity_mining::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type CreateOrigin = AllTechnicalCommitteeMembers;
+	type PalletId = StableswapLMPalletId;
+	type NFTCollectionId = StableswapLMCollectionId;
+	type NFTHandler = Uniques;
+	type LiquidityMiningHandler = StableswapWarehouseLM;
+	type WeightInfo = weights::stableswap_lm::HydraWeight<Runtime>;
+}
+
+impl pallet_omnipool_subpools::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::omnipool_subpools::HydraWeight<Runtime>;
+}
+
 // Bonds
 parameter_types! {
 	pub ProtocolFee: Permill = Permill::from_percent(2);
@@ -919,8 +1096,13 @@ parameter_types! {
 	pub const CurrentStakeWeight: u8 = 2;
 	pub const UnclaimablePeriods: BlockNumber = 1;
 	pub const PointPercentage: FixedU128 = FixedU128::from_rational(2,100);
+	pub const FeeDiscountMinStake: Balance = 100_000 * UNITS;
+	pub const StakingTradeFeeDiscount: Permill = Permill::from_percent(10);
 }
 
+/// Trade fee discount of stakers, applied by omnipool, stableswap and xyk.
+pub type TradeFeeDiscount = StakingFeeDiscount<Runtime, FeeDiscountMinStake, StakingTradeFeeDiscount>;
+
 pub struct PointsPerAction;
 
 impl GetByKey<Action, u32> for PointsPerAction {
@@ -999,8 +1181,10 @@ impl pallet_lbp::Config for Runtime {
 
 parameter_types! {
 	pub XYKExchangeFee: (u32, u32) = (3, 1_000);
-	pub const DiscountedFee: (u32, u32) = (7, 10_000);
+	pub const XYKMaxExchangeFee: (u32, u32) = (1, 100);
 	pub const XYKOracleSourceIdentifier: Source = XYK_SOURCE;
+	pub const XYKMaxLongTermOrderDuration: BlockNumber = 7 * DAYS;
+	pub const XYKMaxLongTermOrderPools: u32 = 50;
 }
 
 impl pallet_xyk::Config for Runtime {
@@ -1008,7 +1192,6 @@ impl pallet_xyk::Config for Runtime {
 	type AssetRegistry = AssetRegistry;
 	type AssetPairAccountId = AssetPairAccountId<Self>;
 	type Currency = Currencies;
-	type NativeAssetId = NativeAssetId;
 	type WeightInfo = weights::xyk::HydraWeight<Runtime>;
 	type GetExchangeFee = XYKExchangeFee;
 	type MinTradingLimit = MinTradingLimit;
@@ -1017,9 +1200,47 @@ impl pallet_xyk::Config for Runtime {
 	type MaxOutRatio = MaxOutRatio;
 	type CanCreatePool = pallet_lbp::DisallowWhenLBPPoolRunning<Runtime>;
 	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
-	type DiscountedFee = DiscountedFee;
+	type FeeDiscount = TradeFeeDiscount;
+	type MaxExchangeFee = XYKMaxExchangeFee;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
 	type NonDustableWhitelistHandler = Duster;
 	type OracleSource = XYKOracleSourceIdentifier;
+	type MaxLongTermOrderDuration = XYKMaxLongTermOrderDuration;
+	type MaxLongTermOrderPools = XYKMaxLongTermOrderPools;
+}
+
+parameter_types! {
+	pub const ConcentratedLiquidityCollectionId: CollectionId = 2586_u128;
+	pub const ConcentratedLiquidityMaxFee: Permill = Permill::from_percent(1);
+	pub const ConcentratedLiquidityOracleSource: Source = CONCENTRATED_LIQUIDITY_SOURCE;
+	pub const ConcentratedLiquidityMinLiquidity: u128 = 1_000_000;
+}
+
+impl pallet_concentrated_liquidity::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type AssetId = AssetId;
+	type Currency = Currencies;
+	type AuthorityOrigin = EnsureRoot<AccountId>;
+	type PositionItemId = ItemId;
+	type CollectionId = CollectionId;
+	type NFTCollectionId = ConcentratedLiquidityCollectionId;
+	type NFTHandler = Uniques;
+	type MaxFee = ConcentratedLiquidityMaxFee;
+	type MinTradingLimit = MinTradingLimit;
+	type MaxTickCrossings = ConstU32<20>;
+	type MinLiquidity = ConcentratedLiquidityMinLiquidity;
+	type OracleSource = ConcentratedLiquidityOracleSource;
+	type AMMHandler = pallet_ema_oracle::OnActivityHandler<Runtime>;
+	type WeightInfo = weights::concentrated_liquidity::HydraWeight<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = RegisterAsset<Runtime>;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_asset_registry::Config> pallet_concentrated_liquidity::BenchmarkHelper<AssetId> for RegisterAsset<T> {
+	fn register_asset(asset_id: AssetId, decimals: u8) -> DispatchResult {
+		<Self as BenchmarkHelper<AssetId>>::register_asset(asset_id, decimals)
+	}
 }
 
 parameter_types! {
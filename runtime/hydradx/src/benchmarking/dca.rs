@@ -437,7 +437,6 @@ fn create_xyk_pool(asset_a: u32, asset_b: u32) {
 		asset_b,
 		10 * ONE,
 		0u128,
-		false,
 	));
 }
 
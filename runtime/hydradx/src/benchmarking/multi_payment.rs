@@ -200,8 +200,7 @@ where
 		asset_a,
 		asset_b,
 		amount_a,
-		u128::MIN,
-		false
+		u128::MIN
 	));
 }
 
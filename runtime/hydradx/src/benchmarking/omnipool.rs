@@ -12,7 +12,8 @@ use frame_support::{
 		traits::{One, SaturatedConversion, Zero},
 		FixedU128, Permill,
 	},
-	traits::{OnFinalize, OnInitialize},
+	traits::{OnFinalize, OnIdle, OnInitialize},
+	weights::Weight,
 };
 use frame_system::RawOrigin;
 use hydradx_traits::{
@@ -331,6 +332,150 @@ runtime_benchmarks! {
 		assert!(Omnipool::positions(current_position_id).is_none());
 	}
 
+	split_position {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		let position = Omnipool::positions(current_position_id).unwrap();
+		let beneficiary: AccountId = account("beneficiary", 1, 1);
+
+	}: {Omnipool::split_position(RawOrigin::Signed(lp_provider).into(), current_position_id, position.shares / 2, beneficiary)? }
+	verify {
+		assert!(Omnipool::positions(current_position_id + 1).is_some());
+	}
+
+	merge_positions {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+		// Change the price so positions are merged with different prices
+		let seller: AccountId = account("seller", 2, 1);
+		update_balance(token_id, &seller, 500_000_000_000_000_u128);
+		Omnipool::sell(RawOrigin::Signed(seller).into(), token_id, DAI, 10_000_000_000_000_u128, 0)?;
+
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider.clone()).into(), token_id, liquidity_added)?;
+
+	}: {Omnipool::merge_positions(RawOrigin::Signed(lp_provider).into(), current_position_id, current_position_id + 1)? }
+	verify {
+		assert!(Omnipool::positions(current_position_id + 1).is_none());
+	}
+
+	announce_delisting {
+		init()?;
+		let beneficiary: AccountId = account("beneficiary", 1, 1);
+	}: { Omnipool::announce_delisting(RawOrigin::Root.into(), DAI, 100u32, beneficiary)? }
+	verify {
+		assert!(Omnipool::delistings(DAI).is_some());
+	}
+
+	cancel_delisting {
+		init()?;
+		let beneficiary: AccountId = account("beneficiary", 1, 1);
+		Omnipool::announce_delisting(RawOrigin::Root.into(), DAI, 100u32, beneficiary)?;
+	}: { Omnipool::cancel_delisting(RawOrigin::Root.into(), DAI)? }
+	verify {
+		assert!(Omnipool::delistings(DAI).is_none());
+	}
+
+	update_delisting {
+		init()?;
+		let beneficiary: AccountId = account("beneficiary", 1, 1);
+		Omnipool::announce_delisting(RawOrigin::Root.into(), DAI, 100u32, beneficiary)?;
+		run_to_block(100);
+	}: { Omnipool::on_initialize(101u32); }
+	verify {
+		assert_eq!(Omnipool::assets(DAI).unwrap().tradable, Tradability::FROZEN);
+	}
+
+	settle_delisted_position {
+		init()?;
+		let acc = Omnipool::protocol_account();
+		let token_id = AssetRegistry::create_asset(&b"FCK".to_vec(), Balance::one())?;
+
+		// Create account for token provider and set balance
+		let owner: AccountId = account("owner", 0, 1);
+
+		let token_price = FixedU128::from((1,5));
+		let token_amount = 200_000_000_000_000_u128;
+
+		update_balance(token_id, &acc, token_amount);
+
+		// Add the token to the pool
+		Omnipool::add_token(RawOrigin::Root.into(), token_id, token_price,Permill::from_percent(100), owner)?;
+
+		// Create LP provider account with correct balance
+		let lp_provider: AccountId = account("provider", 1, 1);
+		update_balance(token_id, &lp_provider, 500_000_000_000_000_u128);
+
+		let liquidity_added = 1_000_000_000_000_u128;
+
+		let current_position_id = Omnipool::next_position_id();
+
+		run_to_block(10);
+		Omnipool::add_liquidity(RawOrigin::Signed(lp_provider).into(), token_id, liquidity_added)?;
+
+		let beneficiary: AccountId = account("beneficiary", 1, 1);
+		Omnipool::announce_delisting(RawOrigin::Root.into(), token_id, 10u32, beneficiary)?;
+		run_to_block(20);
+		Omnipool::on_initialize(21u32);
+	}: { Omnipool::on_idle(21u32, Weight::MAX); }
+	verify {
+		assert!(Omnipool::positions(current_position_id).is_none());
+	}
+
+	set_imbalance_burn_rate {
+		init()?;
+	}: { Omnipool::set_imbalance_burn_rate(RawOrigin::Root.into(), Permill::from_percent(50))? }
+	verify {
+		assert_eq!(Omnipool::imbalance_burn_rate(), Permill::from_percent(50));
+	}
+
 	set_asset_weight_cap {
 		init()?;
 	}: { Omnipool::set_asset_weight_cap(RawOrigin::Root.into(), DAI, Permill::from_percent(10))? }
@@ -52,7 +52,7 @@ use sp_runtime::{
 		AccountIdConversion, BlakeTwo256, Block as BlockT, DispatchInfoOf, PostDispatchInfoOf, UniqueSaturatedInto,
 	},
 	transaction_validity::{TransactionSource, TransactionValidity, TransactionValidityError},
-	ApplyExtrinsicResult, Permill,
+	ApplyExtrinsicResult, DispatchError, Permill,
 };
 
 use sp_std::convert::From;
@@ -99,7 +99,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: create_runtime_str!("hydradx"),
 	impl_name: create_runtime_str!("hydradx"),
 	authoring_version: 1,
-	spec_version: 201,
+	spec_version: 251,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -120,6 +120,8 @@ pub fn get_all_module_accounts() -> Vec<AccountId> {
 		TreasuryPalletId::get().into_account_truncating(),
 		VestingPalletId::get().into_account_truncating(),
 		ReferralsPalletId::get().into_account_truncating(),
+		ConcentratedLiquidity::pool_account(),
+		XYK::long_term_orders_account(),
 	]
 }
 
@@ -166,9 +168,15 @@ construct_runtime!(
 		Staking: pallet_staking = 69,
 		Stableswap: pallet_stableswap = 70,
 		Bonds: pallet_bonds = 71,
+		TriggeredOrders: pallet_triggered_orders = 72,
 		LBP: pallet_lbp = 73,
 		XYK: pallet_xyk = 74,
 		Referrals: pallet_referrals = 75,
+		LimitOrders: pallet_limit_orders = 76,
+		OmnipoolSubpools: pallet_omnipool_subpools = 58,
+		StableswapWarehouseLM: warehouse_liquidity_mining::<Instance2> = 54,
+		StableswapLiquidityMining: pallet_stableswap_liquidity_mining = 56,
+		ConcentratedLiquidity: pallet_concentrated_liquidity = 52,
 
 		// ORML related modules
 		Tokens: orml_tokens = 77,
@@ -386,6 +394,73 @@ impl_runtime_apis! {
 		}
 	}
 
+	#[api_version(3)]
+	impl pallet_omnipool_rpc_runtime_api::OmnipoolApi<Block, AssetId, ItemId> for Runtime {
+		fn asset_state(asset_id: AssetId) -> Option<pallet_omnipool::types::AssetReserveState<Balance>> {
+			Omnipool::load_asset_state(asset_id).ok()
+		}
+
+		fn position_value(
+			position_id: ItemId,
+		) -> Result<pallet_omnipool::types::PositionValuation<AssetId, Balance>, DispatchError> {
+			Omnipool::position_value(position_id)
+		}
+
+		fn quote_sell(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, DispatchError> {
+			Omnipool::quote_sell(asset_in, asset_out, amount_in)
+		}
+
+		fn quote_buy(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Result<pallet_omnipool::types::TradeQuote<Balance>, DispatchError> {
+			Omnipool::quote_buy(asset_in, asset_out, amount_out)
+		}
+
+		fn protocol_liquidity_value(
+			asset_id: AssetId,
+		) -> Result<pallet_omnipool::types::ProtocolLiquidityValuation<AssetId, Balance>, DispatchError> {
+			Omnipool::protocol_liquidity_value(asset_id)
+		}
+
+		fn protocol_liquidity() -> Vec<pallet_omnipool::types::ProtocolLiquidityValuation<AssetId, Balance>> {
+			Omnipool::protocol_liquidity()
+		}
+
+		fn hub_asset_imbalance() -> pallet_omnipool::types::SimpleImbalance<Balance> {
+			Omnipool::current_imbalance()
+		}
+	}
+
+	impl pallet_route_executor_runtime_api::RouterApi<Block, AssetId, Balance> for Runtime {
+		fn find_sell_routes(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_in: Balance,
+		) -> Vec<pallet_route_executor::RouteQuote<AssetId, Balance>> {
+			Router::find_sell_routes(asset_in, asset_out, amount_in)
+		}
+
+		fn find_buy_routes(
+			asset_in: AssetId,
+			asset_out: AssetId,
+			amount_out: Balance,
+		) -> Vec<pallet_route_executor::RouteQuote<AssetId, Balance>> {
+			Router::find_buy_routes(asset_in, asset_out, amount_out)
+		}
+	}
+
+	impl pallet_stableswap_runtime_api::StableswapApi<Block, AssetId> for Runtime {
+		fn amplification(pool_id: AssetId) -> Option<u128> {
+			Stableswap::current_amplification(pool_id)
+		}
+	}
+
 	// Frontier RPC support
 	impl fp_rpc::EthereumRuntimeRPCApi<Block> for Runtime {
 		fn chain_id() -> u64 {
@@ -543,6 +618,7 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_circuit_breaker, CircuitBreaker);
 			list_benchmark!(list, extra, pallet_bonds, Bonds);
 			list_benchmark!(list, extra, pallet_stableswap, Stableswap);
+			list_benchmark!(list, extra, pallet_stableswap_liquidity_mining, StableswapLiquidityMining);
 
 			list_benchmark!(list, extra, pallet_asset_registry, AssetRegistry);
 			list_benchmark!(list, extra, pallet_claims, Claims);
@@ -550,12 +626,16 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, pallet_staking, Staking);
 			list_benchmark!(list, extra, pallet_lbp, LBP);
 			list_benchmark!(list, extra, pallet_xyk, XYK);
+			list_benchmark!(list, extra, pallet_concentrated_liquidity, ConcentratedLiquidity);
 			list_benchmark!(list, extra, pallet_referrals, Referrals);
 
 			list_benchmark!(list, extra, cumulus_pallet_xcmp_queue, XcmpQueue);
 			list_benchmark!(list, extra, pallet_transaction_pause, TransactionPause);
 
 			list_benchmark!(list, extra, pallet_otc, OTC);
+			list_benchmark!(list, extra, pallet_limit_orders, LimitOrders);
+			list_benchmark!(list, extra, pallet_omnipool_subpools, OmnipoolSubpools);
+			list_benchmark!(list, extra, pallet_triggered_orders, TriggeredOrders);
 			list_benchmark!(list, extra, pallet_xcm, PolkadotXcm);
 
 			orml_list_benchmark!(list, extra, pallet_currencies, benchmarking::currencies);
@@ -620,13 +700,18 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, pallet_staking, Staking);
 			add_benchmark!(params, batches, pallet_lbp, LBP);
 			add_benchmark!(params, batches, pallet_xyk, XYK);
+			add_benchmark!(params, batches, pallet_concentrated_liquidity, ConcentratedLiquidity);
 			add_benchmark!(params, batches, pallet_stableswap, Stableswap);
+			add_benchmark!(params, batches, pallet_stableswap_liquidity_mining, StableswapLiquidityMining);
 			add_benchmark!(params, batches, pallet_referrals, Referrals);
 
 			add_benchmark!(params, batches, cumulus_pallet_xcmp_queue, XcmpQueue);
 			add_benchmark!(params, batches, pallet_transaction_pause, TransactionPause);
 
 			add_benchmark!(params, batches, pallet_otc, OTC);
+			add_benchmark!(params, batches, pallet_limit_orders, LimitOrders);
+			add_benchmark!(params, batches, pallet_omnipool_subpools, OmnipoolSubpools);
+			add_benchmark!(params, batches, pallet_triggered_orders, TriggeredOrders);
 			add_benchmark!(params, batches, pallet_xcm, PolkadotXcm);
 
 			orml_add_benchmark!(params, batches, pallet_currencies, benchmarking::currencies);
@@ -1,8 +1,13 @@
 #![allow(unused_imports)]
 use crate::Vec;
-use frame_support::{codec::alloc::vec, traits::OnRuntimeUpgrade, weights::Weight};
+use frame_support::{
+	codec::alloc::vec,
+	traits::{Get, GetStorageVersion, OnRuntimeUpgrade},
+	weights::Weight,
+};
+use hydradx_traits::pools::DustRemovalAccountWhitelist;
 pub struct OnRuntimeUpgradeMigration;
-use super::Runtime;
+use super::{Duster, Runtime, StableswapLiquidityMining, StableswapWarehouseLM};
 
 impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	#[cfg(feature = "try-runtime")]
@@ -11,7 +16,20 @@ impl OnRuntimeUpgrade for OnRuntimeUpgradeMigration {
 	}
 
 	fn on_runtime_upgrade() -> Weight {
-		pallet_referrals::migration::preregister_parachain_codes::<Runtime>()
+		let mut weight = pallet_referrals::migration::preregister_parachain_codes::<Runtime>();
+
+		// Pot of the new liquidity mining instance has to be protected from dusting same as at genesis.
+		if StableswapLiquidityMining::on_chain_storage_version() < 1 {
+			if let Some(pot) = StableswapWarehouseLM::pot_account_id() {
+				let _ = Duster::add_account(&pot);
+				weight.saturating_accrue(<Runtime as frame_system::Config>::DbWeight::get().writes(1));
+			}
+		}
+
+		weight.saturating_add(pallet_stableswap_liquidity_mining::migration::migrate_to_v1::<
+			Runtime,
+			StableswapLiquidityMining,
+		>())
 	}
 
 	#[cfg(feature = "try-runtime")]
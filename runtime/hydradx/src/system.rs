@@ -84,7 +84,9 @@ impl Contains<RuntimeCall> for CallFilter {
 		}
 
 		// XYK pools with LRNA are not allowed
-		if let RuntimeCall::XYK(pallet_xyk::Call::create_pool { asset_a, asset_b, .. }) = call {
+		if let RuntimeCall::XYK(pallet_xyk::Call::create_pool { asset_a, asset_b, .. })
+		| RuntimeCall::XYK(pallet_xyk::Call::create_pool_with_fee { asset_a, asset_b, .. }) = call
+		{
 			if *asset_a == hub_asset_id || *asset_b == hub_asset_id {
 				return false;
 			}
@@ -356,6 +358,14 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					) | RuntimeCall::OmnipoolLiquidityMining(pallet_omnipool_liquidity_mining::Call::claim_rewards { .. })
 					| RuntimeCall::OmnipoolLiquidityMining(
 						pallet_omnipool_liquidity_mining::Call::withdraw_shares { .. }
+					) | RuntimeCall::StableswapLiquidityMining(
+						pallet_stableswap_liquidity_mining::Call::deposit_shares { .. }
+					) | RuntimeCall::StableswapLiquidityMining(
+						pallet_stableswap_liquidity_mining::Call::redeposit_shares { .. }
+					) | RuntimeCall::StableswapLiquidityMining(
+						pallet_stableswap_liquidity_mining::Call::claim_rewards { .. }
+					) | RuntimeCall::StableswapLiquidityMining(
+						pallet_stableswap_liquidity_mining::Call::withdraw_shares { .. }
 					)
 			),
 		}
@@ -3,6 +3,7 @@ pub mod bonds;
 pub mod circuit_breaker;
 pub mod claims;
 pub mod collator_selection;
+pub mod concentrated_liquidity;
 pub mod council;
 pub mod currencies;
 pub mod dca;
@@ -11,8 +12,10 @@ pub mod duster;
 pub mod ema_oracle;
 pub mod identity;
 pub mod lbp;
+pub mod limit_orders;
 pub mod omnipool;
 pub mod omnipool_lm;
+pub mod omnipool_subpools;
 pub mod otc;
 pub mod payment;
 pub mod preimage;
@@ -22,6 +25,7 @@ pub mod registry;
 pub mod route_executor;
 pub mod scheduler;
 pub mod stableswap;
+pub mod stableswap_lm;
 pub mod staking;
 pub mod system;
 pub mod technical_comittee;
@@ -29,6 +33,7 @@ pub mod timestamp;
 pub mod tokens;
 pub mod transaction_pause;
 pub mod treasury;
+pub mod triggered_orders;
 pub mod utility;
 pub mod vesting;
 pub mod xcm;
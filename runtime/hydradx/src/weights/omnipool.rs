@@ -469,4 +469,104 @@ impl<T: frame_system::Config> WeightInfo for HydraWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(33 as u64))
 			.saturating_add(T::DbWeight::get().writes(22 as u64))
 	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:1 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Omnipool NextPositionId (r:1 w:1)
+	// Proof: Omnipool NextPositionId (max_values: Some(1), max_size: Some(16), added: 511, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques CollectionMaxSupply (r:1 w:0)
+	// Proof: Uniques CollectionMaxSupply (max_values: None, max_size: Some(36), added: 2511, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	fn split_position() -> Weight {
+		// Minimum execution time: 71_402 nanoseconds.
+		Weight::from_ref_time(72_120_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Uniques Asset (r:2 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:2 w:2)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	fn merge_positions() -> Weight {
+		// Minimum execution time: 76_815 nanoseconds.
+		Weight::from_ref_time(77_491_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(5 as u64))
+			.saturating_add(T::DbWeight::get().writes(6 as u64))
+	}
+	// Storage: Omnipool Delistings (r:1 w:1)
+	// Proof: Omnipool Delistings (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool CounterForDelistings (r:1 w:1)
+	// Proof: Omnipool CounterForDelistings (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn announce_delisting() -> Weight {
+		// Minimum execution time: 24_102 nanoseconds.
+		Weight::from_ref_time(24_610_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Omnipool Delistings (r:1 w:1)
+	// Proof: Omnipool Delistings (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Omnipool CounterForDelistings (r:1 w:1)
+	// Proof: Omnipool CounterForDelistings (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn cancel_delisting() -> Weight {
+		// Minimum execution time: 24_517 nanoseconds.
+		Weight::from_ref_time(25_003_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(3 as u64))
+	}
+	// Storage: Omnipool Delistings (r:2 w:1)
+	// Proof: Omnipool Delistings (max_values: None, max_size: Some(94), added: 2569, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	fn update_delisting() -> Weight {
+		// Minimum execution time: 19_884 nanoseconds.
+		Weight::from_ref_time(20_311_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(3 as u64))
+			.saturating_add(T::DbWeight::get().writes(2 as u64))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Proof: Uniques Asset (max_values: None, max_size: Some(146), added: 2621, mode: MaxEncodedLen)
+	// Storage: Omnipool Assets (r:1 w:1)
+	// Proof: Omnipool Assets (max_values: None, max_size: Some(85), added: 2560, mode: MaxEncodedLen)
+	// Storage: Tokens Accounts (r:3 w:3)
+	// Proof: Tokens Accounts (max_values: None, max_size: Some(108), added: 2583, mode: MaxEncodedLen)
+	// Storage: Omnipool HubAssetImbalance (r:1 w:1)
+	// Proof: Omnipool HubAssetImbalance (max_values: Some(1), max_size: Some(17), added: 512, mode: MaxEncodedLen)
+	// Storage: Tokens TotalIssuance (r:1 w:1)
+	// Proof: Tokens TotalIssuance (max_values: None, max_size: Some(28), added: 2503, mode: MaxEncodedLen)
+	// Storage: System Account (r:1 w:0)
+	// Proof: System Account (max_values: None, max_size: Some(128), added: 2603, mode: MaxEncodedLen)
+	// Storage: Uniques Class (r:1 w:1)
+	// Proof: Uniques Class (max_values: None, max_size: Some(190), added: 2665, mode: MaxEncodedLen)
+	// Storage: Uniques Account (r:0 w:1)
+	// Proof: Uniques Account (max_values: None, max_size: Some(112), added: 2587, mode: MaxEncodedLen)
+	// Storage: Uniques ItemPriceOf (r:0 w:1)
+	// Proof: Uniques ItemPriceOf (max_values: None, max_size: Some(113), added: 2588, mode: MaxEncodedLen)
+	// Storage: Omnipool Positions (r:0 w:1)
+	// Proof: Omnipool Positions (max_values: None, max_size: Some(100), added: 2575, mode: MaxEncodedLen)
+	fn settle_delisted_position() -> Weight {
+		// Minimum execution time: 98_240 nanoseconds.
+		Weight::from_ref_time(99_172_000 as u64)
+			.saturating_add(T::DbWeight::get().reads(9 as u64))
+			.saturating_add(T::DbWeight::get().writes(11 as u64))
+	}
+	// Storage: Omnipool ImbalanceBurnRate (r:0 w:1)
+	// Proof: Omnipool ImbalanceBurnRate (max_values: Some(1), max_size: Some(4), added: 499, mode: MaxEncodedLen)
+	fn set_imbalance_burn_rate() -> Weight {
+		// Minimum execution time: 11_052 nanoseconds.
+		Weight::from_ref_time(11_398_000 as u64).saturating_add(T::DbWeight::get().writes(1 as u64))
+	}
 }
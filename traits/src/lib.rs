@@ -33,7 +33,7 @@ pub use oracle::*;
 use codec::{Decode, Encode};
 use frame_support::dispatch::{self, DispatchError};
 use frame_support::sp_runtime::traits::Zero;
-use frame_support::sp_runtime::RuntimeDebug;
+use frame_support::sp_runtime::{Permill, RuntimeDebug};
 use frame_support::traits::LockIdentifier;
 use frame_support::weights::Weight;
 #[cfg(feature = "std")]
@@ -49,8 +49,6 @@ pub struct AMMTransfer<AccountId, AssetId, AssetPair, Balance> {
 	pub assets: AssetPair,
 	pub amount: Balance,
 	pub amount_b: Balance,
-	pub discount: bool,
-	pub discount_amount: Balance,
 	pub fee: (AssetId, Balance),
 }
 
@@ -78,7 +76,6 @@ pub trait AMM<AccountId, AssetId, AssetPair, Amount: Zero> {
 		assets: AssetPair,
 		amount: Amount,
 		min_bought: Amount,
-		discount: bool,
 	) -> Result<AMMTransfer<AccountId, AssetId, AssetPair, Amount>, frame_support::sp_runtime::DispatchError>;
 
 	/// Execute buy for given validated transfer.
@@ -86,14 +83,8 @@ pub trait AMM<AccountId, AssetId, AssetPair, Amount: Zero> {
 
 	/// Perform asset swap.
 	/// Call execute following the validation.
-	fn sell(
-		origin: &AccountId,
-		assets: AssetPair,
-		amount: Amount,
-		min_bought: Amount,
-		discount: bool,
-	) -> dispatch::DispatchResult {
-		Self::execute_sell(&Self::validate_sell(origin, assets, amount, min_bought, discount)?)?;
+	fn sell(origin: &AccountId, assets: AssetPair, amount: Amount, min_bought: Amount) -> dispatch::DispatchResult {
+		Self::execute_sell(&Self::validate_sell(origin, assets, amount, min_bought)?)?;
 		Ok(())
 	}
 
@@ -104,21 +95,14 @@ pub trait AMM<AccountId, AssetId, AssetPair, Amount: Zero> {
 		assets: AssetPair,
 		amount: Amount,
 		max_limit: Amount,
-		discount: bool,
 	) -> Result<AMMTransfer<AccountId, AssetId, AssetPair, Amount>, frame_support::sp_runtime::DispatchError>;
 
 	/// Execute buy for given validated transfer.
 	fn execute_buy(transfer: &AMMTransfer<AccountId, AssetId, AssetPair, Amount>) -> dispatch::DispatchResult;
 
 	/// Perform asset swap.
-	fn buy(
-		origin: &AccountId,
-		assets: AssetPair,
-		amount: Amount,
-		max_limit: Amount,
-		discount: bool,
-	) -> dispatch::DispatchResult {
-		Self::execute_buy(&Self::validate_buy(origin, assets, amount, max_limit, discount)?)?;
+	fn buy(origin: &AccountId, assets: AssetPair, amount: Amount, max_limit: Amount) -> dispatch::DispatchResult {
+		Self::execute_buy(&Self::validate_buy(origin, assets, amount, max_limit)?)?;
 		Ok(())
 	}
 
@@ -194,6 +178,30 @@ impl<AssetId, Balance, Price> OnTradeHandler<AssetId, Balance, Price> for () {
 	}
 }
 
+/// Provider of trade fee discounts.
+///
+/// Consulted by AMM pools on every trade, so fee discounts (e.g. based on staking, referrals or NFT ownership)
+/// are applied the same way regardless of the pool used.
+pub trait FeeDiscountProvider<AccountId, AssetId> {
+	/// Return discount of the trade fee for `who` trading `asset_in` for `asset_out`.
+	///
+	/// Discount is a fraction of the fee, e.g. 20% discount reduces 0.3% fee to 0.24%.
+	fn discount(who: &AccountId, asset_in: AssetId, asset_out: AssetId) -> Permill;
+
+	/// Weight of `discount`. Add this weight to an extrinsic from which you call `discount`.
+	fn discount_weight() -> Weight;
+}
+
+impl<AccountId, AssetId> FeeDiscountProvider<AccountId, AssetId> for () {
+	fn discount(_who: &AccountId, _asset_in: AssetId, _asset_out: AssetId) -> Permill {
+		Permill::zero()
+	}
+
+	fn discount_weight() -> Weight {
+		Weight::zero()
+	}
+}
+
 pub trait CanCreatePool<AssetId> {
 	fn can_create(asset_a: AssetId, asset_b: AssetId) -> bool;
 }
@@ -46,6 +46,11 @@ pub trait InspectRegistry<AssetId> {
 	fn asset_symbol(asset_id: AssetId) -> Option<Vec<u8>>;
 }
 
+pub trait MutateRegistry<AssetId> {
+	type Error;
+	fn set_metadata(asset_id: AssetId, symbol: &[u8], decimals: u8) -> Result<(), Self::Error>;
+}
+
 #[derive(Eq, PartialEq, Copy, Clone)]
 pub enum AssetKind {
 	Token,
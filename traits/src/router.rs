@@ -61,6 +61,7 @@ pub enum PoolType<AssetId> {
 	LBP,
 	Stableswap(AssetId),
 	Omnipool,
+	ConcentratedLiquidity,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -77,7 +78,7 @@ pub struct Trade<AssetId> {
 	pub asset_out: AssetId,
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Encode, Decode, Debug, Eq, PartialEq, Clone, TypeInfo)]
 pub struct AmountInAndOut<Balance> {
 	pub amount_in: Balance,
 	pub amount_out: Balance,
@@ -123,6 +124,48 @@ pub trait RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> {
 	fn set_route(origin: Origin, asset_pair: AssetPair<AssetId>, route: Vec<Trade>) -> DispatchResultWithPostInfo;
 }
 
+impl<Origin, AssetId, Balance, Trade, AmountInAndOut> RouterT<Origin, AssetId, Balance, Trade, AmountInAndOut> for () {
+	fn sell(
+		_origin: Origin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_in: Balance,
+		_min_amount_out: Balance,
+		_route: Vec<Trade>,
+	) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn buy(
+		_origin: Origin,
+		_asset_in: AssetId,
+		_asset_out: AssetId,
+		_amount_out: Balance,
+		_max_amount_in: Balance,
+		_route: Vec<Trade>,
+	) -> DispatchResult {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn calculate_sell_trade_amounts(
+		_route: &[Trade],
+		_amount_in: Balance,
+	) -> Result<Vec<AmountInAndOut>, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn calculate_buy_trade_amounts(
+		_route: &[Trade],
+		_amount_out: Balance,
+	) -> Result<Vec<AmountInAndOut>, DispatchError> {
+		Err(DispatchError::Unavailable)
+	}
+
+	fn set_route(_origin: Origin, _asset_pair: AssetPair<AssetId>, _route: Vec<Trade>) -> DispatchResultWithPostInfo {
+		Err(DispatchError::Unavailable.into())
+	}
+}
+
 /// All AMMs used in the router are required to implement this trait.
 pub trait TradeExecution<Origin, AccountId, AssetId, Balance> {
 	type Error;
@@ -268,6 +311,28 @@ impl<E: PartialEq, Origin: Clone, AccountId, AssetId: Copy, Balance: Copy>
 	}
 }
 
+/// Provides the asset pairs which can be traded in an AMM. Used for route discovery.
+pub trait PoolAssetPairs<AssetId> {
+	/// Returns all tradable pairs together with the pool they are traded in.
+	/// Every pair is listed once, the inverse direction is implied.
+	fn asset_pairs() -> Vec<Trade<AssetId>>;
+}
+
+impl<AssetId> PoolAssetPairs<AssetId> for () {
+	fn asset_pairs() -> Vec<Trade<AssetId>> {
+		Vec::new()
+	}
+}
+
+#[impl_trait_for_tuples::impl_for_tuples(1, 5)]
+impl<AssetId> PoolAssetPairs<AssetId> for Tuple {
+	fn asset_pairs() -> Vec<Trade<AssetId>> {
+		let mut pairs = Vec::new();
+		for_tuples!( #( pairs.extend(Tuple::asset_pairs()); )* );
+		pairs
+	}
+}
+
 /// Provides weight info for the router. Calculates the weight of a route based on the AMMs.
 pub trait AmmTradeWeights<Trade> {
 	fn sell_weight(route: &[Trade]) -> Weight;